@@ -153,6 +153,26 @@ pub struct EndpointResult {
     pub primary_type_symbol: Option<String>,
     /// Import path where the type is defined (e.g., "./types/user"), null if inline or same file
     pub type_import_source: Option<String>,
+    /// TypeScript rendering of the request body schema the route declares
+    /// (Fastify `schema.body`). Set deterministically, never by the model;
+    /// preferred over inference when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_schema_type: Option<String>,
+    /// TypeScript rendering of the route's declared success response schema
+    /// (Fastify `schema.response[2xx]`). Preferred over the handler's
+    /// inferred or annotated type when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_schema_type: Option<String>,
+    /// Query keys the route declares (Fastify `schema.querystring`, NestJS
+    /// `@Query('x')`) or its handler reads (`req.query.x`). Set
+    /// deterministically, never by the model; `None` when unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_params: Option<crate::query_params::QueryContract>,
+    /// Headers the route's handler or guards read outside a fallback
+    /// (`req.headers['x-tenant-id']`, `@Headers('x-tenant-id')`), lowercased.
+    /// Set deterministically, never by the model.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub request_headers: Vec<String>,
 }
 
 /// Result of analyzing a single data-fetching call
@@ -189,6 +209,16 @@ pub struct DataCallResult {
     pub primary_type_symbol: Option<String>,
     /// Import path where the type is defined (e.g., "./types/user"), null if inline or same file
     pub type_import_source: Option<String>,
+    /// Headers the call sends, read from its options and the repo's client
+    /// defaults (`crate::headers`). Set deterministically, never by the
+    /// model; `None` when unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_headers: Option<crate::headers::SentHeaders>,
+    /// Whether the call's options may add query keys to its target
+    /// (`crate::headers::CallHeaders::query_options`). Set with
+    /// `sent_headers`; `None` when the call wasn't read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_options: Option<bool>,
 }
 
 /// A GraphQL resolver the file-analyzer found: the schema field it answers and
@@ -1068,6 +1098,7 @@ For each data_call, include: candidate_id, line_number, target, method (null if
 call_expression_text, call_expression_line, payload_expression_text, payload_expression_line,
 primary_type_symbol, type_import_source
   - Echo candidate_id from the candidate context
+  - For target: keep the query string the URL is written with (e.g. "/users?page=${{page}}&limit=20"); do not strip it
   - MUST emit call_expression_text: copy the EXACT text of the fetch/axios/HTTP call (e.g., 'fetch("/api/users")')
   - MUST emit call_expression_line: read the line number from the prefix
   - For payload_expression_text: copy the EXACT payload argument text if detected; emit null when the call sends no payload
@@ -1457,6 +1488,10 @@ mod tests {
             emission_style: None,
             primary_type_symbol: Some("User".to_string()),
             type_import_source: Some("./types/user".to_string()),
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         };
 
         let json = serde_json::to_string(&endpoint).unwrap();
@@ -1485,6 +1520,8 @@ mod tests {
             payload_expression_line: None,
             primary_type_symbol: Some("Comment".to_string()),
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         };
 
         let json = serde_json::to_string(&data_call).unwrap();
@@ -1518,6 +1555,10 @@ mod tests {
                 emission_style: None,
                 primary_type_symbol: Some("-".to_string()),
                 type_import_source: Some(".repo-a_types.ts".to_string()),
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![DataCallResult {
                 call_kind: None,
@@ -1534,6 +1575,8 @@ mod tests {
                 payload_expression_line: None,
                 primary_type_symbol: Some("NULL".to_string()),
                 type_import_source: Some("bad import (oops)".to_string()),
+                sent_headers: None,
+                query_options: None,
             }],
             graphql_operations: vec![],
             pubsub_operations: vec![],
@@ -1666,6 +1709,10 @@ mod tests {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![],
             graphql_operations: vec![],
@@ -1873,6 +1920,10 @@ mod tests {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![DataCallResult {
                 call_kind: None,
@@ -1889,6 +1940,8 @@ mod tests {
                 payload_expression_line: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             }],
             graphql_operations: vec![],
             pubsub_operations: vec![],
@@ -1940,6 +1993,8 @@ mod tests {
             payload_expression_line: None,
             primary_type_symbol: None,
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         }
     }
 
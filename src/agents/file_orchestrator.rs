@@ -20,21 +20,31 @@ use crate::{
     agent_service::AgentService,
     agents::{
         file_analyzer_agent::{
-            EmissionStyle, EndpointResult, FileAnalysisResult, FileAnalyzerAgent, PubsubOperation,
+            DataCallResult, EmissionStyle, EndpointResult, FileAnalysisResult, FileAnalyzerAgent,
+            MountResult, PubsubOperation,
         },
         framework_guidance_agent::ProtocolGuidance,
     },
+    client_wrappers::{self, ClientWrapper, WrapperCalls},
     cloud_storage::{ManifestRole, ManifestTypeKind},
     config::Config,
     env_alias::{
-        EnvAliasExtractor, EnvAliasMap, exported_env_aliases, merge_imported_env_aliases,
-        resolve_target_env_alias,
+        EnvAliasExtractor, EnvAliasMap, UrlBindings, exported_env_aliases,
+        merge_imported_env_aliases, resolve_target_env_alias, resolve_url_binding_target,
     },
     file_based_router::{MethodSource, RoutingConvention, builtin_conventions, derive_route},
     framework_detector::DetectionResult,
+    headers::{self, ClientDefaults},
+    http_instances::{
+        self, HttpInstanceExtractor, HttpInstanceMap, exported_http_instances,
+        merge_imported_http_instances,
+    },
     mount_graph::{DataFetchingCall, GraphNode, MountEdge, MountGraph, NodeType, ResolvedEndpoint},
-    operation::{OperationKey, Protocol},
+    nestjs::{self, NEST_CONTROLLER_PATTERN, NestRoute, NestRouting},
+    operation::{CallKind, OperationKey, Protocol},
     parser::parse_file,
+    query_params::sent_query_keys,
+    serverless::{ServerlessEndpoint, ServerlessRouting},
     services::type_sidecar::{
         ExtractionConfig, InferKind, InferRequestItem, SymbolRequest, TypeResolutionResult,
         TypeSidecar,
@@ -45,7 +55,10 @@ use crate::{
         is_http_method, normalize_manifest_method, parse_file_location,
     },
     url_normalizer::UrlNormalizer,
-    visitor::{ImportSymbolExtractor, ImportedSymbol, SymbolKind, TypeSymbolExtractor},
+    visitor::{
+        ImportSymbolExtractor, ImportedSymbol, RouteSchema, StaticEmission, StaticRouteExtractor,
+        StaticRoutes, SymbolKind, TypeSymbolExtractor,
+    },
 };
 use futures::stream::StreamExt;
 use std::collections::{HashMap, HashSet};
@@ -101,6 +114,29 @@ pub struct ProcessingStats {
     /// (`{ method, path, handler }` in a registry array) rather than from the
     /// file-analyzer LLM. A subset of `total_endpoints`. See #234.
     pub route_descriptor_endpoints: usize,
+    /// Endpoints read deterministically off NestJS controller decorators
+    /// rather than from the file-analyzer LLM. A subset of `total_endpoints`.
+    pub nest_controller_endpoints: usize,
+    /// Endpoints resolved statically from literal Express/Koa/Fastify/Hono/
+    /// Elysia route registrations rather than from the file-analyzer LLM. A
+    /// subset of `total_endpoints`.
+    pub static_route_endpoints: usize,
+    /// Endpoints of Lambda handlers whose routes are declared in
+    /// `serverless.yml`, SAM templates, or SST/CDK constructs. A subset of
+    /// `total_endpoints`.
+    pub serverless_endpoints: usize,
+    /// Data calls read deterministically off Hono `hc` and Eden treaty RPC
+    /// client property chains. A subset of `total_data_calls`.
+    pub rpc_client_calls: usize,
+    /// Data calls read deterministically through the HTTP client wrappers
+    /// declared in `carrick.json`. A subset of `total_data_calls`.
+    pub client_wrapper_calls: usize,
+    /// LLM data-call targets on an `axios.create`/`ky.create`/`ofetch.create`/
+    /// `got.extend` instance that were prefixed with the instance's base URL.
+    pub http_instance_targets: usize,
+    /// LLM data-call targets rebuilt from a `new URL(...)` expression or local,
+    /// or from a `URLSearchParams` local's query keys.
+    pub url_object_targets: usize,
     /// Pub/sub operations asserted deterministically from the AST and merged in
     /// because the file-analyzer's extraction omitted them (carrick#387). The
     /// anchors themselves are computed for every gated file; only the ones the
@@ -126,6 +162,10 @@ const FILE_BASED_ROUTE_OWNER: &str = "__file_based_route__";
 /// path resolution, so the descriptor's already-absolute path is used as-is.
 const ROUTE_DESCRIPTOR_OWNER: &str = "__route_descriptor__";
 
+/// Owner of a Lambda handler's endpoint. API Gateway serves the declared
+/// path as-is, so, like `FILE_BASED_ROUTE_OWNER`, it matches no mount.
+const SERVERLESS_ROUTE_OWNER: &str = "__serverless_route__";
+
 /// `pattern_matched` tag for endpoints emitted deterministically from
 /// route-descriptor data (#234).
 const ROUTE_DESCRIPTOR_PATTERN: &str = "route-descriptor";
@@ -583,6 +623,9 @@ impl FileOrchestrator {
         graphql_producer_hints: &crate::graphql::GraphqlProducerHints,
         graphql_consumer_hints: &crate::graphql::GraphqlConsumerHints,
         normalizer: &UrlNormalizer,
+        // `httpClients` from carrick.json: in-house wrappers whose call sites
+        // are read deterministically.
+        client_wrappers: &[ClientWrapper],
     ) -> Result<FileCentricAnalysisResult, Box<dyn std::error::Error>> {
         debug!("=== AST-GATED FILE-CENTRIC ORCHESTRATOR ===");
         debug!("Processing {} files with SWC gatekeeper", files.len());
@@ -604,6 +647,25 @@ impl FileOrchestrator {
         // which case the file-based pass below is a no-op.
         let conventions = builtin_conventions(&framework_detection.frameworks);
 
+        // NestJS global prefix / RouterModule paths, shared by every
+        // controller. `None` when NestJS isn't detected, in which case the
+        // controller pass below is a no-op.
+        let nest_routing = framework_detection
+            .frameworks
+            .iter()
+            .any(|f| f.to_lowercase().contains("nest"))
+            .then(|| NestRouting::collect(files));
+
+        // Lambda routes declared in `serverless.yml`, SAM templates, and
+        // SST/CDK constructs, keyed by handler file. Empty for repos with
+        // none, in which case the handler pass below is a no-op.
+        let serverless_routing = ServerlessRouting::collect(service_root, files);
+
+        // Headers the repo's HTTP clients add to every request — axios
+        // defaults, instance configs, request interceptors — wherever they
+        // are set up. Applied to each client call's own headers below.
+        let client_defaults = ClientDefaults::collect(files);
+
         // A file that passed the SWC gatekeeper and is ready for the (expensive) LLM call.
         // The CPU-bound preprocessing (read, scan, symbol table) is done serially up front;
         // the LLM calls themselves are then dispatched concurrently.
@@ -620,6 +682,11 @@ impl FileOrchestrator {
             /// through a local const, so the real env-var name reaches
             /// classification and cross-repo matching. See `crate::env_alias`.
             env_alias_map: EnvAliasMap,
+            /// HTTP client instances (`const orders = axios.create({ baseURL })`)
+            /// visible in this file, keyed by binding. Used after the LLM pass
+            /// to prefix relative targets of calls on them with their base URL.
+            /// See `crate::http_instances`.
+            http_instances: HttpInstanceMap,
             /// Endpoints derived from file-based routing conventions, merged in
             /// after the LLM pass. Empty for non-route files.
             route_endpoints: Vec<EndpointResult>,
@@ -628,6 +695,18 @@ impl FileOrchestrator {
             /// LLM ignores route-as-data, so these are the authoritative source
             /// for such endpoints. Empty for files with no route descriptors.
             descriptor_endpoints: Vec<EndpointResult>,
+            /// Routes read off NestJS controller decorators. Replace whatever
+            /// the LLM pass emitted for the same decorated methods.
+            nest_routes: Vec<NestRoute>,
+            /// Endpoints of the Lambda handlers this file exports, merged in
+            /// after the LLM pass like file-based routes.
+            serverless_endpoints: Vec<EndpointResult>,
+            /// Calls through the `carrick.json` client wrappers. Replace
+            /// whatever the LLM pass emitted on the same lines.
+            client_wrapper_calls: Vec<DataCallResult>,
+            /// Literal Express/Koa/Fastify routes and mounts. Replace whatever
+            /// the LLM pass emitted on the same registration lines.
+            static_routes: StaticRoutes,
             /// Repo-global GraphQL producer hint lines (Stage B2), injected into
             /// the user message so the model can link resolver functions in this
             /// file to schema fields. Identical for every file; cloned per-pending
@@ -779,6 +858,67 @@ impl FileOrchestrator {
                 .filter(|c| !descriptor_spans.contains(&(c.span_start, c.span_end)))
                 .collect();
 
+            // NestJS controllers: `@Controller('orders')` + `@Get(':id')` is
+            // the whole route, so it is read off the decorators instead of
+            // asking the LLM. Every decorator call the literal routes account
+            // for (`@Controller`, `@Get`, `@HttpCode`, `@Body`, …) raised a
+            // gatekeeper candidate; those are dropped like descriptor
+            // candidates, so a controller-only file skips the LLM. Calls in
+            // the method bodies and non-literal routes keep their candidates.
+            let nest = match &nest_routing {
+                Some(routing) if content.contains("@nestjs/common") => {
+                    nestjs::controller_routes(file_path, routing)
+                }
+                _ => Default::default(),
+            };
+            let http_candidates: Vec<_> = http_candidates
+                .into_iter()
+                .filter(|c| !nest.owned_spans.contains(&(c.span_start, c.span_end)))
+                .collect();
+
+            // Lambda handlers: the route lives in a deployment definition
+            // and the handler is a plain export, so nothing in this file
+            // raises a route candidate. Each declared route is anchored on
+            // the export it names.
+            let serverless_endpoints =
+                Self::serverless_endpoints(&serverless_routing.handler_endpoints(file_path));
+
+            // Express / Koa-router / Fastify / Hono / Elysia:
+            // `router.get('/users/:id', h)`, `app.use('/users', users)` and
+            // `app.route('/x').get(h)` with literal paths are resolved
+            // statically, as are calls through Hono `hc` and Eden treaty RPC
+            // clients (`client.users[':id'].$get()`). The calls they account
+            // for (registrations, `listen`, middleware factories, `res.json`
+            // in resolved handlers, client calls) drop their candidates, so a
+            // router file made only of literal routes skips the LLM; a
+            // computed path or an opaque handler keeps its candidate and
+            // sends the file through.
+            let static_routes = Self::static_routes(file_path, &content);
+            let http_candidates: Vec<_> = http_candidates
+                .into_iter()
+                .filter(|c| {
+                    !static_routes
+                        .owned_spans
+                        .contains(&(c.span_start, c.span_end))
+                })
+                .collect();
+
+            // In-house client wrappers declared in carrick.json
+            // (`api.request({ op: 'GET /orders/:id' })`): the declaration
+            // says where the method and path sit, so the calls are read
+            // directly and their candidates dropped.
+            let wrapper_calls =
+                Self::client_wrapper_calls(file_path, &content, service_root, client_wrappers);
+            let http_candidates: Vec<_> = http_candidates
+                .into_iter()
+                .filter(|c| {
+                    !wrapper_calls
+                        .owned_spans
+                        .contains(&(c.span_start, c.span_end))
+                })
+                .collect();
+            let client_wrapper_calls = Self::wrapper_data_calls(&wrapper_calls);
+
             // GraphQL resolver routing (Stage B2): a resolver file is loose
             // exported functions with no HTTP route candidate, so the
             // candidate-less skip below would drop it before the file-analyzer
@@ -822,25 +962,56 @@ impl FileOrchestrator {
             // route-descriptor endpoints are still recorded: they're derived
             // structurally and need no LLM.
             if http_candidates.is_empty() {
-                let structural_endpoints: Vec<EndpointResult> = route_endpoints
+                let nest_endpoints = Self::nest_endpoints(&nest.routes);
+                let static_endpoints = Self::static_endpoints(&static_routes);
+                let static_mounts = Self::static_mounts(&static_routes);
+                let client_calls = Self::static_client_calls(&static_routes);
+                let data_calls: Vec<DataCallResult> = client_calls
+                    .iter()
+                    .cloned()
+                    .chain(client_wrapper_calls.iter().cloned())
+                    .collect();
+                let mut structural_endpoints: Vec<EndpointResult> = route_endpoints
                     .iter()
                     .cloned()
                     .chain(descriptor_endpoints.iter().cloned())
+                    .chain(nest_endpoints.iter().cloned())
+                    .chain(static_endpoints.iter().cloned())
+                    .chain(serverless_endpoints.iter().cloned())
                     .collect();
-                if !structural_endpoints.is_empty() {
+                Self::apply_route_schemas(&mut structural_endpoints, &static_routes.schemas);
+                if !structural_endpoints.is_empty()
+                    || !static_mounts.is_empty()
+                    || !data_calls.is_empty()
+                {
                     debug!(
-                        "Structural route(s) (no call-site candidates): {} [{} file-based, {} route-descriptor]",
+                        "Structural route(s) (no call-site candidates): {} [{} file-based, {} route-descriptor, {} nest, {} static, {} serverless, {} static mount(s), {} RPC client call(s), {} client wrapper call(s)]",
                         path_str,
                         route_endpoints.len(),
-                        descriptor_endpoints.len()
+                        descriptor_endpoints.len(),
+                        nest_endpoints.len(),
+                        static_endpoints.len(),
+                        serverless_endpoints.len(),
+                        static_mounts.len(),
+                        client_calls.len(),
+                        client_wrapper_calls.len()
                     );
                     stats.total_endpoints += structural_endpoints.len();
+                    stats.total_mounts += static_mounts.len();
+                    stats.total_data_calls += data_calls.len();
+                    stats.rpc_client_calls += client_calls.len();
+                    stats.client_wrapper_calls += client_wrapper_calls.len();
                     stats.file_based_endpoints += route_endpoints.len();
                     stats.route_descriptor_endpoints += descriptor_endpoints.len();
+                    stats.nest_controller_endpoints += nest_endpoints.len();
+                    stats.static_route_endpoints += static_endpoints.len();
+                    stats.serverless_endpoints += serverless_endpoints.len();
                     file_results.insert(
                         path_str,
                         FileAnalysisResult {
+                            mounts: static_mounts,
                             endpoints: structural_endpoints,
+                            data_calls,
                             ..Default::default()
                         },
                     );
@@ -915,7 +1086,7 @@ impl FileOrchestrator {
                 .map(|candidate| (candidate.candidate_id.clone(), candidate.clone()))
                 .collect();
 
-            let (symbol_table, env_alias_map) =
+            let (symbol_table, env_alias_map, http_instances) =
                 Self::extract_symbol_table(file_path, &cm, &handler);
 
             pending.push(PendingFile {
@@ -926,8 +1097,13 @@ impl FileOrchestrator {
                 candidate_map,
                 symbol_table,
                 env_alias_map,
+                http_instances,
                 route_endpoints,
                 descriptor_endpoints,
+                nest_routes: nest.routes,
+                serverless_endpoints,
+                client_wrapper_calls,
+                static_routes,
                 graphql_producer_hints: graphql_producer_hints.lines.clone(),
                 graphql_consumer_hints: graphql_consumer_hints.lines.clone(),
                 wrapper_context: Vec::new(),
@@ -1046,7 +1222,7 @@ impl FileOrchestrator {
                 file_results.insert(deferred.path_str, FileAnalysisResult::default());
                 continue;
             };
-            let (symbol_table, env_alias_map) =
+            let (symbol_table, env_alias_map, http_instances) =
                 Self::extract_symbol_table(&deferred.file_path, &cm, &handler);
             pending.push(PendingFile {
                 path_str: deferred.path_str,
@@ -1056,8 +1232,13 @@ impl FileOrchestrator {
                 candidate_map: HashMap::new(),
                 symbol_table,
                 env_alias_map,
+                http_instances,
                 route_endpoints: Vec::new(),
                 descriptor_endpoints: Vec::new(),
+                nest_routes: Vec::new(),
+                serverless_endpoints: Vec::new(),
+                client_wrapper_calls: Vec::new(),
+                static_routes: StaticRoutes::default(),
                 graphql_producer_hints: graphql_producer_hints.lines.clone(),
                 graphql_consumer_hints: graphql_consumer_hints.lines.clone(),
                 wrapper_context: ctx,
@@ -1076,8 +1257,13 @@ impl FileOrchestrator {
         // the #369 wrapper pass) and fold the imported modules' exported env
         // aliases into the importer's map. Parsed modules are memoized per
         // canonical path, so each config module is parsed once per scan.
+        //
+        // HTTP client instances (`export const orders = axios.create(...)`)
+        // follow the same one-hop import graph, so a call on an imported
+        // instance still learns its base URL.
         {
             let mut module_exports_cache: HashMap<PathBuf, EnvAliasMap> = HashMap::new();
+            let mut instance_exports_cache: HashMap<PathBuf, HttpInstanceMap> = HashMap::new();
             for pf in &mut pending {
                 let importer = PathBuf::from(&pf.path_str);
                 Self::merge_cross_file_env_aliases(
@@ -1088,6 +1274,14 @@ impl FileOrchestrator {
                     &cm,
                     &handler,
                 );
+                Self::merge_cross_file_http_instances(
+                    &mut pf.http_instances,
+                    &importer,
+                    &pf.symbol_table.imported_symbols,
+                    &mut instance_exports_cache,
+                    &cm,
+                    &handler,
+                );
             }
         }
 
@@ -1146,7 +1340,28 @@ impl FileOrchestrator {
                     // env-var classification, uploads) sees one normalized
                     // form.
                     Self::normalize_fallback_targets(&mut adjusted);
+                    // Rebuild targets that are `new URL(...)` expressions or
+                    // URL / URLSearchParams locals into the URL they compose.
+                    stats.url_object_targets += Self::resolve_url_object_targets(
+                        &mut adjusted,
+                        Path::new(&pf.path_str),
+                        &pf.content,
+                    );
+                    // Prefix relative targets of calls on a configured client
+                    // instance with its base URL, then resolve env aliases —
+                    // the base may itself be one (`${ORDERS_BASE}`).
+                    stats.http_instance_targets += Self::resolve_http_instance_targets(
+                        &mut adjusted,
+                        Path::new(&pf.path_str),
+                        &pf.http_instances,
+                    );
                     Self::resolve_env_var_aliases(&mut adjusted, &pf.env_alias_map);
+                    Self::read_sent_headers(
+                        &mut adjusted,
+                        Path::new(&pf.path_str),
+                        &pf.http_instances,
+                        &client_defaults,
+                    );
                     Self::validate_type_hints(&mut adjusted, &pf.symbol_table);
                     Self::normalize_unusable_types(&mut adjusted, &framework_detection.frameworks);
 
@@ -1177,6 +1392,28 @@ impl FileOrchestrator {
                     stats.route_descriptor_endpoints +=
                         Self::merge_file_based_endpoints(&mut adjusted, pf.descriptor_endpoints);
 
+                    // NestJS controller routes replace the LLM's reading of
+                    // the same decorated methods: the decorators are literal,
+                    // the model's extraction of them is not.
+                    stats.nest_controller_endpoints +=
+                        Self::merge_nest_endpoints(&mut adjusted, &pf.nest_routes);
+
+                    // Lambda handler routes are structural facts like
+                    // file-based routes.
+                    stats.serverless_endpoints +=
+                        Self::merge_file_based_endpoints(&mut adjusted, pf.serverless_endpoints);
+
+                    // Literal Express/Koa/Fastify/Hono/Elysia routes and mounts,
+                    // and RPC client calls, replace the LLM's reading of the
+                    // same registrations and calls.
+                    stats.static_route_endpoints +=
+                        Self::merge_static_routes(&mut adjusted, &pf.static_routes);
+                    stats.rpc_client_calls +=
+                        Self::merge_static_client_calls(&mut adjusted, &pf.static_routes);
+                    stats.client_wrapper_calls +=
+                        Self::merge_data_calls_by_line(&mut adjusted, pf.client_wrapper_calls);
+                    Self::apply_route_schemas(&mut adjusted.endpoints, &pf.static_routes.schemas);
+
                     // Drop LLM-emitted pub/sub ops whose topic has no literal
                     // witness in the file's source (carrick#311): the analyzer
                     // occasionally invents a topic from a wrapper-function
@@ -1232,6 +1469,28 @@ impl FileOrchestrator {
             "  - Route-descriptor endpoints: {}",
             stats.route_descriptor_endpoints
         );
+        debug!(
+            "  - NestJS controller endpoints: {}",
+            stats.nest_controller_endpoints
+        );
+        debug!(
+            "  - Static Express/Koa/Fastify/Hono/Elysia endpoints: {}",
+            stats.static_route_endpoints
+        );
+        debug!(
+            "  - Serverless handler endpoints: {}",
+            stats.serverless_endpoints
+        );
+        debug!("  - RPC client calls: {}", stats.rpc_client_calls);
+        debug!("  - Client wrapper calls: {}", stats.client_wrapper_calls);
+        debug!(
+            "  - Client instance targets given a base URL: {}",
+            stats.http_instance_targets
+        );
+        debug!(
+            "  - URL object targets rebuilt: {}",
+            stats.url_object_targets
+        );
         debug!("  - Total data calls: {}", stats.total_data_calls);
 
         // STEP 5: Build aggregated mount graph from all file results
@@ -1326,6 +1585,9 @@ impl FileOrchestrator {
             /// handler's declaration line and the sidecar resolves the function
             /// via `findFunctionByLine`.
             Line,
+            /// A named parameter of the function declared on the line (a
+            /// NestJS `@Body() dto` parameter).
+            Param { name: &'a str },
         }
 
         let mut push_infer = |file_path: &str,
@@ -1392,6 +1654,20 @@ impl FileOrchestrator {
                     });
                     true
                 }
+                InferLocator::Param { name } => {
+                    infer_requests.push(InferRequestItem {
+                        file_path: file_path.to_string(),
+                        line_number,
+                        infer_kind,
+                        span_start: None,
+                        span_end: None,
+                        expression_text: None,
+                        expression_line: None,
+                        alias: Some(alias),
+                        param_name: Some(name.to_string()),
+                    });
+                    true
+                }
             }
         };
 
@@ -1483,7 +1759,11 @@ impl FileOrchestrator {
                 // type hint the handler never sends.
                 let no_payload = endpoint.emission_style == Some(EmissionStyle::NoPayload);
 
-                if !no_payload {
+                // A declared response schema is the contract the framework
+                // serializes against, so it wins over any handler type.
+                if let Some(schema_type) = &endpoint.response_schema_type {
+                    inline_aliases.push((response_alias.clone(), schema_type.clone()));
+                } else if !no_payload {
                     if let (Some(symbol), Some(import_source)) =
                         (&endpoint.primary_type_symbol, &endpoint.type_import_source)
                     {
@@ -1548,6 +1828,7 @@ impl FileOrchestrator {
                 // pre-emission-style analysis) falls back to imperative-send,
                 // which is the historical behavior.
                 match endpoint.emission_style {
+                    _ if endpoint.response_schema_type.is_some() => {}
                     // The handler's return value IS the payload: ask for the
                     // handler's return type. Prefer the text locator — the
                     // sidecar resolves the expression's *containing* function,
@@ -1607,7 +1888,25 @@ impl FileOrchestrator {
                     }
                 }
 
-                if should_infer_request_body(&method) {
+                // A NestJS request body is the `@Body()` parameter's declared
+                // type, found by name on the method's line. Without one there
+                // is no body: the stored span is the whole method, which the
+                // request-body locators would misread.
+                if let Some(schema_type) = &endpoint.request_schema_type {
+                    inline_aliases.push((request_alias.clone(), schema_type.clone()));
+                } else if endpoint.pattern_matched == NEST_CONTROLLER_PATTERN {
+                    if should_infer_request_body(&method)
+                        && let Some(name) = endpoint.payload_expression_text.as_deref()
+                    {
+                        push_infer(
+                            &file_path_absolute,
+                            line_number,
+                            InferKind::FunctionParam,
+                            request_alias.clone(),
+                            InferLocator::Param { name },
+                        );
+                    }
+                } else if should_infer_request_body(&method) {
                     let _ = push_infer(
                         &file_path_absolute,
                         line_number,
@@ -2412,7 +2711,7 @@ impl FileOrchestrator {
     /// specifiers (packages, tsconfig aliases) return `None` — alias
     /// resolution needs the sidecar's tsconfig knowledge and is out of scope
     /// here.
-    fn resolve_relative_import(importer: &Path, spec: &str) -> Option<PathBuf> {
+    pub(crate) fn resolve_relative_import(importer: &Path, spec: &str) -> Option<PathBuf> {
         if !(spec.starts_with("./") || spec.starts_with("../")) {
             return None;
         }
@@ -2463,9 +2762,13 @@ impl FileOrchestrator {
         file_path: &Path,
         cm: &Lrc<SourceMap>,
         handler: &Handler,
-    ) -> (SymbolTable, EnvAliasMap) {
+    ) -> (SymbolTable, EnvAliasMap, HttpInstanceMap) {
         let Some(module) = parse_file(file_path, cm, handler) else {
-            return (SymbolTable::default(), EnvAliasMap::default());
+            return (
+                SymbolTable::default(),
+                EnvAliasMap::default(),
+                HttpInstanceMap::default(),
+            );
         };
 
         let mut import_extractor = ImportSymbolExtractor::new();
@@ -2475,6 +2778,7 @@ impl FileOrchestrator {
         module.visit_with(&mut type_extractor);
 
         let env_alias_map = EnvAliasExtractor::build(&module);
+        let http_instances = HttpInstanceExtractor::build(&module);
 
         (
             SymbolTable {
@@ -2482,6 +2786,7 @@ impl FileOrchestrator {
                 imported_symbols: import_extractor.imported_symbols,
             },
             env_alias_map,
+            http_instances,
         )
     }
 
@@ -2945,6 +3250,10 @@ impl FileOrchestrator {
                         emission_style: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        request_schema_type: None,
+                        response_schema_type: None,
+                        query_params: None,
+                        request_headers: Vec::new(),
                     }
                 })
                 .collect(),
@@ -2998,11 +3307,338 @@ impl FileOrchestrator {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }
             })
             .collect()
     }
 
+    /// Build endpoints for NestJS controller routes. The owner is the
+    /// controller class, which no mount names, so the route's full path
+    /// (global prefix and `RouterModule` path already applied) is used as-is.
+    ///
+    /// A controller method's return value is its response body, hence
+    /// `ReturnValue`: `collect_type_requests` asks the sidecar for the
+    /// method's return type on its declaration line. The whole-body `@Body()`
+    /// parameter rides `payload_expression_text` and is typed as a parameter;
+    /// `@Query()` / `@Param()` parameter types are not requested, as the
+    /// manifest only carries request and response types.
+    fn nest_endpoints(routes: &[NestRoute]) -> Vec<EndpointResult> {
+        routes
+            .iter()
+            .map(|r| EndpointResult {
+                candidate_id: format!("nest-route:{}:{}:{}", r.method, r.path, r.span_start),
+                line_number: r.line_number as i32,
+                owner_node: r.controller.clone(),
+                method: r.method.clone(),
+                path: r.path.clone(),
+                handler_name: format!("{}.{}", r.controller, r.handler),
+                pattern_matched: NEST_CONTROLLER_PATTERN.to_string(),
+                call_expression_span_start: Some(r.span_start),
+                call_expression_span_end: Some(r.span_end),
+                payload_expression_text: r.body_param.clone(),
+                payload_expression_line: r.body_param_line.map(|l| l as i32),
+                response_expression_text: None,
+                response_expression_line: None,
+                emission_style: Some(EmissionStyle::ReturnValue),
+                primary_type_symbol: None,
+                type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: r.query_params.clone(),
+                request_headers: r.request_headers.clone(),
+            })
+            .collect()
+    }
+
+    /// Replace the LLM's endpoints for NestJS-decorated methods with the
+    /// deterministic ones: an LLM endpoint is dropped when it shares a
+    /// route's method and sits on that route's decorator lines, or shares its
+    /// method and path. Returns the number of endpoints added.
+    fn merge_nest_endpoints(result: &mut FileAnalysisResult, routes: &[NestRoute]) -> usize {
+        if routes.is_empty() {
+            return 0;
+        }
+        result.endpoints.retain(|e| {
+            !routes.iter().any(|r| {
+                let line = e.line_number.max(0) as usize;
+                e.method.eq_ignore_ascii_case(&r.method)
+                    && ((r.line_number..=r.name_line).contains(&line) || e.path == r.path)
+            })
+        });
+        let endpoints = Self::nest_endpoints(routes);
+        let added = endpoints.len();
+        result.endpoints.extend(endpoints);
+        added
+    }
+
+    /// Statically resolve the file's literal Express/Koa/Fastify/Hono/Elysia
+    /// routes and mounts, and its RPC client calls. Parses with a fresh source
+    /// map so the owned spans line up with the scanner's candidate spans.
+    /// Empty for files that mention none of the frameworks and make no
+    /// `.$get()`-style calls.
+    fn static_routes(file_path: &Path, content: &str) -> StaticRoutes {
+        if !["express", "koa", "fastify", "hono", "elysia", ".$"]
+            .iter()
+            .any(|framework| content.contains(framework))
+        {
+            return StaticRoutes::default();
+        }
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let Some(module) = parse_file(file_path, &cm, &handler) else {
+            return StaticRoutes::default();
+        };
+        StaticRouteExtractor::extract(&module, &cm)
+    }
+
+    /// Build endpoints for statically resolved routes. The owner is the
+    /// router binding, so the mount graph prefixes the path exactly as it
+    /// would an LLM-extracted one. The emission style and payload texts feed
+    /// `collect_type_requests` the way the analyzer's would.
+    fn static_endpoints(routes: &StaticRoutes) -> Vec<EndpointResult> {
+        routes
+            .routes
+            .iter()
+            .map(|r| EndpointResult {
+                candidate_id: format!("static-route:{}:{}:{}", r.method, r.path, r.span_start),
+                line_number: r.line_number as i32,
+                owner_node: r.owner.clone(),
+                method: r.method.clone(),
+                path: r.path.clone(),
+                handler_name: r.handler_name.clone(),
+                pattern_matched: r.pattern.clone(),
+                call_expression_span_start: Some(r.span_start),
+                call_expression_span_end: Some(r.span_end),
+                payload_expression_text: r.request_text.clone(),
+                payload_expression_line: r.request_line.map(|l| l as i32),
+                response_expression_text: r.response_text.clone(),
+                response_expression_line: r.response_line.map(|l| l as i32),
+                emission_style: Some(match r.emission {
+                    StaticEmission::Send => EmissionStyle::ImperativeSend,
+                    StaticEmission::Return => EmissionStyle::ReturnValue,
+                    StaticEmission::None => EmissionStyle::NoPayload,
+                }),
+                primary_type_symbol: None,
+                type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: r.query_params.clone(),
+                request_headers: r.request_headers.clone(),
+            })
+            .collect()
+    }
+
+    /// Build endpoints for Lambda handlers. The declared path is absolute, so
+    /// the owner is a sentinel no mount names. A `JSON.stringify`'d success
+    /// body is a send, a returned object the return value; a handler with
+    /// neither claims no payload, so no type is inferred from the
+    /// declaration span.
+    fn serverless_endpoints(endpoints: &[ServerlessEndpoint]) -> Vec<EndpointResult> {
+        endpoints
+            .iter()
+            .map(|e| EndpointResult {
+                candidate_id: format!(
+                    "serverless-route:{}:{}:{}",
+                    e.method,
+                    e.path,
+                    e.span.map_or(0, |(lo, _)| lo)
+                ),
+                line_number: e.line_number as i32,
+                owner_node: SERVERLESS_ROUTE_OWNER.to_string(),
+                method: e.method.clone(),
+                path: e.path.clone(),
+                handler_name: e.export.clone(),
+                pattern_matched: e.source.pattern().to_string(),
+                call_expression_span_start: e.span.map(|(lo, _)| lo),
+                call_expression_span_end: e.span.map(|(_, hi)| hi),
+                payload_expression_text: e.request_text.clone(),
+                payload_expression_line: e.request_line.map(|l| l as i32),
+                response_expression_text: e.response_text.clone(),
+                response_expression_line: e.response_line.map(|l| l as i32),
+                emission_style: Some(match e.emission {
+                    StaticEmission::Send => EmissionStyle::ImperativeSend,
+                    StaticEmission::Return => EmissionStyle::ReturnValue,
+                    StaticEmission::None => EmissionStyle::NoPayload,
+                }),
+                primary_type_symbol: None,
+                type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Build data calls for RPC client calls. The client targets its own
+    /// backend, so the call is internal HTTP; an inline `${URL ?? '…'}`
+    /// fallback in the base collapses like an analyzer target's would.
+    fn static_client_calls(routes: &StaticRoutes) -> Vec<DataCallResult> {
+        routes
+            .client_calls
+            .iter()
+            .map(|c| DataCallResult {
+                candidate_id: format!("rpc-client:{}:{}", c.method, c.span_start),
+                line_number: c.line_number as i32,
+                target: crate::analyzer::normalize_env_fallback_target(&c.target)
+                    .unwrap_or_else(|| c.target.clone()),
+                method: Some(c.method.clone()),
+                call_kind: Some(CallKind::InternalHttp),
+                pattern_matched: c.pattern.clone(),
+                call_expression_span_start: Some(c.span_start),
+                call_expression_span_end: Some(c.span_end),
+                call_expression_text: Some(c.call_text.clone()),
+                call_expression_line: Some(c.line_number as i32),
+                payload_expression_text: c.payload_text.clone(),
+                payload_expression_line: c.payload_line.map(|l| l as i32),
+                primary_type_symbol: None,
+                type_import_source: None,
+                sent_headers: None,
+                query_options: None,
+            })
+            .collect()
+    }
+
+    /// Read the file's calls through the `carrick.json` client wrappers.
+    /// Empty when none are declared or the file mentions none of their
+    /// modules. Parses with a fresh source map so the owned spans line up
+    /// with the scanner's candidate spans.
+    fn client_wrapper_calls(
+        file_path: &Path,
+        content: &str,
+        service_root: &Path,
+        wrappers: &[ClientWrapper],
+    ) -> WrapperCalls {
+        // A relative import names the module by its last segment.
+        if !wrappers.iter().any(|w| {
+            let module = w.import.rsplit('/').next().unwrap_or(&w.import);
+            content.contains(module)
+        }) {
+            return WrapperCalls::default();
+        }
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let Some(module) = parse_file(file_path, &cm, &handler) else {
+            return WrapperCalls::default();
+        };
+        client_wrappers::wrapper_calls(&module, &cm, file_path, service_root, wrappers)
+    }
+
+    /// Build data calls for client wrapper calls. The call kind is left to
+    /// target classification: a wrapper's base URL may be internal or not.
+    fn wrapper_data_calls(calls: &WrapperCalls) -> Vec<DataCallResult> {
+        calls
+            .calls
+            .iter()
+            .map(|c| DataCallResult {
+                candidate_id: format!("client-wrapper:{}:{}", c.method, c.span_start),
+                line_number: c.line_number as i32,
+                target: c.target.clone(),
+                method: Some(c.method.clone()),
+                call_kind: None,
+                pattern_matched: c.pattern.clone(),
+                call_expression_span_start: Some(c.span_start),
+                call_expression_span_end: Some(c.span_end),
+                call_expression_text: Some(c.call_text.clone()),
+                call_expression_line: Some(c.line_number as i32),
+                payload_expression_text: c.payload_text.clone(),
+                payload_expression_line: c.payload_line.map(|l| l as i32),
+                primary_type_symbol: None,
+                type_import_source: None,
+                sent_headers: None,
+                query_options: None,
+            })
+            .collect()
+    }
+
+    fn static_mounts(routes: &StaticRoutes) -> Vec<MountResult> {
+        routes
+            .mounts
+            .iter()
+            .map(|m| MountResult {
+                line_number: m.line_number as i32,
+                parent_node: m.parent.clone(),
+                child_node: m.child.clone(),
+                mount_path: m.path.clone(),
+                import_source: m.import_source.clone(),
+                pattern_matched: m.pattern.clone(),
+            })
+            .collect()
+    }
+
+    /// Attach the contracts Fastify routes declare in their `schema` option to
+    /// the endpoints registered on the same line with the same method,
+    /// whichever pass produced them.
+    fn apply_route_schemas(endpoints: &mut [EndpointResult], schemas: &[RouteSchema]) {
+        for endpoint in endpoints {
+            if let Some(schema) = schemas.iter().find(|s| {
+                endpoint.line_number == s.line_number as i32
+                    && endpoint.method.eq_ignore_ascii_case(&s.method)
+            }) {
+                endpoint.request_schema_type = schema.request.clone();
+                endpoint.response_schema_type = schema.response.clone();
+                // A declared querystring says more than the handler's reads:
+                // it also names the required keys.
+                if schema.query.is_some() {
+                    endpoint.query_params = schema.query.clone();
+                }
+            }
+        }
+    }
+
+    /// Replace the LLM's endpoints and mounts for statically resolved
+    /// registrations: an LLM endpoint is dropped when it shares a static
+    /// route's line and method, an LLM mount when it sits on a static mount's
+    /// line. Returns the number of endpoints added.
+    fn merge_static_routes(result: &mut FileAnalysisResult, routes: &StaticRoutes) -> usize {
+        if routes.routes.is_empty() && routes.mounts.is_empty() {
+            return 0;
+        }
+        result.endpoints.retain(|e| {
+            !routes.routes.iter().any(|r| {
+                e.line_number == r.line_number as i32 && e.method.eq_ignore_ascii_case(&r.method)
+            })
+        });
+        result.mounts.retain(|m| {
+            !routes
+                .mounts
+                .iter()
+                .any(|s| m.line_number == s.line_number as i32)
+        });
+        let endpoints = Self::static_endpoints(routes);
+        let added = endpoints.len();
+        result.endpoints.extend(endpoints);
+        result.mounts.extend(Self::static_mounts(routes));
+        added
+    }
+
+    /// Replace the LLM's data calls on the lines of RPC client calls with the
+    /// calls read off the property chains. Returns the number added.
+    fn merge_static_client_calls(result: &mut FileAnalysisResult, routes: &StaticRoutes) -> usize {
+        Self::merge_data_calls_by_line(result, Self::static_client_calls(routes))
+    }
+
+    /// Replace the LLM's data calls on the lines of deterministically read
+    /// calls with those calls. Returns the number added.
+    fn merge_data_calls_by_line(
+        result: &mut FileAnalysisResult,
+        calls: Vec<DataCallResult>,
+    ) -> usize {
+        if calls.is_empty() {
+            return 0;
+        }
+        result
+            .data_calls
+            .retain(|d| !calls.iter().any(|c| d.line_number == c.line_number));
+        let added = calls.len();
+        result.data_calls.extend(calls);
+        added
+    }
+
     /// Append structurally derived endpoints (file-based routes and
     /// route-descriptor data) the LLM pass didn't already produce (matched by
     /// method + path), keeping the deterministic entries. Returns the number
@@ -3307,6 +3943,169 @@ impl FileOrchestrator {
         });
     }
 
+    /// Fold the HTTP client instances exported by same-repo modules
+    /// `importer` imports into its instance map — the same one-hop, relative-
+    /// specifier resolution as [`Self::merge_cross_file_env_aliases`].
+    fn merge_cross_file_http_instances(
+        instances: &mut HttpInstanceMap,
+        importer: &Path,
+        imported_symbols: &HashMap<String, ImportedSymbol>,
+        module_exports_cache: &mut HashMap<PathBuf, HttpInstanceMap>,
+        cm: &Lrc<SourceMap>,
+        handler: &Handler,
+    ) {
+        merge_imported_http_instances(instances, imported_symbols, |spec| {
+            let resolved = Self::resolve_relative_import(importer, spec)?;
+            Some(
+                module_exports_cache
+                    .entry(resolved.clone())
+                    .or_insert_with(|| {
+                        parse_file(&resolved, cm, handler)
+                            .map(|module| exported_http_instances(&module))
+                            .unwrap_or_default()
+                    })
+                    .clone(),
+            )
+        });
+    }
+
+    /// Rebuild data-call targets the model reported as a URL object: a
+    /// verbatim `new URL(path, base)` expression becomes the target it
+    /// composes, and a `new URL(...)` local (`fetch(url)`) becomes that
+    /// local's target, query keys set through `searchParams` or a
+    /// `URLSearchParams` included. The file is only re-parsed when it
+    /// mentions one. Returns how many targets were rewritten.
+    fn resolve_url_object_targets(
+        result: &mut FileAnalysisResult,
+        file_path: &Path,
+        content: &str,
+    ) -> usize {
+        let mut rewritten = 0;
+        for data_call in &mut result.data_calls {
+            if let Some(target) = crate::url_normalizer::compose_new_url_target(&data_call.target) {
+                data_call.target = target;
+                rewritten += 1;
+            }
+        }
+        if result.data_calls.is_empty()
+            || !(content.contains("new URL(") || content.contains("URLSearchParams"))
+        {
+            return rewritten;
+        }
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let Some(module) = parse_file(file_path, &cm, &handler) else {
+            return rewritten;
+        };
+        let bindings = UrlBindings::build(&module);
+        for data_call in &mut result.data_calls {
+            if let Some(target) = resolve_url_binding_target(&data_call.target, &bindings) {
+                debug!(
+                    "Resolved URL object call target: {:?} -> {:?}",
+                    data_call.target, target
+                );
+                data_call.target = target;
+                rewritten += 1;
+            }
+        }
+        rewritten
+    }
+
+    /// Prefix the relative target of every data call made on a configured
+    /// client instance (`orders.get("/orders/1")` with `orders =
+    /// axios.create({ baseURL: process.env.ORDERS_URL })`) with the
+    /// instance's base URL. The model reports such targets without the base,
+    /// which loses the env var that classifies and matches the call. A call
+    /// is tied to its instance by span, or by line when only one instance
+    /// call sits on it. Returns how many targets were rewritten.
+    fn resolve_http_instance_targets(
+        result: &mut FileAnalysisResult,
+        file_path: &Path,
+        instances: &HttpInstanceMap,
+    ) -> usize {
+        if instances.is_empty() || result.data_calls.is_empty() {
+            return 0;
+        }
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let Some(module) = parse_file(file_path, &cm, &handler) else {
+            return 0;
+        };
+        let calls = http_instances::instance_calls(&module, &cm, instances);
+        if calls.is_empty() {
+            return 0;
+        }
+
+        let mut rewritten = 0;
+        for data_call in &mut result.data_calls {
+            let by_span = data_call
+                .call_expression_span_start
+                .and_then(|start| calls.iter().find(|c| c.span_start == start));
+            let call = by_span.or_else(|| {
+                let mut on_line = calls
+                    .iter()
+                    .filter(|c| c.line_number as i32 == data_call.line_number);
+                match (on_line.next(), on_line.next()) {
+                    (Some(only), None) => Some(only),
+                    _ => None,
+                }
+            });
+            let Some(call) = call else {
+                continue;
+            };
+            if let Some(target) =
+                http_instances::join_instance_target(&call.base, &data_call.target)
+            {
+                debug!(
+                    "Prefixed client-instance call target with its base URL: {:?} -> {:?}",
+                    data_call.target, target
+                );
+                data_call.target = target;
+                rewritten += 1;
+            }
+        }
+        rewritten
+    }
+
+    /// Record the headers each direct client call sends, from its options
+    /// and the repo's client `defaults`, and whether those options may add
+    /// query keys. Calls that aren't direct `fetch` / client / instance calls
+    /// keep `sent_headers` and `query_options` at `None`.
+    fn read_sent_headers(
+        result: &mut FileAnalysisResult,
+        file_path: &Path,
+        instances: &HttpInstanceMap,
+        defaults: &ClientDefaults,
+    ) {
+        if result.data_calls.is_empty() {
+            return;
+        }
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let Some(module) = parse_file(file_path, &cm, &handler) else {
+            return;
+        };
+        let calls = headers::call_headers(&module, &cm, instances, defaults);
+        for data_call in &mut result.data_calls {
+            let by_span = data_call
+                .call_expression_span_start
+                .and_then(|start| calls.iter().find(|c| c.span_start == start));
+            let call = by_span.or_else(|| {
+                let mut on_line = calls
+                    .iter()
+                    .filter(|c| c.line_number as i32 == data_call.line_number);
+                match (on_line.next(), on_line.next()) {
+                    (Some(only), None) => Some(only),
+                    _ => None,
+                }
+            });
+            if let Some(call) = call {
+                data_call.sent_headers = call.sent.clone();
+                data_call.query_options = Some(call.query_options);
+            }
+        }
+    }
+
     /// Collapse inline env-var fallbacks in data-call targets (carrick#399):
     /// `${A ?? <expr>}` / `${A || <expr>}` -> `${A}`. The model intermittently
     /// (~1/3 of scans, guidance-correlated) copies the template literal
@@ -3956,6 +4755,8 @@ impl FileOrchestrator {
                     // `CallSite` by `classify_endpoint_evidence` when the same
                     // source site was also extracted as a data call (#379).
                     evidence: carrick_match::MatchEvidence::RouteDefinition,
+                    query_params: endpoint.query_params.clone(),
+                    request_headers: endpoint.request_headers.clone(),
                 });
             }
         }
@@ -3983,7 +4784,8 @@ impl FileOrchestrator {
                 // can never join a producer key, so they are pure index noise
                 // (#307) — typically a wrapper's internal fetch, whose resolved
                 // call-site emissions are extracted separately and do match.
-                let canonical_path = normalizer.consumer_call_path(&data_call.target);
+                let (canonical_path, target_service) =
+                    normalizer.consumer_call_target(&data_call.target);
                 if !UrlNormalizer::canonical_path_has_literal_segment(&canonical_path) {
                     debug!(
                         "Skipping data call with no literal path segment: {} ({})",
@@ -4000,6 +4802,9 @@ impl FileOrchestrator {
                     call_kind: data_call.call_kind,
                     repo_name: None,
                     service_name: None,
+                    query_params: sent_query_keys(&data_call.target, data_call.query_options),
+                    sent_headers: data_call.sent_headers.clone(),
+                    target_service,
                 });
             }
         }
@@ -4790,6 +5595,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }],
                 data_calls: vec![],
                 graphql_operations: vec![],
@@ -4857,6 +5666,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }],
                 ..Default::default()
             },
@@ -4905,6 +5718,10 @@ mod tests {
             emission_style: None,
             primary_type_symbol: None,
             type_import_source: None,
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         };
         let file_result = |path: &str| FileAnalysisResult {
             endpoints: vec![endpoint(path)],
@@ -4974,6 +5791,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }],
                 ..Default::default()
             },
@@ -5030,6 +5851,8 @@ mod tests {
                     payload_expression_line: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    sent_headers: None,
+                    query_options: None,
                 }],
                 graphql_operations: vec![],
                 pubsub_operations: vec![],
@@ -5077,6 +5900,8 @@ mod tests {
                 payload_expression_line: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             }],
             ..Default::default()
         };
@@ -5162,6 +5987,8 @@ mod tests {
             payload_expression_line: None,
             primary_type_symbol: None,
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         };
 
         let mut file_results = HashMap::new();
@@ -5187,6 +6014,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }],
                 data_calls: vec![
                     // Self-call to the service's own endpoint over localhost.
@@ -5251,6 +6082,10 @@ mod tests {
             emission_style: None,
             primary_type_symbol: None,
             type_import_source: None,
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         };
         let mk_call = |line: u32, method: &str, target: &str| DataCallResult {
             call_kind: None,
@@ -5267,6 +6102,8 @@ mod tests {
             payload_expression_line: None,
             primary_type_symbol: None,
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         };
 
         let mut file_results = HashMap::new();
@@ -5355,6 +6192,8 @@ mod tests {
             payload_expression_line: None,
             primary_type_symbol: None,
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         };
         let mut file_results = HashMap::new();
         file_results.insert(
@@ -5419,6 +6258,8 @@ mod tests {
                         payload_expression_line: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        sent_headers: None,
+                        query_options: None,
                     },
                     DataCallResult {
                         call_kind: None,
@@ -5435,6 +6276,8 @@ mod tests {
                         payload_expression_line: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        sent_headers: None,
+                        query_options: None,
                     },
                 ],
                 graphql_operations: vec![],
@@ -5481,6 +6324,8 @@ mod tests {
                     payload_expression_line: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    sent_headers: None,
+                    query_options: None,
                 }],
                 graphql_operations: vec![],
                 pubsub_operations: vec![],
@@ -5529,6 +6374,8 @@ mod tests {
                         payload_expression_line: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        sent_headers: None,
+                        query_options: None,
                     },
                     DataCallResult {
                         call_kind: None,
@@ -5545,6 +6392,8 @@ mod tests {
                         payload_expression_line: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        sent_headers: None,
+                        query_options: None,
                     },
                 ],
                 graphql_operations: vec![],
@@ -5604,6 +6453,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 }],
                 data_calls: vec![],
                 graphql_operations: vec![],
@@ -5660,6 +6513,10 @@ mod tests {
             emission_style,
             primary_type_symbol: None,
             type_import_source: None,
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         }
     }
 
@@ -5723,6 +6580,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_collect_type_requests_prefers_declared_route_schemas() {
+        // A Fastify route schema is the contract Fastify validates and
+        // serializes against: it becomes the alias text directly, and neither
+        // the handler's return nor its request body is inferred.
+        let mut endpoint =
+            endpoint_with_emission_style("POST", "/users", Some(EmissionStyle::ReturnValue));
+        endpoint.primary_type_symbol = Some("User".to_string());
+        endpoint.payload_expression_text = Some("request.body".to_string());
+        endpoint.request_schema_type = Some("{ name: string }".to_string());
+        endpoint.response_schema_type = Some("{ id: number; name: string }".to_string());
+
+        let (explicit, infer, inline) = collect_for_endpoint(endpoint);
+
+        assert!(explicit.is_empty(), "{:?}", explicit);
+        assert!(infer.is_empty(), "{:?}", infer);
+        let mut texts: Vec<&str> = inline.iter().map(|(_, text)| text.as_str()).collect();
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec!["{ id: number; name: string }", "{ name: string }"]
+        );
+        assert!(inline.iter().any(|(alias, _)| alias.contains("Request")));
+        assert!(inline.iter().any(|(alias, _)| alias.contains("Response")));
+    }
+
     #[test]
     fn test_collect_type_requests_return_value_falls_back_to_line_anchor() {
         // Pairing-invariant violation (return-value but no expression): fall
@@ -5846,6 +6729,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: Some("User".to_string()),
                     type_import_source: Some("react".to_string()),
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
                 EndpointResult {
                     candidate_id: "span:700-740".to_string(),
@@ -5864,6 +6751,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: Some("Models.User".to_string()),
                     type_import_source: Some("./models".to_string()),
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
             ],
             data_calls: vec![DataCallResult {
@@ -5881,6 +6772,8 @@ mod tests {
                 payload_expression_line: None,
                 primary_type_symbol: Some("LocalType".to_string()),
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             }],
             graphql_operations: vec![],
             pubsub_operations: vec![],
@@ -5958,6 +6851,10 @@ mod tests {
                 // `.ts` appended by the model; the extension-less form is imported.
                 primary_type_symbol: Some("OrderPlacedEvent".to_string()),
                 type_import_source: Some("../types/events.ts".to_string()),
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![DataCallResult {
                 call_kind: None,
@@ -5974,6 +6871,8 @@ mod tests {
                 payload_expression_line: None,
                 primary_type_symbol: Some("OrderPlacedEvent".to_string()),
                 type_import_source: Some("../types/events.tsx".to_string()),
+                sent_headers: None,
+                query_options: None,
             }],
             graphql_operations: vec![],
             pubsub_operations: vec![
@@ -6180,7 +7079,8 @@ mod tests {
         .expect("write file");
         let cm: Lrc<SourceMap> = Default::default();
         let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
-        let (symbol_table, _) = FileOrchestrator::extract_symbol_table(&file_path, &cm, &handler);
+        let (symbol_table, _, _) =
+            FileOrchestrator::extract_symbol_table(&file_path, &cm, &handler);
         assert!(
             symbol_table.local_types.contains("OrderPlacedEvent"),
             "class declarations must be collected as local types"
@@ -6304,6 +7204,10 @@ mod tests {
                 // Imported symbol, wrong source: HTTP must null, not rewrite.
                 primary_type_symbol: Some("User".to_string()),
                 type_import_source: Some("./wrong".to_string()),
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![DataCallResult {
                 call_kind: None,
@@ -6321,6 +7225,8 @@ mod tests {
                 // Local type with a claimed import source: HTTP must null.
                 primary_type_symbol: Some("LocalType".to_string()),
                 type_import_source: Some("./local".to_string()),
+                sent_headers: None,
+                query_options: None,
             }],
             graphql_operations: vec![],
             pubsub_operations: vec![],
@@ -6406,6 +7312,10 @@ mod tests {
                         emission_style: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        request_schema_type: None,
+                        response_schema_type: None,
+                        query_params: None,
+                        request_headers: Vec::new(),
                     },
                     EndpointResult {
                         candidate_id: "span:750-780".to_string(),
@@ -6424,6 +7334,10 @@ mod tests {
                         emission_style: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        request_schema_type: None,
+                        response_schema_type: None,
+                        query_params: None,
+                        request_headers: Vec::new(),
                     },
                 ],
                 data_calls: vec![],
@@ -6723,9 +7637,477 @@ export { routes };
             emission_style: None,
             primary_type_symbol: None,
             type_import_source: None,
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         }
     }
 
+    #[test]
+    fn merge_nest_endpoints_replaces_the_llm_reading_of_decorated_methods() {
+        let mut llm_get = synthetic_endpoint("GET", "/:id");
+        llm_get.line_number = 12; // the `@Get(':id')` line, no controller prefix
+        let mut llm_other = synthetic_endpoint("POST", "/webhooks");
+        llm_other.line_number = 40;
+        let mut result = FileAnalysisResult {
+            endpoints: vec![llm_get, llm_other],
+            ..Default::default()
+        };
+        let route = NestRoute {
+            method: "GET".to_string(),
+            path: "/api/orders/:id".to_string(),
+            controller: "OrdersController".to_string(),
+            handler: "get".to_string(),
+            line_number: 11,
+            name_line: 13,
+            span_start: 200,
+            span_end: 260,
+            body_param: None,
+            body_param_line: None,
+            query_params: None,
+            request_headers: Vec::new(),
+        };
+
+        let added = FileOrchestrator::merge_nest_endpoints(&mut result, &[route]);
+
+        assert_eq!(added, 1);
+        let paths: Vec<&str> = result.endpoints.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["/webhooks", "/api/orders/:id"]);
+        let nest = &result.endpoints[1];
+        assert_eq!(nest.owner_node, "OrdersController");
+        assert_eq!(nest.handler_name, "OrdersController.get");
+        assert_eq!(nest.line_number, 11);
+        assert_eq!(nest.emission_style, Some(EmissionStyle::ReturnValue));
+    }
+
+    #[test]
+    fn merge_static_routes_replaces_the_llm_reading_of_literal_registrations() {
+        use crate::visitor::{StaticMount, StaticRoute};
+
+        let mut llm_get = synthetic_endpoint("GET", "/users/:userId");
+        llm_get.line_number = 8;
+        let mut llm_other = synthetic_endpoint("GET", "/computed");
+        llm_other.line_number = 20;
+        let mut result = FileAnalysisResult {
+            mounts: vec![MountResult {
+                line_number: 14,
+                parent_node: "app".to_string(),
+                child_node: "users".to_string(),
+                mount_path: "/user".to_string(),
+                import_source: None,
+                pattern_matched: ".use(".to_string(),
+            }],
+            endpoints: vec![llm_get, llm_other],
+            ..Default::default()
+        };
+        let routes = StaticRoutes {
+            routes: vec![StaticRoute {
+                line_number: 8,
+                owner: "router".to_string(),
+                method: "GET".to_string(),
+                path: "/users/:id".to_string(),
+                handler_name: "anonymous".to_string(),
+                pattern: ".get(".to_string(),
+                span_start: 120,
+                span_end: 210,
+                emission: StaticEmission::Send,
+                response_text: Some("user".to_string()),
+                response_line: Some(10),
+                request_text: None,
+                request_line: None,
+                query_params: None,
+                request_headers: Vec::new(),
+            }],
+            mounts: vec![StaticMount {
+                line_number: 14,
+                parent: "app".to_string(),
+                child: "users".to_string(),
+                path: "/users".to_string(),
+                import_source: Some("./users".to_string()),
+                pattern: ".use(".to_string(),
+            }],
+            schemas: Vec::new(),
+            client_calls: Vec::new(),
+            owned_spans: HashSet::new(),
+        };
+
+        let added = FileOrchestrator::merge_static_routes(&mut result, &routes);
+
+        assert_eq!(added, 1);
+        let paths: Vec<&str> = result.endpoints.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["/computed", "/users/:id"]);
+        let endpoint = &result.endpoints[1];
+        assert_eq!(endpoint.owner_node, "router");
+        assert_eq!(endpoint.emission_style, Some(EmissionStyle::ImperativeSend));
+        assert_eq!(endpoint.response_expression_text.as_deref(), Some("user"));
+        assert_eq!(result.mounts.len(), 1);
+        assert_eq!(result.mounts[0].mount_path, "/users");
+        assert_eq!(result.mounts[0].import_source.as_deref(), Some("./users"));
+    }
+
+    #[test]
+    fn rpc_client_calls_replace_the_llm_reading_and_key_by_rebuilt_path() {
+        use crate::visitor::StaticClientCall;
+
+        let llm_call = |line_number: i32, target: &str| DataCallResult {
+            call_kind: None,
+            candidate_id: format!("span:{}", line_number),
+            line_number,
+            target: target.to_string(),
+            method: Some("GET".to_string()),
+            pattern_matched: "client.users[':id'].$get(".to_string(),
+            call_expression_span_start: None,
+            call_expression_span_end: None,
+            call_expression_text: None,
+            call_expression_line: None,
+            payload_expression_text: None,
+            payload_expression_line: None,
+            primary_type_symbol: None,
+            type_import_source: None,
+            sent_headers: None,
+            query_options: None,
+        };
+        let mut result = FileAnalysisResult {
+            data_calls: vec![
+                llm_call(11, "client.users[':id'].$get"),
+                llm_call(30, "https://api.example.com/data"),
+            ],
+            ..Default::default()
+        };
+        let routes = StaticRoutes {
+            client_calls: vec![StaticClientCall {
+                line_number: 11,
+                method: "GET".to_string(),
+                target: "${API_URL ?? \"http://localhost:8787\"}/users/:id".to_string(),
+                pattern: ".$get(".to_string(),
+                span_start: 300,
+                span_end: 350,
+                call_text: "client.users[':id'].$get({ param: { id } })".to_string(),
+                payload_text: None,
+                payload_line: None,
+            }],
+            ..Default::default()
+        };
+
+        let added = FileOrchestrator::merge_static_client_calls(&mut result, &routes);
+
+        assert_eq!(added, 1);
+        let targets: Vec<&str> = result
+            .data_calls
+            .iter()
+            .map(|d| d.target.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            vec!["https://api.example.com/data", "${API_URL}/users/:id"]
+        );
+        assert_eq!(result.data_calls[1].call_kind, Some(CallKind::InternalHttp));
+
+        let mut file_results = HashMap::new();
+        file_results.insert("src/client.ts".to_string(), result);
+        let config = Config {
+            internal_env_vars: ["API_URL"].iter().map(|s| s.to_string()).collect(),
+            ..Config::default()
+        };
+        let orchestrator = FileOrchestrator::new(AgentService::new());
+        let graph = orchestrator.build_mount_graph(
+            &file_results,
+            &UrlNormalizer::new(&config),
+            Path::new(""),
+        );
+        let rpc = graph
+            .data_calls
+            .iter()
+            .find(|call| call.file_location == "src/client.ts:11")
+            .expect("the RPC client call joins the mount graph");
+        assert_eq!(rpc.method, "GET");
+        assert_eq!(rpc.canonical_path, "/users/:id");
+    }
+
+    #[test]
+    fn client_wrapper_calls_replace_the_llm_reading_on_their_lines() {
+        use crate::client_wrappers::WrapperCall;
+
+        let llm_call = |line_number: i32, target: &str| DataCallResult {
+            call_kind: None,
+            candidate_id: format!("span:{}", line_number),
+            line_number,
+            target: target.to_string(),
+            method: Some("POST".to_string()),
+            pattern_matched: "api.request(".to_string(),
+            call_expression_span_start: None,
+            call_expression_span_end: None,
+            call_expression_text: None,
+            call_expression_line: None,
+            payload_expression_text: None,
+            payload_expression_line: None,
+            primary_type_symbol: None,
+            type_import_source: None,
+            sent_headers: None,
+            query_options: None,
+        };
+        let mut result = FileAnalysisResult {
+            data_calls: vec![llm_call(6, "orders"), llm_call(20, "/health")],
+            ..Default::default()
+        };
+        let calls = WrapperCalls {
+            calls: vec![WrapperCall {
+                line_number: 6,
+                method: "GET".to_string(),
+                target: "${ORDERS_URL}/orders/${id}".to_string(),
+                pattern: "api.request(".to_string(),
+                span_start: 120,
+                span_end: 190,
+                call_text: "api.request({ service: 'orders', op: `GET /orders/${id}` })"
+                    .to_string(),
+                payload_text: None,
+                payload_line: None,
+            }],
+            ..Default::default()
+        };
+
+        let added = FileOrchestrator::merge_data_calls_by_line(
+            &mut result,
+            FileOrchestrator::wrapper_data_calls(&calls),
+        );
+
+        assert_eq!(added, 1);
+        let calls: Vec<(&str, Option<&str>)> = result
+            .data_calls
+            .iter()
+            .map(|d| (d.target.as_str(), d.method.as_deref()))
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                ("/health", Some("POST")),
+                ("${ORDERS_URL}/orders/${id}", Some("GET"))
+            ]
+        );
+
+        let mut file_results = HashMap::new();
+        file_results.insert("src/orders.ts".to_string(), result);
+        let config = Config {
+            internal_env_vars: ["ORDERS_URL"].iter().map(|s| s.to_string()).collect(),
+            ..Config::default()
+        };
+        let orchestrator = FileOrchestrator::new(AgentService::new());
+        let graph = orchestrator.build_mount_graph(
+            &file_results,
+            &UrlNormalizer::new(&config),
+            Path::new(""),
+        );
+        let call = graph
+            .data_calls
+            .iter()
+            .find(|call| call.file_location == "src/orders.ts:6")
+            .expect("the wrapper call joins the mount graph");
+        assert_eq!(call.canonical_path, "/orders/:id");
+    }
+
+    #[test]
+    fn calls_on_imported_client_instances_get_their_base_url() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("clients.ts"),
+            r#"import axios from "axios";
+const ORDERS_BASE = process.env.ORDERS_URL ?? "http://localhost:3001";
+export const orders = axios.create({ baseURL: ORDERS_BASE });
+"#,
+        )
+        .unwrap();
+        let checkout = dir.path().join("checkout.ts");
+        std::fs::write(
+            &checkout,
+            r#"import { orders } from "./clients";
+export async function load(id: string) {
+  const order = await orders.get(`/orders/${id}`);
+  const rates = await fetch("https://rates.example.com/today");
+  return { order, rates };
+}
+"#,
+        )
+        .unwrap();
+
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let (symbol_table, _, mut instances) =
+            FileOrchestrator::extract_symbol_table(&checkout, &cm, &handler);
+        assert!(instances.is_empty());
+        FileOrchestrator::merge_cross_file_http_instances(
+            &mut instances,
+            &checkout,
+            &symbol_table.imported_symbols,
+            &mut HashMap::new(),
+            &cm,
+            &handler,
+        );
+
+        let llm_call = |line_number: i32, target: &str| DataCallResult {
+            call_kind: None,
+            candidate_id: format!("span:{}", line_number),
+            line_number,
+            target: target.to_string(),
+            method: Some("GET".to_string()),
+            pattern_matched: String::new(),
+            call_expression_span_start: None,
+            call_expression_span_end: None,
+            call_expression_text: None,
+            call_expression_line: None,
+            payload_expression_text: None,
+            payload_expression_line: None,
+            primary_type_symbol: None,
+            type_import_source: None,
+            sent_headers: None,
+            query_options: None,
+        };
+        let mut result = FileAnalysisResult {
+            data_calls: vec![
+                llm_call(3, "/orders/${id}"),
+                llm_call(4, "https://rates.example.com/today"),
+            ],
+            ..Default::default()
+        };
+
+        let rewritten =
+            FileOrchestrator::resolve_http_instance_targets(&mut result, &checkout, &instances);
+
+        assert_eq!(rewritten, 1);
+        let targets: Vec<&str> = result
+            .data_calls
+            .iter()
+            .map(|d| d.target.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "${process.env.ORDERS_URL}/orders/${id}",
+                "https://rates.example.com/today"
+            ]
+        );
+    }
+
+    #[test]
+    fn url_object_targets_resolve_before_env_aliases() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("orders.ts");
+        let source = r#"const ORDERS_BASE = process.env.ORDERS_URL;
+export async function list(status: string) {
+  const url = new URL("/orders", ORDERS_BASE);
+  url.searchParams.set("status", status);
+  const all = await fetch(url);
+  const one = await fetch(new URL(`/orders/${id}`, ORDERS_BASE));
+  return { all, one };
+}
+"#;
+        std::fs::write(&file, source).unwrap();
+
+        let llm_call = |line_number: i32, target: &str| DataCallResult {
+            call_kind: None,
+            candidate_id: format!("span:{}", line_number),
+            line_number,
+            target: target.to_string(),
+            method: Some("GET".to_string()),
+            pattern_matched: "fetch(".to_string(),
+            call_expression_span_start: None,
+            call_expression_span_end: None,
+            call_expression_text: None,
+            call_expression_line: None,
+            payload_expression_text: None,
+            payload_expression_line: None,
+            primary_type_symbol: None,
+            type_import_source: None,
+            sent_headers: None,
+            query_options: None,
+        };
+        let mut result = FileAnalysisResult {
+            data_calls: vec![
+                llm_call(5, "url"),
+                llm_call(6, "new URL(`/orders/${id}`, ORDERS_BASE)"),
+            ],
+            ..Default::default()
+        };
+
+        let rewritten = FileOrchestrator::resolve_url_object_targets(&mut result, &file, source);
+        let aliases = EnvAliasMap::from([("ORDERS_BASE".to_string(), "ORDERS_URL".to_string())]);
+        FileOrchestrator::resolve_env_var_aliases(&mut result, &aliases);
+
+        assert_eq!(rewritten, 2);
+        let targets: Vec<&str> = result
+            .data_calls
+            .iter()
+            .map(|d| d.target.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "${process.env.ORDERS_URL}/orders?status=${status}",
+                "${process.env.ORDERS_URL}/orders/${id}"
+            ]
+        );
+    }
+
+    #[test]
+    fn serverless_endpoints_keep_the_declared_path_and_their_payload_reading() {
+        use crate::serverless::RouteSource;
+
+        let endpoint =
+            |method: &str, path: &str, emission, response: Option<&str>| ServerlessEndpoint {
+                method: method.to_string(),
+                path: path.to_string(),
+                export: "handler".to_string(),
+                source: RouteSource::ServerlessFramework,
+                line_number: 3,
+                span: Some((40, 200)),
+                emission,
+                response_text: response.map(str::to_string),
+                response_line: response.map(|_| 8),
+                request_text: None,
+                request_line: None,
+            };
+        let endpoints = FileOrchestrator::serverless_endpoints(&[
+            endpoint("GET", "/users/:id", StaticEmission::Send, Some("user")),
+            endpoint("DELETE", "/users/:id", StaticEmission::None, None),
+        ]);
+
+        assert_eq!(endpoints[0].owner_node, SERVERLESS_ROUTE_OWNER);
+        assert_eq!(endpoints[0].pattern_matched, "serverless-yml");
+        assert_eq!(
+            endpoints[0].emission_style,
+            Some(EmissionStyle::ImperativeSend)
+        );
+        assert_eq!(
+            endpoints[0].response_expression_text.as_deref(),
+            Some("user")
+        );
+        // No recognizable payload: claim none rather than let inference
+        // read the whole handler declaration.
+        assert_eq!(endpoints[1].emission_style, Some(EmissionStyle::NoPayload));
+
+        let mut file_results = HashMap::new();
+        file_results.insert(
+            "src/handlers/users.ts".to_string(),
+            FileAnalysisResult {
+                endpoints,
+                ..Default::default()
+            },
+        );
+        let orchestrator = FileOrchestrator::new(AgentService::new());
+        let graph = orchestrator.build_mount_graph(
+            &file_results,
+            &UrlNormalizer::new(&Config::default()),
+            Path::new(""),
+        );
+        let paths: Vec<(&str, &str)> = graph
+            .get_resolved_endpoints()
+            .iter()
+            .map(|e| (e.method.as_str(), e.full_path.as_str()))
+            .collect();
+        assert!(paths.contains(&("GET", "/users/:id")));
+        assert!(paths.contains(&("DELETE", "/users/:id")));
+    }
+
     #[test]
     fn test_merge_file_based_endpoints_dedups_by_method_and_path() {
         let mut result = FileAnalysisResult {
@@ -7219,6 +8601,8 @@ export function publishWrapped(order: OrderPlaced): void {
             payload_expression_line: Some(line_number),
             primary_type_symbol: primary_type_symbol.map(str::to_string),
             type_import_source: type_import_source.map(str::to_string),
+            sent_headers: None,
+            query_options: None,
         }
     }
 
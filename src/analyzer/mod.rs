@@ -6,6 +6,7 @@ use swc_ecma_ast::TsTypeAnn;
 use crate::{
     app_context::AppContext,
     config::Config,
+    env_profiles::{self, ProfileTarget},
     extractor::CoreExtractor,
     findings::{Finding, PackageVersionRef, tier},
     mount_graph::MountGraph,
@@ -240,6 +241,10 @@ pub struct ApiAnalysisResult {
     /// formatter renders (type/method mismatches, missing/orphaned endpoints,
     /// env-var calls, dependency conflicts).
     pub findings: Vec<Finding>,
+    /// Findings the repo's `carrick-baseline.json` already records. Empty out
+    /// of the analyzer; the engine moves matching entries here from
+    /// `findings` (see [`crate::baseline`]) so the report can list them apart.
+    pub baselined_findings: Vec<Finding>,
     /// The structured dependency conflicts behind the `dependency_conflict`
     /// findings, kept alongside because the eval contract (§4) scores the full
     /// Critical/Warning/Info severity, which the wire tier collapses to
@@ -385,7 +390,7 @@ fn consumer_identity(location: &str) -> (String, u32) {
 /// from a call-site location so PR-comment risk rows cite `server.ts:66`, not
 /// the runner's absolute workspace path (#337). Anything else (local absolute
 /// paths, already-relative paths) passes through unchanged.
-fn strip_ci_workspace_prefix(location: &str) -> &str {
+pub(crate) fn strip_ci_workspace_prefix(location: &str) -> &str {
     location
         .strip_prefix("/home/runner/work/")
         .and_then(|rest| rest.split_once('/'))
@@ -866,6 +871,9 @@ pub struct Analyzer {
     /// Merged manifest entries across every participating repo, for the
     /// alias -> display-name map the findings projection uses.
     type_manifests: Vec<crate::cloud_storage::TypeManifestEntry>,
+    /// The scanned repo's `policy` block; its `rules` are evaluated over the
+    /// merged index in `get_results`.
+    policy: crate::policy::Policy,
 }
 
 /// One structured type-compat outcome for a matched (producer, consumer,
@@ -895,6 +903,8 @@ pub struct PairCheckOutcome {
     pub gate: Option<String>,
     /// Scrubbed compiler diagnostic or synthesized reason.
     pub diagnostic: Option<String>,
+    /// Field-level breakdown of an incompatible verdict's diagnostic.
+    pub fields: Vec<crate::findings::FieldMismatch>,
     pub producer_alias: String,
     pub consumer_alias: String,
     #[allow(dead_code)]
@@ -921,6 +931,7 @@ impl Analyzer {
             mount_graph: None,
             pair_outcomes: None,
             type_manifests: Vec::new(),
+            policy: crate::policy::Policy::default(),
         }
     }
 
@@ -940,6 +951,11 @@ impl Analyzer {
         self.type_manifests = entries;
     }
 
+    /// Store the repo policy whose governance rules `get_results` evaluates.
+    pub fn set_policy(&mut self, policy: crate::policy::Policy) {
+        self.policy = policy;
+    }
+
     pub fn add_repo_packages(&mut self, repo_name: String, packages: Packages) {
         self.all_repo_packages.insert(repo_name, packages);
     }
@@ -1222,6 +1238,30 @@ impl Analyzer {
         "UNKNOWN_API".to_string()
     }
 
+    /// Classify an env var by the origins its environment profiles resolve
+    /// it to: `Some(true)` when every non-local origin is an internal host,
+    /// `Some(false)` when every one is external, `None` when the profiles
+    /// don't settle it (no profiles, mixed, or unclassified hosts).
+    fn classify_profile_origins(
+        targets: &[ProfileTarget],
+        normalizer: &UrlNormalizer,
+    ) -> Option<bool> {
+        let classified: Vec<_> = targets
+            .iter()
+            .filter(|t| !env_profiles::is_local_origin(&t.origin))
+            .map(|t| normalizer.normalize(&t.origin))
+            .collect();
+        if classified.is_empty() {
+            None
+        } else if classified.iter().all(|n| n.is_internal) {
+            Some(true)
+        } else if classified.iter().all(|n| n.is_external) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
     /// Check if a route represents an environment variable base URL.
     ///
     /// Returns true for:
@@ -1545,6 +1585,12 @@ impl Analyzer {
         // (env_var, METHOD, path) → call sites.
         let mut env_var_calls: BTreeMap<(String, String, String), BTreeSet<String>> =
             BTreeMap::new();
+        // (env_var, METHOD, path, pinned origin) → (per-profile targets, call
+        // sites) for env-var calls whose target service differs between
+        // profiles, and hardcoded calls pinned to one profile's origin.
+        type DriftGroup = (Vec<ProfileTarget>, BTreeSet<String>);
+        let mut profile_drift: BTreeMap<(String, String, String, Option<String>), DriftGroup> =
+            BTreeMap::new();
         // The single producer each method-mismatch finding names: suppressed
         // from the orphan list so a wrong-verb call reports once, as a risk —
         // not as a missing+orphaned pair. Its exact-path siblings keep their
@@ -1592,6 +1638,88 @@ impl Analyzer {
             })
             .collect();
 
+        // The query keys each call sends, keyed like `consumer_repo_by_call`.
+        // Only calls whose whole query string was readable are present.
+        let query_keys_by_call: HashMap<(String, String, String), &[String]> = mount_graph
+            .get_data_calls()
+            .iter()
+            .filter_map(|c| {
+                c.query_params.as_deref().map(|keys| {
+                    (
+                        (
+                            c.method.to_uppercase(),
+                            c.canonical_path.clone(),
+                            c.file_location.clone(),
+                        ),
+                        keys,
+                    )
+                })
+            })
+            .collect();
+        // (METHOD, producer path, call site) → (service, unread keys, missing
+        // keys) for matched calls whose query string disagrees with what the
+        // producer reads. Keyed per call site: two callers of one endpoint
+        // send different keys, and merging them would report each caller's
+        // keys against the other.
+        type QueryGroup = (Option<String>, Vec<String>, Vec<String>);
+        let mut query_mismatches: BTreeMap<(String, String, String), QueryGroup> = BTreeMap::new();
+        // The headers each call sends, keyed the same way; only calls whose
+        // headers were all readable are present.
+        let headers_by_call: HashMap<(String, String, String), &crate::headers::SentHeaders> =
+            mount_graph
+                .get_data_calls()
+                .iter()
+                .filter_map(|c| {
+                    c.sent_headers.as_ref().map(|sent| {
+                        (
+                            (
+                                c.method.to_uppercase(),
+                                c.canonical_path.clone(),
+                                c.file_location.clone(),
+                            ),
+                            sent,
+                        )
+                    })
+                })
+                .collect();
+        // (METHOD, producer path) → (service, missing headers, unparsed
+        // content types, parsed content types, call sites).
+        type HeaderGroup = (
+            Option<String>,
+            BTreeSet<String>,
+            BTreeSet<String>,
+            BTreeSet<String>,
+            BTreeSet<String>,
+        );
+        let mut header_mismatches: BTreeMap<(String, String), HeaderGroup> = BTreeMap::new();
+        // The service each call is known to reach (a gateway route's, or
+        // the sibling its env var or host names in deployment manifests),
+        // keyed the same way. Such a call only matches that service's
+        // producers — when the index has any: a manifest's service name that
+        // no indexed producer carries restricts nothing.
+        let producer_ids: HashSet<&str> = mount_graph
+            .get_resolved_endpoints()
+            .iter()
+            .filter_map(|e| e.service_name.as_deref().or(e.repo_name.as_deref()))
+            .collect();
+        let target_service_by_call: HashMap<(String, String, String), &str> = mount_graph
+            .get_data_calls()
+            .iter()
+            .filter_map(|c| {
+                let service = c.target_service.as_deref()?;
+                producer_ids.contains(service).then(|| {
+                    (
+                        (
+                            c.method.to_uppercase(),
+                            c.canonical_path.clone(),
+                            c.file_location.clone(),
+                        ),
+                        service,
+                    )
+                })
+            })
+            .collect();
+
         // Track which endpoints have been matched
         let mut matched_endpoints: HashSet<String> = HashSet::new();
 
@@ -1644,7 +1772,28 @@ impl Analyzer {
                 if self.config.is_external_call(&canonical_env_var_route) {
                     continue;
                 }
-                if !self.config.is_internal_call(&canonical_env_var_route) {
+                let targets =
+                    env_profiles::profile_targets(&self.config.profiles, target, &normalizer);
+                // Name-based classification wins; an unclassified var falls
+                // back to where the profiles say it points.
+                let internal_by_name = self.config.is_internal_call(&canonical_env_var_route);
+                let by_origin = Self::classify_profile_origins(&targets, &normalizer);
+                if !internal_by_name && by_origin == Some(false) {
+                    continue;
+                }
+                if env_profiles::targets_drift(&targets) {
+                    profile_drift
+                        .entry((
+                            env_var_name.clone(),
+                            method.to_string(),
+                            normalized_path.clone(),
+                            None,
+                        ))
+                        .or_insert_with(|| (targets, BTreeSet::new()))
+                        .1
+                        .insert(call_site.clone());
+                }
+                if !internal_by_name && by_origin != Some(true) {
                     env_var_calls
                         .entry((env_var_name, method.to_string(), normalized_path))
                         .or_default()
@@ -1656,12 +1805,25 @@ impl Analyzer {
             } else {
                 lookup_url = target.to_string();
                 miss_path = normalizer.extract_path(target);
+                if let Some((env_var, pinned, targets)) =
+                    env_profiles::pinned_profile_var(&self.config.profiles, target, &normalizer)
+                {
+                    profile_drift
+                        .entry((env_var, method.to_string(), miss_path.clone(), Some(pinned)))
+                        .or_insert_with(|| (targets, BTreeSet::new()))
+                        .1
+                        .insert(call_site.clone());
+                }
             }
 
+            let target_service = target_service_by_call
+                .get(&(method.to_uppercase(), target.to_string(), call_site.clone()))
+                .copied();
             match mount_graph.find_matching_endpoints_with_normalizer(
                 &lookup_url,
                 method,
                 &normalizer,
+                target_service,
             ) {
                 None => {
                     // URL was identified as external - skip it
@@ -1692,6 +1854,65 @@ impl Analyzer {
                         }
                         let key = format!("{}:{}", endpoint.method, endpoint.full_path);
                         matched_endpoints.insert(key);
+                        // Compare query strings only when both sides are
+                        // fully known, and only against a real producer.
+                        if endpoint.evidence == carrick_match::MatchEvidence::RouteDefinition
+                            && let Some(contract) = &endpoint.query_params
+                            && let Some(sent) = query_keys_by_call.get(&(
+                                method.to_uppercase(),
+                                target.to_string(),
+                                call_site.clone(),
+                            ))
+                            && let Some((unread, missing)) = contract.mismatch(sent)
+                        {
+                            query_mismatches.insert(
+                                (
+                                    endpoint.method.to_uppercase(),
+                                    endpoint.full_path.clone(),
+                                    call_site.clone(),
+                                ),
+                                (
+                                    endpoint
+                                        .service_name
+                                        .clone()
+                                        .or_else(|| endpoint.repo_name.clone()),
+                                    unread,
+                                    missing,
+                                ),
+                            );
+                        }
+                        // Likewise headers: the call's must be fully known.
+                        if endpoint.evidence == carrick_match::MatchEvidence::RouteDefinition
+                            && let Some(sent) = headers_by_call.get(&(
+                                method.to_uppercase(),
+                                target.to_string(),
+                                call_site.clone(),
+                            ))
+                            && let Some(gap) = crate::headers::header_gap(
+                                &endpoint.request_headers,
+                                &endpoint.middleware_chain,
+                                sent,
+                            )
+                        {
+                            let group = header_mismatches
+                                .entry((endpoint.method.to_uppercase(), endpoint.full_path.clone()))
+                                .or_insert_with(|| {
+                                    (
+                                        endpoint
+                                            .service_name
+                                            .clone()
+                                            .or_else(|| endpoint.repo_name.clone()),
+                                        BTreeSet::new(),
+                                        BTreeSet::new(),
+                                        BTreeSet::new(),
+                                        BTreeSet::new(),
+                                    )
+                                });
+                            group.1.extend(gap.missing);
+                            group.2.extend(gap.content_type);
+                            group.3.extend(gap.accepted);
+                            group.4.insert(call_site.clone());
+                        }
                         let Some(edge) = Self::build_cross_repo_match(
                             call,
                             method,
@@ -1762,7 +1983,11 @@ impl Analyzer {
                     // same role the shared-external-contract classification
                     // exists to remove.
                     let mut path_matches = mount_graph
-                        .find_exact_path_matches_any_method(&lookup_url, &normalizer)
+                        .find_exact_path_matches_any_method(
+                            &lookup_url,
+                            &normalizer,
+                            target_service,
+                        )
                         .unwrap_or_default();
                     path_matches.retain(|endpoint| {
                         endpoint.evidence == carrick_match::MatchEvidence::RouteDefinition
@@ -1817,8 +2042,8 @@ impl Analyzer {
                 .extend(candidate.call_sites);
         }
 
-        // Findings order: risks (method mismatches) first, then gaps, then
-        // advisories — mirrors the report's section order.
+        // Findings order: risks (method and query mismatches) first, then
+        // gaps, then advisories — mirrors the report's section order.
         let mut findings: Vec<Finding> = Vec::new();
         for ((method, path, expected), sites) in method_mismatches {
             findings.push(Finding::method_mismatch(
@@ -1829,6 +2054,29 @@ impl Analyzer {
                 expected,
             ));
         }
+        for ((method, path, call_site), (service, unread, missing)) in query_mismatches {
+            findings.push(Finding::query_param_mismatch(
+                method,
+                path,
+                service,
+                vec![call_site],
+                unread,
+                missing,
+            ));
+        }
+        for ((method, path), (service, missing, content_types, accepted, sites)) in
+            header_mismatches
+        {
+            findings.push(Finding::header_mismatch(
+                method,
+                path,
+                service,
+                sites.into_iter().collect(),
+                missing.into_iter().collect(),
+                content_types.into_iter().collect(),
+                accepted.into_iter().collect(),
+            ));
+        }
         for ((method, path), sites) in missing {
             findings.push(Finding::missing_endpoint(
                 method,
@@ -1891,6 +2139,16 @@ impl Analyzer {
                 sites.into_iter().collect(),
             ));
         }
+        for ((env_var, method, path, pinned_origin), (targets, sites)) in profile_drift {
+            findings.push(Finding::profile_target_drift(
+                method,
+                path,
+                env_var,
+                targets,
+                pinned_origin,
+                sites.into_iter().collect(),
+            ));
+        }
         // Shared-external-contract groups (#379): only groups spanning ≥2
         // repos are reported — "N repos encode the same external contract" is
         // signal; a single repo's own encoding (every SDK operation would
@@ -2273,6 +2531,13 @@ impl Analyzer {
         // Re-sort/dedup over the combined HTTP + non-HTTP edge set so the final
         // ordering is stable regardless of which matcher produced an edge.
         sort_dedup_cross_repo_matches(&mut cross_repo_matches);
+        // Governance rules run over the sorted edge set so their findings come
+        // out in a stable order too.
+        findings.extend(self.policy.evaluate_rules(
+            mount_graph,
+            &cross_repo_matches,
+            &self.type_manifests,
+        ));
         // Sorted by package so the findings (and the eval projection's source
         // data) don't inherit HashMap iteration order.
         let mut dependency_conflicts = self.analyze_dependencies();
@@ -2338,6 +2603,7 @@ impl Analyzer {
             verified_endpoints,
             detected_graphql_libraries,
             graphql_operations_indexed,
+            baselined_findings: vec![],
             cross_repo_matches,
         }
     }
@@ -2375,7 +2641,7 @@ impl Analyzer {
     }
 
     /// Project the incompatible v2 pair outcomes into typed
-    /// [`Finding::TypeMismatch`]s. Returns empty when compat was not
+    /// [`Issue::TypeMismatch`](crate::findings::Issue::TypeMismatch)s. Returns empty when compat was not
     /// evaluated for this run. The producer/consumer type labels come from
     /// the manifest entries (real anchor symbol, else the expanded
     /// definition, else the display name); the detail is the scrubbed
@@ -2423,7 +2689,37 @@ impl Analyzer {
                     .clone()
                     .filter(|s| !s.is_empty())
                     .unwrap_or_else(|| "producer and consumer types are incompatible".to_string());
-                Finding::type_mismatch(
+                // Which response fields the consumer reads, when traceable.
+                let accessed = manifest_by_alias
+                    .get(outcome.consumer_alias.as_str())
+                    .and_then(|entry| entry.accessed_fields.as_deref());
+                let fields: Vec<crate::findings::FieldMismatch> = outcome
+                    .fields
+                    .iter()
+                    .map(|field| crate::findings::FieldMismatch {
+                        producer: field
+                            .producer
+                            .as_deref()
+                            .map(|t| self.clean_type_string(t, &display_names)),
+                        consumer: field
+                            .consumer
+                            .as_deref()
+                            .map(|t| self.clean_type_string(t, &display_names)),
+                        read: accessed.map(|a| crate::field_usage::is_read(&field.path, a)),
+                        ..field.clone()
+                    })
+                    .collect();
+                // A mismatch the consumer never reads can't break it: grade
+                // it advisory. Without a field breakdown, only a consumer that
+                // reads nothing of the body qualifies.
+                let unread = accessed.is_some_and(|a| {
+                    if fields.is_empty() {
+                        a.is_empty()
+                    } else {
+                        fields.iter().all(|f| f.read == Some(false))
+                    }
+                });
+                let finding = Finding::type_mismatch(
                     method,
                     path,
                     None,
@@ -2433,6 +2729,12 @@ impl Analyzer {
                     &self.clean_error_message(&detail, &display_names),
                 )
                 .with_producer_provenance(producer_provenance)
+                .with_field_mismatches(fields);
+                if unread {
+                    finding.with_severity(crate::findings::Severity::Advisory)
+                } else {
+                    finding
+                }
             })
             .collect()
     }
@@ -2544,6 +2846,7 @@ impl Analyzer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::findings::Issue;
 
     /// Regression for #334: a duplicated producer manifest entry made ts_check
     /// emit the same mismatch once per duplicate, which reached the PR comment
@@ -3468,21 +3771,21 @@ mod tests {
         // 1. Valid internal call surfaces as a missing endpoint (graph is
         // empty), carrying the normalized path and the typed call site.
         assert!(findings.iter().any(|f| matches!(
-            f,
-            Finding::MissingEndpoint { method, path, call_sites, .. }
+            &f.issue,
+            Issue::MissingEndpoint { method, path, call_sites, .. }
                 if method == "GET" && path == "/users" && call_sites == &vec!["test.ts".to_string()]
         )));
 
         // 2. Unclassified var becomes an env-var finding.
         assert!(findings.iter().any(|f| matches!(
-            f,
-            Finding::EnvVarCall { env_var, path, .. } if env_var == "UNKNOWN_VAR" && path == "/posts"
+            &f.issue,
+            Issue::EnvVarCall { env_var, path, .. } if env_var == "UNKNOWN_VAR" && path == "/posts"
         )));
 
         // 3. Process.env var becomes an env-var finding too.
         assert!(findings.iter().any(|f| matches!(
-            f,
-            Finding::EnvVarCall { env_var, .. } if env_var == "OTHER_VAR"
+            &f.issue,
+            Issue::EnvVarCall { env_var, .. } if env_var == "OTHER_VAR"
         )));
 
         // 4. Raw, unresolved `LEGACY_API_URL + "/users"` expressions are now
@@ -3492,11 +3795,120 @@ mod tests {
         // bare uppercase identifiers (`CarrickApiKeys`, `DynamoDB`) from being
         // mis-reported as env-var calls.
         assert!(!findings.iter().any(|f| matches!(
-            f,
-            Finding::EnvVarCall { env_var, .. } if env_var == "LEGACY_API_URL"
+            &f.issue,
+            Issue::EnvVarCall { env_var, .. } if env_var == "LEGACY_API_URL"
         )));
     }
 
+    /// Environment profiles classify an unnamed env var by where it points; a
+    /// var whose target service changes between profiles is reported, and so
+    /// is a hardcoded URL pinned to one profile's origin.
+    #[test]
+    fn test_env_var_calls_resolved_through_profiles() {
+        use crate::env_profiles::EnvProfile;
+        let profile = |vars: &[(&str, &str)]| EnvProfile {
+            origins: vars
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let config = Config {
+            internal_domains: ["acme.dev".to_string()].into_iter().collect(),
+            external_domains: ["stripe.com".to_string()].into_iter().collect(),
+            host_services: BTreeMap::from([
+                ("orders.acme.dev".to_string(), "orders".to_string()),
+                (
+                    "billing.staging.acme.dev".to_string(),
+                    "billing".to_string(),
+                ),
+            ]),
+            profiles: BTreeMap::from([
+                (
+                    "production".to_string(),
+                    profile(&[
+                        ("ORDERS_URL", "https://orders.acme.dev"),
+                        ("PAY_URL", "https://api.stripe.com"),
+                    ]),
+                ),
+                (
+                    "staging".to_string(),
+                    profile(&[
+                        ("ORDERS_URL", "https://billing.staging.acme.dev"),
+                        ("PAY_URL", "https://api.stripe.com"),
+                    ]),
+                ),
+            ]),
+            ..Config::default()
+        };
+        let mut analyzer = Analyzer::new(config);
+        for target in [
+            "ENV_VAR:ORDERS_URL:/orders",
+            "ENV_VAR:PAY_URL:/charges",
+            "https://billing.staging.acme.dev/orders",
+        ] {
+            analyzer.calls.push(ApiEndpointDetails {
+                owner: None,
+                key: OperationKey::http("GET", target),
+                params: vec![],
+                request_body: None,
+                response_body: None,
+                handler_name: None,
+                request_type: None,
+                response_type: None,
+                file_path: PathBuf::from("client.ts"),
+                repo_name: None,
+                service_name: None,
+                provenance: Default::default(),
+            });
+        }
+
+        let (findings, _verified, _cross_repo_matches) =
+            analyzer.analyze_matches_with_mount_graph(&MountGraph::new());
+
+        // Neither var is a configuration suggestion: ORDERS_URL resolves to
+        // internal hosts (so it is matched, and missing), PAY_URL to an
+        // external one (so it is ignored).
+        assert!(
+            !findings
+                .iter()
+                .any(|f| matches!(&f.issue, Issue::EnvVarCall { .. }))
+        );
+        assert!(findings.iter().any(|f| matches!(
+            &f.issue,
+            Issue::MissingEndpoint { path, .. } if path == "/orders"
+        )));
+        assert!(!findings.iter().any(|f| matches!(
+            &f.issue,
+            Issue::MissingEndpoint { path, .. } if path == "/charges"
+        )));
+        let drift: Vec<_> = findings
+            .iter()
+            .filter_map(|f| match &f.issue {
+                Issue::ProfileTargetDrift {
+                    env_var,
+                    targets,
+                    pinned_origin,
+                    ..
+                } => Some((env_var.as_str(), targets, pinned_origin.as_deref())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(drift.len(), 2);
+        for (env_var, targets, _) in &drift {
+            assert_eq!(*env_var, "ORDERS_URL");
+            assert_eq!(
+                targets
+                    .iter()
+                    .map(|t| t.service.as_deref().unwrap())
+                    .collect::<Vec<_>>(),
+                vec!["orders", "billing"]
+            );
+        }
+        assert_eq!(drift[0].2, None);
+        assert_eq!(drift[1].2, Some("https://billing.staging.acme.dev"));
+    }
+
     /// A consumer call whose path exists in the index under a different verb
     /// must surface exactly once, as a method-mismatch risk — not as a
     /// missing-endpoint + orphaned-endpoint pair.
@@ -3534,6 +3946,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let (findings, verified, _edges) = analyzer.analyze_matches_with_mount_graph(&mount_graph);
@@ -3589,6 +4003,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let (findings, _, _) = analyzer.analyze_matches_with_mount_graph(&mount_graph);
@@ -3641,6 +4057,8 @@ mod tests {
             service_name: None,
             provenance: EndpointProvenance::Mock,
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         // An unmatched mock producer: must orphan WITH the mock tag.
         mount_graph.endpoints.push(ResolvedEndpoint {
@@ -3655,6 +4073,8 @@ mod tests {
             service_name: None,
             provenance: EndpointProvenance::Mock,
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         mount_graph.data_calls.push(DataFetchingCall {
             method: "GET".to_string(),
@@ -3665,6 +4085,9 @@ mod tests {
             call_kind: None,
             repo_name: Some("consumer-repo".to_string()),
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         });
 
         let (findings, verified, edges) = analyzer.analyze_matches_with_mount_graph(&mount_graph);
@@ -3757,6 +4180,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::CallSite,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         // repo-beta: the identical call to the same external endpoint.
         mount_graph.data_calls.push(DataFetchingCall {
@@ -3768,6 +4193,9 @@ mod tests {
             call_kind: None,
             repo_name: Some("repo-beta".to_string()),
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         });
         analyzer
             .calls
@@ -3830,6 +4258,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         mount_graph.data_calls.push(DataFetchingCall {
             method: "POST".to_string(),
@@ -3840,6 +4270,9 @@ mod tests {
             call_kind: None,
             repo_name: Some("repo-beta".to_string()),
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         });
         analyzer
             .calls
@@ -3885,6 +4318,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::CallSite,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         mount_graph.data_calls.push(DataFetchingCall {
             method: "POST".to_string(),
@@ -3895,6 +4330,9 @@ mod tests {
             call_kind: None,
             repo_name: Some("repo-alpha".to_string()),
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         });
         analyzer.calls.push(http_call(
             "POST",
@@ -3931,6 +4369,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         }
     }
 
@@ -4035,6 +4475,141 @@ mod tests {
     /// When every exact-path producer is verified, fall back to the first in
     /// sorted order; a verified producer never sat in the orphan list, so
     /// nothing is hidden.
+    #[test]
+    fn test_query_keys_the_producer_never_reads_surface_as_risk() {
+        use crate::mount_graph::DataFetchingCall;
+        use crate::query_params::QueryContract;
+
+        let mut analyzer = Analyzer::new(Config::default());
+        let mut mount_graph = MountGraph::new();
+        let mut users = resolved("GET", "/users");
+        users.query_params = Some(QueryContract::new(
+            ["limit".to_string(), "cursor".to_string()],
+            ["tenant".to_string()],
+        ));
+        mount_graph.endpoints.push(users);
+        for (file, target, keys) in [
+            (
+                "web.ts:3",
+                "/users?pageSize=20&tenant=a",
+                Some(vec!["pageSize", "tenant"]),
+            ),
+            ("admin.ts:9", "/users?limit=5", Some(vec!["limit"])),
+            (
+                "ok.ts:1",
+                "/users?limit=5&tenant=a",
+                Some(vec!["limit", "tenant"]),
+            ),
+            // An opaque query string is never compared.
+            ("opaque.ts:4", "/users?${qs}", None),
+        ] {
+            analyzer.calls.push(http_call("GET", "/users", file));
+            mount_graph.data_calls.push(DataFetchingCall {
+                method: "GET".to_string(),
+                target_url: target.to_string(),
+                canonical_path: "/users".to_string(),
+                client: "fetch".to_string(),
+                file_location: file.to_string(),
+                call_kind: None,
+                repo_name: None,
+                service_name: None,
+                query_params: keys.map(|keys| keys.into_iter().map(String::from).collect()),
+                sent_headers: None,
+                target_service: None,
+            });
+        }
+
+        let (findings, verified, _) = analyzer.analyze_matches_with_mount_graph(&mount_graph);
+
+        // One finding per call, each with only that call's keys.
+        assert_eq!(
+            findings,
+            vec![
+                Finding::query_param_mismatch(
+                    "GET",
+                    "/users",
+                    Some("api".to_string()),
+                    vec!["admin.ts:9".into()],
+                    vec![],
+                    vec!["tenant".into()],
+                ),
+                Finding::query_param_mismatch(
+                    "GET",
+                    "/users",
+                    Some("api".to_string()),
+                    vec!["web.ts:3".into()],
+                    vec!["pageSize".into()],
+                    vec![],
+                ),
+            ]
+        );
+        // The calls still reach the endpoint.
+        assert_eq!(verified.len(), 1);
+    }
+
+    #[test]
+    fn test_calls_missing_required_headers_surface_as_risk() {
+        use crate::headers::SentHeaders;
+        use crate::mount_graph::DataFetchingCall;
+
+        let mut analyzer = Analyzer::new(Config::default());
+        let mut mount_graph = MountGraph::new();
+        let mut orders = resolved("POST", "/orders");
+        orders.middleware_chain = vec!["express.json".to_string(), "requireAuth".to_string()];
+        orders.request_headers = vec!["x-tenant-id".to_string(), "x-request-id".to_string()];
+        mount_graph.endpoints.push(orders);
+        let sent = |names: &[&str], content_type: Option<&str>| {
+            Some(SentHeaders {
+                names: names.iter().map(|n| n.to_string()).collect(),
+                content_type: content_type.map(String::from),
+            })
+        };
+        for (file, headers) in [
+            ("web.ts:3", sent(&["x-tenant-id"], Some("text/plain"))),
+            (
+                "admin.ts:9",
+                sent(&["authorization"], Some("application/json")),
+            ),
+            (
+                "ok.ts:1",
+                sent(&["cookie", "x-tenant-id"], Some("application/json")),
+            ),
+            // Unreadable headers are never compared.
+            ("opaque.ts:4", None),
+        ] {
+            analyzer.calls.push(http_call("POST", "/orders", file));
+            mount_graph.data_calls.push(DataFetchingCall {
+                method: "POST".to_string(),
+                target_url: "/orders".to_string(),
+                canonical_path: "/orders".to_string(),
+                client: "fetch".to_string(),
+                file_location: file.to_string(),
+                call_kind: None,
+                repo_name: None,
+                service_name: None,
+                query_params: None,
+                sent_headers: headers,
+                target_service: None,
+            });
+        }
+
+        let (findings, verified, _) = analyzer.analyze_matches_with_mount_graph(&mount_graph);
+
+        assert_eq!(
+            findings,
+            vec![Finding::header_mismatch(
+                "POST",
+                "/orders",
+                Some("api".to_string()),
+                vec!["admin.ts:9".into(), "web.ts:3".into()],
+                vec!["authorization".into(), "x-tenant-id".into()],
+                vec!["text/plain".into()],
+                vec!["application/json".into()],
+            )]
+        );
+        assert_eq!(verified.len(), 1);
+    }
+
     #[test]
     fn test_method_mismatch_falls_back_to_sorted_verified_producer() {
         let mut analyzer = Analyzer::new(Config::default());
@@ -4109,6 +4684,7 @@ mod tests {
             bucket,
             gate: None,
             diagnostic: diagnostic.map(str::to_string),
+            fields: Vec::new(),
             producer_alias: "Producer_Alias".to_string(),
             consumer_alias: "Consumer_Alias".to_string(),
             producer_service: "producer-svc".to_string(),
@@ -4377,9 +4953,9 @@ mod tests {
 
         let findings = analyzer.get_type_mismatch_findings();
         assert_eq!(findings.len(), 1);
-        let Finding::TypeMismatch {
+        let Issue::TypeMismatch {
             call_sites, detail, ..
-        } = &findings[0]
+        } = &findings[0].issue
         else {
             panic!("expected a TypeMismatch finding, got {:?}", findings[0]);
         };
@@ -4387,6 +4963,72 @@ mod tests {
         assert_eq!(detail, "NotificationStatus not assignable to StatusView");
     }
 
+    /// Mismatches are graded by what the consumer reads: a breakdown touching
+    /// only never-read fields is advisory, one touching a read field stays a
+    /// risk, and an untraced consumer keeps the default grade.
+    #[test]
+    fn type_mismatch_severity_follows_consumer_field_usage() {
+        use crate::cloud_storage::{
+            ManifestRole, ManifestTypeKind, ManifestTypeState, TypeEvidence, TypeManifestEntry,
+        };
+        use crate::findings::{FieldMismatch, FieldMismatchKind, Severity};
+        use crate::services::type_sidecar::InferKind;
+
+        let consumer_entry = |accessed: Option<&[&str]>| TypeManifestEntry {
+            key: crate::operation::OperationKey::http("GET", "/users/:id"),
+            role: ManifestRole::Consumer,
+            type_kind: ManifestTypeKind::Response,
+            type_alias: "Consumer_Alias".to_string(),
+            file_path: "web/src/client.ts".to_string(),
+            line_number: 3,
+            is_explicit: false,
+            type_state: ManifestTypeState::Unknown,
+            evidence: TypeEvidence {
+                file_path: "web/src/client.ts".to_string(),
+                span_start: None,
+                span_end: None,
+                line_number: 3,
+                infer_kind: InferKind::CallResult,
+                is_explicit: false,
+                type_state: ManifestTypeState::Unknown,
+            },
+            resolved_definition: None,
+            expanded_definition: None,
+            primary_type_symbol: None,
+            accessed_fields: accessed.map(|a| a.iter().map(|f| f.to_string()).collect()),
+        };
+        let grade = |accessed: Option<&[&str]>| {
+            let mut mismatch = outcome(
+                "GET",
+                "/users/:id",
+                "web/src/client.ts:3",
+                VerdictBucket::Incompatible,
+                None,
+            );
+            mismatch.fields = vec![FieldMismatch {
+                path: "createdAt".to_string(),
+                kind: FieldMismatchKind::TypeChanged,
+                producer: Some("string".to_string()),
+                consumer: Some("Date".to_string()),
+                read: None,
+            }];
+            let mut analyzer = analyzer_with_outcomes(vec![mismatch]);
+            analyzer.set_type_manifests(vec![consumer_entry(accessed)]);
+            let findings = analyzer.get_type_mismatch_findings();
+            let Issue::TypeMismatch { fields, .. } = &findings[0].issue else {
+                panic!("expected a TypeMismatch finding, got {:?}", findings[0]);
+            };
+            (findings[0].severity(), fields[0].read)
+        };
+
+        assert_eq!(
+            grade(Some(&["name", "email"])),
+            (Severity::Advisory, Some(false))
+        );
+        assert_eq!(grade(Some(&["createdAt"])), (Severity::Risk, Some(true)));
+        assert_eq!(grade(None), (Severity::Risk, None));
+    }
+
     /// A consumer location outside the GitHub Actions workspace passes through
     /// untouched; the prefix strip must not eat local or repo-relative paths.
     #[test]
@@ -4975,6 +5617,9 @@ mod tests {
             call_kind: None,
             repo_name: Some(repo.to_string()),
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         }
     }
 
@@ -0,0 +1,179 @@
+//! `carrick-baseline.json`: findings accepted when Carrick was adopted.
+//!
+//! A legacy repo can start with hundreds of pre-existing findings, which
+//! would drown the ones a PR actually introduces. `carrick baseline` records
+//! the current findings by [`Finding::fingerprint`]; later runs report any
+//! finding whose fingerprint is in the file as *baselined* — listed apart,
+//! never counted in the headline — and everything else as new.
+//!
+//! The file is meant to be committed and to shrink over time. Entries keep a
+//! `kind` and `label` next to the fingerprint purely so reviewers can read
+//! the diff; matching uses the fingerprint alone.
+
+use crate::findings::Finding;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Baseline file name, at the scan root next to `carrick.json`.
+pub const BASELINE_FILE: &str = "carrick-baseline.json";
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub kind: String,
+    pub label: String,
+}
+
+impl Baseline {
+    /// Record `findings`, sorted and de-duplicated by fingerprint so the
+    /// file diffs cleanly when it is regenerated.
+    pub fn from_findings(findings: &[Finding]) -> Self {
+        let mut entries: Vec<BaselineEntry> = findings
+            .iter()
+            .map(|f| BaselineEntry {
+                fingerprint: f.fingerprint(),
+                kind: f.kind().to_string(),
+                label: f.label(),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.kind, &a.label, &a.fingerprint).cmp(&(&b.kind, &b.label, &b.fingerprint))
+        });
+        entries.dedup_by(|a, b| a.fingerprint == b.fingerprint);
+        Self {
+            version: BASELINE_VERSION,
+            findings: entries,
+        }
+    }
+
+    /// Load the repo's baseline. `Ok(None)` when there is none; an
+    /// unreadable or malformed file is an error rather than an empty
+    /// baseline, which would silently report every accepted finding as new.
+    pub fn load(repo_root: &Path) -> Result<Option<Self>, String> {
+        let path = repo_root.join(BASELINE_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let baseline: Baseline = serde_json::from_str(&content).map_err(|e| {
+            format!(
+                "Failed to parse {}: {}. Regenerate it with `carrick baseline`.",
+                path.display(),
+                e
+            )
+        })?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!(
+                "{} has version {}, expected {}. Regenerate it with `carrick baseline`.",
+                path.display(),
+                baseline.version,
+                BASELINE_VERSION
+            ));
+        }
+        Ok(Some(baseline))
+    }
+
+    /// Write the baseline to the repo root, returning the path written.
+    pub fn write(&self, repo_root: &Path) -> Result<PathBuf, String> {
+        let path = repo_root.join(BASELINE_FILE);
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize baseline: {}", e))?;
+        std::fs::write(&path, format!("{}\n", json))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Split findings into `(new, baselined)`, preserving order.
+    pub fn partition(&self, findings: Vec<Finding>) -> (Vec<Finding>, Vec<Finding>) {
+        let known: HashSet<&str> = self
+            .findings
+            .iter()
+            .map(|e| e.fingerprint.as_str())
+            .collect();
+        findings
+            .into_iter()
+            .partition(|f| !known.contains(f.fingerprint().as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_partitions_by_fingerprint() {
+        let dir = tempfile::tempdir().unwrap();
+        let accepted = Finding::orphaned_endpoint("GET", "/legacy", Some("api".to_string()));
+        let missing = Finding::missing_endpoint("GET", "/users", None, vec!["a.ts:3".into()]);
+        Baseline::from_findings(&[accepted.clone(), missing.clone(), accepted.clone()])
+            .write(dir.path())
+            .unwrap();
+
+        let baseline = Baseline::load(dir.path()).unwrap().unwrap();
+        assert_eq!(baseline.findings.len(), 2);
+
+        // Call-site lines moved: still the same finding.
+        let moved = Finding::missing_endpoint("GET", "/users", None, vec!["a.ts:40".into()]);
+        let fresh = Finding::missing_endpoint("POST", "/users", None, vec!["a.ts:41".into()]);
+        let (new, baselined) =
+            baseline.partition(vec![accepted.clone(), fresh.clone(), moved.clone()]);
+        assert_eq!(new, vec![fresh]);
+        assert_eq!(baselined, vec![accepted, moved]);
+    }
+
+    #[test]
+    fn a_new_consumer_of_a_baselined_type_mismatch_is_new() {
+        let mismatch = |site: &str| {
+            Finding::type_mismatch(
+                "GET",
+                "/users",
+                None,
+                vec![site.to_string()],
+                "User",
+                "Order",
+                "id: number vs string",
+            )
+        };
+        let web = mismatch("web/src/users.ts:12");
+        let baseline = Baseline::from_findings(std::slice::from_ref(&web));
+
+        // The same consumer, its call moved down the file, stays baselined;
+        // a second consumer of the endpoint is a new finding.
+        let moved = mismatch("web/src/users.ts:30");
+        let admin = mismatch("admin/src/users.ts:12");
+        let (new, baselined) = baseline.partition(vec![moved.clone(), admin.clone()]);
+        assert_eq!(new, vec![admin]);
+        assert_eq!(baselined, vec![moved]);
+
+        // Anchored call sites are told apart by their anchors.
+        let anchored = |anchor: &str| {
+            mismatch("web/src/users.ts:12").with_call_site_anchors(vec![anchor.into()])
+        };
+        let baseline = Baseline::from_findings(&[anchored("loadUser#3f2a9c1e04b7")]);
+        let (new, _) = baseline.partition(vec![
+            anchored("loadUser#3f2a9c1e04b7"),
+            anchored("listUsers#0d1e2f3a4b5c"),
+        ]);
+        assert_eq!(new, vec![anchored("listUsers#0d1e2f3a4b5c")]);
+    }
+
+    #[test]
+    fn missing_file_is_none_and_malformed_file_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(Baseline::load(dir.path()).unwrap(), None);
+
+        std::fs::write(dir.path().join(BASELINE_FILE), "{ nope").unwrap();
+        let err = Baseline::load(dir.path()).unwrap_err();
+        assert!(err.contains("carrick baseline"), "{err}");
+    }
+}
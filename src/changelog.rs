@@ -0,0 +1,661 @@
+//! `carrick changelog`: an API changelog between two index snapshots.
+//!
+//! A snapshot is the `CloudRepoData` JSON a scan uploads (one object, or an
+//! array for a multi-service repo). Each service's operations are diffed by
+//! `OperationKey`: removed and added operations are paired into method
+//! changes (same path, different verb) and renames (same named handler,
+//! different key, matched one-to-one) where the evidence allows; producer request/response types are
+//! compared field by field. Every entry is classed breaking or not, which
+//! drives the suggested semver bump.
+
+use crate::cloud_storage::{CloudRepoData, ManifestRole, ManifestTypeKind};
+use crate::operation::OperationKey;
+use crate::type_diff::{self, TypeChange};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::path::Path;
+
+/// Suggested version bump, ordered by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SemverBump {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl SemverBump {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SemverBump::None => "none",
+            SemverBump::Patch => "patch",
+            SemverBump::Minor => "minor",
+            SemverBump::Major => "major",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    Added,
+    Removed,
+    Renamed,
+    MethodChanged,
+    TypeChanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangelogEntry {
+    pub kind: EntryKind,
+    /// `http`, `graphql`, `socket`, or `pubsub`.
+    pub protocol: String,
+    /// The operation as it reads in the new snapshot (the old one for
+    /// removals).
+    pub operation: String,
+    /// The old operation for renames and method changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<String>,
+    /// The field-level change for type changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// The structured change behind `detail`, which drives the bump.
+    #[serde(skip)]
+    pub change: Option<TypeChange>,
+    pub breaking: bool,
+}
+
+impl ChangelogEntry {
+    fn bump(&self) -> SemverBump {
+        match (self.breaking, self.kind) {
+            (true, _) => SemverBump::Major,
+            (false, EntryKind::Added) => SemverBump::Minor,
+            (false, EntryKind::TypeChanged)
+                if matches!(self.change, Some(TypeChange::Added { .. })) =>
+            {
+                SemverBump::Minor
+            }
+            _ => SemverBump::Patch,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ServiceChangelog {
+    pub service: String,
+    pub bump: SemverBump,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Changelog {
+    pub bump: SemverBump,
+    pub services: Vec<ServiceChangelog>,
+}
+
+/// Read a snapshot from a file, or from `<rev>:<path>` in the git repo at
+/// the working directory.
+pub fn load_snapshot(spec: &str) -> Result<Vec<CloudRepoData>, Box<dyn Error>> {
+    let text = if Path::new(spec).is_file() {
+        std::fs::read_to_string(spec)?
+    } else if spec.contains(':') {
+        let output = std::process::Command::new("git")
+            .args(["show", spec])
+            .output()
+            .map_err(|e| format!("Failed to run git show {}: {}", spec, e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "Could not read snapshot '{}': {}",
+                spec,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )
+            .into());
+        }
+        String::from_utf8(output.stdout)?
+    } else {
+        return Err(format!("Snapshot '{}' does not exist", spec).into());
+    };
+    parse_snapshot(&text).map_err(|e| format!("Invalid snapshot '{}': {}", spec, e).into())
+}
+
+fn parse_snapshot(text: &str) -> Result<Vec<CloudRepoData>, serde_json::Error> {
+    match serde_json::from_str::<Vec<CloudRepoData>>(text) {
+        Ok(services) => Ok(services),
+        Err(_) => serde_json::from_str::<CloudRepoData>(text).map(|data| vec![data]),
+    }
+}
+
+/// One operation a snapshot's service defines.
+struct Operation {
+    key: OperationKey,
+    /// The handler's name, unless it is empty or a placeholder.
+    handler: Option<String>,
+}
+
+/// Handler names extraction falls back to when a handler has no name of
+/// its own; many unrelated operations share them, so they are no evidence
+/// of a rename.
+const PLACEHOLDER_HANDLERS: &[&str] = &["anonymous", "<anonymous>", "handler", "unknown"];
+
+fn named_handler(handler: Option<&str>) -> Option<String> {
+    handler
+        .map(str::trim)
+        .filter(|h| {
+            !h.is_empty()
+                && !PLACEHOLDER_HANDLERS
+                    .iter()
+                    .any(|p| h.eq_ignore_ascii_case(p))
+        })
+        .map(String::from)
+}
+
+/// A service's operations and producer types, keyed by canonical key.
+#[derive(Default)]
+struct ServiceSurface {
+    operations: BTreeMap<String, Operation>,
+    types: BTreeMap<(String, bool), String>,
+}
+
+fn surfaces(snapshot: &[CloudRepoData]) -> BTreeMap<String, ServiceSurface> {
+    let mut surfaces: BTreeMap<String, ServiceSurface> = BTreeMap::new();
+    for repo in snapshot {
+        let service = repo
+            .service_name
+            .clone()
+            .unwrap_or_else(|| repo.repo_name.clone());
+        let surface = surfaces.entry(service).or_default();
+        let mut add = |key: OperationKey, handler: Option<&str>| {
+            surface
+                .operations
+                .entry(key.canonical())
+                .or_insert(Operation {
+                    key,
+                    handler: named_handler(handler),
+                });
+        };
+        // Mount-resolved routes when the snapshot carries a mount graph, so
+        // a route moved between routers reads as a path change.
+        let resolved = repo
+            .mount_graph
+            .as_ref()
+            .map(|graph| graph.get_resolved_endpoints())
+            .filter(|endpoints| !endpoints.is_empty());
+        if let Some(endpoints) = resolved {
+            for endpoint in endpoints {
+                add(
+                    OperationKey::http(&endpoint.method, endpoint.full_path.clone()),
+                    endpoint.handler.as_deref(),
+                );
+            }
+        }
+        for endpoint in &repo.endpoints {
+            if resolved.is_some() && endpoint.key.protocol() == crate::operation::Protocol::Http {
+                continue;
+            }
+            add(endpoint.key.clone(), endpoint.handler_name.as_deref());
+        }
+        for entry in repo.type_manifest.iter().flatten() {
+            if entry.role != ManifestRole::Producer {
+                continue;
+            }
+            let definition = entry
+                .expanded_definition
+                .as_ref()
+                .or(entry.resolved_definition.as_ref());
+            if let Some(definition) = definition {
+                surface.types.insert(
+                    (
+                        entry.key.canonical(),
+                        entry.type_kind == ManifestTypeKind::Request,
+                    ),
+                    definition.clone(),
+                );
+            }
+        }
+    }
+    surfaces
+}
+
+fn protocol(key: &OperationKey) -> String {
+    key.canonical()
+        .split('|')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// How an operation reads in the changelog.
+fn describe(key: &OperationKey) -> String {
+    match key {
+        OperationKey::Http { method, path } => format!("`{} {}`", method, path),
+        OperationKey::Graphql { kind, field } => {
+            format!("GraphQL {} `{}`", kind.as_str(), field)
+        }
+        OperationKey::Socket { event, direction } => {
+            format!("socket event `{}` ({})", event, direction.as_str())
+        }
+        OperationKey::Pubsub { topic } => format!("topic `{}`", topic),
+    }
+}
+
+/// Diff two snapshots.
+pub fn diff(old: &[CloudRepoData], new: &[CloudRepoData]) -> Changelog {
+    let old = surfaces(old);
+    let new = surfaces(new);
+    let empty = ServiceSurface::default();
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    let services: Vec<ServiceChangelog> = names
+        .into_iter()
+        .map(|name| {
+            let entries = diff_service(
+                old.get(name).unwrap_or(&empty),
+                new.get(name).unwrap_or(&empty),
+            );
+            ServiceChangelog {
+                service: name.clone(),
+                bump: entries
+                    .iter()
+                    .map(ChangelogEntry::bump)
+                    .max()
+                    .unwrap_or(SemverBump::None),
+                entries,
+            }
+        })
+        .filter(|service| !service.entries.is_empty())
+        .collect();
+    Changelog {
+        bump: services
+            .iter()
+            .map(|s| s.bump)
+            .max()
+            .unwrap_or(SemverBump::None),
+        services,
+    }
+}
+
+fn diff_service(old: &ServiceSurface, new: &ServiceSurface) -> Vec<ChangelogEntry> {
+    let mut removed: Vec<&Operation> = old
+        .operations
+        .iter()
+        .filter(|(key, _)| !new.operations.contains_key(*key))
+        .map(|(_, op)| op)
+        .collect();
+    let mut added: Vec<&Operation> = new
+        .operations
+        .iter()
+        .filter(|(key, _)| !old.operations.contains_key(*key))
+        .map(|(_, op)| op)
+        .collect();
+    let mut entries = Vec::new();
+    // Pairs of (old, new) canonical keys whose types are compared like an
+    // unchanged operation's.
+    let mut moved: Vec<(String, String)> = Vec::new();
+
+    // Same path, different verb (and no conflicting handler): a method
+    // change.
+    removed.retain(|old_op| {
+        let OperationKey::Http { method, path } = &old_op.key else {
+            return true;
+        };
+        let Some(index) = added.iter().position(|new_op| {
+            let same_handler = match (&old_op.handler, &new_op.handler) {
+                (Some(old), Some(new)) => old == new,
+                _ => true,
+            };
+            same_handler
+                && matches!(&new_op.key, OperationKey::Http { method: m, path: p } if p == path && m != method)
+        }) else {
+            return true;
+        };
+        let new_op = added.remove(index);
+        entries.push(ChangelogEntry {
+            kind: EntryKind::MethodChanged,
+            protocol: protocol(&new_op.key),
+            operation: describe(&new_op.key),
+            previous: Some(describe(&old_op.key)),
+            detail: None,
+            change: None,
+            breaking: true,
+        });
+        moved.push((old_op.key.canonical(), new_op.key.canonical()));
+        false
+    });
+
+    // Same handler under a new key: a rename, but only when the handler
+    // names exactly one removed and one added operation of the protocol.
+    let sharing = |ops: &[&Operation], handler: &str, protocol_name: &str| {
+        ops.iter()
+            .filter(|op| {
+                op.handler.as_deref() == Some(handler) && protocol(&op.key) == protocol_name
+            })
+            .count()
+    };
+    let removed_before = removed.clone();
+    removed.retain(|old_op| {
+        let Some(handler) = &old_op.handler else {
+            return true;
+        };
+        let old_protocol = protocol(&old_op.key);
+        if sharing(&removed_before, handler, &old_protocol) != 1
+            || sharing(&added, handler, &old_protocol) != 1
+        {
+            return true;
+        }
+        let Some(index) = added.iter().position(|new_op| {
+            new_op.handler.as_ref() == Some(handler) && protocol(&new_op.key) == old_protocol
+        }) else {
+            return true;
+        };
+        let new_op = added.remove(index);
+        entries.push(ChangelogEntry {
+            kind: EntryKind::Renamed,
+            protocol: protocol(&new_op.key),
+            operation: describe(&new_op.key),
+            previous: Some(describe(&old_op.key)),
+            detail: None,
+            change: None,
+            breaking: true,
+        });
+        moved.push((old_op.key.canonical(), new_op.key.canonical()));
+        false
+    });
+
+    for op in removed {
+        entries.push(ChangelogEntry {
+            kind: EntryKind::Removed,
+            protocol: protocol(&op.key),
+            operation: describe(&op.key),
+            previous: None,
+            detail: None,
+            change: None,
+            breaking: true,
+        });
+    }
+    for op in added {
+        entries.push(ChangelogEntry {
+            kind: EntryKind::Added,
+            protocol: protocol(&op.key),
+            operation: describe(&op.key),
+            previous: None,
+            detail: None,
+            change: None,
+            breaking: false,
+        });
+    }
+
+    let kept = old
+        .operations
+        .keys()
+        .filter(|key| new.operations.contains_key(*key))
+        .map(|key| (key.clone(), key.clone()));
+    for (old_key, new_key) in kept.chain(moved) {
+        let op = &new.operations[&new_key];
+        for (request, kind, side) in [
+            (true, ManifestTypeKind::Request, "request"),
+            (false, ManifestTypeKind::Response, "response"),
+        ] {
+            let (Some(before), Some(after)) = (
+                old.types.get(&(old_key.clone(), request)),
+                new.types.get(&(new_key.clone(), request)),
+            ) else {
+                continue;
+            };
+            for change in type_diff::diff(before, after) {
+                entries.push(type_entry(&op.key, side, kind, change));
+            }
+        }
+    }
+    entries
+}
+
+fn type_entry(
+    key: &OperationKey,
+    side: &str,
+    kind: ManifestTypeKind,
+    change: TypeChange,
+) -> ChangelogEntry {
+    ChangelogEntry {
+        kind: EntryKind::TypeChanged,
+        protocol: protocol(key),
+        operation: describe(key),
+        previous: None,
+        detail: Some(format!("{}: {}", side, change)),
+        breaking: change.is_breaking(kind),
+        change: Some(change),
+    }
+}
+
+impl Changelog {
+    /// Release-notes Markdown: per service, breaking changes first, then
+    /// additions, then everything else.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# API changelog\n\n");
+        if self.services.is_empty() {
+            out.push_str("No API changes.\n");
+            return out;
+        }
+        out.push_str(&format!(
+            "Suggested version bump: **{}**\n",
+            self.bump.as_str()
+        ));
+        for service in &self.services {
+            out.push_str(&format!(
+                "\n## {} ({})\n",
+                service.service,
+                service.bump.as_str()
+            ));
+            for (title, bump) in [
+                ("Breaking", SemverBump::Major),
+                ("Added", SemverBump::Minor),
+                ("Changed", SemverBump::Patch),
+            ] {
+                let lines: Vec<String> = service
+                    .entries
+                    .iter()
+                    .filter(|e| e.bump() == bump)
+                    .map(entry_line)
+                    .collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                out.push_str(&format!("\n### {}\n\n", title));
+                for line in lines {
+                    out.push_str(&format!("- {}\n", line));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn entry_line(entry: &ChangelogEntry) -> String {
+    let previous = entry.previous.as_deref().unwrap_or_default();
+    match entry.kind {
+        EntryKind::Added => format!("Added {}", entry.operation),
+        EntryKind::Removed => format!("Removed {}", entry.operation),
+        EntryKind::Renamed => format!("Renamed {} to {}", previous, entry.operation),
+        EntryKind::MethodChanged => format!("{} is now {}", previous, entry.operation),
+        EntryKind::TypeChanged => format!(
+            "{} {}",
+            entry.operation,
+            entry.detail.as_deref().unwrap_or_default()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::ApiEndpointDetails;
+    use crate::cloud_storage::{ManifestTypeState, TypeEvidence, TypeManifestEntry};
+    use crate::services::type_sidecar::InferKind;
+    use std::collections::HashMap;
+
+    fn op(key: OperationKey, handler: &str) -> ApiEndpointDetails {
+        ApiEndpointDetails {
+            owner: None,
+            key,
+            params: vec![],
+            request_body: None,
+            response_body: None,
+            handler_name: Some(handler.to_string()),
+            request_type: None,
+            response_type: None,
+            file_path: "src/routes.ts".into(),
+            repo_name: None,
+            service_name: None,
+            provenance: Default::default(),
+        }
+    }
+
+    fn response_type(method: &str, path: &str, definition: &str) -> TypeManifestEntry {
+        TypeManifestEntry {
+            key: OperationKey::http(method, path),
+            role: ManifestRole::Producer,
+            type_kind: ManifestTypeKind::Response,
+            type_alias: "Endpoint_abc_Response".to_string(),
+            file_path: "src/routes.ts".to_string(),
+            line_number: 3,
+            is_explicit: true,
+            type_state: ManifestTypeState::Explicit,
+            evidence: TypeEvidence {
+                file_path: "src/routes.ts".to_string(),
+                span_start: None,
+                span_end: None,
+                line_number: 3,
+                infer_kind: InferKind::ResponseBody,
+                is_explicit: true,
+                type_state: ManifestTypeState::Explicit,
+            },
+            resolved_definition: None,
+            expanded_definition: Some(definition.to_string()),
+            primary_type_symbol: None,
+            accessed_fields: None,
+        }
+    }
+
+    fn snapshot(
+        endpoints: Vec<ApiEndpointDetails>,
+        types: Vec<TypeManifestEntry>,
+    ) -> Vec<CloudRepoData> {
+        vec![CloudRepoData {
+            repo_name: "orders".to_string(),
+            service_name: None,
+            endpoints,
+            calls: vec![],
+            mounts: vec![],
+            apps: HashMap::new(),
+            imported_handlers: vec![],
+            function_definitions: HashMap::new(),
+            config_json: None,
+            package_json: None,
+            packages: None,
+            last_updated: chrono::Utc::now(),
+            commit_hash: "deadbeef".to_string(),
+            mount_graph: None,
+            bundled_types: None,
+            type_manifest: Some(types),
+            file_results: None,
+            cached_detection: None,
+            cached_guidance: None,
+            cached_extraction_config: None,
+            package_json_hash: None,
+            cache_version: None,
+            type_extraction_status: None,
+            compat_verdicts: None,
+            capture_stub: None,
+        }]
+    }
+
+    #[test]
+    fn classifies_operation_and_type_changes() {
+        let old = snapshot(
+            vec![
+                op(OperationKey::http("GET", "/orders/:id"), "getOrder"),
+                op(OperationKey::http("PUT", "/orders/:id"), "updateOrder"),
+                op(OperationKey::http("GET", "/order-list"), "listOrders"),
+                op(OperationKey::http("DELETE", "/orders/:id"), "deleteOrder"),
+            ],
+            vec![response_type(
+                "GET",
+                "/orders/:id",
+                "{ id: string; email: string }",
+            )],
+        );
+        let new = snapshot(
+            vec![
+                op(OperationKey::http("GET", "/orders/:id"), "getOrder"),
+                op(OperationKey::http("PATCH", "/orders/:id"), "updateOrder"),
+                op(OperationKey::http("GET", "/orders"), "listOrders"),
+                op(OperationKey::pubsub("order.created"), "publishCreated"),
+            ],
+            vec![response_type(
+                "GET",
+                "/orders/:id",
+                "{ id: string; total?: number }",
+            )],
+        );
+
+        let changelog = diff(&old, &new);
+        assert_eq!(changelog.bump, SemverBump::Major);
+        let markdown = changelog.to_markdown();
+        for line in [
+            "- `PUT /orders/:id` is now `PATCH /orders/:id`",
+            "- Renamed `GET /order-list` to `GET /orders`",
+            "- Removed `DELETE /orders/:id`",
+            "- `GET /orders/:id` response: `email` removed",
+            "- Added topic `order.created`",
+            "- `GET /orders/:id` response: `total` added (optional)",
+        ] {
+            assert!(markdown.contains(line), "missing {line:?} in\n{markdown}");
+        }
+
+        let additive = diff(
+            &new,
+            &snapshot(
+                new[0].endpoints.clone(),
+                vec![response_type(
+                    "GET",
+                    "/orders/:id",
+                    "{ id: string; total?: number; note?: string }",
+                )],
+            ),
+        );
+        assert_eq!(additive.bump, SemverBump::Minor);
+        assert_eq!(diff(&new, &new).bump, SemverBump::None);
+    }
+
+    #[test]
+    fn renames_need_a_named_handler_matched_one_to_one() {
+        let old = snapshot(
+            vec![
+                op(OperationKey::http("GET", "/users"), "anonymous"),
+                op(OperationKey::http("GET", "/carts"), "list"),
+                op(OperationKey::http("GET", "/wishlists"), "list"),
+            ],
+            vec![],
+        );
+        let new = snapshot(
+            vec![
+                op(OperationKey::http("GET", "/accounts"), "anonymous"),
+                op(OperationKey::http("GET", "/baskets"), "list"),
+            ],
+            vec![],
+        );
+
+        let entries = &diff(&old, &new).services[0].entries;
+        assert!(
+            entries.iter().all(|e| e.kind != EntryKind::Renamed),
+            "{entries:?}"
+        );
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|e| e.kind == EntryKind::Removed)
+                .count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,716 @@
+//! In-house HTTP client wrappers declared in `carrick.json`.
+//!
+//! Services often call each other through a shared wrapper rather than
+//! `fetch`/`axios`:
+//!
+//! ```ts
+//! import { api, ordersClient } from '@acme/http';
+//! await api.request({ service: 'orders', op: 'GET /orders/:id', body });
+//! await ordersClient.get(`/orders/${id}`);
+//! ```
+//!
+//! Neither call says what it does in a shape the scanner's heuristics know,
+//! so the method and target are whatever the file analyzer guesses. The
+//! `httpClients` section of `carrick.json` declares each wrapper instead —
+//! the module it is imported from, the methods that make requests, and which
+//! argument holds the method, path, body, and base URL — and
+//! [`wrapper_calls`] reads every matching call site off the AST.
+//!
+//! A receiver is any binding imported from the wrapper's module (optionally
+//! only the named `export`), or a local created from one
+//! (`const orders = createClient(...)`, `new HttpClient(...)`). A call
+//! counts when it invokes one of the declared methods on a receiver, or is
+//! a direct call of an imported function named like one. A call whose
+//! method or path isn't literal is left to the scanner and the LLM.
+
+use crate::parser::unwrap_expr;
+use crate::type_manifest::is_http_method;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use swc_common::{SourceMap, SourceMapper, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// One entry of the `httpClients` section of `carrick.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientWrapper {
+    /// Module the wrapper is imported from: a package (`@acme/http`) or a
+    /// service-relative source path (`src/lib/http`).
+    pub import: String,
+    /// Only bindings of this export are receivers. Unset: every binding
+    /// imported from `import`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export: Option<String>,
+    /// Method names that make a request (`request`, `get`, `post`, …).
+    pub methods: Vec<String>,
+    /// The HTTP method: a literal (`"POST"`) or an argument. A value shaped
+    /// `GET /orders/:id` also carries the path. Unset: the called method's
+    /// name when it is an HTTP verb.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<MethodSpec>,
+    /// The argument holding the path. Defaults to the first argument.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<ArgLocator>,
+    /// The argument holding the request body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<ArgLocator>,
+    /// The argument naming the target, looked up in `base_urls`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<ArgLocator>,
+    /// `base` argument value → base URL (`"orders": "${ORDERS_URL}"`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub base_urls: BTreeMap<String, String>,
+    /// Base URL of every call whose `base` doesn't resolve.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+}
+
+/// Where a value sits in a call's arguments: `"1"` is the second argument,
+/// `"0.op"` the `op` property of an object literal first argument.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ArgLocator {
+    pub index: usize,
+    pub keys: Vec<String>,
+}
+
+impl TryFrom<String> for ArgLocator {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut parts = value.split('.');
+        let index = parts
+            .next()
+            .and_then(|i| i.trim().parse().ok())
+            .ok_or_else(|| {
+                format!("argument locator `{value}` must start with an argument index")
+            })?;
+        let keys: Vec<String> = parts.map(|k| k.trim().to_string()).collect();
+        if keys.iter().any(String::is_empty) {
+            return Err(format!("argument locator `{value}` has an empty property"));
+        }
+        Ok(Self { index, keys })
+    }
+}
+
+impl From<ArgLocator> for String {
+    fn from(locator: ArgLocator) -> Self {
+        locator.to_string()
+    }
+}
+
+impl fmt::Display for ArgLocator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index)?;
+        for key in &self.keys {
+            write!(f, ".{key}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `ClientWrapper::method`: an HTTP verb, or the argument holding one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum MethodSpec {
+    Literal(String),
+    Arg(ArgLocator),
+}
+
+impl TryFrom<String> for MethodSpec {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if is_http_method(&value) {
+            Ok(MethodSpec::Literal(value.trim().to_uppercase()))
+        } else {
+            ArgLocator::try_from(value).map(MethodSpec::Arg)
+        }
+    }
+}
+
+impl From<MethodSpec> for String {
+    fn from(spec: MethodSpec) -> Self {
+        match spec {
+            MethodSpec::Literal(method) => method,
+            MethodSpec::Arg(locator) => locator.to_string(),
+        }
+    }
+}
+
+/// One wrapper call site, resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrapperCall {
+    pub line_number: u32,
+    pub method: String,
+    /// Base URL (if any) joined with the path, interpolations kept.
+    pub target: String,
+    /// `receiver.method(`, for `pattern_matched`.
+    pub pattern: String,
+    pub span_start: u32,
+    pub span_end: u32,
+    pub call_text: String,
+    pub payload_text: Option<String>,
+    pub payload_line: Option<u32>,
+}
+
+/// The wrapper calls of one file.
+#[derive(Debug, Default)]
+pub struct WrapperCalls {
+    pub calls: Vec<WrapperCall>,
+    /// `(lo, hi)` spans of the resolved calls — directly comparable with
+    /// gatekeeper candidate spans.
+    pub owned_spans: HashSet<(u32, u32)>,
+}
+
+/// Resolve every call through a declared wrapper in `module`, parsed from
+/// `file_path`. Relative import specifiers are resolved against the file's
+/// directory and compared with `import` relative to `service_root`.
+pub fn wrapper_calls(
+    module: &Module,
+    cm: &SourceMap,
+    file_path: &Path,
+    service_root: &Path,
+    wrappers: &[ClientWrapper],
+) -> WrapperCalls {
+    let mut receivers: HashMap<Id, usize> = HashMap::new();
+    let mut functions: HashMap<Id, usize> = HashMap::new();
+    for item in &module.body {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            continue;
+        };
+        if import.type_only {
+            continue;
+        }
+        let matching: Vec<usize> = wrappers
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| import_matches(&w.import, &import.src.value, file_path, service_root))
+            .map(|(index, _)| index)
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+        for specifier in &import.specifiers {
+            let (local, imported) = match specifier {
+                ImportSpecifier::Named(named) if !named.is_type_only => {
+                    let imported = match &named.imported {
+                        Some(ModuleExportName::Ident(i)) => i.sym.to_string(),
+                        Some(ModuleExportName::Str(s)) => s.value.to_string(),
+                        None => named.local.sym.to_string(),
+                    };
+                    (&named.local, imported)
+                }
+                ImportSpecifier::Default(default) => (&default.local, "default".to_string()),
+                ImportSpecifier::Namespace(namespace) => (&namespace.local, String::new()),
+                _ => continue,
+            };
+            // A wrapper declared for this very export beats a catch-all one.
+            let Some(&index) = matching
+                .iter()
+                .find(|&&i| wrappers[i].export.as_deref() == Some(imported.as_str()))
+                .or_else(|| matching.iter().find(|&&i| wrappers[i].export.is_none()))
+            else {
+                continue;
+            };
+            receivers.insert(local.to_id(), index);
+            if wrappers[index].methods.contains(&imported) {
+                functions.insert(local.to_id(), index);
+            }
+        }
+    }
+    if receivers.is_empty() {
+        return WrapperCalls::default();
+    }
+
+    let mut visitor = WrapperVisitor {
+        cm,
+        wrappers,
+        receivers,
+        functions,
+        out: WrapperCalls::default(),
+    };
+    module.visit_with(&mut visitor);
+    visitor.out.calls.sort_by_key(|c| c.line_number);
+    visitor.out
+}
+
+/// Whether an import specifier names the wrapper's module: the same package,
+/// or a relative path that resolves to `import` under the service root
+/// (extensions and a trailing `/index` ignored).
+fn import_matches(import: &str, specifier: &str, file_path: &Path, service_root: &Path) -> bool {
+    if specifier == import {
+        return true;
+    }
+    if !specifier.starts_with('.') {
+        return false;
+    }
+    let Some(dir) = file_path.parent() else {
+        return false;
+    };
+    let resolved = normalize_lexically(&dir.join(specifier));
+    let root = normalize_lexically(service_root);
+    let Ok(relative) = resolved.strip_prefix(&root) else {
+        return false;
+    };
+    module_key(&relative.to_string_lossy()) == module_key(import)
+}
+
+/// A module path without `./`, source extension, or trailing `/index`.
+fn module_key(path: &str) -> String {
+    let path = path.trim().trim_start_matches("./").replace('\\', "/");
+    let path = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"]
+        .iter()
+        .find_map(|ext| path.strip_suffix(&format!(".{ext}")))
+        .unwrap_or(&path);
+    path.strip_suffix("/index").unwrap_or(path).to_string()
+}
+
+/// `a/b/../c/./d` → `a/c/d`, without touching the filesystem.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+struct WrapperVisitor<'a> {
+    cm: &'a SourceMap,
+    wrappers: &'a [ClientWrapper],
+    /// Bindings calls are made on → index into `wrappers`.
+    receivers: HashMap<Id, usize>,
+    /// Imported functions that are themselves request methods.
+    functions: HashMap<Id, usize>,
+    out: WrapperCalls,
+}
+
+/// A located argument: an expression, or the identifier of a shorthand
+/// property (`{ body }`).
+enum Located<'a> {
+    Expr(&'a Expr),
+    Shorthand(&'a Ident),
+}
+
+impl Located<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Located::Expr(expr) => expr.span(),
+            Located::Shorthand(ident) => ident.span,
+        }
+    }
+}
+
+fn locate<'a>(args: &'a [ExprOrSpread], locator: &ArgLocator) -> Option<Located<'a>> {
+    let arg = args.get(locator.index).filter(|a| a.spread.is_none())?;
+    let mut located = Located::Expr(&arg.expr);
+    for key in &locator.keys {
+        let Located::Expr(expr) = located else {
+            return None;
+        };
+        let Expr::Object(object) = unwrap_expr(expr) else {
+            return None;
+        };
+        located = object.props.iter().rev().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(kv) if prop_key(&kv.key).as_deref() == Some(key.as_str()) => {
+                    Some(Located::Expr(&kv.value))
+                }
+                Prop::Shorthand(ident) if ident.sym == *key.as_str() => {
+                    Some(Located::Shorthand(ident))
+                }
+                _ => None,
+            },
+            _ => None,
+        })?;
+    }
+    Some(located)
+}
+
+fn prop_key(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.to_string()),
+        PropName::Str(s) => Some(s.value.to_string()),
+        _ => None,
+    }
+}
+
+/// The binding a call or `new` chain starts from: `createClient(...)`,
+/// `http.create(...)`, `new http.Client(...)` → `createClient` / `http`.
+fn root_ident(expr: &Expr) -> Option<&Ident> {
+    match unwrap_expr(expr) {
+        Expr::Ident(i) => Some(i),
+        Expr::Member(member) => root_ident(&member.obj),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => root_ident(callee),
+        Expr::New(new) => root_ident(&new.callee),
+        Expr::Await(a) => root_ident(&a.arg),
+        _ => None,
+    }
+}
+
+impl WrapperVisitor<'_> {
+    fn text(&self, span: Span) -> String {
+        self.cm.span_to_snippet(span).unwrap_or_default()
+    }
+
+    fn line(&self, span: Span) -> u32 {
+        self.cm.lookup_char_pos(span.lo).line as u32
+    }
+
+    /// A literal string value; a template literal keeps its `${…}`
+    /// interpolations for the normalizer to turn into params.
+    fn literal(&self, located: &Located) -> Option<String> {
+        let Located::Expr(expr) = located else {
+            return None;
+        };
+        match unwrap_expr(expr) {
+            Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+            Expr::Tpl(tpl) => Some(self.text(tpl.span).trim_matches('`').to_string()),
+            _ => None,
+        }
+    }
+
+    /// The wrapper a call goes through, and the called method's name.
+    fn wrapper_of(&self, call: &CallExpr) -> Option<(usize, String)> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        match &**callee {
+            Expr::Ident(function) => {
+                let index = *self.functions.get(&function.to_id())?;
+                Some((index, function.sym.to_string()))
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(method),
+                ..
+            }) => {
+                let Expr::Ident(receiver) = &**obj else {
+                    return None;
+                };
+                let index = *self.receivers.get(&receiver.to_id())?;
+                self.wrappers[index]
+                    .methods
+                    .iter()
+                    .any(|m| *m == *method.sym)
+                    .then(|| (index, method.sym.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve(
+        &self,
+        call: &CallExpr,
+        wrapper: &ClientWrapper,
+        called: &str,
+    ) -> Option<WrapperCall> {
+        let path_locator = wrapper.path.clone().unwrap_or(ArgLocator {
+            index: 0,
+            keys: Vec::new(),
+        });
+        let mut path = self.literal(&locate(&call.args, &path_locator)?)?;
+
+        let method = match &wrapper.method {
+            Some(MethodSpec::Literal(method)) => method.clone(),
+            Some(MethodSpec::Arg(locator)) => {
+                let located = locate(&call.args, locator)?;
+                let value = match &located {
+                    // `Method.POST`
+                    Located::Expr(expr) => match unwrap_expr(expr) {
+                        Expr::Member(MemberExpr {
+                            prop: MemberProp::Ident(p),
+                            ..
+                        }) => p.sym.to_string(),
+                        _ => self.literal(&located)?,
+                    },
+                    Located::Shorthand(_) => return None,
+                };
+                // `GET /orders/:id` names the method and the path.
+                match value.trim().split_once(' ') {
+                    Some((method, op_path)) if is_http_method(method) => {
+                        if *locator == path_locator {
+                            path = op_path.trim().to_string();
+                        }
+                        method.to_uppercase()
+                    }
+                    _ => value.trim().to_uppercase(),
+                }
+            }
+            None => match called {
+                "del" => "DELETE".to_string(),
+                verb => verb.to_uppercase(),
+            },
+        };
+        if !is_http_method(&method) {
+            return None;
+        }
+
+        let base = wrapper
+            .base
+            .as_ref()
+            .and_then(|locator| locate(&call.args, locator))
+            .and_then(|located| self.literal(&located))
+            .and_then(|name| wrapper.base_urls.get(&name).cloned())
+            .or_else(|| wrapper.base_url.clone());
+        let absolute =
+            path.starts_with("http://") || path.starts_with("https://") || path.starts_with("${");
+        let target = match base {
+            Some(base) if !absolute => format!(
+                "{}/{}",
+                base.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            ),
+            _ => path,
+        };
+
+        let payload = wrapper
+            .body
+            .as_ref()
+            .and_then(|locator| locate(&call.args, locator))
+            .map(|located| located.span());
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        Some(WrapperCall {
+            line_number: self.line(call.span),
+            method,
+            target,
+            pattern: format!("{}(", self.text(callee.span())),
+            span_start: call.span.lo.0,
+            span_end: call.span.hi.0,
+            call_text: self.text(call.span),
+            payload_text: payload.map(|span| self.text(span)),
+            payload_line: payload.map(|span| self.line(span)),
+        })
+    }
+}
+
+impl Visit for WrapperVisitor<'_> {
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        // `const orders = createClient(...)`: an instance of the wrapper is a
+        // receiver too. A request made through it isn't.
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init)
+            && matches!(
+                unwrap_expr(init),
+                Expr::Call(_) | Expr::New(_) | Expr::Await(_)
+            )
+            && let Some(root) = root_ident(init)
+            && let Some(&index) = self.receivers.get(&root.to_id())
+        {
+            let is_request = match unwrap_expr(init) {
+                Expr::Call(call) => self.wrapper_of(call).is_some(),
+                Expr::Await(a) => {
+                    matches!(unwrap_expr(&a.arg), Expr::Call(call) if self.wrapper_of(call).is_some())
+                }
+                _ => false,
+            };
+            if !is_request {
+                self.receivers.insert(binding.id.to_id(), index);
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some((index, called)) = self.wrapper_of(call)
+            && let Some(resolved) = self.resolve(call, &self.wrappers[index], &called)
+        {
+            self.out
+                .owned_spans
+                .insert((resolved.span_start, resolved.span_end));
+            self.out.calls.push(resolved);
+        }
+        call.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_file;
+    use swc_common::{
+        GLOBALS, Globals,
+        errors::{ColorConfig, Handler},
+        sync::Lrc,
+    };
+
+    fn calls(source: &str, wrappers: serde_json::Value) -> Vec<WrapperCall> {
+        let wrappers: Vec<ClientWrapper> = serde_json::from_value(wrappers).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("src/orders/client.ts");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, source).unwrap();
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+        let module = GLOBALS
+            .set(&Globals::new(), || parse_file(&file, &cm, &handler))
+            .unwrap();
+        wrapper_calls(&module, &cm, &file, dir.path(), &wrappers).calls
+    }
+
+    fn summary(calls: &[WrapperCall]) -> Vec<(u32, &str, &str, Option<&str>)> {
+        calls
+            .iter()
+            .map(|c| {
+                (
+                    c.line_number,
+                    c.method.as_str(),
+                    c.target.as_str(),
+                    c.payload_text.as_deref(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn operation_strings_name_method_and_path_and_pick_the_base_url() {
+        let source = r#"
+import { api } from "@acme/http";
+import { request } from "@acme/http";
+
+export async function load(id: string, body: NewOrder) {
+  await api.request({ service: "orders", op: "GET /orders/:id" });
+  await api.request({ service: "billing", op: `POST /invoices/${id}`, body });
+  await api.request({ service: "orders", op: opFor(id) });
+  await request({ service: "unknown", op: "DELETE /orders/:id" });
+  await api.describe({ op: "GET /x" });
+}
+"#;
+        let wrappers = serde_json::json!([{
+            "import": "@acme/http",
+            "methods": ["request"],
+            "method": "0.op",
+            "path": "0.op",
+            "body": "0.body",
+            "base": "0.service",
+            "baseUrls": { "orders": "${ORDERS_URL}", "billing": "${BILLING_URL}/" },
+        }]);
+        let found = calls(source, wrappers);
+        assert_eq!(
+            summary(&found),
+            vec![
+                (6, "GET", "${ORDERS_URL}/orders/:id", None),
+                (7, "POST", "${BILLING_URL}/invoices/${id}", Some("body")),
+                (9, "DELETE", "/orders/:id", None),
+            ],
+            "a computed op and an undeclared method are left alone"
+        );
+        assert_eq!(found[0].pattern, "api.request(");
+        assert_eq!(found[2].pattern, "request(");
+    }
+
+    #[test]
+    fn verb_methods_on_wrapper_instances_and_local_modules() {
+        let source = r#"
+import { createClient } from "../lib/http";
+import * as legacy from "@acme/legacy-http";
+import type { Order } from "./types";
+
+const orders = createClient({ name: "orders" });
+
+export async function sync(order: Order) {
+  const current = await orders.get(`/orders/${order.id}`);
+  await orders.put(`/orders/${order.id}`, order);
+  await orders.del("/orders/stale");
+  await legacy.call("PATCH", "/orders/" + order.id);
+  await legacy.call(Method.POST, "https://partner.example.com/orders", order);
+  return current;
+}
+"#;
+        let wrappers = serde_json::json!([
+            {
+                "import": "src/lib/http",
+                "methods": ["get", "put", "del"],
+                "body": "1",
+                "baseUrl": "${ORDERS_URL}",
+            },
+            {
+                "import": "@acme/legacy-http",
+                "methods": ["call"],
+                "method": "0",
+                "path": "1",
+                "body": "2",
+                "baseUrl": "${LEGACY_URL}",
+            },
+        ]);
+        assert_eq!(
+            summary(&calls(source, wrappers)),
+            vec![
+                (9, "GET", "${ORDERS_URL}/orders/${order.id}", None),
+                (10, "PUT", "${ORDERS_URL}/orders/${order.id}", Some("order")),
+                (11, "DELETE", "${ORDERS_URL}/orders/stale", None),
+                (
+                    13,
+                    "POST",
+                    "https://partner.example.com/orders",
+                    Some("order")
+                ),
+            ],
+            "a concatenated path isn't literal"
+        );
+    }
+
+    #[test]
+    fn export_restricts_receivers_and_locators_round_trip() {
+        let source = r#"
+import { ordersClient, usersClient } from "@acme/http";
+ordersClient.get("/orders");
+usersClient.get("/users");
+"#;
+        let wrappers = serde_json::json!([{
+            "import": "@acme/http",
+            "export": "ordersClient",
+            "methods": ["get"],
+        }]);
+        assert_eq!(
+            summary(&calls(source, wrappers)),
+            vec![(3, "GET", "/orders", None)]
+        );
+        let wrappers = serde_json::json!([
+            { "import": "@acme/http", "methods": ["get"], "baseUrl": "${USERS_URL}" },
+            { "import": "@acme/http", "export": "ordersClient", "methods": ["get"], "baseUrl": "${ORDERS_URL}" },
+        ]);
+        assert_eq!(
+            summary(&calls(source, wrappers)),
+            vec![
+                (3, "GET", "${ORDERS_URL}/orders", None),
+                (4, "GET", "${USERS_URL}/users", None),
+            ]
+        );
+
+        let wrapper: ClientWrapper = serde_json::from_value(serde_json::json!({
+            "import": "@acme/http",
+            "methods": ["request"],
+            "method": "post",
+            "path": "0.url",
+        }))
+        .unwrap();
+        assert_eq!(
+            wrapper.method,
+            Some(MethodSpec::Literal("POST".to_string()))
+        );
+        let round_trip = serde_json::to_value(&wrapper).unwrap();
+        assert_eq!(round_trip["path"], "0.url");
+        assert_eq!(round_trip["method"], "POST");
+
+        let bad = serde_json::from_value::<ClientWrapper>(serde_json::json!({
+            "import": "@acme/http",
+            "methods": ["request"],
+            "path": "url",
+        }));
+        assert!(bad.unwrap_err().to_string().contains("argument index"));
+    }
+}
@@ -608,6 +608,7 @@ mod tests {
                 calls: 2,
             },
             findings: vec![],
+            baselined: vec![],
             delta: None,
             verified: vec![],
             graphql: crate::findings::GraphqlStatus {
@@ -104,6 +104,12 @@ pub struct TypeManifestEntry {
     /// model emitted no anchor for this op.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_type_symbol: Option<String>,
+    /// Response body paths (dotted, e.g. `user.email`) the consumer reads
+    /// downstream of this call, stamped by [`crate::field_usage`]. `None` when
+    /// usage could not be bounded (the value escapes into a call, a spread, a
+    /// computed key…) — the type check then treats every field as read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessed_fields: Option<Vec<String>>,
 }
 
 /// A persisted per-pair type-compatibility verdict, keyed by CANONICAL pair
@@ -142,6 +148,10 @@ pub struct CompatVerdict {
     /// Scanner release that produced this verdict (`CARGO_PKG_VERSION`), so a
     /// reader can see how stale the verdict is relative to the current scanner.
     pub scanner_version: String,
+    /// The consumer service's strictness profile — which assignability rules
+    /// produced `compatible`. Blobs written before profiles read `standard`.
+    #[serde(default)]
+    pub strictness: crate::config::StrictnessProfile,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -512,6 +522,13 @@ pub fn attach_compat_verdicts(
             .service_name
             .clone()
             .unwrap_or_else(|| payload.repo_name.clone());
+        // Verdicts are keyed on the consumer, so its own profile judged them.
+        let strictness = payload
+            .config_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str::<crate::config::Config>(json).ok())
+            .map(|config| config.strictness)
+            .unwrap_or_default();
 
         // Dedup by canonical pair key; incompatible wins if call sites disagree.
         let mut by_pair: std::collections::BTreeMap<
@@ -546,6 +563,7 @@ pub fn attach_compat_verdicts(
                     m.mismatch_reason.clone()
                 },
                 scanner_version: scanner_version.to_string(),
+                strictness,
             };
             by_pair
                 .entry(pair)
@@ -615,6 +633,7 @@ mod tests {
             resolved_definition: None,
             expanded_definition: None,
             primary_type_symbol: None,
+            accessed_fields: None,
         };
 
         let json: serde_json::Value = serde_json::to_value(&entry).unwrap();
@@ -647,6 +666,8 @@ mod tests {
             service_name: None,
             provenance: EndpointProvenance::Mock,
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let (endpoints, _calls) = mount_graph_to_api_details(&graph);
@@ -861,4 +882,36 @@ mod tests {
         assert!(!verdicts[0].compatible);
         assert_eq!(verdicts[0].mismatch_reason.as_deref(), Some("mismatch"));
     }
+
+    /// Each verdict records the consumer's strictness profile, and a blob
+    /// written before profiles existed reads as `standard`.
+    #[test]
+    fn compat_verdicts_record_consumer_strictness() {
+        let mut payload = empty_repo("org/consumer", Some("consumer"));
+        payload.config_json =
+            Some(r#"{"serviceName":"consumer","strictness":"exact"}"#.to_string());
+        let mut payloads = vec![payload];
+        let matches = vec![edge(
+            "producer",
+            "http|GET|/x",
+            "consumer",
+            "http|GET|/x",
+            Some(true),
+            None,
+        )];
+        attach_compat_verdicts(&mut payloads, &matches);
+        let verdicts = payloads[0].compat_verdicts.clone().unwrap();
+        assert_eq!(
+            verdicts[0].strictness,
+            crate::config::StrictnessProfile::Exact
+        );
+        let json = serde_json::to_value(&verdicts[0]).unwrap();
+        assert_eq!(json["strictness"], "exact");
+
+        let old: CompatVerdict = serde_json::from_str(
+            r#"{"producer_repo":"p","producer_key":"k","consumer_repo":"c","consumer_key":"k","compatible":true,"scanner_version":"0.1.0"}"#,
+        )
+        .unwrap();
+        assert_eq!(old.strictness, crate::config::StrictnessProfile::Standard);
+    }
 }
@@ -1,7 +1,16 @@
-use std::{collections::HashSet, io, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
 
+use crate::client_wrappers::ClientWrapper;
+use crate::env_profiles::EnvProfile;
+use crate::gateway::GatewayConfig;
+use crate::policy::Policy;
+
 /// Classification + location for a single service.
 ///
 /// In single-service repos a flat `carrick.json` deserializes directly into one
@@ -38,15 +47,106 @@ pub struct Config {
     #[serde(default)]
     #[serde(rename = "externalDomains")]
     pub external_domains: HashSet<String>,
+    /// Internal env var → the sibling service its value points at, as inferred
+    /// from deployment manifests. See [`crate::deploy_manifests`].
+    #[serde(
+        default,
+        rename = "envVarServices",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub env_var_services: BTreeMap<String, String>,
+    /// Internal host → the sibling service it names, as inferred from
+    /// deployment manifests. With `envVarServices`, lets the matcher pair a
+    /// call with the service it reaches (see
+    /// [`crate::url_normalizer::UrlNormalizer::consumer_call_target`]).
+    #[serde(
+        default,
+        rename = "hostServices",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub host_services: BTreeMap<String, String>,
+    /// API-gateway / reverse-proxy prefix rewrites applied to this service's
+    /// outbound call paths before matching. See [`crate::gateway`].
+    #[serde(default, skip_serializing_if = "GatewayConfig::is_empty")]
+    pub gateway: GatewayConfig,
+    /// Named environment profiles whose dotenv files resolve env-var URL
+    /// prefixes to concrete origins. See [`crate::env_profiles`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, EnvProfile>,
+    /// How this service's HTTP handlers put responses on the wire. `json`
+    /// makes the type check compare consumers against what `JSON.stringify`
+    /// sends (`Date` → `string`, `undefined` members dropped, `toJSON()`
+    /// honoured) rather than the handler's in-memory type.
+    #[serde(
+        default,
+        rename = "responseSerialization",
+        skip_serializing_if = "WireSerialization::is_native"
+    )]
+    pub response_serialization: WireSerialization,
+    /// Which rules judge type compatibility for this service's calls. See
+    /// [`StrictnessProfile`].
+    #[serde(default, skip_serializing_if = "StrictnessProfile::is_standard")]
+    pub strictness: StrictnessProfile,
+    /// In-house HTTP client wrappers whose call sites are read
+    /// deterministically. See [`crate::client_wrappers`].
+    #[serde(default, rename = "httpClients", skip_serializing_if = "Vec::is_empty")]
+    pub http_clients: Vec<ClientWrapper>,
+}
+
+/// Producer response serialization mode; see
+/// [`Config::response_serialization`].
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WireSerialization {
+    /// Compare the handler's declared type as-is.
+    #[default]
+    Native,
+    /// Compare the handler's type as transformed by `JSON.stringify`.
+    Json,
+}
+
+impl WireSerialization {
+    pub fn is_native(&self) -> bool {
+        *self == WireSerialization::Native
+    }
+}
+
+/// Named rule sets for the type-compatibility check, set per consumer
+/// service. The profile is applied sidecar-side to both sides of each pair
+/// and recorded on every [`crate::cloud_storage::CompatVerdict`].
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum StrictnessProfile {
+    /// Optionality and null/undefined differences are ignored.
+    Lenient,
+    /// Plain assignability: extra producer fields are fine, and an optional
+    /// field reads as `T | undefined`.
+    #[default]
+    Standard,
+    /// Standard, but an optional field never satisfies a required one, even
+    /// one typed `T | undefined`.
+    StrictOptional,
+    /// Strict-optional, and the producer may not send fields the consumer
+    /// doesn't declare.
+    Exact,
+}
+
+impl StrictnessProfile {
+    pub fn is_standard(&self) -> bool {
+        *self == StrictnessProfile::Standard
+    }
 }
 
 /// File-level shape of `carrick.json`: either a single flat service (the flat
 /// fields, captured via `flatten`) or an explicit `services` array for a
-/// monorepo. Resolved by [`Config::load_services`].
+/// monorepo. Resolved by [`Config::load_services`]. The `policy` block is
+/// repo-wide in both layouts; see [`Config::load_policy`].
 #[derive(Debug, Deserialize)]
 struct RootConfig {
     #[serde(default)]
     services: Vec<Config>,
+    #[serde(default)]
+    policy: Policy,
     #[serde(flatten)]
     flat: Config,
 }
@@ -77,6 +177,24 @@ impl Config {
             merged_config
                 .external_domains
                 .extend(config.external_domains);
+            for (env_var, service) in config.env_var_services {
+                merged_config
+                    .env_var_services
+                    .entry(env_var)
+                    .or_insert(service);
+            }
+            for (host, service) in config.host_services {
+                merged_config.host_services.entry(host).or_insert(service);
+            }
+            merged_config.gateway.routes.extend(config.gateway.routes);
+            for (name, profile) in config.profiles {
+                merged_config
+                    .profiles
+                    .entry(name)
+                    .or_default()
+                    .origins
+                    .extend(profile.origins);
+            }
         }
 
         Ok(merged_config)
@@ -108,6 +226,17 @@ impl Config {
         Ok(services)
     }
 
+    /// The repo-wide `policy` block of a `carrick.json` (empty when absent).
+    pub fn load_policy(path: &Path) -> Result<Policy, std::io::Error> {
+        let content = std::fs::read_to_string(path)?;
+        let root: RootConfig = serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        root.policy
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(root.policy)
+    }
+
     pub fn is_internal_call(&self, route: &str) -> bool {
         // Check if route starts with any internal env var
         if route.starts_with("ENV_VAR:") {
@@ -195,6 +324,34 @@ mod tests {
         assert!(!config.is_external_call("https://unknown.com/users"));
     }
 
+    #[test]
+    fn test_gateway_section_round_trips_and_merges() {
+        let json = r#"{
+            "serviceName": "web",
+            "gateway": {
+                "routes": [
+                    { "prefix": "/api/orders", "service": "orders" },
+                    { "prefix": "/api/billing", "service": "billing", "rewrite": "/v2" }
+                ]
+            }
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.gateway.routes.len(), 2);
+        assert_eq!(config.gateway.routes[0].rewrite, "");
+        assert_eq!(config.gateway.routes[1].rewrite, "/v2");
+
+        // Absent section stays off the wire; a present one survives the
+        // cross-repo merge (which reads each repo's serialized config).
+        let plain = serde_json::to_value(Config::default()).unwrap();
+        assert!(plain.get("gateway").is_none());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("web.json");
+        std::fs::write(&path, serde_json::to_string(&config).unwrap()).unwrap();
+        let merged = Config::new(vec![path]).unwrap();
+        assert_eq!(merged.gateway.routes, config.gateway.routes);
+    }
+
     #[test]
     fn test_flat_config_has_no_directory() {
         // A flat single-service config leaves the monorepo fields empty.
@@ -285,4 +442,29 @@ mod tests {
         assert_eq!(services.len(), 1);
         assert_eq!(services[0].service_name, Some("flat".to_string()));
     }
+
+    #[test]
+    fn test_load_policy_is_repo_wide_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("carrick.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "services": [{ "name": "api", "directory": "api" }],
+                "policy": { "severity": { "env_var_call": "gap" } }
+            }"#,
+        )
+        .unwrap();
+        let policy = Config::load_policy(&path).unwrap();
+        assert_eq!(
+            policy.severity.get("env_var_call"),
+            Some(&crate::findings::Severity::Gap)
+        );
+        // The policy block doesn't leak into the service configs.
+        assert_eq!(Config::load_services(vec![path.clone()]).unwrap().len(), 1);
+
+        std::fs::write(&path, r#"{ "policy": { "severity": { "nope": "gap" } } }"#).unwrap();
+        let err = Config::load_policy(&path).unwrap_err();
+        assert!(err.to_string().contains("unknown finding kind 'nope'"));
+    }
 }
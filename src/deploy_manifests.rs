@@ -0,0 +1,508 @@
+//! Internal env-var inference from deployment manifests.
+//!
+//! `carrick.json` `internalEnvVars` has to be hand-maintained, and every var it
+//! misses turns a real cross-service call into an `EnvVarCall` advisory. Yet
+//! the repo usually already states the answer in its deployment config:
+//!
+//! ```yaml
+//! # docker-compose.yml
+//! services:
+//!   web:
+//!     environment:
+//!       ORDERS_URL: http://orders-service:3000
+//!   orders-service:
+//!     build: ./orders
+//! ```
+//!
+//! This module reads docker-compose files, Kubernetes manifests
+//! (Deployment/StatefulSet/ConfigMap/Service) and Helm `values.yaml` files in
+//! the repo, collects the names of the services they declare, and classifies
+//! every env var whose value is a URL pointing at one of those services (or at
+//! in-cluster DNS, `orders.default.svc.cluster.local`) as internal, mapped to
+//! that service. The engine folds the result into each service's [`Config`]
+//! (explicit `carrick.json` classifications always win): the vars and hosts
+//! become internal for [`Config::is_internal_call`] and the URL normalizer's
+//! host matching, and their service mapping lets the matcher pair a call with
+//! the producers of the service it reaches.
+//!
+//! Only files named like manifests are read (`docker-compose*.yml`,
+//! `values*.yaml`, any other `*.y(a)ml` as Kubernetes), and only when their
+//! text looks like one — `services:`, `apiVersion:` plus `kind:`, a URL — so
+//! lockfiles and CI configs are never parsed.
+//!
+//! Values that need interpolation (`http://${ORDERS_HOST}`), templated Helm
+//! strings (`{{ .Values.url }}`), and `localhost` are skipped: they don't name
+//! a sibling structurally.
+
+use crate::config::Config;
+use crate::packages::MANIFEST_SKIP_DIRS;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tracing::debug;
+
+/// Env vars and hosts inferred from a repo's deployment manifests.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeployInference {
+    /// Env var name → the sibling service its URL value points at.
+    pub env_var_services: BTreeMap<String, String>,
+    /// Hostnames of the sibling services those values use (`orders-service`,
+    /// `orders.default.svc.cluster.local`) → the service each names, for
+    /// host-based classification and matching of literal URLs.
+    pub host_services: BTreeMap<String, String>,
+}
+
+impl DeployInference {
+    pub fn is_empty(&self) -> bool {
+        self.env_var_services.is_empty() && self.host_services.is_empty()
+    }
+
+    /// Fold the inference into a service config. An env var or host the
+    /// config already classifies as external stays external — the manifests
+    /// only fill gaps, never override a human decision.
+    pub fn apply_to(&self, config: &mut Config) {
+        for (env_var, service) in &self.env_var_services {
+            if config.external_env_vars.contains(env_var) {
+                continue;
+            }
+            config.internal_env_vars.insert(env_var.clone());
+            config
+                .env_var_services
+                .entry(env_var.clone())
+                .or_insert_with(|| service.clone());
+        }
+        for (host, service) in &self.host_services {
+            if config.external_domains.contains(host) {
+                continue;
+            }
+            config.internal_domains.insert(host.clone());
+            config
+                .host_services
+                .entry(host.clone())
+                .or_insert_with(|| service.clone());
+        }
+    }
+}
+
+/// Manifests larger than this are generated output, not deployment config.
+const MAX_MANIFEST_BYTES: u64 = 1 << 20;
+
+/// Deployment manifest flavours this module understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ManifestKind {
+    Compose,
+    Kubernetes,
+    HelmValues,
+}
+
+/// Scan `repo_root` for deployment manifests and infer internal env vars.
+pub fn infer_from_repo(repo_root: &Path) -> DeployInference {
+    let walker = walkdir::WalkDir::new(repo_root)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !(e.file_type().is_dir()
+                    && e.file_name()
+                        .to_str()
+                        .is_some_and(|n| MANIFEST_SKIP_DIRS.contains(&n) || n == ".git"))
+        });
+
+    let mut documents: Vec<(ManifestKind, serde_yaml::Value)> = Vec::new();
+    for entry in walker.flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Some(kind) = entry.file_name().to_str().and_then(classify_file_name) else {
+            continue;
+        };
+        if entry.metadata().is_ok_and(|m| m.len() > MAX_MANIFEST_BYTES) {
+            continue;
+        }
+        let Ok(text) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        if !looks_like(kind, &text) {
+            continue;
+        }
+        for document in parse_documents(&text) {
+            // A generic `*.yaml` is only a Kubernetes manifest if it says so.
+            let kind = match kind {
+                ManifestKind::Kubernetes if !is_kubernetes_object(&document) => continue,
+                other => other,
+            };
+            documents.push((kind, document));
+        }
+    }
+
+    let inference = infer_from_documents(&documents);
+    if !inference.is_empty() {
+        debug!(
+            env_vars = inference.env_var_services.len(),
+            hosts = inference.host_services.len(),
+            "Inferred internal env vars from deployment manifests"
+        );
+    }
+    inference
+}
+
+fn classify_file_name(name: &str) -> Option<ManifestKind> {
+    let (stem, ext) = name.rsplit_once('.')?;
+    if ext != "yml" && ext != "yaml" {
+        return None;
+    }
+    if stem.starts_with("docker-compose") || stem.starts_with("compose") {
+        Some(ManifestKind::Compose)
+    } else if stem == "values" || stem.starts_with("values-") || stem.starts_with("values.") {
+        Some(ManifestKind::HelmValues)
+    } else {
+        Some(ManifestKind::Kubernetes)
+    }
+}
+
+/// Cheap text check run before a file is parsed, so lockfiles, CI configs
+/// and other YAML that merely shares an extension are skipped: compose files
+/// declare `services:`, Kubernetes objects open with `apiVersion:` and
+/// `kind:`, and only a Helm values file holding a URL can name a sibling.
+fn looks_like(kind: ManifestKind, text: &str) -> bool {
+    let has_key = |key: &str| text.lines().any(|line| line.trim_start().starts_with(key));
+    match kind {
+        ManifestKind::Compose => has_key("services:"),
+        ManifestKind::Kubernetes => {
+            text.lines().any(|line| line.starts_with("apiVersion:"))
+                && text.lines().any(|line| line.starts_with("kind:"))
+        }
+        ManifestKind::HelmValues => text.contains("://"),
+    }
+}
+
+/// Every YAML document in a (possibly multi-document) file. Unparseable
+/// documents are dropped: a broken manifest must not fail the scan.
+fn parse_documents(text: &str) -> Vec<serde_yaml::Value> {
+    use serde::Deserialize;
+    serde_yaml::Deserializer::from_str(text)
+        .filter_map(|doc| serde_yaml::Value::deserialize(doc).ok())
+        .filter(|doc| !doc.is_null())
+        .collect()
+}
+
+fn is_kubernetes_object(doc: &serde_yaml::Value) -> bool {
+    doc.get("apiVersion").is_some() && doc.get("kind").is_some()
+}
+
+fn infer_from_documents(documents: &[(ManifestKind, serde_yaml::Value)]) -> DeployInference {
+    // Pass 1: every service name the manifests declare.
+    let mut services: BTreeSet<String> = BTreeSet::new();
+    for (kind, doc) in documents {
+        match kind {
+            ManifestKind::Compose => {
+                if let Some(map) = doc.get("services").and_then(|s| s.as_mapping()) {
+                    for (name, service) in map {
+                        services.extend(name.as_str().map(str::to_string));
+                        for key in ["container_name", "hostname"] {
+                            services.extend(
+                                service
+                                    .get(key)
+                                    .and_then(|v| v.as_str())
+                                    .map(str::to_string),
+                            );
+                        }
+                    }
+                }
+            }
+            ManifestKind::Kubernetes => {
+                let kind = doc.get("kind").and_then(|k| k.as_str()).unwrap_or_default();
+                if matches!(kind, "Service" | "Deployment" | "StatefulSet") {
+                    services.extend(
+                        doc.get("metadata")
+                            .and_then(|m| m.get("name"))
+                            .and_then(|n| n.as_str())
+                            .map(str::to_string),
+                    );
+                }
+            }
+            ManifestKind::HelmValues => {}
+        }
+    }
+
+    // Pass 2: env vars whose URL value names one of those services.
+    let mut inference = DeployInference::default();
+    for (_, doc) in documents {
+        let mut pairs = Vec::new();
+        collect_env_pairs(doc, &mut pairs);
+        for (name, value) in pairs {
+            let Some(host) = url_host(&value) else {
+                continue;
+            };
+            if let Some(service) = sibling_service(&host, &services) {
+                inference
+                    .host_services
+                    .entry(host)
+                    .or_insert_with(|| service.clone());
+                inference.env_var_services.entry(name).or_insert(service);
+            }
+        }
+    }
+    inference
+}
+
+/// Collect `(ENV_NAME, value)` pairs anywhere in a document, in the three
+/// shapes manifests use: a mapping entry (`ORDERS_URL: http://...` — compose
+/// `environment`, ConfigMap `data`, Helm `env`), a Kubernetes env entry
+/// (`{ name: ORDERS_URL, value: http://... }`), and a compose list item
+/// (`- ORDERS_URL=http://...`).
+fn collect_env_pairs(value: &serde_yaml::Value, out: &mut Vec<(String, String)>) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            if let (Some(name), Some(val)) = (
+                map.get("name").and_then(|n| n.as_str()),
+                map.get("value").and_then(|v| v.as_str()),
+            ) && is_env_var_name(name)
+            {
+                out.push((name.to_string(), val.to_string()));
+            }
+            for (key, val) in map {
+                if let (Some(key), Some(val)) = (key.as_str(), val.as_str())
+                    && is_env_var_name(key)
+                {
+                    out.push((key.to_string(), val.to_string()));
+                } else {
+                    collect_env_pairs(val, out);
+                }
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                if let Some((key, val)) = item.as_str().and_then(|s| s.split_once('='))
+                    && is_env_var_name(key)
+                {
+                    out.push((key.to_string(), val.to_string()));
+                } else {
+                    collect_env_pairs(item, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `UPPER_SNAKE_CASE` with at least one letter — the env-var naming
+/// convention, which keeps `name`/`image`/`ports` keys out.
+fn is_env_var_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().any(|c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Host of an `http(s)://` / `ws(s)://` / `grpc://` URL value, lowercased and
+/// port-stripped. `None` for non-URLs and for values that still need
+/// interpolation or templating.
+fn url_host(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    if value.contains("${") || value.contains("{{") || value.contains('$') {
+        return None;
+    }
+    let (_, rest) = value.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let authority = authority.rsplit('@').next()?;
+    let host = authority.split(':').next()?.to_ascii_lowercase();
+    if host.is_empty() || host == "localhost" || host == "127.0.0.1" || host == "0.0.0.0" {
+        return None;
+    }
+    Some(host)
+}
+
+/// The declared service a host names: the host itself, or the first label of
+/// an in-cluster DNS name (`orders.default`, `orders.default.svc`,
+/// `orders.default.svc.cluster.local`). An in-cluster name resolves even when
+/// its service is declared outside this repo (Helm values pointing at a
+/// sibling chart).
+fn sibling_service(host: &str, services: &BTreeSet<String>) -> Option<String> {
+    if services.contains(host) {
+        return Some(host.to_string());
+    }
+    let (first, rest) = host.split_once('.')?;
+    let is_cluster_dns = rest.ends_with(".svc")
+        || rest.ends_with(".svc.cluster.local")
+        || (services.contains(first) && !rest.contains('.'));
+    is_cluster_dns.then(|| first.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, rel: &str, content: &str) {
+        let path = dir.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn compose_env_pointing_at_sibling_is_internal() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "docker-compose.yml",
+            r#"
+services:
+  web:
+    environment:
+      ORDERS_URL: http://orders-service:3000
+      STRIPE_URL: https://api.stripe.com
+      LOCAL_URL: http://localhost:4000
+  worker:
+    environment:
+      - USERS_API=http://users/api
+      - TEMPLATED=http://${USERS_HOST}
+  orders-service:
+    build: ./orders
+  users:
+    image: users:latest
+"#,
+        );
+
+        let inference = infer_from_repo(dir.path());
+        assert_eq!(
+            inference.env_var_services,
+            BTreeMap::from([
+                ("ORDERS_URL".to_string(), "orders-service".to_string()),
+                ("USERS_API".to_string(), "users".to_string()),
+            ])
+        );
+        assert_eq!(
+            inference.host_services,
+            BTreeMap::from([
+                ("orders-service".to_string(), "orders-service".to_string()),
+                ("users".to_string(), "users".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn kubernetes_deployment_configmap_and_cluster_dns() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "k8s/web.yaml",
+            r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+spec:
+  template:
+    spec:
+      containers:
+        - name: web
+          env:
+            - name: ORDERS_URL
+              value: http://orders.default.svc.cluster.local:8080
+            - name: LOG_LEVEL
+              value: debug
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: web-config
+data:
+  INVENTORY_URL: http://inventory:9000/v1
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: inventory
+"#,
+        );
+        // A YAML file that is not a Kubernetes object is ignored, and is
+        // never even parsed.
+        write(
+            dir.path(),
+            ".github/workflows/ci.yml",
+            "env:\n  DEPLOY_URL: http://inventory:9000\n",
+        );
+        assert!(!looks_like(
+            ManifestKind::Kubernetes,
+            "env:\n  DEPLOY_URL: http://inventory:9000\n"
+        ));
+        assert!(!looks_like(
+            ManifestKind::Kubernetes,
+            "lockfileVersion: '9.0'\npackages:\n  /kind@1.0.0: {}\n"
+        ));
+
+        let inference = infer_from_repo(dir.path());
+        assert_eq!(
+            inference.env_var_services,
+            BTreeMap::from([
+                ("INVENTORY_URL".to_string(), "inventory".to_string()),
+                ("ORDERS_URL".to_string(), "orders".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn helm_values_resolve_cluster_dns() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "charts/web/values.yaml",
+            r#"
+image: web
+env:
+  BILLING_URL: http://billing.payments.svc:80
+  DOCS_URL: https://docs.example.com
+  TEMPLATED: "{{ .Values.billing.url }}"
+"#,
+        );
+        // Manifests inside dependency/build dirs are not the repo's own.
+        write(
+            dir.path(),
+            "node_modules/pkg/docker-compose.yml",
+            "services:\n  a:\n    environment:\n      B_URL: http://b\n  b: {}\n",
+        );
+
+        let inference = infer_from_repo(dir.path());
+        assert_eq!(
+            inference.env_var_services,
+            BTreeMap::from([("BILLING_URL".to_string(), "billing".to_string())])
+        );
+    }
+
+    #[test]
+    fn apply_to_respects_explicit_external_classification() {
+        let inference = DeployInference {
+            env_var_services: BTreeMap::from([
+                ("ORDERS_URL".to_string(), "orders".to_string()),
+                ("PARTNER_URL".to_string(), "partner".to_string()),
+            ]),
+            host_services: BTreeMap::from([
+                ("orders".to_string(), "orders".to_string()),
+                ("partner".to_string(), "partner".to_string()),
+            ]),
+        };
+        let mut config = Config {
+            external_env_vars: ["PARTNER_URL".to_string()].into_iter().collect(),
+            external_domains: ["partner".to_string()].into_iter().collect(),
+            ..Default::default()
+        };
+        inference.apply_to(&mut config);
+
+        assert!(config.is_internal_call("ENV_VAR:ORDERS_URL:/orders"));
+        assert!(!config.is_internal_call("ENV_VAR:PARTNER_URL:/x"));
+        assert_eq!(
+            config
+                .env_var_services
+                .get("ORDERS_URL")
+                .map(String::as_str),
+            Some("orders")
+        );
+        assert_eq!(
+            config.host_services.get("orders").map(String::as_str),
+            Some("orders")
+        );
+        assert!(!config.host_services.contains_key("partner"));
+        assert!(config.internal_domains.contains("orders"));
+        assert!(!config.internal_domains.contains("partner"));
+    }
+}
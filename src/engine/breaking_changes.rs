@@ -0,0 +1,379 @@
+//! Breaking-change detection for producers on pull requests.
+//!
+//! `PrDelta` only says which operations a PR added or removed. A PR that
+//! keeps a route but changes its type (drops a field, makes one optional,
+//! narrows an enum) breaks its consumers in other repos silently, until they
+//! rescan. On a PR run this pass finds every producer manifest entry whose
+//! resolved type differs from the previous index's entry for the same
+//! service and `OperationKey`, and runs the v2 check for the affected pairs
+//! twice — once against the previous index's `capture_stub`, once against
+//! this run's. A consumer that was compatible before and is incompatible now
+//! is broken by this PR; each affected operation becomes one
+//! [`Issue::BreakingChange`] naming the broken consumers and the
+//! field-level change.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use tracing::debug;
+
+use super::type_compat_v2::{BuiltPair, build_check_pairs, check_pairs};
+use crate::analyzer::{PairCheckOutcome, strip_ci_workspace_prefix};
+use crate::cloud_storage::{CloudRepoData, ManifestRole, ManifestTypeKind, TypeManifestEntry};
+use crate::findings::{Finding, Issue};
+use crate::operation::OperationKey;
+use crate::services::TypeSidecar;
+use crate::services::type_sidecar::VerdictBucket;
+use crate::type_diff;
+
+/// A producer type this run changed relative to the previous index.
+struct ChangedProducer {
+    service: String,
+    key: OperationKey,
+    type_kind: ManifestTypeKind,
+    before: String,
+    after: String,
+}
+
+/// Identity of one (producer op, consumer call site) pair, independent of
+/// the type aliases the two scans happened to emit.
+type PairIdentity = (String, String, String, String, String, u32, bool);
+
+fn pair_identity(
+    producer_service: &str,
+    pseudo_method: &str,
+    identity: &str,
+    consumer_service: &str,
+    consumer_file: &str,
+    consumer_line: u32,
+    type_kind: ManifestTypeKind,
+) -> PairIdentity {
+    (
+        producer_service.to_string(),
+        pseudo_method.to_string(),
+        identity.to_string(),
+        consumer_service.to_string(),
+        consumer_file.to_string(),
+        consumer_line,
+        type_kind == ManifestTypeKind::Request,
+    )
+}
+
+fn built_identity(pair: &BuiltPair) -> PairIdentity {
+    pair_identity(
+        &pair.producer_service,
+        &pair.pseudo_method,
+        &pair.identity,
+        &pair.consumer_service,
+        &pair.consumer_file,
+        pair.consumer_line,
+        pair.type_kind,
+    )
+}
+
+fn outcome_identity(outcome: &PairCheckOutcome) -> PairIdentity {
+    pair_identity(
+        &outcome.producer_service,
+        &outcome.pseudo_method,
+        &outcome.identity,
+        &outcome.consumer_service,
+        &outcome.consumer_file,
+        outcome.consumer_line,
+        outcome.type_kind,
+    )
+}
+
+fn service_id(repo: &CloudRepoData) -> &str {
+    repo.service_name.as_deref().unwrap_or(&repo.repo_name)
+}
+
+/// Find the consumers this PR's producer changes break. `previous` is this
+/// repo's last-indexed data, `current` this run's, `peers` every other
+/// indexed repo.
+pub(crate) fn detect(
+    sidecar: &TypeSidecar,
+    previous: &[CloudRepoData],
+    current: &[CloudRepoData],
+    peers: &[CloudRepoData],
+) -> Vec<Finding> {
+    let changed = changed_producers(previous, current);
+    if changed.is_empty() {
+        return Vec::new();
+    }
+    let is_changed = |pair: &BuiltPair| {
+        changed.iter().any(|c| {
+            c.service == pair.producer_service
+                && c.type_kind == pair.type_kind
+                && super::type_compat_v2::join_identity(&c.key)
+                    .is_some_and(|(m, id)| m == pair.pseudo_method && id == pair.identity)
+        })
+    };
+
+    let mut before_pairs = build_check_pairs(previous.iter().chain(peers));
+    let mut after_pairs = build_check_pairs(current.iter().chain(peers));
+    before_pairs.retain(|p| p.pre_verdict.is_none() && is_changed(p));
+    after_pairs.retain(|p| p.pre_verdict.is_none() && is_changed(p));
+    // Only consumers present on both sides can regress; new consumers are
+    // ordinary type mismatches.
+    let before_ids: HashSet<PairIdentity> = before_pairs.iter().map(built_identity).collect();
+    after_pairs.retain(|p| before_ids.contains(&built_identity(p)));
+    let after_ids: HashSet<PairIdentity> = after_pairs.iter().map(built_identity).collect();
+    before_pairs.retain(|p| after_ids.contains(&built_identity(p)));
+    if after_pairs.is_empty() {
+        return Vec::new();
+    }
+    debug!(
+        "Breaking-change check: {} changed producer type(s), {} consumer pair(s)",
+        changed.len(),
+        after_pairs.len()
+    );
+
+    let compatible_before: HashSet<PairIdentity> =
+        check_pairs(sidecar, previous.iter().chain(peers), before_pairs)
+            .iter()
+            .filter(|o| o.bucket == VerdictBucket::Compatible)
+            .map(outcome_identity)
+            .collect();
+    let broken: Vec<PairCheckOutcome> =
+        check_pairs(sidecar, current.iter().chain(peers), after_pairs)
+            .into_iter()
+            .filter(|o| {
+                o.bucket == VerdictBucket::Incompatible
+                    && compatible_before.contains(&outcome_identity(o))
+            })
+            .collect();
+    breaking_findings(&changed, &broken)
+}
+
+/// Producer entries whose resolved type differs from the previous index's
+/// entry for the same service, operation, and type kind. Entries without a
+/// resolved definition on either side can't be compared and are skipped.
+fn changed_producers(
+    previous: &[CloudRepoData],
+    current: &[CloudRepoData],
+) -> Vec<ChangedProducer> {
+    let definition = |entry: &TypeManifestEntry| {
+        entry
+            .expanded_definition
+            .clone()
+            .or_else(|| entry.resolved_definition.clone())
+    };
+    let mut before: BTreeMap<(String, String, bool), String> = BTreeMap::new();
+    for repo in previous {
+        for entry in repo.type_manifest.iter().flatten() {
+            if entry.role != ManifestRole::Producer {
+                continue;
+            }
+            if let Some(text) = definition(entry) {
+                before.insert(
+                    (
+                        service_id(repo).to_string(),
+                        entry.key.canonical(),
+                        entry.type_kind == ManifestTypeKind::Request,
+                    ),
+                    text,
+                );
+            }
+        }
+    }
+
+    let mut changed = Vec::new();
+    let mut seen = HashSet::new();
+    for repo in current {
+        for entry in repo.type_manifest.iter().flatten() {
+            if entry.role != ManifestRole::Producer {
+                continue;
+            }
+            let id = (
+                service_id(repo).to_string(),
+                entry.key.canonical(),
+                entry.type_kind == ManifestTypeKind::Request,
+            );
+            let (Some(old), Some(new)) = (before.get(&id), definition(entry)) else {
+                continue;
+            };
+            if *old != new && seen.insert(id) {
+                changed.push(ChangedProducer {
+                    service: service_id(repo).to_string(),
+                    key: entry.key.clone(),
+                    type_kind: entry.type_kind,
+                    before: old.clone(),
+                    after: new,
+                });
+            }
+        }
+    }
+    changed
+}
+
+/// One finding per broken producer operation.
+fn breaking_findings(changed: &[ChangedProducer], broken: &[PairCheckOutcome]) -> Vec<Finding> {
+    #[derive(Default)]
+    struct Group {
+        consumers: BTreeSet<String>,
+        call_sites: BTreeSet<String>,
+        kinds: BTreeSet<bool>,
+        diagnostics: BTreeSet<String>,
+    }
+    let mut groups: BTreeMap<(String, String, String), Group> = BTreeMap::new();
+    for outcome in broken {
+        let group = groups
+            .entry((
+                outcome.producer_service.clone(),
+                outcome.pseudo_method.clone(),
+                outcome.identity.clone(),
+            ))
+            .or_default();
+        group.consumers.insert(outcome.consumer_service.clone());
+        let location = format!("{}:{}", outcome.consumer_file, outcome.consumer_line);
+        group
+            .call_sites
+            .insert(strip_ci_workspace_prefix(&location).to_string());
+        group
+            .kinds
+            .insert(outcome.type_kind == ManifestTypeKind::Request);
+        group
+            .diagnostics
+            .extend(outcome.diagnostic.clone().filter(|d| !d.is_empty()));
+    }
+
+    groups
+        .into_iter()
+        .map(|((service, method, path), group)| {
+            let mut changes = Vec::new();
+            for change in changed.iter().filter(|c| {
+                c.service == service
+                    && group
+                        .kinds
+                        .contains(&(c.type_kind == ManifestTypeKind::Request))
+                    && super::type_compat_v2::join_identity(&c.key)
+                        .is_some_and(|(m, id)| m == method && id == path)
+            }) {
+                let side = match change.type_kind {
+                    ManifestTypeKind::Request => "request",
+                    ManifestTypeKind::Response => "response",
+                };
+                changes.extend(
+                    type_diff::diff(&change.before, &change.after)
+                        .into_iter()
+                        .filter(|c| c.is_breaking(change.type_kind))
+                        .map(|c| format!("{}: {}", side, c)),
+                );
+            }
+            if changes.is_empty() {
+                changes.extend(group.diagnostics);
+            }
+            Finding::breaking_change(
+                method,
+                path,
+                Some(service),
+                group.consumers.into_iter().collect(),
+                changes,
+                group.call_sites.into_iter().collect(),
+            )
+        })
+        .collect()
+}
+
+/// Drop type mismatches a breaking change already reports: same operation,
+/// every call site covered. The breaking change says more (which PR change,
+/// which field), so listing both would double-count the risk.
+pub(crate) fn supersede_type_mismatches(findings: &mut Vec<Finding>, breaking: &[Finding]) {
+    findings.retain(|finding| {
+        let Issue::TypeMismatch {
+            method,
+            path,
+            call_sites,
+            ..
+        } = &finding.issue
+        else {
+            return true;
+        };
+        !breaking.iter().any(|b| {
+            matches!(&b.issue, Issue::BreakingChange { method: m, path: p, call_sites: sites, .. }
+                if m == method && p == path && call_sites.iter().all(|s| sites.contains(s)))
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(consumer: &str, line: u32, diagnostic: &str) -> PairCheckOutcome {
+        PairCheckOutcome {
+            pair_key: format!("orders/A~{}/B", consumer),
+            pseudo_method: "GET".to_string(),
+            identity: "/orders/:id".to_string(),
+            consumer_file: format!("/home/runner/work/{0}/{0}/src/client.ts", consumer),
+            consumer_line: line,
+            type_kind: ManifestTypeKind::Response,
+            bucket: VerdictBucket::Incompatible,
+            gate: None,
+            diagnostic: Some(diagnostic.to_string()),
+            fields: Vec::new(),
+            producer_alias: "A".to_string(),
+            consumer_alias: "B".to_string(),
+            producer_service: "orders".to_string(),
+            consumer_service: consumer.to_string(),
+        }
+    }
+
+    #[test]
+    fn groups_broken_consumers_per_operation() {
+        let changed = vec![ChangedProducer {
+            service: "orders".to_string(),
+            key: OperationKey::http("GET", "/orders/:id"),
+            type_kind: ManifestTypeKind::Response,
+            before: "{ id: string; email: string }".to_string(),
+            after: "{ id: string }".to_string(),
+        }];
+        let findings = breaking_findings(
+            &changed,
+            &[
+                outcome("web", 12, "Property 'email' is missing"),
+                outcome("billing", 40, "Property 'email' is missing"),
+            ],
+        );
+        assert_eq!(
+            findings,
+            vec![Finding::breaking_change(
+                "GET",
+                "/orders/:id",
+                Some("orders".to_string()),
+                vec!["billing".to_string(), "web".to_string()],
+                vec!["response: `email` removed".to_string()],
+                vec![
+                    "src/client.ts:12".to_string(),
+                    "src/client.ts:40".to_string()
+                ],
+            )]
+        );
+
+        let mut all = vec![
+            Finding::type_mismatch(
+                "GET",
+                "/orders/:id",
+                None,
+                vec!["src/client.ts:12".into()],
+                "A",
+                "B",
+                "",
+            ),
+            Finding::type_mismatch(
+                "GET",
+                "/orders/:id",
+                None,
+                vec!["src/other.ts:3".into()],
+                "A",
+                "B",
+                "",
+            ),
+        ];
+        supersede_type_mismatches(&mut all, &findings);
+        assert_eq!(all.len(), 1);
+        assert!(matches!(
+            &all[0].issue,
+            Issue::TypeMismatch { call_sites, .. } if call_sites == &["src/other.ts:3"]
+        ));
+    }
+}
@@ -42,6 +42,7 @@ use swc_common::{
 };
 use swc_ecma_visit::VisitWith;
 
+mod breaking_changes;
 mod type_compat_v2;
 
 /// Current cache format version. Increment when FileAnalysisResult schema changes.
@@ -139,6 +140,32 @@ fn head_sha_from_event() -> Option<String> {
         .map(str::to_string)
 }
 
+/// Per-run switches for the analysis engine, set from the CLI.
+#[derive(Debug, Clone, Default)]
+pub struct EngineOptions {
+    /// Skip the incremental cache and run a full analysis.
+    pub no_cache: bool,
+    /// `carrick baseline`: record the run's findings to
+    /// `carrick-baseline.json` instead of reporting them. Never uploads to
+    /// the index or relays a PR result.
+    pub write_baseline: bool,
+    /// `--fail-on`: the run reports how many new findings are at or above
+    /// this severity, and the CLI exits non-zero when there are any.
+    pub fail_on: Option<crate::findings::Severity>,
+    /// `carrick impact`: report the blast radius of the changes since a base
+    /// revision instead of the findings. Never uploads to the index or
+    /// relays a PR result.
+    pub impact: Option<crate::impact::ImpactOptions>,
+}
+
+/// What a completed run reports back to the CLI.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunOutcome {
+    /// New (non-baselined) findings at or above [`EngineOptions::fail_on`];
+    /// always 0 without it.
+    pub failing_findings: usize,
+}
+
 #[allow(dead_code)]
 pub async fn run_analysis_engine<T: CloudStorage>(
     storage: T,
@@ -159,7 +186,23 @@ pub async fn run_analysis_engine_with_sidecar<T: CloudStorage>(
     sidecar: Option<&TypeSidecar>,
     no_cache: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let result = run_analysis_engine_inner(&storage, repo_path, sidecar, no_cache).await;
+    let options = EngineOptions {
+        no_cache,
+        ..EngineOptions::default()
+    };
+    run_analysis_engine_with_options(storage, repo_path, sidecar, &options)
+        .await
+        .map(|_| ())
+}
+
+/// [`run_analysis_engine_with_sidecar`] with the full set of CLI switches.
+pub async fn run_analysis_engine_with_options<T: CloudStorage>(
+    storage: T,
+    repo_path: &str,
+    sidecar: Option<&TypeSidecar>,
+    options: &EngineOptions,
+) -> Result<RunOutcome, Box<dyn std::error::Error>> {
+    let result = run_analysis_engine_inner(&storage, repo_path, sidecar, options).await;
     upload_run_logs(&storage, repo_path).await;
     result
 }
@@ -168,9 +211,11 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
     storage: &T,
     repo_path: &str,
     sidecar: Option<&TypeSidecar>,
-    no_cache: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let should_upload = should_upload_data();
+    options: &EngineOptions,
+) -> Result<RunOutcome, Box<dyn std::error::Error>> {
+    let no_cache = options.no_cache;
+    // Baseline and impact runs report locally; they must not refresh the index.
+    let should_upload = should_upload_data() && !options.write_baseline && options.impact.is_none();
     debug!(upload = should_upload, "Running Carrick in CI mode");
 
     // 1. Health check
@@ -192,6 +237,7 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
     //    single-service case; one per directory for a monorepo carrick.json).
     let repo_name = get_repository_name(repo_path);
     let services = resolve_services(repo_path)?;
+    let policy = resolve_policy(repo_path)?;
     let multi_service = services.len() > 1;
     if multi_service {
         info!(
@@ -331,6 +377,18 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         (false, std::collections::HashSet::new())
     };
 
+    // This repo's previously-indexed data, kept on PR runs so producer type
+    // changes can be checked against the consumers they used to satisfy.
+    let previous_self_data: Vec<CloudRepoData> = if is_pr_run {
+        all_repo_data
+            .iter()
+            .filter(|repo| repo.repo_name == repo_name)
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // 6. Cross-repo analysis (reuse already-downloaded data).
     // Remove this repo's downloaded copies so the freshly-analyzed services
     // are the ones used.
@@ -416,8 +474,36 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         Vec::new()
     };
 
+    // `carrick impact`: the operations the change set touches, read off this
+    // run's services before the analyzer takes ownership of them.
+    let impact_seeds = match &options.impact {
+        Some(impact) => {
+            let changed = get_changed_files(repo_path, &impact.base).ok_or_else(|| {
+                format!(
+                    "Could not diff against '{}' to find changed files",
+                    impact.base
+                )
+            })?;
+            let seeds = crate::impact::affected_operations(&changed, &current_services_data);
+            Some((changed, seeds))
+        }
+        None => None,
+    };
+
+    // Producer type changes that break consumers compatible with the previous
+    // index. Runs before the analyzer takes ownership of the repo data.
+    let breaking_changes = match sidecar {
+        Some(sidecar) if !previous_self_data.is_empty() => breaking_changes::detect(
+            sidecar,
+            &previous_self_data,
+            &current_services_data,
+            &all_repo_data,
+        ),
+        _ => Vec::new(),
+    };
+
     let sp = logging::spinner("Running cross-repo analysis...");
-    let analyzer =
+    let mut analyzer =
         match build_cross_repo_analyzer(all_repo_data, current_services_data, sidecar).await {
             Ok(analyzer) => analyzer,
             Err(e) => {
@@ -437,7 +523,43 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         };
     logging::finish_spinner(&sp, "Cross-repo analysis complete");
 
-    let results = analyzer.get_results();
+    analyzer.set_policy(policy.clone());
+    let mut results = analyzer.get_results();
+    if !breaking_changes.is_empty() {
+        breaking_changes::supersede_type_mismatches(&mut results.findings, &breaking_changes);
+        results.findings.splice(0..0, breaking_changes);
+    }
+
+    if let (Some(impact), Some((changed, seeds))) = (&options.impact, impact_seeds) {
+        let report = crate::impact::ImpactReport::build(
+            &impact.base,
+            changed,
+            seeds,
+            &results.cross_repo_matches,
+        );
+        if impact.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            println!("{}", report.to_markdown());
+        }
+        return Ok(RunOutcome::default());
+    }
+
+    // Inline `carrick-ignore-next-line` directives drop findings outright.
+    // Anchoring runs on what survives, so a fingerprint only reflects the
+    // call sites actually reported.
+    let repo_root = Path::new(repo_path);
+    let suppressions = crate::suppressions::InlineSuppressions::scan(repo_root);
+    let (findings, suppressed) =
+        suppressions.apply(std::mem::take(&mut results.findings), &results.endpoints);
+    if suppressed > 0 {
+        info!(
+            "Suppressed {} finding(s) via carrick-ignore-next-line",
+            suppressed
+        );
+    }
+    let findings = crate::finding_anchors::attach_anchors(findings, repo_root);
+    results.findings = policy.apply(findings);
 
     // Eval harness output mode: emit a machine-readable projection of the
     // results and skip the human Markdown report + PR-comment relay. Consumed
@@ -448,7 +570,7 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         let projection =
             crate::eval_output::EvalProjection::from_results(&results, &eval_type_manifest);
         println!("{}", serde_json::to_string_pretty(&projection)?);
-        return Ok(());
+        return Ok(RunOutcome::default());
     }
 
     // 6b. Upload each service's data, now carrying the per-pair type-compat
@@ -469,6 +591,23 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         upload_service_payloads(storage, &payloads).await?;
     }
 
+    // The baseline splits the findings into new vs. already accepted.
+    if options.write_baseline {
+        let baseline = crate::baseline::Baseline::from_findings(&results.findings);
+        let path = baseline.write(repo_root)?;
+        println!(
+            "Recorded {} finding(s) in {}",
+            baseline.findings.len(),
+            path.display()
+        );
+        return Ok(RunOutcome::default());
+    }
+    if let Some(baseline) = crate::baseline::Baseline::load(repo_root)? {
+        let (new, baselined) = baseline.partition(std::mem::take(&mut results.findings));
+        results.findings = new;
+        results.baselined_findings = baselined;
+    }
+
     let topology = crate::findings::Topology {
         repo_name: repo_name.clone(),
         local_service_count,
@@ -493,6 +632,7 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
             calls: results.calls.len(),
         },
         findings: results.findings.clone(),
+        baselined: results.baselined_findings.clone(),
         delta: pr_delta.clone(),
         verified: results
             .verified_endpoints
@@ -510,6 +650,7 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         },
     });
 
+    let new_findings = results.findings.clone();
     let formatted = crate::formatter::FormattedOutput::new(results, topology, pr_delta);
     formatted.print();
 
@@ -519,7 +660,11 @@ async fn run_analysis_engine_inner<T: CloudStorage>(
         warn!("Failed to post PR result: {}", e);
     }
 
-    Ok(())
+    Ok(RunOutcome {
+        failing_findings: options.fail_on.map_or(0, |threshold| {
+            crate::policy::count_failing(&new_findings, threshold)
+        }),
+    })
 }
 
 /// Best-effort upload of the current run's log tail to S3.
@@ -733,11 +878,28 @@ fn enforce_payload_size_limit(data: &mut CloudRepoData) {
     }
 }
 
-/// Get files changed between a base commit and HEAD.
+/// Get source files changed between a base commit and HEAD.
 /// Returns relative paths matching the file discovery format.
 fn get_changed_files(repo_path: &str, base_commit: &str) -> Option<Vec<String>> {
+    let changed = get_changed_paths(repo_path, base_commit)?
+        .into_iter()
+        .filter(|line| {
+            let ext = Path::new(line)
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("");
+            matches!(ext, "ts" | "tsx" | "js" | "jsx")
+        })
+        .collect();
+
+    Some(changed)
+}
+
+/// Get every path changed between a base commit and HEAD, relative to
+/// `repo_path` (changes outside it are left out).
+fn get_changed_paths(repo_path: &str, base_commit: &str) -> Option<Vec<String>> {
     let output = std::process::Command::new("git")
-        .args(["diff", "--name-only", base_commit, "HEAD"])
+        .args(["diff", "--name-only", "--relative", base_commit, "HEAD"])
         .current_dir(repo_path)
         // Clear git env vars so git uses repo_path for repo discovery, not an
         // ambient GIT_DIR / GIT_WORK_TREE inherited from a parent process (e.g.
@@ -783,13 +945,6 @@ fn get_changed_files(repo_path: &str, base_commit: &str) -> Option<Vec<String>>
     let changed: Vec<String> = stdout
         .lines()
         .filter(|line| !line.is_empty())
-        .filter(|line| {
-            let ext = Path::new(line)
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("");
-            matches!(ext, "ts" | "tsx" | "js" | "jsx")
-        })
         .map(|line| line.to_string())
         .collect();
 
@@ -893,6 +1048,212 @@ fn strip_diagnostic_fields(
     }
 }
 
+/// A changed path's text at HEAD and at `base_commit`; either is missing
+/// when the file was added or deleted in between.
+fn changed_texts(repo_path: &str, base_commit: &str, relative: &str) -> Vec<String> {
+    let mut texts: Vec<String> = std::fs::read_to_string(Path::new(repo_path).join(relative))
+        .into_iter()
+        .collect();
+    let previous = std::process::Command::new("git")
+        .args(["show", &format!("{}:./{}", base_commit, relative)])
+        .current_dir(repo_path)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .env_remove("GIT_INDEX_FILE")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok());
+    texts.extend(previous);
+    texts
+}
+
+/// The `httpClients[].import` modules a `carrick.json` text declares, at the
+/// root or under any service.
+fn declared_wrapper_modules(text: &str, out: &mut HashSet<String>) {
+    fn walk(value: &serde_json::Value, out: &mut HashSet<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::Array(clients)) = map.get("httpClients") {
+                    for import in clients.iter().filter_map(|c| c.get("import")?.as_str()) {
+                        out.insert(import.rsplit('/').next().unwrap_or(import).to_string());
+                    }
+                }
+                map.values().for_each(|v| walk(v, out));
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| walk(v, out)),
+            _ => {}
+        }
+    }
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
+        walk(&value, out);
+    }
+}
+
+/// Repo-relative files whose cached results were built from repo-wide inputs
+/// that `changed` touches, so they're re-analyzed though they didn't change:
+///
+/// - a `carrick.json` (root or service): files mentioning a client wrapper
+///   module it declares, before or after the edit;
+/// - NestJS routing (global prefix, `RouterModule`, versioning): every
+///   controller;
+/// - `serverless.yml`, SAM templates, SST/CDK definitions: the handlers they
+///   name now, and files whose cached endpoints came from one;
+/// - axios defaults and interceptors: every file with cached data calls;
+/// - a module defining HTTP client instances: the files importing it.
+///
+/// Changed text is read at both commits, so a deleted prefix or definition
+/// file counts too.
+fn repo_input_dependents(
+    repo_path: &str,
+    base_commit: &str,
+    changed: &HashSet<String>,
+    service_root: &Path,
+    files: &[PathBuf],
+    cached: &HashMap<String, crate::agents::file_analyzer_agent::FileAnalysisResult>,
+    normalize: &dyn Fn(&PathBuf) -> String,
+) -> HashSet<String> {
+    let is_source = |p: &str| {
+        matches!(
+            Path::new(p).extension().and_then(|e| e.to_str()),
+            Some("ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs")
+        )
+    };
+    let texts: Vec<(&String, Vec<String>)> = changed
+        .iter()
+        .map(|p| (p, changed_texts(repo_path, base_commit, p)))
+        .collect();
+    let source_mentions = |markers: &[&str]| {
+        texts.iter().any(|(p, texts)| {
+            is_source(p) && texts.iter().any(|t| markers.iter().any(|m| t.contains(m)))
+        })
+    };
+    let read = |f: &PathBuf| std::fs::read_to_string(f).unwrap_or_default();
+    let mut dependents = HashSet::new();
+
+    let mut wrapper_modules = HashSet::new();
+    for (path, texts) in &texts {
+        if Path::new(path.as_str())
+            .file_name()
+            .is_some_and(|n| n == "carrick.json")
+        {
+            texts
+                .iter()
+                .for_each(|t| declared_wrapper_modules(t, &mut wrapper_modules));
+        }
+    }
+    if !wrapper_modules.is_empty() {
+        for file in files {
+            let content = read(file);
+            if wrapper_modules.iter().any(|m| content.contains(m.as_str())) {
+                dependents.insert(normalize(file));
+            }
+        }
+    }
+
+    if source_mentions(crate::nestjs::ROUTING_MARKERS) {
+        dependents.extend(
+            files
+                .iter()
+                .filter(|f| read(f).contains("@Controller"))
+                .map(normalize),
+        );
+    }
+
+    let templates_changed = texts.iter().any(|(path, texts)| {
+        let name = Path::new(path.as_str())
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("");
+        texts
+            .iter()
+            .any(|t| crate::serverless::is_route_template(name, t))
+    });
+    if templates_changed || source_mentions(crate::serverless::DEFINITION_MARKERS) {
+        let routing = crate::serverless::ServerlessRouting::collect(service_root, files);
+        let handlers: HashSet<&Path> = routing.handler_files().collect();
+        dependents.extend(
+            files
+                .iter()
+                .filter(|f| {
+                    f.canonicalize()
+                        .is_ok_and(|c| handlers.contains(c.as_path()))
+                })
+                .map(normalize),
+        );
+        let patterns: Vec<&str> = crate::serverless::RouteSource::ALL
+            .iter()
+            .map(|s| s.pattern())
+            .collect();
+        dependents.extend(
+            cached
+                .iter()
+                .filter(|(_, r)| {
+                    r.endpoints
+                        .iter()
+                        .any(|e| patterns.contains(&e.pattern_matched.as_str()))
+                })
+                .map(|(path, _)| path.clone()),
+        );
+    }
+
+    if source_mentions(crate::headers::CLIENT_DEFAULT_MARKERS) {
+        dependents.extend(
+            cached
+                .iter()
+                .filter(|(_, r)| !r.data_calls.is_empty())
+                .map(|(path, _)| path.clone()),
+        );
+    }
+
+    let instance_modules: Vec<PathBuf> = texts
+        .iter()
+        .filter(|(path, texts)| {
+            is_source(path)
+                && texts.iter().any(|t| {
+                    crate::http_instances::INSTANCE_MARKERS
+                        .iter()
+                        .any(|m| t.contains(m))
+                })
+        })
+        .map(|(path, _)| Path::new(repo_path).join(path.as_str()))
+        .collect();
+    if !instance_modules.is_empty() {
+        for file in files {
+            let content = read(file);
+            let mentions = instance_modules.iter().any(|m| {
+                m.file_stem()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|stem| content.contains(stem))
+            });
+            if !mentions {
+                continue;
+            }
+            let Some((_, module)) = crate::parser::parse_module(file) else {
+                continue;
+            };
+            let imports_instance = module.body.iter().any(|item| {
+                let swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(import)) =
+                    item
+                else {
+                    return false;
+                };
+                FileOrchestrator::resolve_relative_import(file, import.src.value.as_ref())
+                    .is_some_and(|resolved| {
+                        instance_modules
+                            .iter()
+                            .any(|m| m.canonicalize().is_ok_and(|c| c == resolved))
+                    })
+            });
+            if imports_instance {
+                dependents.insert(normalize(file));
+            }
+        }
+    }
+
+    dependents
+}
+
 /// Incremental analysis: reuse cached per-file LLM results for unchanged files.
 async fn analyze_current_repo_incremental(
     repo_path: &str,
@@ -945,7 +1306,7 @@ async fn analyze_current_repo_incremental(
         );
 
         // Get changed files via git diff
-        if let Some(changed_files) = get_changed_files(repo_path, prev_commit) {
+        if let Some(changed_files) = get_changed_paths(repo_path, prev_commit) {
             let prev_file_results = prev.file_results.as_ref().unwrap();
             let repo_prefix = format!("{}/", repo_path);
 
@@ -967,12 +1328,28 @@ async fn analyze_current_repo_incremental(
             // Normalize changed files relative to repo root
             let changed_set: HashSet<String> = changed_files.into_iter().collect();
 
+            // Repo-wide inputs (carrick.json, Nest routing, serverless
+            // definitions, client defaults and instances) are read outside
+            // the files they affect, so their dependents are re-analyzed too.
+            let service_root = service_scan_root(repo_path, config);
+            let dependents = repo_input_dependents(
+                repo_path,
+                prev_commit,
+                &changed_set,
+                &service_root,
+                &files,
+                prev_file_results,
+                &normalize_path,
+            );
+
             // Partition: which files need fresh analysis?
             let files_to_analyze: Vec<PathBuf> = files
                 .iter()
                 .filter(|f| {
                     let relative = normalize_path(f);
-                    changed_set.contains(&relative) || !prev_file_results.contains_key(&relative)
+                    changed_set.contains(&relative)
+                        || !prev_file_results.contains_key(&relative)
+                        || dependents.contains(&relative)
                 })
                 .cloned()
                 .collect();
@@ -1044,7 +1421,6 @@ async fn analyze_current_repo_incremental(
             );
 
             let normalizer = UrlNormalizer::new(config);
-            let service_root = service_scan_root(repo_path, config);
             let new_file_results = if !files_to_analyze.is_empty() {
                 let result = file_orchestrator
                     .analyze_files(
@@ -1055,6 +1431,7 @@ async fn analyze_current_repo_incremental(
                         &graphql_producer_hints,
                         &graphql_consumer_hints,
                         &normalizer,
+                        &config.http_clients,
                     )
                     .await?;
                 result.file_results
@@ -1098,6 +1475,7 @@ async fn analyze_current_repo_incremental(
             let protocol_extractions =
                 scan_protocol_extractions(repo_path, service, &files, &merged_results);
             fold_graphql_transport_calls(&mut mount_graph, &protocol_extractions.graphql);
+            crate::middleware::attach_middleware_chains(&mut mount_graph, Path::new(repo_path));
 
             // Generate function intents (also strips body_source before upload).
             // Run on the same path as the full analysis so incremental scans
@@ -1157,6 +1535,7 @@ async fn analyze_current_repo_incremental(
             // Build type manifest
             let mut manifest_entries = build_type_manifest_entries(&mount_graph, config, repo_path);
             stamp_manifest_anchor_symbols(&mut manifest_entries, &merged_results);
+            crate::field_usage::stamp_accessed_fields(&mut manifest_entries, Path::new(repo_path));
             append_protocol_manifest_entries(&mut manifest_entries, &protocol_extractions);
             append_pubsub_manifest_entries(
                 &mut manifest_entries,
@@ -2123,6 +2502,7 @@ fn add_protocol_manifest_entry(
         resolved_definition: None,
         expanded_definition: None,
         primary_type_symbol,
+        accessed_fields: None,
     });
 }
 
@@ -2549,7 +2929,7 @@ fn resolve_services(repo_path: &str) -> Result<Vec<Config>, Box<dyn std::error::
     // configs in a repo that contains them).
     let config_path = std::path::Path::new(repo_path).join("carrick.json");
 
-    let services = if config_path.is_file() {
+    let mut services = if config_path.is_file() {
         debug!("Found carrick.json: {}", config_path.display());
         Config::load_services(vec![config_path.clone()]).map_err(|e| {
             format!(
@@ -2566,6 +2946,24 @@ fn resolve_services(repo_path: &str) -> Result<Vec<Config>, Box<dyn std::error::
     // A typo'd directory would otherwise walk nothing and upload an empty
     // service, silently erasing its coverage from the index.
     let root = std::path::Path::new(repo_path);
+    for service in &mut services {
+        // Gateway imports are relative to carrick.json; resolve them into
+        // concrete routes once, here, so the serialized per-service config
+        // never carries a path into this checkout.
+        service
+            .gateway
+            .resolve_imports(root)
+            .map_err(|e| format!("Invalid gateway config in {}: {}", config_path.display(), e))?;
+        // Same for profile dotenv files: only the URL origins they resolve
+        // to are kept, never the raw (possibly secret) values.
+        crate::env_profiles::resolve_profiles(&mut service.profiles, root).map_err(|e| {
+            format!(
+                "Invalid profiles config in {}: {}",
+                config_path.display(),
+                e
+            )
+        })?;
+    }
     for service in &services {
         let label = service
             .service_name
@@ -2599,10 +2997,27 @@ fn resolve_services(repo_path: &str) -> Result<Vec<Config>, Box<dyn std::error::
     }
 
     if services.is_empty() {
-        Ok(vec![Config::default()])
-    } else {
-        Ok(services)
+        services.push(Config::default());
     }
+
+    // Env vars the repo's docker-compose / Kubernetes / Helm manifests point at
+    // sibling services are internal even when carrick.json doesn't say so.
+    let inferred = crate::deploy_manifests::infer_from_repo(root);
+    for service in &mut services {
+        inferred.apply_to(service);
+    }
+
+    Ok(services)
+}
+
+/// The repo's `carrick.json` `policy` block; empty without a config.
+fn resolve_policy(repo_path: &str) -> Result<crate::policy::Policy, Box<dyn std::error::Error>> {
+    let config_path = std::path::Path::new(repo_path).join("carrick.json");
+    if !config_path.is_file() {
+        return Ok(crate::policy::Policy::default());
+    }
+    Config::load_policy(&config_path)
+        .map_err(|e| format!("Invalid policy in {}: {}", config_path.display(), e).into())
 }
 
 /// Build-artifact directories to skip everywhere.
@@ -2878,6 +3293,7 @@ fn add_manifest_pair(
             // Threaded on after the fact by `stamp_manifest_anchor_symbols`,
             // which joins the LLM's real anchor symbol by `(file_path, line)`.
             primary_type_symbol: None,
+            accessed_fields: None,
         });
     }
 }
@@ -3111,6 +3527,7 @@ async fn analyze_current_repo(
             &graphql_producer_hints,
             &graphql_consumer_hints,
             &normalizer,
+            &config.http_clients,
         )
         .await?;
 
@@ -3144,6 +3561,10 @@ async fn analyze_current_repo(
         &mut analysis_result.mount_graph,
         &protocol_extractions.graphql,
     );
+    crate::middleware::attach_middleware_chains(
+        &mut analysis_result.mount_graph,
+        Path::new(repo_path),
+    );
     let analysis_result = analysis_result;
 
     // Cloud-bound paths must be repo-relative. The incremental path gets
@@ -3171,6 +3592,7 @@ async fn analyze_current_repo(
     let mut manifest_entries =
         build_type_manifest_entries(&analysis_result.mount_graph, config, repo_path);
     stamp_manifest_anchor_symbols(&mut manifest_entries, &analysis_result.file_results);
+    crate::field_usage::stamp_accessed_fields(&mut manifest_entries, Path::new(repo_path));
     append_protocol_manifest_entries(&mut manifest_entries, &protocol_extractions);
     append_pubsub_manifest_entries(
         &mut manifest_entries,
@@ -3710,6 +4132,10 @@ mod tests {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 })
                 .collect(),
             data_calls: data_calls
@@ -3729,6 +4155,8 @@ mod tests {
                     payload_expression_line: Some(22),
                     primary_type_symbol: None,
                     type_import_source: None,
+                    sent_headers: None,
+                    query_options: None,
                 })
                 .collect(),
             graphql_operations: vec![],
@@ -3768,6 +4196,9 @@ mod tests {
                 call_kind: None,
                 repo_name: None,
                 service_name: None,
+                query_params: None,
+                sent_headers: None,
+                target_service: None,
             }
         };
         let mut mount_graph = MountGraph::new();
@@ -3834,6 +4265,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         };
         let mut mount_graph = MountGraph::new();
         mount_graph.endpoints = vec![
@@ -3892,6 +4325,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::CallSite,
+            query_params: None,
+            request_headers: Vec::new(),
         }];
         mount_graph.data_calls = vec![crate::mount_graph::DataFetchingCall {
             method: "POST".to_string(),
@@ -3902,6 +4337,9 @@ mod tests {
             call_kind: None,
             repo_name: None,
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         }];
 
         let entries = build_type_manifest_entries(&mount_graph, &config, ".");
@@ -4141,6 +4579,104 @@ mod tests {
         assert!(!changed.contains(&"readme.md".to_string()));
     }
 
+    #[test]
+    fn repo_input_changes_invalidate_their_dependents() {
+        use std::process::Command;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        let repo_path = root.to_str().unwrap();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(repo_path)
+                .env_remove("GIT_DIR")
+                .env_remove("GIT_WORK_TREE")
+                .env_remove("GIT_INDEX_FILE")
+                .output()
+                .unwrap()
+        };
+        git(&["init"]);
+        git(&["config", "user.email", "test@test.com"]);
+        git(&["config", "user.name", "Test"]);
+
+        let svc = root.join("svc");
+        std::fs::create_dir_all(svc.join("lib")).unwrap();
+        let write = |name: &str, text: &str| std::fs::write(svc.join(name), text).unwrap();
+        write("carrick.json", r#"{ "httpClients": [] }"#);
+        write("main.ts", "app.setGlobalPrefix('v1');");
+        write(
+            "users.controller.ts",
+            "@Controller('users') export class Users {}",
+        );
+        write(
+            "http.ts",
+            "export const api = axios.create({ baseURL: '/a' });",
+        );
+        write("client.ts", "import { api } from './http'; api.get('/x');");
+        write("lib/fetcher.ts", "export function fetcher() {}");
+        write(
+            "orders.ts",
+            "import { fetcher } from './lib/fetcher'; fetcher('/o');",
+        );
+        write("other.ts", "export const x = 1;");
+        git(&["add", "."]);
+        git(&["commit", "-m", "initial"]);
+        let base = String::from_utf8(git(&["rev-parse", "HEAD"]).stdout).unwrap();
+        let base = base.trim();
+
+        write(
+            "carrick.json",
+            r#"{ "httpClients": [{ "import": "./lib/fetcher", "export": "fetcher" }] }"#,
+        );
+        write("main.ts", "app.setGlobalPrefix('v2');");
+        write(
+            "http.ts",
+            "export const api = axios.create({ baseURL: '/b' });",
+        );
+        git(&["add", "."]);
+        git(&["commit", "-m", "changes"]);
+
+        let changed: HashSet<String> = get_changed_paths(repo_path, base)
+            .unwrap()
+            .into_iter()
+            .collect();
+        // Non-source inputs are no longer filtered out of the diff.
+        assert!(changed.contains("svc/carrick.json"));
+
+        let files: Vec<PathBuf> = [
+            "main.ts",
+            "users.controller.ts",
+            "http.ts",
+            "client.ts",
+            "lib/fetcher.ts",
+            "orders.ts",
+            "other.ts",
+        ]
+        .iter()
+        .map(|f| svc.join(f))
+        .collect();
+        let prefix = format!("{}/", repo_path);
+        let normalize =
+            |f: &PathBuf| -> String { f.to_string_lossy().trim_start_matches(&prefix).to_string() };
+        let dependents = repo_input_dependents(
+            repo_path,
+            base,
+            &changed,
+            &svc,
+            &files,
+            &HashMap::new(),
+            &normalize,
+        );
+
+        // The nested carrick.json's wrapper, the global prefix, and the
+        // instance's importer.
+        assert!(dependents.contains("svc/orders.ts"));
+        assert!(dependents.contains("svc/users.controller.ts"));
+        assert!(dependents.contains("svc/client.ts"));
+        assert!(!dependents.contains("svc/other.ts"));
+    }
+
     #[test]
     fn test_get_changed_files_returns_none_for_invalid_commit() {
         use std::process::Command;
@@ -4224,6 +4760,10 @@ mod tests {
                         emission_style: None,
                         primary_type_symbol: None,
                         type_import_source: None,
+                        request_schema_type: None,
+                        response_schema_type: None,
+                        query_params: None,
+                        request_headers: Vec::new(),
                     }],
                     data_calls: vec![],
                     graphql_operations: vec![],
@@ -4675,6 +5215,28 @@ mod tests {
         assert!(services[0].directory.is_none());
     }
 
+    #[test]
+    fn resolve_services_infers_internal_env_vars_from_compose() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("docker-compose.yml"),
+            "services:\n  web:\n    environment:\n      ORDERS_URL: http://orders:3000\n      \
+             PAYMENTS_URL: http://payments:4000\n  orders: {}\n  payments: {}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("carrick.json"),
+            r#"{ "externalEnvVars": ["PAYMENTS_URL"] }"#,
+        )
+        .unwrap();
+
+        let services = resolve_services(tmp.path().to_str().unwrap()).unwrap();
+        assert_eq!(services.len(), 1);
+        assert!(services[0].is_internal_call("ENV_VAR:ORDERS_URL:/orders"));
+        assert!(services[0].is_external_call("ENV_VAR:PAYMENTS_URL:/charge"));
+        assert!(!services[0].is_internal_call("ENV_VAR:PAYMENTS_URL:/charge"));
+    }
+
     #[test]
     fn resolve_services_rejects_malformed_config() {
         let tmp = tempfile::tempdir().unwrap();
@@ -4737,6 +5299,50 @@ mod tests {
         assert_eq!(services[0].directory.as_deref(), Some("svc"));
     }
 
+    #[test]
+    fn resolve_services_expands_gateway_imports() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir(tmp.path().join("deploy")).unwrap();
+        std::fs::write(
+            tmp.path().join("deploy/gateway.conf"),
+            "location /api/orders/ { proxy_pass http://orders:3000/; }",
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("carrick.json"),
+            r#"{
+                "serviceName": "web",
+                "gateway": {
+                    "routes": [{ "prefix": "/api/users", "service": "users" }],
+                    "import": ["deploy/gateway.conf"]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let services = resolve_services(tmp.path().to_str().unwrap()).unwrap();
+        let gateway = &services[0].gateway;
+        assert!(gateway.imports.is_empty());
+        assert_eq!(gateway.routes.len(), 2);
+        assert_eq!(gateway.routes[1].service.as_deref(), Some("orders"));
+    }
+
+    #[test]
+    fn resolve_services_rejects_missing_gateway_import() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("carrick.json"),
+            r#"{ "gateway": { "import": ["vercel.json"] } }"#,
+        )
+        .unwrap();
+
+        let err = resolve_services(tmp.path().to_str().unwrap()).unwrap_err();
+        assert!(
+            err.to_string().contains("gateway import 'vercel.json'"),
+            "expected missing-import error, got: {err}"
+        );
+    }
+
     #[test]
     fn load_packages_rejects_malformed_package_json() {
         let tmp = tempfile::tempdir().unwrap();
@@ -4798,6 +5404,7 @@ mod tests {
             resolved_definition: None,
             expanded_definition: None,
             primary_type_symbol: None,
+            accessed_fields: None,
         }
     }
 
@@ -5095,6 +5702,9 @@ mod tests {
             call_kind: None,
             repo_name: None,
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         }
     }
 
@@ -5146,6 +5756,9 @@ mod tests {
             call_kind: None,
             repo_name: None,
             service_name: None,
+            query_params: None,
+            sent_headers: None,
+            target_service: None,
         }];
         let graphql = crate::graphql::GraphqlExtraction {
             producers: vec![],
@@ -5601,6 +6214,10 @@ mod tests {
             emission_style: None,
             primary_type_symbol: None,
             type_import_source: None,
+            request_schema_type: None,
+            response_schema_type: None,
+            query_params: None,
+            request_headers: Vec::new(),
         }
     }
 
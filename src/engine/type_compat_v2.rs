@@ -24,6 +24,7 @@ use crate::cloud_storage::{
     CAPTURE_ARTIFACT_VERSION, CaptureStubArtifact, CloudRepoData, ManifestRole, ManifestTypeKind,
     TypeManifestEntry,
 };
+use crate::config::{Config, StrictnessProfile, WireSerialization};
 use crate::operation::OperationKey;
 use crate::services::TypeSidecar;
 use crate::services::type_sidecar::{
@@ -529,7 +530,7 @@ pub(crate) fn backfill_accepted(
 /// `parse_producer_key` recovers from an edge's canonical producer key
 /// (`("GET", "/orders/:id")`, `("SOCKET", "SERVER->CLIENT|event")`,
 /// `("GRAPHQL", "query|field")`, `("PUBSUB", "topic")`).
-fn join_identity(key: &OperationKey) -> Option<(String, String)> {
+pub(crate) fn join_identity(key: &OperationKey) -> Option<(String, String)> {
     match key {
         OperationKey::Http { method, path } => Some((method.to_uppercase(), path.clone())),
         OperationKey::Socket { event, direction } => Some((
@@ -636,6 +637,10 @@ pub(crate) struct BuiltPair {
 struct ServiceEntry<'a> {
     service_id: &'a str,
     has_surface: bool,
+    /// The service's `responseSerialization` (from its uploaded config).
+    serialization: WireSerialization,
+    /// The service's `strictness` (applies where it is the consumer).
+    strictness: StrictnessProfile,
     entry: &'a TypeManifestEntry,
 }
 
@@ -656,7 +661,9 @@ struct ServiceEntry<'a> {
 /// authority — an alias baked to any/unknown (or absent) is caught by its own
 /// gate. Gating the pre-verdict on the stale `type_state` dropped real,
 /// resolvable, compatible edges to "not compared".
-pub(crate) fn build_check_pairs(all_repo_data: &[CloudRepoData]) -> Vec<BuiltPair> {
+pub(crate) fn build_check_pairs<'a>(
+    all_repo_data: impl IntoIterator<Item = &'a CloudRepoData>,
+) -> Vec<BuiltPair> {
     let mut producers: Vec<ServiceEntry> = Vec::new();
     let mut consumers: Vec<ServiceEntry> = Vec::new();
 
@@ -669,6 +676,11 @@ pub(crate) fn build_check_pairs(all_repo_data: &[CloudRepoData]) -> Vec<BuiltPai
             .capture_stub
             .as_ref()
             .is_some_and(|s| s.artifact_version == CAPTURE_ARTIFACT_VERSION);
+        let config = repo
+            .config_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str::<Config>(json).ok())
+            .unwrap_or_default();
         let Some(entries) = repo.type_manifest.as_ref() else {
             continue;
         };
@@ -680,6 +692,8 @@ pub(crate) fn build_check_pairs(all_repo_data: &[CloudRepoData]) -> Vec<BuiltPai
             target.push(ServiceEntry {
                 service_id,
                 has_surface,
+                serialization: config.response_serialization,
+                strictness: config.strictness,
                 entry,
             });
         }
@@ -814,6 +828,8 @@ fn build_pair(producer: &ServiceEntry, consumer: &ServiceEntry) -> Option<BuiltP
                 service_name: consumer.service_id.to_string(),
                 alias: consumer.entry.type_alias.clone(),
             },
+            serialization: producer.serialization,
+            strictness: consumer.strictness,
         },
         pseudo_method,
         identity,
@@ -832,8 +848,8 @@ fn build_pair(producer: &ServiceEntry, consumer: &ServiceEntry) -> Option<BuiltP
 /// return the check inputs. Stub dir names are keyed on the sanitized
 /// service id (collisions suffixed) so two services of one monorepo can
 /// never clobber each other's stub.
-pub(crate) fn materialize_stubs(
-    all_repo_data: &[CloudRepoData],
+pub(crate) fn materialize_stubs<'a>(
+    all_repo_data: impl IntoIterator<Item = &'a CloudRepoData>,
     dest_root: &Path,
 ) -> Vec<CheckStubInput> {
     let mut used: HashMap<String, usize> = HashMap::new();
@@ -883,7 +899,16 @@ pub(crate) fn run_check(
     sidecar: &TypeSidecar,
     all_repo_data: &[CloudRepoData],
 ) -> Vec<PairCheckOutcome> {
-    let pairs = build_check_pairs(all_repo_data);
+    check_pairs(sidecar, all_repo_data, build_check_pairs(all_repo_data))
+}
+
+/// Run the v2 check over an already-built (possibly filtered) pair set;
+/// `all_repo_data` supplies the capture stubs.
+pub(crate) fn check_pairs<'a>(
+    sidecar: &TypeSidecar,
+    all_repo_data: impl IntoIterator<Item = &'a CloudRepoData>,
+    pairs: Vec<BuiltPair>,
+) -> Vec<PairCheckOutcome> {
     if pairs.is_empty() {
         return Vec::new();
     }
@@ -928,12 +953,15 @@ pub(crate) fn run_check(
                     .collect();
                 for pair in &probing {
                     match by_key.get(pair.spec.pair_key.as_str()) {
-                        Some(verdict) => outcomes.push(outcome_for(
-                            pair,
-                            verdict.bucket,
-                            verdict.gate.clone(),
-                            verdict.diagnostic.clone(),
-                        )),
+                        Some(verdict) => outcomes.push(PairCheckOutcome {
+                            fields: verdict.fields.clone(),
+                            ..outcome_for(
+                                pair,
+                                verdict.bucket,
+                                verdict.gate.clone(),
+                                verdict.diagnostic.clone(),
+                            )
+                        }),
                         None => outcomes.push(outcome_for(
                             pair,
                             VerdictBucket::Unverifiable,
@@ -990,6 +1018,7 @@ fn outcome_for(
         bucket,
         gate,
         diagnostic,
+        fields: Vec::new(),
         producer_alias: pair.producer_alias.clone(),
         consumer_alias: pair.consumer_alias.clone(),
         producer_service: pair.producer_service.clone(),
@@ -1039,6 +1068,7 @@ mod tests {
             resolved_definition: None,
             expanded_definition: None,
             primary_type_symbol: None,
+            accessed_fields: None,
         }
     }
 
@@ -1737,6 +1767,72 @@ mod tests {
         assert_eq!(pairs[0].consumer_service, "orders-engine");
     }
 
+    /// The producer's `responseSerialization` rides every pair it produces
+    /// (the consumer parses, it doesn't serialize); the consumer's
+    /// `strictness` rides every pair it consumes.
+    #[test]
+    fn build_pairs_carry_service_config() {
+        let key = OperationKey::http("GET", "/orders");
+        let mut producer_repo = repo(
+            "api",
+            None,
+            vec![entry(
+                key.clone(),
+                ManifestRole::Producer,
+                ManifestTypeKind::Response,
+                "P",
+                "src/routes.ts",
+                3,
+                ManifestTypeState::Explicit,
+            )],
+            Some(fake_artifact()),
+        );
+        producer_repo.config_json =
+            Some(r#"{"serviceName":"api","responseSerialization":"json"}"#.to_string());
+        let mut consumer_repo = repo(
+            "web",
+            None,
+            vec![entry(
+                key.clone(),
+                ManifestRole::Consumer,
+                ManifestTypeKind::Response,
+                "C",
+                "src/client.ts",
+                8,
+                ManifestTypeState::Explicit,
+            )],
+            Some(fake_artifact()),
+        );
+        consumer_repo.config_json = Some(
+            r#"{"serviceName":"web","responseSerialization":"native","strictness":"strict-optional"}"#
+                .to_string(),
+        );
+
+        let pairs = build_check_pairs(&[producer_repo.clone(), consumer_repo]);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].spec.serialization, WireSerialization::Json);
+        assert_eq!(pairs[0].spec.strictness, StrictnessProfile::StrictOptional);
+
+        producer_repo.config_json = None;
+        let consumer_repo = repo(
+            "web",
+            None,
+            vec![entry(
+                key,
+                ManifestRole::Consumer,
+                ManifestTypeKind::Response,
+                "C",
+                "src/client.ts",
+                8,
+                ManifestTypeState::Explicit,
+            )],
+            Some(fake_artifact()),
+        );
+        let pairs = build_check_pairs(&[producer_repo, consumer_repo]);
+        assert_eq!(pairs[0].spec.serialization, WireSerialization::Native);
+        assert_eq!(pairs[0].spec.strictness, StrictnessProfile::Standard);
+    }
+
     /// A missing capture surface — and ONLY a missing surface — pre-verdicts
     /// the pair unverifiable before any probe. A manifest `type_state ==
     /// Unknown` on a side that HAS a surface no longer pre-verdicts: the
@@ -50,9 +50,26 @@
 //! config objects (`config.api.url`), `Object.freeze(...)` wrappers, re-export
 //! chains (`export * from`), tsconfig path aliases — is intentionally not
 //! resolved. See the TODO in [`EnvAliasExtractor`].
-
+//!
+//! URL objects are the third pattern:
+//!
+//! ```ts
+//! const url = new URL(`/orders/${id}`, ORDERS_BASE);
+//! url.searchParams.set("expand", "items");
+//! await fetch(url);
+//! ```
+//!
+//! The call target is then just `url`. [`UrlBindings`] records what each
+//! `new URL(...)` local composes (via
+//! [`crate::url_normalizer::join_url_base`]) and the query keys set on it or
+//! on a `URLSearchParams` it is given, and [`resolve_url_binding_target`]
+//! substitutes that — `${ORDERS_BASE}/orders/${id}?expand=items` — so the
+//! alias rewrite above can then resolve the base. The query string stays on
+//! the target as part of the call's contract.
+
+use crate::url_normalizer::join_url_base;
 use crate::visitor::{ImportedSymbol, SymbolKind};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{Visit, VisitWith};
 
@@ -163,7 +180,7 @@ fn object_env_props(obj: &ObjectLit, known_aliases: &EnvAliasMap) -> Vec<(String
 
 /// Strip expression wrappers that do not change the runtime value:
 /// parentheses, `as` / `satisfies` / `as const` assertions, and non-null `!`.
-fn unwrap_transparent(expr: &Expr) -> &Expr {
+pub(crate) fn unwrap_transparent(expr: &Expr) -> &Expr {
     match expr {
         Expr::Paren(e) => unwrap_transparent(&e.expr),
         Expr::TsAs(e) => unwrap_transparent(&e.expr),
@@ -324,6 +341,7 @@ pub fn merge_imported_env_aliases<F>(
 /// - `process.env.NAME`
 /// - `process.env["NAME"]`
 /// - `process.env.NAME ?? <default>` / `process.env.NAME || <default>`
+/// - `new URL(process.env.NAME)`, which denotes the same base
 ///
 /// Transparent wrappers (parens, `!`, `as`, `as const`, `satisfies`) are
 /// stripped via [`unwrap_transparent`] before matching, so every caller —
@@ -338,6 +356,10 @@ fn process_env_name(expr: &Expr) -> Option<String> {
         Expr::Bin(bin) if matches!(bin.op, BinaryOp::NullishCoalescing | BinaryOp::LogicalOr) => {
             process_env_name(&bin.left)
         }
+        Expr::New(new) if is_ident(&new.callee, "URL") => match new.args.as_deref() {
+            Some([arg]) if arg.spread.is_none() => process_env_name(&arg.expr),
+            _ => None,
+        },
         _ => None,
     }
 }
@@ -403,6 +425,365 @@ pub fn resolve_target_env_alias(target: &str, aliases: &EnvAliasMap) -> Option<S
     ))
 }
 
+/// Resolve a call target's leading env-var base against one environment
+/// profile's origins (see [`crate::env_profiles`]): the second step after
+/// [`resolve_target_env_alias`] has turned an alias into
+/// `${process.env.NAME}`. Accepts that form, a bare `${NAME}`, and the
+/// canonical `ENV_VAR:NAME:/path`. Returns the origin the profile gives the
+/// var and the concrete URL the call reaches with it, or `None` when the
+/// target has no env-var base or the profile leaves the var undefined.
+pub fn resolve_target_profile_origin<'a>(
+    target: &str,
+    origins: &'a BTreeMap<String, String>,
+) -> Option<(&'a str, String)> {
+    let trimmed = target.trim_matches(['`', '"', '\'']);
+    let (env_name, rest) = if let Some(canonical) = trimmed.strip_prefix("ENV_VAR:") {
+        canonical.split_once(':').unwrap_or((canonical, ""))
+    } else {
+        let inner = trimmed.strip_prefix("${")?;
+        let end = inner.find('}')?;
+        let name = &inner[..end];
+        (
+            name.strip_prefix("process.env.").unwrap_or(name),
+            &inner[end + 1..],
+        )
+    };
+    let origin = origins.get(env_name)?;
+    let path = if rest.is_empty() || rest.starts_with(['/', '?']) {
+        rest.to_string()
+    } else {
+        format!("/{}", rest)
+    };
+    Some((origin.as_str(), format!("{}{}", origin, path)))
+}
+
+/// `new URL(...)` locals and `URLSearchParams` locals of one module.
+#[derive(Debug, Default)]
+pub struct UrlBindings {
+    /// URL local → the target it composes, query string included.
+    pub urls: HashMap<String, String>,
+    /// `URLSearchParams` local → its query string (`page=${page}&limit=20`).
+    pub params: HashMap<String, String>,
+}
+
+impl UrlBindings {
+    /// Collect the URL bindings of a parsed module.
+    pub fn build(module: &Module) -> Self {
+        let mut extractor = UrlBindingExtractor::default();
+        module.visit_with(&mut extractor);
+        extractor.finish()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty() && self.params.is_empty()
+    }
+}
+
+/// Visitor behind [`UrlBindings::build`]. Query keys set after a binding is
+/// created (`url.searchParams.set`, `params.append`, `url.search = params`)
+/// are folded in once the whole module has been seen.
+#[derive(Default)]
+struct UrlBindingExtractor {
+    urls: HashMap<String, String>,
+    params: HashMap<String, Vec<(String, String)>>,
+    url_pairs: HashMap<String, Vec<(String, String)>>,
+    url_params: HashMap<String, Vec<String>>,
+}
+
+impl UrlBindingExtractor {
+    fn finish(self) -> UrlBindings {
+        let params: HashMap<String, String> = self
+            .params
+            .iter()
+            .map(|(name, pairs)| (name.clone(), query_string(pairs)))
+            .collect();
+        let urls = self
+            .urls
+            .iter()
+            .map(|(name, target)| {
+                let mut target =
+                    substitute_search_params(target, &params).unwrap_or(target.clone());
+                let mut pairs = self.url_pairs.get(name).cloned().unwrap_or_default();
+                for source in self.url_params.get(name).into_iter().flatten() {
+                    pairs.extend(self.params.get(source).cloned().unwrap_or_default());
+                }
+                if !pairs.is_empty() {
+                    target.push(if target.contains('?') { '&' } else { '?' });
+                    target.push_str(&query_string(&pairs));
+                }
+                (name.clone(), target)
+            })
+            .collect();
+        UrlBindings { urls, params }
+    }
+}
+
+impl Visit for UrlBindingExtractor {
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        for decl in &var_decl.decls {
+            let (Pat::Ident(binding), Some(init)) = (&decl.name, &decl.init) else {
+                continue;
+            };
+            let Expr::New(new) = unwrap_transparent(init) else {
+                continue;
+            };
+            let args = new.args.as_deref().unwrap_or_default();
+            let name = binding.id.sym.to_string();
+            if is_ident(&new.callee, "URL") {
+                let target = match args {
+                    [path] => url_part_text(&path.expr),
+                    [path, base] => url_part_text(&path.expr)
+                        .zip(url_part_text(&base.expr))
+                        .map(|(path, base)| join_url_base(&path, &base)),
+                    _ => None,
+                };
+                if let Some(target) = target {
+                    self.urls.insert(name, target);
+                }
+            } else if is_ident(&new.callee, "URLSearchParams") {
+                let pairs = match args.first().map(|a| unwrap_transparent(&a.expr)) {
+                    Some(Expr::Object(obj)) => object_query_pairs(obj),
+                    _ => Vec::new(),
+                };
+                self.params.insert(name, pairs);
+            }
+        }
+        var_decl.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        // `url.searchParams.set("k", v)` / `params.append("k", v)`
+        if let Callee::Expr(callee) = &call.callee
+            && let Expr::Member(member) = &**callee
+            && (is_ident_prop(&member.prop, "set") || is_ident_prop(&member.prop, "append"))
+            && let Some((key, value)) = call_query_pair(&call.args)
+        {
+            match &*member.obj {
+                Expr::Ident(params) if self.params.contains_key(params.sym.as_ref()) => {
+                    if let Some(pairs) = self.params.get_mut(params.sym.as_ref()) {
+                        pairs.push((key, value));
+                    }
+                }
+                Expr::Member(search) if is_ident_prop(&search.prop, "searchParams") => {
+                    if let Expr::Ident(url) = &*search.obj
+                        && self.urls.contains_key(url.sym.as_ref())
+                    {
+                        self.url_pairs
+                            .entry(url.sym.to_string())
+                            .or_default()
+                            .push((key, value));
+                    }
+                }
+                _ => {}
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        // `url.search = params.toString()` / `` url.search = `?${params}` ``
+        if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left
+            && is_ident_prop(&member.prop, "search")
+            && let Expr::Ident(url) = &*member.obj
+            && self.urls.contains_key(url.sym.as_ref())
+            && let Some(params) = search_params_ref(&assign.right)
+            && self.params.contains_key(&params)
+        {
+            self.url_params
+                .entry(url.sym.to_string())
+                .or_default()
+                .push(params);
+        }
+        assign.visit_children_with(self);
+    }
+}
+
+/// A `new URL` argument as target text: a literal's contents, a template
+/// with each interpolation reduced to a reference, or `${reference}`.
+fn url_part_text(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) => {
+            let mut text = String::new();
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                text.push_str(&quasi.raw);
+                if let Some(expr) = tpl.exprs.get(i) {
+                    let reference = interpolated_reference(expr)?;
+                    text.push_str(&format!("${{{}}}", reference));
+                }
+            }
+            Some(text)
+        }
+        Expr::Bin(bin) if matches!(bin.op, BinaryOp::NullishCoalescing | BinaryOp::LogicalOr) => {
+            url_part_text(&bin.left)
+        }
+        Expr::New(new) if is_ident(&new.callee, "URL") => match new.args.as_deref() {
+            Some([arg]) => url_part_text(&arg.expr),
+            _ => None,
+        },
+        other => reference_text(other).map(|r| format!("${{{}}}", r)),
+    }
+}
+
+/// The reference an interpolation stands for: `id`, `user.id`, or the
+/// argument of a one-argument call such as `encodeURIComponent(id)` or
+/// `params.toString()`'s receiver.
+fn interpolated_reference(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Call(call) => {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            match (&**callee, call.args.as_slice()) {
+                (Expr::Member(member), []) if is_ident_prop(&member.prop, "toString") => {
+                    reference_text(&member.obj)
+                }
+                (_, [arg]) => reference_text(&arg.expr),
+                _ => None,
+            }
+        }
+        other => reference_text(other),
+    }
+}
+
+/// Dotted text of an identifier or member chain.
+fn reference_text(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Member(member) => match &member.prop {
+            MemberProp::Ident(prop) => {
+                Some(format!("{}.{}", reference_text(&member.obj)?, prop.sym))
+            }
+            MemberProp::Computed(computed) => match &*computed.expr {
+                Expr::Lit(Lit::Str(s)) => {
+                    Some(format!("{}.{}", reference_text(&member.obj)?, s.value))
+                }
+                _ => None,
+            },
+            MemberProp::PrivateName(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// `("k", v)` of a `set`/`append` call with a literal key.
+fn call_query_pair(args: &[ExprOrSpread]) -> Option<(String, String)> {
+    let [key, value] = args else {
+        return None;
+    };
+    let Expr::Lit(Lit::Str(key)) = unwrap_transparent(&key.expr) else {
+        return None;
+    };
+    let key = key.value.to_string();
+    let value = query_value_text(&value.expr, &key);
+    Some((key, value))
+}
+
+/// Key-value pairs of a `new URLSearchParams({ ... })` object literal.
+fn object_query_pairs(obj: &ObjectLit) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    for prop in &obj.props {
+        let PropOrSpread::Prop(prop) = prop else {
+            continue;
+        };
+        match &**prop {
+            Prop::KeyValue(kv) => {
+                let key = match &kv.key {
+                    PropName::Ident(ident) => ident.sym.to_string(),
+                    PropName::Str(s) => s.value.to_string(),
+                    _ => continue,
+                };
+                let value = query_value_text(&kv.value, &key);
+                pairs.push((key, value));
+            }
+            Prop::Shorthand(ident) => {
+                pairs.push((ident.sym.to_string(), format!("${{{}}}", ident.sym)));
+            }
+            _ => {}
+        }
+    }
+    pairs
+}
+
+/// A query value as target text: a literal, `${reference}`, or `${key}` for
+/// anything computed.
+fn query_value_text(expr: &Expr, key: &str) -> String {
+    match unwrap_transparent(expr) {
+        Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+        Expr::Lit(Lit::Num(n)) => n.value.to_string(),
+        Expr::Lit(Lit::Bool(b)) => b.value.to_string(),
+        other => format!(
+            "${{{}}}",
+            interpolated_reference(other).unwrap_or_else(|| key.to_string())
+        ),
+    }
+}
+
+fn query_string(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// The `URLSearchParams` local a `url.search = ...` right-hand side reads:
+/// `params`, `params.toString()`, `String(params)`, or `` `?${params}` ``.
+fn search_params_ref(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Tpl(tpl) if tpl.exprs.len() == 1 => interpolated_reference(&tpl.exprs[0]),
+        other => interpolated_reference(other),
+    }
+}
+
+/// Replace `${params}` / `${params.toString()}` interpolations of a known
+/// `URLSearchParams` local with its query string. `None` when nothing matched.
+fn substitute_search_params(target: &str, params: &HashMap<String, String>) -> Option<String> {
+    let mut out = target.to_string();
+    let mut changed = false;
+    for (name, query) in params {
+        for form in [
+            format!("${{{}}}", name),
+            format!("${{{}.toString()}}", name),
+        ] {
+            if out.contains(&form) {
+                out = out.replace(&form, query);
+                changed = true;
+            }
+        }
+    }
+    changed.then_some(out)
+}
+
+/// Rewrite a call target that names a URL local (`url`, `${url}`,
+/// `url.toString()`, `url.href`, `String(url)`) to the target it composes,
+/// or splice a `URLSearchParams` local's query string in for its
+/// interpolation (`` `${BASE}/users?${params}` ``). Returns `None` when
+/// nothing was rewritten.
+pub fn resolve_url_binding_target(target: &str, bindings: &UrlBindings) -> Option<String> {
+    if bindings.is_empty() {
+        return None;
+    }
+    let mut name = target.trim().trim_matches(['`', '"', '\'']);
+    if let Some(inner) = name.strip_prefix("${").and_then(|n| n.strip_suffix('}')) {
+        name = inner;
+    }
+    if let Some(inner) = name
+        .strip_prefix("String(")
+        .and_then(|n| n.strip_suffix(')'))
+    {
+        name = inner;
+    }
+    let name = name
+        .strip_suffix(".toString()")
+        .or_else(|| name.strip_suffix(".href"))
+        .unwrap_or(name);
+    if let Some(url) = bindings.urls.get(name) {
+        return Some(url.clone());
+    }
+    substitute_search_params(target, &bindings.params)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -797,4 +1178,86 @@ export * from "./other";"#,
         // Empty alias map short-circuits.
         assert!(resolve_target_env_alias("${ORDERS_BASE}/x", &EnvAliasMap::new()).is_none());
     }
+
+    #[test]
+    fn resolves_env_var_base_against_profile_origins() {
+        let origins = BTreeMap::from([(
+            "ORDERS_URL".to_string(),
+            "https://orders.acme.dev".to_string(),
+        )]);
+        for target in [
+            "${process.env.ORDERS_URL}/orders/${id}",
+            "`${ORDERS_URL}/orders/${id}`",
+            "ENV_VAR:ORDERS_URL:/orders/${id}",
+        ] {
+            assert_eq!(
+                resolve_target_profile_origin(target, &origins),
+                Some((
+                    "https://orders.acme.dev",
+                    "https://orders.acme.dev/orders/${id}".to_string()
+                )),
+                "{target}"
+            );
+        }
+        // Undefined in this profile, or no env-var base at all.
+        assert!(resolve_target_profile_origin("${process.env.PAY_URL}/x", &origins).is_none());
+        assert!(resolve_target_profile_origin("/orders/${ORDERS_URL}", &origins).is_none());
+    }
+
+    fn build_url_bindings(source: &str) -> UrlBindings {
+        let tmp_dir = tempfile::tempdir().expect("tempdir");
+        let file_path = tmp_dir.path().join("input.ts");
+        std::fs::write(&file_path, source).expect("write file");
+
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+        let module = parse_file(&file_path, &cm, &handler).expect("parsed module");
+
+        UrlBindings::build(&module)
+    }
+
+    #[test]
+    fn new_url_of_process_env_is_an_alias() {
+        let map = build_map(r#"const ORDERS_BASE = new URL(process.env.ORDERS_URL!);"#);
+        assert_eq!(
+            map.get("ORDERS_BASE").map(String::as_str),
+            Some("ORDERS_URL")
+        );
+    }
+
+    #[test]
+    fn url_locals_compose_their_target_and_query_keys() {
+        let bindings = build_url_bindings(
+            r#"
+const ORDERS_BASE = process.env.ORDERS_URL;
+export async function list(status: string, page: number) {
+  const url = new URL(`/orders/${encodeURIComponent(customerId)}`, ORDERS_BASE);
+  url.searchParams.set("status", status);
+  url.searchParams.append("expand", "items");
+  const params = new URLSearchParams({ page: String(page), limit: 20 });
+  params.set("sort", sort.field);
+  const search = new URL("/search", "http://catalog:4000/api/");
+  search.search = params.toString();
+  await fetch(url);
+  await fetch(search.href);
+  await fetch(`${process.env.USERS_URL}/users?${params}`);
+}
+"#,
+        );
+
+        assert_eq!(
+            resolve_url_binding_target("url", &bindings).as_deref(),
+            Some("${ORDERS_BASE}/orders/${customerId}?status=${status}&expand=items")
+        );
+        assert_eq!(
+            resolve_url_binding_target("${search.href}", &bindings).as_deref(),
+            Some("http://catalog:4000/search?page=${page}&limit=20&sort=${sort.field}")
+        );
+        assert_eq!(
+            resolve_url_binding_target("`${process.env.USERS_URL}/users?${params}`", &bindings)
+                .as_deref(),
+            Some("`${process.env.USERS_URL}/users?page=${page}&limit=20&sort=${sort.field}`")
+        );
+        assert!(resolve_url_binding_target("/health", &bindings).is_none());
+    }
 }
@@ -0,0 +1,443 @@
+//! Environment profiles: per-environment dotenv files for URL resolution.
+//!
+//! A call like `fetch(`${process.env.ORDERS_URL}/orders`)` names no host, so
+//! the matcher can only classify it by env-var name. Most repos already record
+//! the concrete value per environment in committed dotenv files. Declaring
+//! them as named profiles in `carrick.json` lets Carrick resolve the prefix to
+//! a concrete origin in every environment:
+//!
+//! ```json
+//! {
+//!   "profiles": {
+//!     "development": { "envFiles": [".env", ".env.development"] },
+//!     "production": { "envFiles": [".env", ".env.production"] }
+//!   }
+//! }
+//! ```
+//!
+//! The engine reads the files once when resolving services
+//! ([`resolve_profiles`]) and keeps only the URL-valued variables, reduced to
+//! their origin (`scheme://host[:port]`, credentials dropped), so the
+//! serialized config never carries secrets or a path into the checkout.
+//! [`crate::env_alias`] resolves a call's `${process.env.X}` base against each
+//! profile's origins, and the analyzer uses the result three ways: an env var
+//! the config does not classify by name is classified by where it points; a
+//! call whose target service differs between profiles is reported as a
+//! [`Issue::ProfileTargetDrift`](crate::findings::Issue::ProfileTargetDrift);
+//! and so is a hardcoded URL on one profile's origin for a var that other
+//! profiles point elsewhere.
+
+use crate::env_alias::resolve_target_profile_origin;
+use crate::url_normalizer::UrlNormalizer;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// One named environment profile.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvProfile {
+    /// Dotenv files to read, relative to `carrick.json`. Later files override
+    /// earlier ones, matching the usual `.env` → `.env.<profile>` layering.
+    /// Drained by [`resolve_profiles`].
+    #[serde(
+        default,
+        rename = "envFiles",
+        alias = "envFile",
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub env_files: Vec<String>,
+    /// Env var name → origin its value points at in this profile.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub origins: BTreeMap<String, String>,
+}
+
+fn one_or_many<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(file) => vec![file],
+        OneOrMany::Many(files) => files,
+    })
+}
+
+/// Where one call target points in one profile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfileTarget {
+    pub profile: String,
+    pub origin: String,
+    /// The service the resolved URL reaches, when the config names one (see
+    /// [`profile_targets`]).
+    pub service: Option<String>,
+}
+
+/// Read every profile's dotenv files into URL origins. Errors name the
+/// profile and file so a typo'd path is caught at load time rather than
+/// silently resolving nothing.
+pub fn resolve_profiles(
+    profiles: &mut BTreeMap<String, EnvProfile>,
+    root: &Path,
+) -> Result<(), String> {
+    for (name, profile) in profiles.iter_mut() {
+        let mut vars = BTreeMap::new();
+        for file in std::mem::take(&mut profile.env_files) {
+            let content = std::fs::read_to_string(root.join(&file))
+                .map_err(|e| format!("profile '{}' env file '{}': {}", name, file, e))?;
+            vars.extend(parse_dotenv(&content));
+        }
+        for (var, value) in &vars {
+            if let Some(origin) = url_origin(&expand(value, &vars)) {
+                profile.origins.insert(var.clone(), origin);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse dotenv syntax: `KEY=value`, optional `export ` prefix, `#` comments,
+/// single/double-quoted values. Multi-line values are not supported.
+fn parse_dotenv(content: &str) -> BTreeMap<String, String> {
+    let mut vars = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        let value = if let Some(quoted) = value
+            .strip_prefix('"')
+            .and_then(|v| v.split_once('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.split_once('\'')))
+        {
+            quoted.0
+        } else {
+            // Unquoted: an inline comment starts at ` #`.
+            value.split(" #").next().unwrap_or(value).trim()
+        };
+        if !key.is_empty() {
+            vars.insert(key.to_string(), value.to_string());
+        }
+    }
+    vars
+}
+
+/// Expand `${VAR}` / `$VAR` references against the same profile's variables
+/// (dotenv-expand semantics, one level deep). Unknown references are left as
+/// written, which keeps the value from parsing as a URL.
+fn expand(value: &str, vars: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+        let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            }
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        match vars.get(name) {
+            Some(resolved) if !name.is_empty() => out.push_str(resolved),
+            _ => out.push_str(&rest[idx..idx + 1 + consumed]),
+        }
+        rest = &after[consumed..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `scheme://host[:port]` of an http(s)/ws(s) URL value, lowercased, with any
+/// userinfo dropped. A base path is kept (`http://gw/api/orders`), since it is
+/// part of the prefix the code appends to.
+fn url_origin(value: &str) -> Option<String> {
+    let (scheme, rest) = value.split_once("://")?;
+    let scheme = scheme.to_ascii_lowercase();
+    if !matches!(scheme.as_str(), "http" | "https" | "ws" | "wss") || value.contains('$') {
+        return None;
+    }
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let rest = &rest[..end];
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let authority = authority.rsplit('@').next()?.to_ascii_lowercase();
+    if authority.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "{}://{}{}",
+        scheme,
+        authority,
+        path.trim_end_matches('/')
+    ))
+}
+
+/// Host of an origin produced by [`url_origin`], without port.
+pub fn origin_host(origin: &str) -> &str {
+    let rest = origin.split_once("://").map_or(origin, |(_, r)| r);
+    let authority = rest.split('/').next().unwrap_or(rest);
+    authority.split(':').next().unwrap_or(authority)
+}
+
+/// Whether an origin points at the local machine. Pointing development at a
+/// local process is the norm, so such origins never classify a var.
+pub fn is_local_origin(origin: &str) -> bool {
+    matches!(origin_host(origin), "localhost" | "127.0.0.1" | "0.0.0.0")
+        || origin.contains("://[::1]")
+}
+
+/// Resolve a call target's env-var base in every profile that defines it,
+/// through [`resolve_target_profile_origin`]. Each resolved URL names a
+/// service only when the config does: a gateway route's `service` or a host
+/// listed in `hostServices` (or found in a deployment manifest). A hostname
+/// alone is never taken to name a service.
+pub fn profile_targets(
+    profiles: &BTreeMap<String, EnvProfile>,
+    target: &str,
+    normalizer: &UrlNormalizer,
+) -> Vec<ProfileTarget> {
+    profiles
+        .iter()
+        .filter_map(|(name, profile)| {
+            let (origin, url) = resolve_target_profile_origin(target, &profile.origins)?;
+            Some(ProfileTarget {
+                profile: name.clone(),
+                origin: origin.to_string(),
+                service: normalizer.consumer_call_target(&url).1,
+            })
+        })
+        .collect()
+}
+
+/// Whether the resolved targets disagree on which service the call reaches.
+/// Only targets that name a service take part, so drift is reported between
+/// two resolved services, never against a host nothing maps.
+pub fn targets_drift(targets: &[ProfileTarget]) -> bool {
+    let services: BTreeSet<&str> = targets
+        .iter()
+        .filter_map(|t| t.service.as_deref())
+        .collect();
+    services.len() > 1
+}
+
+/// The env var a hardcoded URL pins to one environment: the var some profile
+/// resolves to the URL's own origin while another profile points it at a
+/// different one (typically a dev host committed in one file, with
+/// `${process.env.X}` used for the same service elsewhere). Returns the var,
+/// the pinned origin and the var's per-profile targets; the longest matching
+/// origin wins.
+pub fn pinned_profile_var(
+    profiles: &BTreeMap<String, EnvProfile>,
+    url: &str,
+    normalizer: &UrlNormalizer,
+) -> Option<(String, String, Vec<ProfileTarget>)> {
+    let url = url.trim_matches(['`', '"', '\'']);
+    let (env_var, origin) = profiles
+        .values()
+        .flat_map(|profile| profile.origins.iter())
+        .filter(|(_, origin)| {
+            url.get(..origin.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(origin))
+                && url[origin.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| matches!(c, '/' | '?' | '#'))
+        })
+        .max_by_key(|(_, origin)| origin.len())?;
+    let targets = profile_targets(
+        profiles,
+        &format!("ENV_VAR:{}:{}", env_var, &url[origin.len()..]),
+        normalizer,
+    );
+    targets
+        .iter()
+        .any(|t| t.origin != *origin)
+        .then(|| (env_var.clone(), origin.clone(), targets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dotenv_syntax() {
+        let vars = parse_dotenv(
+            "# comment\nexport API_URL=https://api.acme.dev\nQUOTED=\"http://a:1\" # trailing\n\
+             SINGLE='x y'\nPLAIN=value # note\nnot a pair\n",
+        );
+        assert_eq!(vars["API_URL"], "https://api.acme.dev");
+        assert_eq!(vars["QUOTED"], "http://a:1");
+        assert_eq!(vars["SINGLE"], "x y");
+        assert_eq!(vars["PLAIN"], "value");
+        assert_eq!(vars.len(), 4);
+    }
+
+    #[test]
+    fn resolves_layered_files_to_origins() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".env"),
+            "HOST=orders.acme.dev\nORDERS_URL=https://${HOST}\nTOKEN=secret\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(".env.staging"),
+            "HOST=orders.staging.acme.dev\nBILLING_URL=http://user:pw@billing:8080/v2/\n",
+        )
+        .unwrap();
+
+        let mut profiles: BTreeMap<String, EnvProfile> = serde_json::from_str(
+            r#"{ "staging": { "envFiles": [".env", ".env.staging"] }, "prod": { "envFile": ".env" } }"#,
+        )
+        .unwrap();
+        resolve_profiles(&mut profiles, dir.path()).unwrap();
+
+        let staging = &profiles["staging"];
+        assert!(staging.env_files.is_empty());
+        assert_eq!(
+            staging.origins,
+            BTreeMap::from([
+                (
+                    "BILLING_URL".to_string(),
+                    "http://billing:8080/v2".to_string()
+                ),
+                (
+                    "ORDERS_URL".to_string(),
+                    "https://orders.staging.acme.dev".to_string()
+                ),
+            ])
+        );
+        assert_eq!(
+            profiles["prod"].origins,
+            BTreeMap::from([(
+                "ORDERS_URL".to_string(),
+                "https://orders.acme.dev".to_string()
+            )])
+        );
+    }
+
+    #[test]
+    fn missing_env_file_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut profiles = BTreeMap::from([(
+            "staging".to_string(),
+            EnvProfile {
+                env_files: vec![".env.staging".to_string()],
+                ..Default::default()
+            },
+        )]);
+        let err = resolve_profiles(&mut profiles, dir.path()).unwrap_err();
+        assert!(err.contains("profile 'staging'") && err.contains(".env.staging"));
+    }
+
+    #[test]
+    fn drift_only_when_both_services_resolve() {
+        let profile = |origin: &str| EnvProfile {
+            origins: BTreeMap::from([("ORDERS_URL".to_string(), origin.to_string())]),
+            ..Default::default()
+        };
+        let config = crate::config::Config {
+            host_services: BTreeMap::from([
+                ("orders.acme.dev".to_string(), "orders".to_string()),
+                ("orders-staging.acme.dev".to_string(), "orders".to_string()),
+                (
+                    "billing.staging.acme.dev".to_string(),
+                    "billing".to_string(),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let normalizer = UrlNormalizer::new(&config);
+        let target = "${process.env.ORDERS_URL}/orders";
+
+        // Same service behind environment-specific hosts; dev is local.
+        let consistent = BTreeMap::from([
+            ("development".to_string(), profile("http://localhost:3000")),
+            (
+                "staging".to_string(),
+                profile("https://orders-staging.acme.dev"),
+            ),
+            ("production".to_string(), profile("https://orders.acme.dev")),
+        ]);
+        let targets = profile_targets(&consistent, target, &normalizer);
+        assert_eq!(targets.len(), 3);
+        assert_eq!(targets[0].service, None);
+        assert!(!targets_drift(&targets));
+
+        // Staging points at a different service than production.
+        let drifted = BTreeMap::from([
+            (
+                "staging".to_string(),
+                profile("https://billing.staging.acme.dev"),
+            ),
+            ("production".to_string(), profile("https://orders.acme.dev")),
+        ]);
+        let targets = profile_targets(&drifted, target, &normalizer);
+        assert!(targets_drift(&targets));
+        assert_eq!(targets[0].service.as_deref(), Some("orders"));
+        assert_eq!(targets[1].service.as_deref(), Some("billing"));
+
+        // A host nothing maps is not guessed from its name.
+        let unmapped = BTreeMap::from([
+            (
+                "staging".to_string(),
+                profile("https://billing-v2.acme.dev"),
+            ),
+            ("production".to_string(), profile("https://orders.acme.dev")),
+        ]);
+        assert!(!targets_drift(&profile_targets(
+            &unmapped,
+            target,
+            &normalizer
+        )));
+    }
+
+    #[test]
+    fn hardcoded_url_pinned_to_one_profile() {
+        let profile = |origin: &str| EnvProfile {
+            origins: BTreeMap::from([("ORDERS_URL".to_string(), origin.to_string())]),
+            ..Default::default()
+        };
+        let profiles = BTreeMap::from([
+            ("development".to_string(), profile("http://localhost:3001")),
+            ("production".to_string(), profile("https://orders.acme.dev")),
+        ]);
+        let normalizer = UrlNormalizer::default_permissive();
+
+        let (env_var, origin, targets) = pinned_profile_var(
+            &profiles,
+            "`http://localhost:3001/orders/${id}`",
+            &normalizer,
+        )
+        .unwrap();
+        assert_eq!(env_var, "ORDERS_URL");
+        assert_eq!(origin, "http://localhost:3001");
+        assert_eq!(
+            targets
+                .iter()
+                .map(|t| t.origin.as_str())
+                .collect::<Vec<_>>(),
+            vec!["http://localhost:3001", "https://orders.acme.dev"]
+        );
+        // A different port is a different origin.
+        assert!(pinned_profile_var(&profiles, "http://localhost:30011/x", &normalizer).is_none());
+        // A var every profile points at the same origin pins nothing.
+        let same = BTreeMap::from([
+            ("staging".to_string(), profile("https://orders.acme.dev")),
+            ("production".to_string(), profile("https://orders.acme.dev")),
+        ]);
+        assert!(pinned_profile_var(&same, "https://orders.acme.dev/orders", &normalizer).is_none());
+    }
+}
@@ -31,6 +31,10 @@ pub struct EvalProjection {
     pub cross_repo_matches: Vec<CrossRepoMatch>,
     /// Dependency version conflicts across the scanned repos (contract §4).
     pub dependency_conflicts: Vec<EvalDependencyConflict>,
+    /// Every finding, by fingerprint, so runs can be diffed finding by
+    /// finding. Defaults to empty for projections recorded before it existed.
+    #[serde(default)]
+    pub findings: Vec<EvalFinding>,
 }
 
 /// One extracted operation (endpoint or call), flattened for scoring.
@@ -122,6 +126,17 @@ pub struct EvalDependencyConflict {
     pub severity: String,
 }
 
+/// One finding, reduced to its stable identity.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvalFinding {
+    /// [`crate::findings::Finding::fingerprint`].
+    pub fingerprint: String,
+    /// The wire `kind` tag.
+    pub kind: String,
+    /// `METHOD path`, or the package name for dependency conflicts.
+    pub label: String,
+}
+
 impl EvalProjection {
     /// Build the projection from analyzer results plus the merged type manifest.
     /// The manifest is joined per op by `OperationKey`; deps come straight from
@@ -156,6 +171,16 @@ impl EvalProjection {
             .map(EvalDependencyConflict::from_conflict)
             .collect();
         dependency_conflicts.sort_by(|a, b| a.package.cmp(&b.package));
+        let mut findings: Vec<EvalFinding> = result
+            .findings
+            .iter()
+            .map(|f| EvalFinding {
+                fingerprint: f.fingerprint(),
+                kind: f.kind().to_string(),
+                label: f.label(),
+            })
+            .collect();
+        findings.sort_by(|a, b| a.fingerprint.cmp(&b.fingerprint));
         // Intra-repo pub/sub (and any exact-key) self-loops must not surface in
         // the cross-repo projection. A topic that ONE repo both produces and
         // consumes, with no other repo on the key, is a self-edge — not a
@@ -198,6 +223,7 @@ impl EvalProjection {
                 .collect(),
             cross_repo_matches,
             dependency_conflicts,
+            findings,
         }
     }
 
@@ -605,6 +631,7 @@ mod tests {
             resolved_definition: resolved.map(String::from),
             expanded_definition: None,
             primary_type_symbol: primary_type_symbol.map(String::from),
+            accessed_fields: None,
         }
     }
 
@@ -689,6 +716,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
 
@@ -749,6 +777,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
 
@@ -848,6 +877,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: matches,
         };
 
@@ -932,6 +962,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
 
@@ -993,6 +1024,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
 
@@ -1061,6 +1093,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
 
@@ -1130,6 +1163,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
         // Request entry FIRST (the manifest order that used to win).
@@ -1202,6 +1236,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
         let manifest = vec![manifest_entry_at(
@@ -1265,6 +1300,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         };
         let manifest = vec![
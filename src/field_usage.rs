@@ -0,0 +1,531 @@
+//! Which response fields a consumer actually reads.
+//!
+//! A type mismatch on a field the caller never touches can't break it. This
+//! pass follows the value a consumer call site binds —
+//!
+//! ```text
+//! const res = await fetch(`/users/${id}`);
+//! const user = await res.json();
+//! render(user.profile.name, user.email);
+//! ```
+//!
+//! and records the body paths read downstream (`profile.name`, `email`). The
+//! type check then grades a mismatch on a never-read field as advisory and
+//! keeps one on a read field at full weight.
+//!
+//! The walk is deliberately conservative: the moment the value escapes —
+//! passed to a function, spread into an object, indexed with a computed
+//! key, or returned — usage is unknown (`None`) and every field counts as read.
+//! Bindings are matched by `to_id()` on a resolved module
+//! ([`crate::parser::parse_module`]), so a same-named binding in another
+//! scope is never mistaken for the traced one.
+
+use crate::cloud_storage::{ManifestRole, ManifestTypeKind, TypeManifestEntry};
+use crate::operation::Protocol;
+use crate::parser::{parse_module, unwrap_expr};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use swc_common::{SourceMap, sync::Lrc};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Stamp [`TypeManifestEntry::accessed_fields`] on every HTTP consumer
+/// response entry whose call site can be traced. Each source file is parsed
+/// at most once.
+pub fn stamp_accessed_fields(manifest: &mut [TypeManifestEntry], repo_root: &Path) {
+    let mut parsed: HashMap<PathBuf, Option<(Lrc<SourceMap>, Module)>> = HashMap::new();
+    for entry in manifest.iter_mut() {
+        if entry.role != ManifestRole::Consumer
+            || entry.type_kind != ManifestTypeKind::Response
+            || entry.key.protocol() != Protocol::Http
+        {
+            continue;
+        }
+        let Some(path) = resolve(&entry.file_path, repo_root) else {
+            continue;
+        };
+        let Some((cm, module)) = parsed
+            .entry(path.clone())
+            .or_insert_with(|| parse_module(&path))
+            .as_ref()
+        else {
+            continue;
+        };
+        entry.accessed_fields = accessed_fields(module, cm, entry.line_number as usize)
+            .map(|f| f.into_iter().collect());
+    }
+}
+
+/// Whether a mismatch at `path` touches anything the consumer reads. The
+/// empty path (the whole type) is read as soon as any field is; otherwise a
+/// read of the field itself, of something under it, or of its parent counts.
+pub fn is_read(path: &str, accessed: &[String]) -> bool {
+    if path.is_empty() {
+        return !accessed.is_empty();
+    }
+    accessed.iter().any(|a| {
+        a == path
+            || a.strip_prefix(path)
+                .is_some_and(|rest| rest.starts_with('.'))
+            || path
+                .strip_prefix(a.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+fn resolve(file: &str, repo_root: &Path) -> Option<PathBuf> {
+    let direct = Path::new(file);
+    if direct.is_absolute() && direct.is_file() {
+        return Some(direct.to_path_buf());
+    }
+    let joined = repo_root.join(file.trim_start_matches("./"));
+    joined.is_file().then_some(joined)
+}
+
+/// What a binding holds: the raw client result (a fetch `Response`, an axios
+/// response, or — for wrapper clients — the body itself), or the decoded body.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Holds {
+    Result,
+    Body,
+}
+
+/// Body paths read from the value bound by the call on 1-based `line`.
+fn accessed_fields(module: &Module, cm: &Lrc<SourceMap>, line: usize) -> Option<BTreeSet<String>> {
+    let mut finder = DeclaratorFinder {
+        cm,
+        line,
+        found: None,
+    };
+    module.visit_with(&mut finder);
+    let (name, holds) = finder.found?;
+    pattern_fields(module, &name, holds)
+}
+
+/// Finds the innermost `const x = <call on line>` declarator.
+struct DeclaratorFinder<'a> {
+    cm: &'a Lrc<SourceMap>,
+    line: usize,
+    found: Option<(Pat, Holds)>,
+}
+
+impl Visit for DeclaratorFinder<'_> {
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Some(init) = node.init.as_deref() {
+            let init = unwrap(init);
+            if let Expr::Call(call) = init
+                && self.cm.lookup_char_pos(call.span.lo).line == self.line
+            {
+                let holds = if is_json_terminated(call) {
+                    Holds::Body
+                } else {
+                    Holds::Result
+                };
+                self.found = Some((node.name.clone(), holds));
+            }
+        }
+        node.visit_children_with(self);
+    }
+}
+
+/// Strip `await` as well as [`unwrap_expr`]'s wrappers.
+fn unwrap(expr: &Expr) -> &Expr {
+    match unwrap_expr(expr) {
+        Expr::Await(e) => unwrap(&e.arg),
+        expr => expr,
+    }
+}
+
+fn method_name(call: &CallExpr) -> Option<&str> {
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let Expr::Member(member) = unwrap(callee) else {
+        return None;
+    };
+    match &member.prop {
+        MemberProp::Ident(ident) => Some(ident.sym.as_ref()),
+        _ => None,
+    }
+}
+
+/// `….json()` or `….then(r => r.json())`: the call yields the decoded body.
+fn is_json_terminated(call: &CallExpr) -> bool {
+    match method_name(call) {
+        Some("json") => true,
+        Some("then") => call.args.first().is_some_and(|arg| match unwrap(&arg.expr) {
+            Expr::Arrow(arrow) => match &*arrow.body {
+                BlockStmtOrExpr::Expr(body) => {
+                    matches!(unwrap(body), Expr::Call(inner) if method_name(inner) == Some("json"))
+                }
+                BlockStmtOrExpr::BlockStmt(_) => false,
+            },
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+fn pattern_fields(module: &Module, pat: &Pat, holds: Holds) -> Option<BTreeSet<String>> {
+    match pat {
+        Pat::Ident(binding) => binding_fields(module, binding.id.to_id(), holds),
+        Pat::Object(object) => object_fields(module, object, holds),
+        Pat::Assign(assign) => pattern_fields(module, &assign.left, holds),
+        _ => None,
+    }
+}
+
+fn object_fields(module: &Module, object: &ObjectPat, holds: Holds) -> Option<BTreeSet<String>> {
+    let mut props = Vec::new();
+    for prop in &object.props {
+        match prop {
+            ObjectPatProp::KeyValue(kv) => {
+                let key = match &kv.key {
+                    PropName::Ident(ident) => ident.sym.to_string(),
+                    PropName::Str(s) => s.value.to_string(),
+                    _ => return None,
+                };
+                props.push((key, Bound::Pat(&kv.value)));
+            }
+            ObjectPatProp::Assign(assign) => {
+                props.push((assign.key.sym.to_string(), Bound::Ident(assign.key.to_id())));
+            }
+            ObjectPatProp::Rest(_) => return None,
+        }
+    }
+    // An axios-style `{ data }` unwraps the body; any other result pattern is
+    // a wrapper client handing back the body itself.
+    if holds == Holds::Result
+        && let Some((_, bound)) = props.iter().find(|(key, _)| key == "data")
+    {
+        return bound.fields(module);
+    }
+    let mut fields = BTreeSet::new();
+    for (key, bound) in props {
+        // A destructured key whose own use is unknown still counts as read.
+        let sub = bound.fields(module).unwrap_or_default();
+        fields.extend(prefixed(&[key], sub));
+    }
+    Some(fields)
+}
+
+/// The binding one destructured key lands in.
+enum Bound<'a> {
+    Pat(&'a Pat),
+    Ident(Id),
+}
+
+impl Bound<'_> {
+    fn fields(&self, module: &Module) -> Option<BTreeSet<String>> {
+        match self {
+            Bound::Pat(pat) => pattern_fields(module, pat, Holds::Body),
+            Bound::Ident(id) => binding_fields(module, id.clone(), Holds::Body),
+        }
+    }
+}
+
+/// `sub` under `prefix`; the prefix itself when nothing below it is read.
+fn prefixed(prefix: &[String], sub: BTreeSet<String>) -> BTreeSet<String> {
+    let head = prefix.join(".");
+    if sub.is_empty() {
+        return [head].into_iter().filter(|h| !h.is_empty()).collect();
+    }
+    sub.into_iter()
+        .map(|s| {
+            if head.is_empty() {
+                s
+            } else {
+                format!("{}.{}", head, s)
+            }
+        })
+        .collect()
+}
+
+fn binding_fields(module: &Module, target: Id, holds: Holds) -> Option<BTreeSet<String>> {
+    let mut uses = UseCollector {
+        target,
+        paths: Vec::new(),
+        json_bindings: Vec::new(),
+        bindings: Vec::new(),
+        escaped: false,
+    };
+    module.visit_with(&mut uses);
+    if uses.escaped {
+        return None;
+    }
+    let mut fields = BTreeSet::new();
+    if holds == Holds::Body {
+        if !uses.json_bindings.is_empty() {
+            return None;
+        }
+        fields.extend(uses.paths.iter().map(|p| p.join(".")));
+        for (prefix, pat) in &uses.bindings {
+            fields.extend(prefixed(prefix, pattern_fields(module, pat, Holds::Body)?));
+        }
+        return Some(fields);
+    }
+    if !uses.json_bindings.is_empty() {
+        // `const body = await res.json()`: status/header reads on the
+        // response itself say nothing about the body.
+        for pat in &uses.json_bindings {
+            fields.extend(pattern_fields(module, pat, Holds::Body)?);
+        }
+        return Some(fields);
+    }
+    let is_data = |path: &[String]| path.first().is_some_and(|s| s == "data");
+    let axios_like = uses.paths.iter().any(|p| is_data(p))
+        || uses.bindings.iter().any(|(prefix, pat)| {
+            is_data(prefix) || (prefix.is_empty() && matches!(pat, Pat::Object(_)))
+        });
+    if !axios_like {
+        fields.extend(uses.paths.iter().map(|p| p.join(".")));
+        for (prefix, pat) in &uses.bindings {
+            fields.extend(prefixed(
+                prefix,
+                pattern_fields(module, pat, Holds::Result)?,
+            ));
+        }
+        return Some(fields);
+    }
+    for path in uses.paths.iter().filter(|p| is_data(p)) {
+        if path.len() == 1 {
+            return None;
+        }
+        fields.insert(path[1..].join("."));
+    }
+    for (prefix, pat) in &uses.bindings {
+        if prefix.is_empty() {
+            fields.extend(pattern_fields(module, pat, Holds::Result)?);
+        } else if is_data(prefix) {
+            let sub = pattern_fields(module, pat, Holds::Body)?;
+            if prefix.len() == 1 && sub.is_empty() {
+                continue;
+            }
+            fields.extend(prefixed(&prefix[1..], sub));
+        }
+    }
+    Some(fields)
+}
+
+/// Collects the static member paths read off one binding, the bindings that
+/// decode it (`const body = await v.json()`), and the declarations that
+/// alias or destructure it or a path under it (`const { a } = v.data`). Any
+/// other use escapes.
+struct UseCollector {
+    target: Id,
+    paths: Vec<Vec<String>>,
+    json_bindings: Vec<Pat>,
+    bindings: Vec<(Vec<String>, Pat)>,
+    escaped: bool,
+}
+
+impl UseCollector {
+    fn is_target(&self, expr: &Expr) -> bool {
+        matches!(unwrap(expr), Expr::Ident(ident) if ident.to_id() == self.target)
+    }
+
+    /// Segments of a member chain rooted at the target. `Err(())` when a
+    /// segment is computed from a non-literal key.
+    fn chain(&self, expr: &Expr) -> Option<Result<Vec<String>, ()>> {
+        let (obj, prop) = match expr {
+            Expr::Member(member) => (&*member.obj, &member.prop),
+            Expr::OptChain(opt) => match &*opt.base {
+                OptChainBase::Member(member) => (&*member.obj, &member.prop),
+                OptChainBase::Call(_) => return None,
+            },
+            Expr::Paren(p) => return self.chain(&p.expr),
+            Expr::TsNonNull(e) => return self.chain(&e.expr),
+            Expr::TsAs(e) => return self.chain(&e.expr),
+            _ => return None,
+        };
+        let mut segments = if self.is_target(obj) {
+            Ok(Vec::new())
+        } else {
+            self.chain(obj)?
+        };
+        let segment = match prop {
+            MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+            MemberProp::Computed(computed) => match &*computed.expr {
+                Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+                Expr::Lit(Lit::Num(_)) => None,
+                _ => {
+                    segments = Err(());
+                    None
+                }
+            },
+            MemberProp::PrivateName(_) => None,
+        };
+        // Numeric indexes (`items[0]`) stay on the array's own path.
+        if let (Ok(segments), Some(segment)) = (&mut segments, segment) {
+            segments.push(segment);
+        }
+        Some(segments)
+    }
+
+    fn visit_computed_keys(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Member(member) => {
+                self.visit_computed_keys(&member.obj);
+                if let MemberProp::Computed(computed) = &member.prop {
+                    computed.expr.visit_with(self);
+                }
+            }
+            Expr::OptChain(opt) => {
+                if let OptChainBase::Member(member) = &*opt.base {
+                    self.visit_computed_keys(&member.obj);
+                    if let MemberProp::Computed(computed) = &member.prop {
+                        computed.expr.visit_with(self);
+                    }
+                }
+            }
+            Expr::Paren(p) => self.visit_computed_keys(&p.expr),
+            Expr::TsNonNull(e) => self.visit_computed_keys(&e.expr),
+            Expr::TsAs(e) => self.visit_computed_keys(&e.expr),
+            _ => {}
+        }
+    }
+}
+
+impl Visit for UseCollector {
+    fn visit_expr(&mut self, expr: &Expr) {
+        if self.is_target(expr) {
+            self.escaped = true;
+            return;
+        }
+        match self.chain(expr) {
+            Some(Ok(path)) => {
+                if path.is_empty() {
+                    self.escaped = true;
+                } else {
+                    self.paths.push(path);
+                }
+                self.visit_computed_keys(expr);
+            }
+            Some(Err(())) => self.escaped = true,
+            None => expr.visit_children_with(self),
+        }
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && let Some(chain) = self.chain(callee)
+        {
+            // `v.items.map(…)` reads `items`; the method name is not a field.
+            match chain {
+                Ok(mut path) if path.len() > 1 => {
+                    path.pop();
+                    self.paths.push(path);
+                }
+                _ => self.escaped = true,
+            }
+            self.visit_computed_keys(callee);
+            call.args.visit_with(self);
+            return;
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Some(init) = node.init.as_deref() {
+            let init = unwrap(init);
+            if matches!(node.name, Pat::Ident(_) | Pat::Object(_)) {
+                let prefix = if self.is_target(init) {
+                    Some(Vec::new())
+                } else {
+                    self.chain(init).and_then(Result::ok)
+                };
+                if let Some(prefix) = prefix {
+                    self.bindings.push((prefix, node.name.clone()));
+                    self.visit_computed_keys(init);
+                    return;
+                }
+            }
+            if let Expr::Call(call) = init
+                && call.args.is_empty()
+                && method_name(call) == Some("json")
+                && let Callee::Expr(callee) = &call.callee
+                && let Expr::Member(member) = unwrap(callee)
+                && self.is_target(&member.obj)
+            {
+                self.json_bindings.push(node.name.clone());
+                return;
+            }
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_prop(&mut self, prop: &Prop) {
+        if let Prop::Shorthand(ident) = prop
+            && ident.to_id() == self.target
+        {
+            self.escaped = true;
+            return;
+        }
+        prop.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields_at(source: &str, line: usize) -> Option<Vec<String>> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("client.ts");
+        std::fs::write(&path, source).unwrap();
+        let (cm, module) = parse_module(&path).unwrap();
+        accessed_fields(&module, &cm, line).map(|f| f.into_iter().collect())
+    }
+
+    #[test]
+    fn fetch_then_json_reads_body_paths() {
+        let source = "async function load(id: string) {\n  const res = await fetch(`/users/${id}`);\n  if (!res.ok) throw new Error('x');\n  const user = await res.json();\n  return [user.profile.name, user.email, user.tags.map((t) => t)];\n}\n";
+        assert_eq!(
+            fields_at(source, 2),
+            Some(vec![
+                "email".to_string(),
+                "profile.name".to_string(),
+                "tags".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn axios_data_and_destructuring() {
+        let source = "const { data } = await axios.get('/orders');\nconsole.log(data.total, data['currency']);\nconst res = await axios.get('/users');\nconst { id, address: { city } } = res.data;\n";
+        assert_eq!(
+            fields_at(source, 1),
+            Some(vec!["currency".to_string(), "total".to_string()])
+        );
+        assert_eq!(
+            fields_at(source, 3),
+            Some(vec!["address.city".to_string(), "id".to_string()])
+        );
+    }
+
+    #[test]
+    fn escaping_values_have_unknown_usage() {
+        let source = "const a = await fetch('/a').then((r) => r.json());\nsave(a);\nconst b = await api.get('/b');\nconst c = { b };\nconst d = await fetch('/d').then((r) => r.json());\nconst k = pick();\nuse(d[k]);\n";
+        assert_eq!(fields_at(source, 1), None);
+        assert_eq!(fields_at(source, 3), None);
+        assert_eq!(fields_at(source, 5), None);
+    }
+
+    #[test]
+    fn shadowed_bindings_are_not_the_traced_value() {
+        let source = "const res = await fetch('/users');\nconst user = await res.json();\nconsole.log(user.email);\nfunction audit(user: any) {\n  return user.password;\n}\n{\n  const user = load();\n  save(user);\n}\n";
+        assert_eq!(fields_at(source, 1), Some(vec!["email".to_string()]));
+    }
+
+    #[test]
+    fn read_matching_covers_parents_and_children() {
+        let accessed = vec!["profile.name".to_string(), "email".to_string()];
+        assert!(is_read("profile", &accessed));
+        assert!(is_read("profile.name.first", &accessed));
+        assert!(is_read("email", &accessed));
+        assert!(is_read("", &accessed));
+        assert!(!is_read("emailVerified", &accessed));
+        assert!(!is_read("createdAt", &accessed));
+        assert!(!is_read("", &[]));
+    }
+}
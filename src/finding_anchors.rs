@@ -0,0 +1,326 @@
+//! Line-insensitive call-site anchors for finding fingerprints.
+//!
+//! A call site is reported as `file:line`, and the line moves whenever code
+//! above it changes. An anchor names the same site without the line number:
+//! the enclosing named function plus a hash of the call's source line,
+//! whitespace stripped —
+//!
+//! ```text
+//! loadOrders#3f2a9c1e04b7
+//! ```
+//!
+//! so adding an import or reformatting the file leaves the anchor, and with it
+//! [`Finding::fingerprint`], unchanged. Code outside any named function
+//! anchors to `<module>`. Call sites whose file isn't in the scanned checkout
+//! (another repo's consumers) get no anchor; the fingerprint falls back to
+//! their file paths.
+
+use crate::file_finder::find_files;
+use crate::findings::Finding;
+use crate::parser::parse_file;
+use crate::type_manifest::parse_file_location;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use swc_common::{
+    GLOBALS, Globals, SourceMap, Span,
+    errors::{ColorConfig, Handler},
+    sync::Lrc,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Anchor name for code outside every named function.
+const MODULE_SCOPE: &str = "<module>";
+
+/// Attach anchors to every finding's call sites. Source files are parsed at
+/// most once per run.
+pub fn attach_anchors(findings: Vec<Finding>, repo_root: &Path) -> Vec<Finding> {
+    let mut sources = SourceIndex::new(repo_root);
+    findings
+        .into_iter()
+        .map(|finding| {
+            let anchors: Vec<String> = finding
+                .call_sites()
+                .iter()
+                .filter_map(|site| sources.anchor(site))
+                .collect();
+            if anchors.is_empty() {
+                finding
+            } else {
+                finding.with_call_site_anchors(anchors)
+            }
+        })
+        .collect()
+}
+
+/// One parsed source file: its lines and named function extents.
+struct SourceFile {
+    lines: Vec<String>,
+    functions: Vec<FunctionExtent>,
+}
+
+struct FunctionExtent {
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+struct SourceIndex<'a> {
+    repo_root: &'a Path,
+    /// Repo files, listed lazily for call sites whose path is relative to a
+    /// service directory rather than the repo root.
+    repo_files: Option<Vec<PathBuf>>,
+    parsed: HashMap<PathBuf, Option<SourceFile>>,
+}
+
+impl<'a> SourceIndex<'a> {
+    fn new(repo_root: &'a Path) -> Self {
+        Self {
+            repo_root,
+            repo_files: None,
+            parsed: HashMap::new(),
+        }
+    }
+
+    fn anchor(&mut self, call_site: &str) -> Option<String> {
+        if !call_site.contains(':') {
+            // A bare file carries no line to anchor.
+            return None;
+        }
+        let (file, line) = parse_file_location(call_site);
+        let path = self.resolve(&file)?;
+        let source = self
+            .parsed
+            .entry(path.clone())
+            .or_insert_with(|| parse_source(&path))
+            .as_ref()?;
+        anchor_at(source, line as usize)
+    }
+
+    fn resolve(&mut self, file: &str) -> Option<PathBuf> {
+        let direct = Path::new(file);
+        if direct.is_absolute() && direct.is_file() {
+            return Some(direct.to_path_buf());
+        }
+        let joined = self.repo_root.join(file.trim_start_matches("./"));
+        if joined.is_file() {
+            return Some(joined);
+        }
+        let root = self.repo_root;
+        let files = self.repo_files.get_or_insert_with(|| {
+            find_files(
+                &root.to_string_lossy(),
+                &["node_modules", "dist", "build", ".next"],
+            )
+            .0
+        });
+        let suffix = Path::new(file.trim_start_matches("./"));
+        let mut matches = files.iter().filter(|f| f.ends_with(suffix));
+        // Ambiguous suffixes (two services with the same relative file)
+        // stay unanchored rather than hashing the wrong source.
+        match (matches.next(), matches.next()) {
+            (Some(only), None) => Some(only.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn parse_source(path: &Path) -> Option<SourceFile> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let cm: Lrc<SourceMap> = Default::default();
+    let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+    let functions = GLOBALS.set(&Globals::new(), || {
+        let module = parse_file(path, &cm, &handler)?;
+        let mut collector = FunctionCollector {
+            cm: &cm,
+            class: None,
+            functions: Vec::new(),
+        };
+        module.visit_with(&mut collector);
+        Some(collector.functions)
+    });
+    Some(SourceFile {
+        lines: content.lines().map(str::to_string).collect(),
+        // An unparseable file still anchors on its snippet, at module scope.
+        functions: functions.unwrap_or_default(),
+    })
+}
+
+/// The anchor for a 1-based line: innermost named function containing it,
+/// plus the hash of the normalized line.
+fn anchor_at(source: &SourceFile, line: usize) -> Option<String> {
+    let snippet = normalize_snippet(source.lines.get(line.checked_sub(1)?)?);
+    if snippet.is_empty() {
+        return None;
+    }
+    let function = source
+        .functions
+        .iter()
+        .filter(|f| f.start <= line && line <= f.end)
+        .min_by_key(|f| f.end - f.start)
+        .map_or(MODULE_SCOPE, |f| f.name.as_str());
+    let hash = format!("{:x}", Sha256::digest(snippet.as_bytes()));
+    Some(format!("{}#{}", function, &hash[..12]))
+}
+
+/// Strip whitespace and trailing separators so reformatting doesn't change
+/// the snippet hash.
+fn normalize_snippet(line: &str) -> String {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.trim_end_matches([';', ',']).to_string()
+}
+
+/// Collects named function extents: declarations, class methods
+/// (`Class.method`), object methods, and function/arrow expressions bound to
+/// a variable or property. Anonymous callbacks are skipped so their body
+/// anchors to the named function around them.
+struct FunctionCollector<'a> {
+    cm: &'a Lrc<SourceMap>,
+    class: Option<String>,
+    functions: Vec<FunctionExtent>,
+}
+
+impl FunctionCollector<'_> {
+    fn record(&mut self, name: String, span: Span) {
+        if span.is_dummy() {
+            return;
+        }
+        self.functions.push(FunctionExtent {
+            name,
+            start: self.cm.lookup_char_pos(span.lo).line,
+            end: self.cm.lookup_char_pos(span.hi).line,
+        });
+    }
+
+    fn member_name(&self, key: &PropName) -> Option<String> {
+        let name = match key {
+            PropName::Ident(ident) => ident.sym.to_string(),
+            PropName::Str(s) => s.value.to_string(),
+            _ => return None,
+        };
+        Some(match &self.class {
+            Some(class) => format!("{}.{}", class, name),
+            None => name,
+        })
+    }
+}
+
+fn function_span(expr: &Expr) -> Option<Span> {
+    match expr {
+        Expr::Arrow(arrow) => Some(arrow.span),
+        Expr::Fn(f) => Some(f.function.span),
+        Expr::Paren(p) => function_span(&p.expr),
+        _ => None,
+    }
+}
+
+impl Visit for FunctionCollector<'_> {
+    fn visit_fn_decl(&mut self, node: &FnDecl) {
+        self.record(node.ident.sym.to_string(), node.function.span);
+        node.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, node: &ClassDecl) {
+        let outer = self.class.replace(node.ident.sym.to_string());
+        node.visit_children_with(self);
+        self.class = outer;
+    }
+
+    fn visit_class_method(&mut self, node: &ClassMethod) {
+        if let Some(name) = self.member_name(&node.key) {
+            self.record(name, node.function.span);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_class_prop(&mut self, node: &ClassProp) {
+        if let Some(span) = node.value.as_deref().and_then(function_span)
+            && let Some(name) = self.member_name(&node.key)
+        {
+            self.record(name, span);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_method_prop(&mut self, node: &MethodProp) {
+        if let Some(name) = self.member_name(&node.key) {
+            self.record(name, node.function.span);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_key_value_prop(&mut self, node: &KeyValueProp) {
+        if let Some(span) = function_span(&node.value)
+            && let Some(name) = self.member_name(&node.key)
+        {
+            self.record(name, span);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        if let Pat::Ident(binding) = &node.name
+            && let Some(span) = node.init.as_deref().and_then(function_span)
+        {
+            self.record(binding.id.sym.to_string(), span);
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_object_lit(&mut self, node: &ObjectLit) {
+        // Object members are not class members, even inside a class body.
+        let outer = self.class.take();
+        node.visit_children_with(self);
+        self.class = outer;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchors_for(source: &str, sites: &[&str]) -> Vec<String> {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("web/src")).unwrap();
+        std::fs::write(dir.path().join("web/src/client.ts"), source).unwrap();
+        let finding = Finding::missing_endpoint(
+            "GET",
+            "/orders",
+            None,
+            sites.iter().map(|s| s.to_string()).collect(),
+        );
+        let anchored = attach_anchors(vec![finding], dir.path());
+        anchored[0].call_site_anchors().to_vec()
+    }
+
+    #[test]
+    fn anchors_name_the_enclosing_function_and_ignore_line_moves() {
+        let before = "export async function loadOrders() {\n  return fetch('/orders');\n}\n";
+        let after = "import x from 'y';\n\n// Orders.\nexport async function loadOrders() {\n    return fetch('/orders')\n}\n";
+        let a = anchors_for(before, &["web/src/client.ts:2"]);
+        let b = anchors_for(after, &["src/client.ts:5"]);
+        assert_eq!(a.len(), 1);
+        assert!(a[0].starts_with("loadOrders#"), "{:?}", a);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn class_methods_callbacks_and_module_scope() {
+        let source = "class OrdersClient {\n  list() {\n    return items.map(async (id) => {\n      await fetch(`/orders/${id}`);\n    });\n  }\n}\nconst handlers = { refresh: () => fetch('/orders') };\nfetch('/ping');\n";
+        let anchors = anchors_for(
+            source,
+            &[
+                "web/src/client.ts:4",
+                "web/src/client.ts:8",
+                "web/src/client.ts:9",
+                "elsewhere/not-scanned.ts:3",
+            ],
+        );
+        let functions: Vec<&str> = anchors
+            .iter()
+            .map(|a| a.split('#').next().unwrap())
+            .collect();
+        assert_eq!(functions, vec!["<module>", "OrdersClient.list", "refresh"]);
+    }
+}
@@ -5,8 +5,12 @@
 //! source of truth: the cloud renders the PR comment/check run from this
 //! JSON and never re-parses prose.
 
-use crate::operation::EndpointProvenance;
-use serde::{Serialize, Serializer, ser::SerializeMap};
+use crate::env_profiles::ProfileTarget;
+use crate::operation::{EndpointProvenance, OperationKey};
+use crate::type_manifest::parse_file_location;
+use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 
 /// Wire cap on `call_sites` entries per finding. Applied when serializing
 /// only — the in-memory finding keeps the full set so the terminal report
@@ -37,9 +41,10 @@ fn wire_call_sites(call_sites: &[String]) -> &[String] {
     &call_sites[..call_sites.len().min(MAX_CALL_SITES)]
 }
 
-/// Fixed per finding kind (see [`Finding::severity`]); sent explicitly on the
-/// wire so the cloud never re-derives it.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+/// Defaulted per finding kind and re-gradable by the repo's policy (see
+/// [`Finding::severity`]); sent explicitly on the wire so the cloud never
+/// re-derives it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Risk,
@@ -47,6 +52,28 @@ pub enum Severity {
     Advisory,
 }
 
+impl Severity {
+    /// Parse a `--fail-on` level.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "risk" => Some(Severity::Risk),
+            "gap" => Some(Severity::Gap),
+            "advisory" => Some(Severity::Advisory),
+            _ => None,
+        }
+    }
+
+    /// Whether this severity is `threshold` or worse (risk > gap > advisory).
+    pub fn at_least(self, threshold: Severity) -> bool {
+        let rank = |s: Severity| match s {
+            Severity::Advisory => 0,
+            Severity::Gap => 1,
+            Severity::Risk => 2,
+        };
+        rank(self) >= rank(threshold)
+    }
+}
+
 /// One repo's pinned version of a conflicting package.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct PackageVersionRef {
@@ -55,6 +82,53 @@ pub struct PackageVersionRef {
     pub source: String,
 }
 
+/// How one field of a type mismatch disagrees (see [`FieldMismatch`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldMismatchKind {
+    /// The producer lacks a field the consumer reads.
+    Missing,
+    /// The producer requires a field the consumer doesn't send.
+    ExtraRequired,
+    /// Both sides have the field with incompatible types.
+    TypeChanged,
+    /// One side admits null/undefined (or is optional) where the other
+    /// requires a value.
+    Nullability,
+    /// A literal one side can produce is not in the other's literal union.
+    EnumNarrowing,
+}
+
+impl FieldMismatchKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FieldMismatchKind::Missing => "missing",
+            FieldMismatchKind::ExtraRequired => "extra required",
+            FieldMismatchKind::TypeChanged => "type changed",
+            FieldMismatchKind::Nullability => "nullability",
+            FieldMismatchKind::EnumNarrowing => "enum narrowing",
+        }
+    }
+}
+
+/// One field-level explanation of a type mismatch, parsed by the sidecar
+/// from the compiler's elaboration chain. `path` is dotted (`customer.email`);
+/// empty when the whole type disagrees. `producer`/`consumer` are what each
+/// side has there (a type, or `required`/`optional`), when known. `read` is
+/// whether the consumer reads the field (see [`crate::field_usage`]); `None`
+/// when its usage couldn't be traced.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldMismatch {
+    pub path: String,
+    pub kind: FieldMismatchKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consumer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read: Option<bool>,
+}
+
 /// Dependency-conflict tier: `"major"` (semver-incompatible major spread,
 /// counts as a gap) or `"unparseable"` (non-semver pins that differ as raw
 /// strings, advisory).
@@ -63,13 +137,36 @@ pub mod tier {
     pub const UNPARSEABLE: &str = "unparseable";
 }
 
-/// A single scan finding. Serializes as a `kind`-tagged union with an
-/// explicit `severity` field (see the custom [`Serialize`] impl below).
+/// A single scan finding: what was found, plus the policy's re-grading of
+/// its severity. Serializes as a `kind`-tagged union with an explicit
+/// `severity` field (see the custom [`Serialize`] impl below).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    pub issue: Issue,
+    /// Policy re-grading of the kind's default severity (see
+    /// [`crate::policy`]); `None` keeps the default.
+    severity_override: Option<Severity>,
+    /// Line-insensitive anchors for the call sites (see
+    /// [`Finding::with_call_site_anchors`]); feeds the fingerprint only.
+    call_site_anchors: Vec<String>,
+}
+
+impl From<Issue> for Finding {
+    fn from(issue: Issue) -> Self {
+        Finding {
+            issue,
+            severity_override: None,
+            call_site_anchors: Vec::new(),
+        }
+    }
+}
+
+/// What a [`Finding`] is about, one variant per wire `kind`.
 /// `method`/`path` are protocol-agnostic display labels (HTTP verb + route,
 /// GraphQL kind + field, socket direction + event); `call_sites` entries are
 /// `file:line` (or bare `file`), capped at [`MAX_CALL_SITES`] on the wire.
 #[derive(Clone, Debug, PartialEq)]
-pub enum Finding {
+pub enum Issue {
     /// Producer and consumer types for one endpoint are incompatible.
     TypeMismatch {
         method: String,
@@ -80,6 +177,10 @@ pub enum Finding {
         consumer_type: String,
         /// Compiler error, pre-truncated to [`MAX_DETAIL_CHARS`] chars.
         detail: String,
+        /// Field-level breakdown of `detail` (see
+        /// [`Finding::with_field_mismatches`]); empty when the sidecar
+        /// couldn't attribute the error to fields.
+        fields: Vec<FieldMismatch>,
         /// Whether the producer shape comes from a real route or a mock/test
         /// handler (#380) — a mismatch against a mock is often still real
         /// (mocks frequently encode the canonical contract) but should be
@@ -120,6 +221,21 @@ pub enum Finding {
         env_var: String,
         call_sites: Vec<String>,
     },
+    /// An env-var call whose target service differs between environment
+    /// profiles (e.g. staging's `ORDERS_URL` points at a service production's
+    /// does not), or a hardcoded call pinned to one profile's origin for a var
+    /// the other profiles point elsewhere (`pinned_origin`). `targets` lists
+    /// every profile that defines the var, sorted by profile name.
+    ProfileTargetDrift {
+        method: String,
+        path: String,
+        env_var: String,
+        targets: Vec<ProfileTarget>,
+        /// The origin a hardcoded call site uses in every profile; `None` for
+        /// env-var calls.
+        pinned_origin: Option<String>,
+        call_sites: Vec<String>,
+    },
     /// One package pinned to conflicting versions across repos. No
     /// method/path — this finding is not endpoint-scoped.
     DependencyConflict {
@@ -140,6 +256,65 @@ pub enum Finding {
         repos: Vec<String>,
         call_sites: Vec<String>,
     },
+    /// An endpoint or call that breaks one of the repo's declarative policy
+    /// rules (see [`crate::policy::PolicyRule`]). `call_sites` locate the
+    /// offending route definition or consumer call.
+    PolicyViolation {
+        rule_id: String,
+        method: String,
+        path: String,
+        service: Option<String>,
+        /// The calling repo, for rules on cross-repo calls (`forbid_call`);
+        /// `None` for rules on the route itself.
+        consumer: Option<String>,
+        /// Why the rule fired, e.g. the middleware the route is missing.
+        message: String,
+        call_sites: Vec<String>,
+    },
+    /// A producer type this PR changed that indexed consumers were
+    /// compatible with before the change and are not after it. `service` is
+    /// the producer; `consumers` the broken consumer services, sorted.
+    BreakingChange {
+        method: String,
+        path: String,
+        service: Option<String>,
+        consumers: Vec<String>,
+        /// Field-level changes between the indexed and the new type
+        /// (`` `email` removed ``), or the compiler diagnostic when the types
+        /// aren't object-shaped.
+        changes: Vec<String>,
+        call_sites: Vec<String>,
+    },
+    /// A consumer call's query string disagrees with what the producer
+    /// reads: `unread` keys it sends that the handler never reads, `missing`
+    /// keys the route declares required that it doesn't send. Raised once
+    /// per call, and only when both sides are known (see
+    /// [`crate::query_params`]).
+    QueryParamMismatch {
+        method: String,
+        path: String,
+        service: Option<String>,
+        call_sites: Vec<String>,
+        unread: Vec<String>,
+        missing: Vec<String>,
+    },
+    /// A consumer call doesn't send the headers the producer needs:
+    /// `missing` required headers (`authorization` behind an auth
+    /// middleware, custom `x-*` headers the handler reads), or a
+    /// `content_type` none of the route's body parsers (`accepted`) read.
+    /// Only raised when the call's headers are fully known (see
+    /// [`crate::headers`]).
+    HeaderMismatch {
+        method: String,
+        path: String,
+        service: Option<String>,
+        call_sites: Vec<String>,
+        missing: Vec<String>,
+        /// Content types calls send that the route doesn't parse; empty
+        /// when they agree.
+        content_types: Vec<String>,
+        accepted: Vec<String>,
+    },
 }
 
 impl Finding {
@@ -152,7 +327,7 @@ impl Finding {
         consumer_type: impl Into<String>,
         detail: &str,
     ) -> Self {
-        Finding::TypeMismatch {
+        Issue::TypeMismatch {
             method: method.into(),
             path: path.into(),
             service,
@@ -160,8 +335,10 @@ impl Finding {
             producer_type: producer_type.into(),
             consumer_type: consumer_type.into(),
             detail: truncate_chars(detail, MAX_DETAIL_CHARS),
+            fields: Vec::new(),
             producer_provenance: EndpointProvenance::default(),
         }
+        .into()
     }
 
     pub fn method_mismatch(
@@ -171,13 +348,14 @@ impl Finding {
         call_sites: Vec<String>,
         expected_method: impl Into<String>,
     ) -> Self {
-        Finding::MethodMismatch {
+        Issue::MethodMismatch {
             method: method.into(),
             path: path.into(),
             service,
             call_sites,
             expected_method: expected_method.into(),
         }
+        .into()
     }
 
     pub fn missing_endpoint(
@@ -186,12 +364,13 @@ impl Finding {
         service: Option<String>,
         call_sites: Vec<String>,
     ) -> Self {
-        Finding::MissingEndpoint {
+        Issue::MissingEndpoint {
             method: method.into(),
             path: path.into(),
             service,
             call_sites,
         }
+        .into()
     }
 
     pub fn orphaned_endpoint(
@@ -199,12 +378,13 @@ impl Finding {
         path: impl Into<String>,
         service: Option<String>,
     ) -> Self {
-        Finding::OrphanedEndpoint {
+        Issue::OrphanedEndpoint {
             method: method.into(),
             path: path.into(),
             service,
             provenance: EndpointProvenance::default(),
         }
+        .into()
     }
 
     /// Attach producer-side provenance (real route vs mock/test handler) to a
@@ -213,29 +393,106 @@ impl Finding {
     /// producer's classification is known. No-op for kinds without a
     /// producer-side provenance field.
     pub fn with_producer_provenance(mut self, producer: EndpointProvenance) -> Self {
-        match &mut self {
-            Finding::TypeMismatch {
+        match &mut self.issue {
+            Issue::TypeMismatch {
                 producer_provenance,
                 ..
             } => *producer_provenance = producer,
-            Finding::OrphanedEndpoint { provenance, .. } => *provenance = producer,
+            Issue::OrphanedEndpoint { provenance, .. } => *provenance = producer,
             _ => {}
         }
         self
     }
 
+    /// Attach the field-level explanation of a type mismatch. No-op for
+    /// other kinds.
+    pub fn with_field_mismatches(mut self, mismatches: Vec<FieldMismatch>) -> Self {
+        if let Issue::TypeMismatch { fields, .. } = &mut self.issue {
+            *fields = mismatches;
+        }
+        self
+    }
+
+    /// Attach line-insensitive call-site anchors (enclosing function plus a
+    /// normalized snippet hash, see [`crate::finding_anchors`]) so the
+    /// fingerprint survives edits that only move the call. Sorted and
+    /// de-duplicated.
+    pub fn with_call_site_anchors(mut self, mut anchors: Vec<String>) -> Self {
+        anchors.sort();
+        anchors.dedup();
+        self.call_site_anchors = anchors;
+        self
+    }
+
+    pub fn call_site_anchors(&self) -> &[String] {
+        &self.call_site_anchors
+    }
+
+    /// The finding's call sites; empty for kinds that have none.
+    pub fn call_sites(&self) -> &[String] {
+        match &self.issue {
+            Issue::TypeMismatch { call_sites, .. }
+            | Issue::MethodMismatch { call_sites, .. }
+            | Issue::MissingEndpoint { call_sites, .. }
+            | Issue::EnvVarCall { call_sites, .. }
+            | Issue::ProfileTargetDrift { call_sites, .. }
+            | Issue::SharedExternalContract { call_sites, .. }
+            | Issue::PolicyViolation { call_sites, .. }
+            | Issue::BreakingChange { call_sites, .. }
+            | Issue::QueryParamMismatch { call_sites, .. }
+            | Issue::HeaderMismatch { call_sites, .. } => call_sites,
+            Issue::OrphanedEndpoint { .. } | Issue::DependencyConflict { .. } => &[],
+        }
+    }
+
+    /// The consumer call site a per-call-site finding is raised for: its
+    /// anchors, or — for a call site outside the scanned checkout — its file
+    /// without the line.
+    fn call_site_identity(&self) -> String {
+        if !self.call_site_anchors().is_empty() {
+            return self.call_site_anchors().join(",");
+        }
+        self.call_sites()
+            .iter()
+            .map(|site| parse_file_location(site).0)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
     pub fn env_var_call(
         method: impl Into<String>,
         path: impl Into<String>,
         env_var: impl Into<String>,
         call_sites: Vec<String>,
     ) -> Self {
-        Finding::EnvVarCall {
+        Issue::EnvVarCall {
+            method: method.into(),
+            path: path.into(),
+            env_var: env_var.into(),
+            call_sites,
+        }
+        .into()
+    }
+
+    pub fn profile_target_drift(
+        method: impl Into<String>,
+        path: impl Into<String>,
+        env_var: impl Into<String>,
+        targets: Vec<ProfileTarget>,
+        pinned_origin: Option<String>,
+        call_sites: Vec<String>,
+    ) -> Self {
+        Issue::ProfileTargetDrift {
             method: method.into(),
             path: path.into(),
             env_var: env_var.into(),
+            targets,
+            pinned_origin,
             call_sites,
         }
+        .into()
     }
 
     pub fn dependency_conflict(
@@ -243,11 +500,12 @@ impl Finding {
         tier: impl Into<String>,
         versions: Vec<PackageVersionRef>,
     ) -> Self {
-        Finding::DependencyConflict {
+        Issue::DependencyConflict {
             package_name: package_name.into(),
             tier: tier.into(),
             versions,
         }
+        .into()
     }
 
     pub fn shared_external_contract(
@@ -256,38 +514,278 @@ impl Finding {
         repos: Vec<String>,
         call_sites: Vec<String>,
     ) -> Self {
-        Finding::SharedExternalContract {
+        Issue::SharedExternalContract {
             method: method.into(),
             path: path.into(),
             repos,
             call_sites,
         }
+        .into()
+    }
+
+    pub fn policy_violation(
+        rule_id: impl Into<String>,
+        method: impl Into<String>,
+        path: impl Into<String>,
+        service: Option<String>,
+        consumer: Option<String>,
+        message: impl Into<String>,
+        call_sites: Vec<String>,
+    ) -> Self {
+        Issue::PolicyViolation {
+            rule_id: rule_id.into(),
+            method: method.into(),
+            path: path.into(),
+            service,
+            consumer,
+            message: truncate_chars(&message.into(), MAX_DETAIL_CHARS),
+            call_sites,
+        }
+        .into()
+    }
+
+    pub fn breaking_change(
+        method: impl Into<String>,
+        path: impl Into<String>,
+        service: Option<String>,
+        consumers: Vec<String>,
+        changes: Vec<String>,
+        call_sites: Vec<String>,
+    ) -> Self {
+        Issue::BreakingChange {
+            method: method.into(),
+            path: path.into(),
+            service,
+            consumers,
+            changes,
+            call_sites,
+        }
+        .into()
+    }
+
+    pub fn query_param_mismatch(
+        method: impl Into<String>,
+        path: impl Into<String>,
+        service: Option<String>,
+        call_sites: Vec<String>,
+        unread: Vec<String>,
+        missing: Vec<String>,
+    ) -> Self {
+        Issue::QueryParamMismatch {
+            method: method.into(),
+            path: path.into(),
+            service,
+            call_sites,
+            unread,
+            missing,
+        }
+        .into()
+    }
+
+    pub fn header_mismatch(
+        method: impl Into<String>,
+        path: impl Into<String>,
+        service: Option<String>,
+        call_sites: Vec<String>,
+        missing: Vec<String>,
+        content_types: Vec<String>,
+        accepted: Vec<String>,
+    ) -> Self {
+        Issue::HeaderMismatch {
+            method: method.into(),
+            path: path.into(),
+            service,
+            call_sites,
+            missing,
+            content_types,
+            accepted,
+        }
+        .into()
+    }
+
+    /// Short human label naming what the finding is about: `METHOD path`
+    /// for endpoint-scoped kinds, the package name for dependency conflicts.
+    pub fn label(&self) -> String {
+        match &self.issue {
+            Issue::TypeMismatch { method, path, .. }
+            | Issue::MethodMismatch { method, path, .. }
+            | Issue::MissingEndpoint { method, path, .. }
+            | Issue::OrphanedEndpoint { method, path, .. }
+            | Issue::EnvVarCall { method, path, .. }
+            | Issue::ProfileTargetDrift { method, path, .. }
+            | Issue::SharedExternalContract { method, path, .. }
+            | Issue::PolicyViolation { method, path, .. }
+            | Issue::BreakingChange { method, path, .. }
+            | Issue::QueryParamMismatch { method, path, .. }
+            | Issue::HeaderMismatch { method, path, .. } => {
+                format!("{} {}", method, path)
+            }
+            Issue::DependencyConflict { package_name, .. } => package_name.clone(),
+        }
     }
 
+    /// Deterministic identity of the finding across runs: a hash of the
+    /// kind, the canonical [`OperationKey`], the service id and the one field
+    /// that tells two findings on the same operation apart (the expected
+    /// method, the env var, the policy rule, or — for a type or query
+    /// parameter mismatch, raised once per consumer call — that call site's
+    /// line-insensitive anchor).
+    /// The call sites of grouped findings, consumers and the mismatched keys
+    /// are left out: they grow and shift as code changes, and a finding that
+    /// gains a call site is still the same finding. Line numbers never count.
+    /// Baselines, and the cloud's "introduced by this PR" tracking, key
+    /// findings by this value.
+    pub fn fingerprint(&self) -> String {
+        let operation =
+            |method: &str, path: &str| OperationKey::from_display_labels(method, path).canonical();
+        let service = |s: &Option<String>| s.clone().unwrap_or_default();
+        let parts: Vec<String> = match &self.issue {
+            Issue::TypeMismatch {
+                method,
+                path,
+                service: svc,
+                ..
+            }
+            | Issue::QueryParamMismatch {
+                method,
+                path,
+                service: svc,
+                ..
+            } => vec![
+                operation(method, path),
+                service(svc),
+                self.call_site_identity(),
+            ],
+            Issue::MissingEndpoint {
+                method,
+                path,
+                service: svc,
+                ..
+            }
+            | Issue::OrphanedEndpoint {
+                method,
+                path,
+                service: svc,
+                ..
+            } => vec![operation(method, path), service(svc)],
+            Issue::MethodMismatch {
+                method,
+                path,
+                service: svc,
+                expected_method,
+                ..
+            } => vec![
+                operation(method, path),
+                service(svc),
+                operation(expected_method, path),
+            ],
+            Issue::EnvVarCall {
+                method,
+                path,
+                env_var,
+                ..
+            }
+            | Issue::ProfileTargetDrift {
+                method,
+                path,
+                env_var,
+                ..
+            } => vec![operation(method, path), env_var.clone()],
+            Issue::DependencyConflict { package_name, .. } => vec![package_name.clone()],
+            Issue::SharedExternalContract { method, path, .. } => vec![operation(method, path)],
+            Issue::PolicyViolation {
+                rule_id,
+                method,
+                path,
+                service: svc,
+                consumer,
+                ..
+            } => vec![
+                rule_id.clone(),
+                operation(method, path),
+                service(svc),
+                service(consumer),
+            ],
+            Issue::BreakingChange {
+                method,
+                path,
+                service: svc,
+                ..
+            }
+            | Issue::HeaderMismatch {
+                method,
+                path,
+                service: svc,
+                ..
+            } => vec![operation(method, path), service(svc)],
+        };
+        let mut hasher = Sha256::new();
+        hasher.update(self.kind().as_bytes());
+        for part in parts {
+            hasher.update([0]);
+            hasher.update(part.as_bytes());
+        }
+        format!("{:x}", hasher.finalize())[..16].to_string()
+    }
+
+    /// Every wire `kind` tag, in declaration order.
+    pub const KINDS: &'static [&'static str] = &[
+        "type_mismatch",
+        "method_mismatch",
+        "missing_endpoint",
+        "orphaned_endpoint",
+        "env_var_call",
+        "profile_target_drift",
+        "dependency_conflict",
+        "shared_external_contract",
+        "policy_violation",
+        "breaking_change",
+        "query_param_mismatch",
+        "header_mismatch",
+    ];
+
     /// The wire `kind` tag.
     pub fn kind(&self) -> &'static str {
-        match self {
-            Finding::TypeMismatch { .. } => "type_mismatch",
-            Finding::MethodMismatch { .. } => "method_mismatch",
-            Finding::MissingEndpoint { .. } => "missing_endpoint",
-            Finding::OrphanedEndpoint { .. } => "orphaned_endpoint",
-            Finding::EnvVarCall { .. } => "env_var_call",
-            Finding::DependencyConflict { .. } => "dependency_conflict",
-            Finding::SharedExternalContract { .. } => "shared_external_contract",
+        match &self.issue {
+            Issue::TypeMismatch { .. } => "type_mismatch",
+            Issue::MethodMismatch { .. } => "method_mismatch",
+            Issue::MissingEndpoint { .. } => "missing_endpoint",
+            Issue::OrphanedEndpoint { .. } => "orphaned_endpoint",
+            Issue::EnvVarCall { .. } => "env_var_call",
+            Issue::ProfileTargetDrift { .. } => "profile_target_drift",
+            Issue::DependencyConflict { .. } => "dependency_conflict",
+            Issue::SharedExternalContract { .. } => "shared_external_contract",
+            Issue::PolicyViolation { .. } => "policy_violation",
+            Issue::BreakingChange { .. } => "breaking_change",
+            Issue::QueryParamMismatch { .. } => "query_param_mismatch",
+            Issue::HeaderMismatch { .. } => "header_mismatch",
         }
     }
 
-    /// Severity is a function of the kind (plus tier for dependency
-    /// conflicts), per the wire contract's table — never stored, so it can't
-    /// drift from the kind.
+    /// Severity defaults to a function of the kind (plus tier for
+    /// dependency conflicts), per the wire contract's table. The repo's
+    /// policy may re-grade it (see [`Finding::with_severity`]); only that
+    /// override is stored.
     pub fn severity(&self) -> Severity {
-        match self {
-            Finding::TypeMismatch { .. } | Finding::MethodMismatch { .. } => Severity::Risk,
-            Finding::MissingEndpoint { .. } | Finding::OrphanedEndpoint { .. } => Severity::Gap,
-            Finding::EnvVarCall { .. } | Finding::SharedExternalContract { .. } => {
-                Severity::Advisory
-            }
-            Finding::DependencyConflict { tier, .. } => {
+        self.severity_override
+            .unwrap_or_else(|| self.default_severity())
+    }
+
+    /// The kind's severity before any policy override.
+    pub fn default_severity(&self) -> Severity {
+        match &self.issue {
+            Issue::TypeMismatch { .. }
+            | Issue::MethodMismatch { .. }
+            | Issue::BreakingChange { .. }
+            | Issue::QueryParamMismatch { .. }
+            | Issue::HeaderMismatch { .. } => Severity::Risk,
+            Issue::MissingEndpoint { .. }
+            | Issue::OrphanedEndpoint { .. }
+            | Issue::PolicyViolation { .. } => Severity::Gap,
+            Issue::EnvVarCall { .. }
+            | Issue::ProfileTargetDrift { .. }
+            | Issue::SharedExternalContract { .. } => Severity::Advisory,
+            Issue::DependencyConflict { tier, .. } => {
                 if tier == tier::MAJOR {
                     Severity::Gap
                 } else {
@@ -296,18 +794,33 @@ impl Finding {
             }
         }
     }
+
+    /// Whether the finding was graded away from its kind's default when it
+    /// was raised (a usage-downgraded type mismatch, a policy rule's own
+    /// `severity`).
+    pub fn is_regraded(&self) -> bool {
+        self.severity_override.is_some()
+    }
+
+    /// Re-grade the finding per the repo's policy (see [`crate::policy`]).
+    /// Setting the kind's default severity clears the override.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity_override = (severity != self.default_severity()).then_some(severity);
+        self
+    }
 }
 
-/// Hand-rolled so `severity` is emitted alongside the `kind` tag without
-/// being a stored field (a derived internally-tagged enum can't add a
-/// computed sibling field).
+/// Hand-rolled so `severity` and `fingerprint` are emitted alongside the
+/// `kind` tag without being stored fields (a derived internally-tagged enum
+/// can't add computed sibling fields).
 impl Serialize for Finding {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut map = serializer.serialize_map(None)?;
         map.serialize_entry("kind", self.kind())?;
         map.serialize_entry("severity", &self.severity())?;
-        match self {
-            Finding::TypeMismatch {
+        map.serialize_entry("fingerprint", &self.fingerprint())?;
+        match &self.issue {
+            Issue::TypeMismatch {
                 method,
                 path,
                 service,
@@ -315,7 +828,9 @@ impl Serialize for Finding {
                 producer_type,
                 consumer_type,
                 detail,
+                fields,
                 producer_provenance,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
@@ -324,14 +839,18 @@ impl Serialize for Finding {
                 map.serialize_entry("producer_type", producer_type)?;
                 map.serialize_entry("consumer_type", consumer_type)?;
                 map.serialize_entry("detail", detail)?;
+                if !fields.is_empty() {
+                    map.serialize_entry("fields", fields)?;
+                }
                 map.serialize_entry("producer_provenance", producer_provenance)?;
             }
-            Finding::MethodMismatch {
+            Issue::MethodMismatch {
                 method,
                 path,
                 service,
                 call_sites,
                 expected_method,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
@@ -339,59 +858,150 @@ impl Serialize for Finding {
                 map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
                 map.serialize_entry("expected_method", expected_method)?;
             }
-            Finding::MissingEndpoint {
+            Issue::MissingEndpoint {
                 method,
                 path,
                 service,
                 call_sites,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
                 map.serialize_entry("service", service)?;
                 map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
             }
-            Finding::OrphanedEndpoint {
+            Issue::OrphanedEndpoint {
                 method,
                 path,
                 service,
                 provenance,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
                 map.serialize_entry("service", service)?;
                 map.serialize_entry("provenance", provenance)?;
             }
-            Finding::EnvVarCall {
+            Issue::EnvVarCall {
                 method,
                 path,
                 env_var,
                 call_sites,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
                 map.serialize_entry("env_var", env_var)?;
                 map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
             }
-            Finding::DependencyConflict {
+            Issue::ProfileTargetDrift {
+                method,
+                path,
+                env_var,
+                targets,
+                pinned_origin,
+                call_sites,
+                ..
+            } => {
+                map.serialize_entry("method", method)?;
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("env_var", env_var)?;
+                map.serialize_entry("targets", targets)?;
+                if let Some(origin) = pinned_origin {
+                    map.serialize_entry("pinned_origin", origin)?;
+                }
+                map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
+            }
+            Issue::DependencyConflict {
                 package_name,
                 tier,
                 versions,
+                ..
             } => {
                 map.serialize_entry("package_name", package_name)?;
                 map.serialize_entry("tier", tier)?;
                 map.serialize_entry("versions", versions)?;
             }
-            Finding::SharedExternalContract {
+            Issue::SharedExternalContract {
                 method,
                 path,
                 repos,
                 call_sites,
+                ..
             } => {
                 map.serialize_entry("method", method)?;
                 map.serialize_entry("path", path)?;
                 map.serialize_entry("repos", repos)?;
                 map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
             }
+            Issue::PolicyViolation {
+                rule_id,
+                method,
+                path,
+                service,
+                consumer,
+                message,
+                call_sites,
+                ..
+            } => {
+                map.serialize_entry("rule_id", rule_id)?;
+                map.serialize_entry("method", method)?;
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("consumer", consumer)?;
+                map.serialize_entry("message", message)?;
+                map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
+            }
+            Issue::BreakingChange {
+                method,
+                path,
+                service,
+                consumers,
+                changes,
+                call_sites,
+                ..
+            } => {
+                map.serialize_entry("method", method)?;
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("consumers", consumers)?;
+                map.serialize_entry("changes", changes)?;
+                map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
+            }
+            Issue::QueryParamMismatch {
+                method,
+                path,
+                service,
+                call_sites,
+                unread,
+                missing,
+                ..
+            } => {
+                map.serialize_entry("method", method)?;
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("unread", unread)?;
+                map.serialize_entry("missing", missing)?;
+                map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
+            }
+            Issue::HeaderMismatch {
+                method,
+                path,
+                service,
+                call_sites,
+                missing,
+                content_types,
+                accepted,
+                ..
+            } => {
+                map.serialize_entry("method", method)?;
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("missing", missing)?;
+                map.serialize_entry("content_types", content_types)?;
+                map.serialize_entry("accepted", accepted)?;
+                map.serialize_entry("call_sites", wire_call_sites(call_sites))?;
+            }
         }
         map.end()
     }
@@ -483,6 +1093,12 @@ pub struct PrResultPayload {
     pub topology: Topology,
     pub stats: ScanStats,
     pub findings: Vec<Finding>,
+    /// Findings recorded in the repo's `carrick-baseline.json`: still present,
+    /// but accepted when the baseline was taken, so they are reported apart
+    /// from `findings` and never count toward the headline. Omitted from the
+    /// wire when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub baselined: Vec<Finding>,
     pub delta: Option<PrDelta>,
     pub verified: Vec<VerifiedEndpoint>,
     pub graphql: GraphqlStatus,
@@ -533,7 +1149,7 @@ mod tests {
         // shows true counts); only the serialized wire form is capped.
         let sites: Vec<String> = (0..9).map(|i| format!("src/a.ts:{i}")).collect();
         let finding = Finding::missing_endpoint("GET", "/x", None, sites);
-        let Finding::MissingEndpoint { call_sites, .. } = &finding else {
+        let Issue::MissingEndpoint { call_sites, .. } = &finding.issue else {
             panic!("wrong variant");
         };
         assert_eq!(call_sites.len(), 9);
@@ -572,17 +1188,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn field_mismatches_serialize_only_when_present() {
+        let mismatch = Finding::type_mismatch("GET", "/x", None, vec![], "A", "B", "boom");
+        let v = serde_json::to_value(&mismatch).unwrap();
+        assert!(v.get("fields").is_none());
+
+        let mismatch = mismatch.with_field_mismatches(vec![
+            FieldMismatch {
+                path: "customer.email".to_string(),
+                kind: FieldMismatchKind::Missing,
+                producer: None,
+                consumer: Some("required".to_string()),
+                read: None,
+            },
+            FieldMismatch {
+                path: "status".to_string(),
+                kind: FieldMismatchKind::EnumNarrowing,
+                producer: Some(r#""open" | "void""#.to_string()),
+                consumer: Some(r#""open""#.to_string()),
+                read: None,
+            },
+        ]);
+        let v = serde_json::to_value(&mismatch).unwrap();
+        assert_eq!(
+            v["fields"],
+            json!([
+                { "path": "customer.email", "kind": "missing", "consumer": "required" },
+                {
+                    "path": "status",
+                    "kind": "enum_narrowing",
+                    "producer": "\"open\" | \"void\"",
+                    "consumer": "\"open\""
+                }
+            ])
+        );
+    }
+
     #[test]
     fn detail_truncated_at_construction() {
         let long = "☕".repeat(500);
         let finding = Finding::type_mismatch("GET", "/x", None, vec![], "A", "B", &long);
-        let Finding::TypeMismatch { detail, .. } = &finding else {
+        let Issue::TypeMismatch { detail, .. } = &finding.issue else {
             panic!("wrong variant");
         };
         assert_eq!(detail.chars().count(), MAX_DETAIL_CHARS);
         assert!(detail.ends_with("..."));
     }
 
+    #[test]
+    fn profile_target_drift_serializes_targets() {
+        let finding = Finding::profile_target_drift(
+            "GET",
+            "/orders",
+            "ORDERS_URL",
+            vec![ProfileTarget {
+                profile: "staging".to_string(),
+                origin: "https://billing.staging.acme.dev".to_string(),
+                service: Some("billing".to_string()),
+            }],
+            None,
+            vec!["src/orders.ts:4".to_string()],
+        );
+        assert_eq!(finding.severity(), Severity::Advisory);
+        assert_eq!(
+            serde_json::to_value(&finding).unwrap(),
+            json!({
+                "kind": "profile_target_drift",
+                "severity": "advisory",
+                "fingerprint": finding.fingerprint(),
+                "method": "GET",
+                "path": "/orders",
+                "env_var": "ORDERS_URL",
+                "targets": [{
+                    "profile": "staging",
+                    "origin": "https://billing.staging.acme.dev",
+                    "service": "billing"
+                }],
+                "call_sites": ["src/orders.ts:4"]
+            })
+        );
+    }
+
     /// Snapshot of the wire shape: kind tags, severity strings, tier values,
     /// and field names must match docs/internal/pr-result-pipeline.md
     /// (schema_version 1) in carrick-cloud exactly.
@@ -649,6 +1336,7 @@ mod tests {
                     vec!["src/widgets-client.ts:33".to_string()],
                 ),
             ],
+            baselined: vec![],
             delta: Some(PrDelta {
                 new_endpoints: vec![EndpointRef {
                     method: "GET".to_string(),
@@ -706,6 +1394,7 @@ mod tests {
             json!({
                 "kind": "type_mismatch",
                 "severity": "risk",
+                "fingerprint": payload.findings[0].fingerprint(),
                 "method": "GET",
                 "path": "/api/users",
                 "service": null,
@@ -721,6 +1410,7 @@ mod tests {
             json!({
                 "kind": "method_mismatch",
                 "severity": "risk",
+                "fingerprint": payload.findings[1].fingerprint(),
                 "method": "GET",
                 "path": "/api/orders",
                 "service": null,
@@ -733,6 +1423,7 @@ mod tests {
             json!({
                 "kind": "missing_endpoint",
                 "severity": "gap",
+                "fingerprint": payload.findings[2].fingerprint(),
                 "method": "DELETE",
                 "path": "/api/sessions",
                 "service": null,
@@ -744,6 +1435,7 @@ mod tests {
             json!({
                 "kind": "orphaned_endpoint",
                 "severity": "gap",
+                "fingerprint": payload.findings[3].fingerprint(),
                 "method": "GET",
                 "path": "/legacy/ping",
                 "service": "billing",
@@ -755,6 +1447,7 @@ mod tests {
             json!({
                 "kind": "env_var_call",
                 "severity": "advisory",
+                "fingerprint": payload.findings[4].fingerprint(),
                 "method": "GET",
                 "path": "/orders",
                 "env_var": "ORDER_SERVICE_URL",
@@ -766,6 +1459,7 @@ mod tests {
             json!({
                 "kind": "dependency_conflict",
                 "severity": "gap",
+                "fingerprint": payload.findings[5].fingerprint(),
                 "package_name": "zod",
                 "tier": "major",
                 "versions": [{ "repo": "billing", "version": "4.0.0", "source": "package.json" }],
@@ -781,6 +1475,7 @@ mod tests {
             json!({
                 "kind": "shared_external_contract",
                 "severity": "advisory",
+                "fingerprint": payload.findings[7].fingerprint(),
                 "method": "POST",
                 "path": "/v2/widgets",
                 "repos": ["repo-alpha", "repo-beta"],
@@ -847,6 +1542,7 @@ mod tests {
                 calls: 0,
             },
             findings: vec![],
+            baselined: vec![],
             delta: None,
             verified: vec![],
             graphql: GraphqlStatus {
@@ -858,5 +1554,90 @@ mod tests {
         assert!(v.get("run_id").is_none());
         assert_eq!(v["head_sha"], "a".repeat(40));
         assert_eq!(v["delta"], serde_json::Value::Null);
+        // No baseline in play: the field stays off the wire entirely.
+        assert!(v.get("baselined").is_none());
+    }
+
+    #[test]
+    fn policy_violation_serializes_rule_id() {
+        let finding = Finding::policy_violation(
+            "admin-auth",
+            "GET",
+            "/admin/stats",
+            Some("orders".into()),
+            None,
+            "no middleware matching requireAuth",
+            vec!["src/admin.ts:4".into()],
+        );
+        assert_eq!(finding.severity(), Severity::Gap);
+        assert_eq!(
+            serde_json::to_value(&finding).unwrap(),
+            json!({
+                "kind": "policy_violation",
+                "severity": "gap",
+                "fingerprint": finding.fingerprint(),
+                "rule_id": "admin-auth",
+                "method": "GET",
+                "path": "/admin/stats",
+                "service": "orders",
+                "consumer": null,
+                "message": "no middleware matching requireAuth",
+                "call_sites": ["src/admin.ts:4"],
+            })
+        );
+    }
+
+    #[test]
+    fn fingerprint_ignores_call_site_lines() {
+        let at = |line: u32| {
+            Finding::missing_endpoint("GET", "/users", None, vec![format!("a.ts:{line}")])
+        };
+        assert_eq!(at(3).fingerprint(), at(90).fingerprint());
+        assert_eq!(at(3).fingerprint().len(), 16);
+        assert_ne!(
+            at(3).fingerprint(),
+            Finding::missing_endpoint("GET", "/users", Some("web".into()), vec![]).fingerprint()
+        );
+        assert_ne!(
+            Finding::orphaned_endpoint("GET", "/users", None).fingerprint(),
+            Finding::missing_endpoint("GET", "/users", None, vec![]).fingerprint()
+        );
+    }
+
+    #[test]
+    fn fingerprint_survives_new_call_sites_and_consumers() {
+        // A second call site, in another file, is the same finding.
+        assert_eq!(
+            Finding::missing_endpoint("GET", "/users", None, vec!["a.ts:3".into()]).fingerprint(),
+            Finding::missing_endpoint(
+                "GET",
+                "/users",
+                None,
+                vec!["a.ts:3".into(), "src/b.ts:70".into()]
+            )
+            .fingerprint()
+        );
+        let breaking = |consumers: &[&str]| {
+            Finding::breaking_change(
+                "GET",
+                "/users",
+                Some("users".into()),
+                consumers.iter().map(|c| c.to_string()).collect(),
+                vec!["response.id removed".into()],
+                vec![],
+            )
+            .fingerprint()
+        };
+        assert_eq!(breaking(&["web"]), breaking(&["web", "admin"]));
+        // The discriminating field still separates findings on one operation.
+        assert_ne!(
+            Finding::env_var_call("GET", "/users", "USERS_URL", vec![]).fingerprint(),
+            Finding::env_var_call("GET", "/users", "API_URL", vec![]).fingerprint()
+        );
+        // The method is canonicalized through the operation key.
+        assert_eq!(
+            Finding::orphaned_endpoint("get", "/users", None).fingerprint(),
+            Finding::orphaned_endpoint("GET", "/users", None).fingerprint()
+        );
     }
 }
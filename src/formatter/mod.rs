@@ -1,5 +1,5 @@
 use crate::analyzer::ApiAnalysisResult;
-use crate::findings::{Finding, PrDelta, Topology, tier};
+use crate::findings::{FieldMismatch, Finding, Issue, PrDelta, Severity, Topology, tier};
 use std::collections::{BTreeMap, BTreeSet};
 
 // Display helpers for the wire [`Topology`]. Defined here (not in
@@ -76,17 +76,31 @@ pub fn format_analysis_results(
     let categorized = categorize_findings(&result.findings);
     let has_baseline = topology.has_baseline();
     // Headline rule (mirrored by the cloud renderer): risks always count;
-    // connectivity gaps count only with a baseline (a lone single-service repo
-    // has nothing to match against, so they are listed as informational);
-    // major dependency conflicts count; advisory findings (env-var
-    // suggestions, unparseable version pins) never do.
-    let connectivity_in_headline = if has_baseline {
-        categorized.connectivity_len()
-    } else {
-        0
+    // gaps count, except connectivity gaps without a baseline (a lone
+    // single-service repo has nothing to match against, so they are listed
+    // as informational); advisory findings never do. Severity is the
+    // finding's effective one, so a `policy` re-grade in carrick.json moves
+    // a finding in or out of the headline.
+    let counts_toward_headline = |finding: &&Finding| match finding.severity() {
+        Severity::Risk => true,
+        Severity::Gap => {
+            has_baseline
+                || !matches!(
+                    &finding.issue,
+                    Issue::MissingEndpoint { .. } | Issue::OrphanedEndpoint { .. }
+                )
+        }
+        Severity::Advisory => false,
     };
-    let total_issues =
-        categorized.risks.len() + connectivity_in_headline + categorized.major_dependencies.len();
+    let total_issues = result
+        .findings
+        .iter()
+        .filter(counts_toward_headline)
+        .count();
+    let has_risk = result
+        .findings
+        .iter()
+        .any(|f| f.severity() == Severity::Risk);
 
     let mut output = String::new();
 
@@ -94,6 +108,13 @@ pub fn format_analysis_results(
     // must stay parseable.
     output.push_str("<!-- CARRICK_OUTPUT_START -->\n");
     output.push_str(&format!("<!-- CARRICK_ISSUE_COUNT:{} -->\n", total_issues));
+    // Fingerprints of every reported finding, so a later run (or the cloud)
+    // can tell which findings are new without re-parsing the tables.
+    let fingerprints: Vec<String> = result.findings.iter().map(Finding::fingerprint).collect();
+    output.push_str(&format!(
+        "<!-- CARRICK_FINDINGS:{} -->\n",
+        fingerprints.join(",")
+    ));
 
     output.push_str(&format!("## 🪢 Carrick{}\n\n", topology.header_suffix()));
 
@@ -102,6 +123,7 @@ pub fn format_analysis_results(
     output.push_str(&format_verdict(
         &categorized,
         total_issues,
+        has_risk,
         has_baseline,
         topology,
     ));
@@ -123,10 +145,18 @@ pub fn format_analysis_results(
 
     // Sections, ordered by actionability. Verified runs last as a collapsed
     // positive signal.
+    if !categorized.breaking.is_empty() {
+        output.push_str(&format_breaking_section(&categorized.breaking));
+        output.push_str("\n\n");
+    }
     if !categorized.risks.is_empty() {
         output.push_str(&format_critical_section(&categorized.risks));
         output.push_str("\n\n");
     }
+    if !categorized.policy_violations.is_empty() {
+        output.push_str(&format_policy_section(&categorized.policy_violations));
+        output.push_str("\n\n");
+    }
     if !categorized.connectivity_is_empty() {
         output.push_str(&format_connectivity_section(
             &categorized.missing,
@@ -146,12 +176,20 @@ pub fn format_analysis_results(
         output.push_str(&format_configuration_section(&categorized.configuration));
         output.push_str("\n\n");
     }
+    if !categorized.profile_drift.is_empty() {
+        output.push_str(&format_profile_drift_section(&categorized.profile_drift));
+        output.push_str("\n\n");
+    }
     if !categorized.shared_contracts.is_empty() {
         output.push_str(&format_shared_contract_section(
             &categorized.shared_contracts,
         ));
         output.push_str("\n\n");
     }
+    if !result.baselined_findings.is_empty() {
+        output.push_str(&format_baselined_section(&result.baselined_findings));
+        output.push_str("\n\n");
+    }
     if !result.verified_endpoints.is_empty() {
         output.push_str(&format_verified_section(&result.verified_endpoints));
         output.push_str("\n\n");
@@ -244,10 +282,11 @@ fn service_suffix(service: Option<&str>) -> String {
 fn format_verdict(
     categorized: &CategorizedFindings,
     total_issues: usize,
+    has_risk: bool,
     has_baseline: bool,
     topology: &Topology,
 ) -> String {
-    let kind = if !categorized.risks.is_empty() {
+    let kind = if has_risk {
         "CAUTION"
     } else if total_issues > 0 {
         "WARNING"
@@ -256,6 +295,13 @@ fn format_verdict(
     };
 
     let mut parts: Vec<String> = Vec::new();
+    if !categorized.breaking.is_empty() {
+        parts.push(format!(
+            "**{} breaking change{}**",
+            categorized.breaking.len(),
+            plural(categorized.breaking.len())
+        ));
+    }
     if !categorized.risks.is_empty() {
         parts.push(format!(
             "**{} contract risk{}**",
@@ -263,6 +309,13 @@ fn format_verdict(
             plural(categorized.risks.len())
         ));
     }
+    if !categorized.policy_violations.is_empty() {
+        parts.push(format!(
+            "{} policy violation{}",
+            categorized.policy_violations.len(),
+            plural(categorized.policy_violations.len())
+        ));
+    }
     if !categorized.connectivity_is_empty() {
         let noun = if has_baseline {
             "connectivity gap"
@@ -287,6 +340,13 @@ fn format_verdict(
             plural(categorized.configuration.len())
         ));
     }
+    if !categorized.profile_drift.is_empty() {
+        parts.push(format!(
+            "{} profile drift{}",
+            categorized.profile_drift.len(),
+            plural(categorized.profile_drift.len())
+        ));
+    }
     if !categorized.shared_contracts.is_empty() {
         parts.push(format!(
             "{} shared external contract{}",
@@ -344,10 +404,21 @@ fn format_no_issues(
     let mut output = String::new();
     output.push_str("<!-- CARRICK_OUTPUT_START -->\n<!-- CARRICK_ISSUE_COUNT:0 -->\n");
     output.push_str(&format!("## 🪢 Carrick{}\n\n", topology.header_suffix()));
-    output.push_str(&format!(
-        "> [!TIP]\n> All cross-service calls match the indexed contracts across {}.\n\n",
-        topology.scope_phrase()
-    ));
+    if result.baselined_findings.is_empty() {
+        output.push_str(&format!(
+            "> [!TIP]\n> All cross-service calls match the indexed contracts across {}.\n\n",
+            topology.scope_phrase()
+        ));
+    } else {
+        let n = result.baselined_findings.len();
+        output.push_str(&format!(
+            "> [!TIP]\n> No new findings across {}. {} baselined finding{} remain{}.\n\n",
+            topology.scope_phrase(),
+            n,
+            plural(n),
+            if n == 1 { "s" } else { "" }
+        ));
+    }
     output.push_str(&format_pr_delta(pr_delta, &[]));
     output.push_str(&format!(
         "Indexed **{} endpoints** and **{} cross-service calls**.\n\n",
@@ -358,6 +429,10 @@ fn format_no_issues(
         &result.detected_graphql_libraries,
         result.graphql_operations_indexed,
     ));
+    if !result.baselined_findings.is_empty() {
+        output.push_str(&format_baselined_section(&result.baselined_findings));
+        output.push_str("\n\n");
+    }
     if !result.verified_endpoints.is_empty() {
         output.push_str(&format_verified_section(&result.verified_endpoints));
         output.push_str("\n\n");
@@ -366,6 +441,29 @@ fn format_no_issues(
     output
 }
 
+/// Render findings the repo's `carrick-baseline.json` already accepts:
+/// collapsed, one row per finding, no headline weight.
+fn format_baselined_section(baselined: &[Finding]) -> String {
+    let mut output = format!(
+        "<details>\n<summary><strong>Baselined ({})</strong></summary>\n\n",
+        baselined.len()
+    );
+    output.push_str(
+        "> Pre-existing findings recorded in `carrick-baseline.json`. They are not counted above; fix them and regenerate the baseline with `carrick baseline` to shrink it.\n\n",
+    );
+    output.push_str("| Kind | Finding | Fingerprint |\n| :--- | :--- | :--- |\n");
+    for finding in baselined {
+        output.push_str(&format!(
+            "| `{}` | `{}` | `{}` |\n",
+            finding.kind(),
+            code_cell(&finding.label()),
+            finding.fingerprint()
+        ));
+    }
+    output.push_str("\n</details>");
+    output
+}
+
 /// Render one verified-endpoint table row, marking mock/test-handler
 /// producers so the match is trusted accordingly (#380).
 fn format_verified_row(entry: &crate::analyzer::VerifiedEndpointEntry) -> String {
@@ -501,14 +599,21 @@ struct EnvVarSuggestionGroup {
 }
 
 struct CategorizedFindings<'a> {
-    /// Contract risks: type mismatches and method mismatches.
+    /// Producer changes in this PR that break indexed consumers.
+    breaking: Vec<&'a Finding>,
+    /// Contract risks: type, method, query-parameter and header mismatches.
     risks: Vec<&'a Finding>,
+    /// Breaches of the repo's `policy.rules`.
+    policy_violations: Vec<&'a Finding>,
     /// Consumer calls with no producer.
     missing: Vec<&'a Finding>,
     /// Producers with no consumer.
     orphaned: Vec<&'a Finding>,
     /// Env-var calls regrouped for the configuration section.
     configuration: Vec<EnvVarSuggestionGroup>,
+    /// Env-var calls whose target service differs between environment
+    /// profiles. Advisory, rendered with the configuration suggestions.
+    profile_drift: Vec<&'a Finding>,
     /// Dependency conflicts split by tier: `major` counts toward the headline,
     /// `unparseable` is advisory.
     major_dependencies: Vec<&'a Finding>,
@@ -538,10 +643,8 @@ impl CategorizedFindings<'_> {
     fn missing_keys(&self) -> Vec<(String, String)> {
         self.missing
             .iter()
-            .filter_map(|finding| match finding {
-                Finding::MissingEndpoint { method, path, .. } => {
-                    Some((method.clone(), path.clone()))
-                }
+            .filter_map(|finding| match &finding.issue {
+                Issue::MissingEndpoint { method, path, .. } => Some((method.clone(), path.clone())),
                 _ => None,
             })
             .collect()
@@ -549,36 +652,48 @@ impl CategorizedFindings<'_> {
 }
 
 fn categorize_findings(findings: &[Finding]) -> CategorizedFindings<'_> {
+    let mut breaking = Vec::new();
     let mut risks = Vec::new();
+    let mut policy_violations = Vec::new();
     let mut missing = Vec::new();
     let mut orphaned = Vec::new();
     let mut env_var_calls = Vec::new();
+    let mut profile_drift = Vec::new();
     let mut major_dependencies = Vec::new();
     let mut unparseable_dependencies = Vec::new();
     let mut shared_contracts = Vec::new();
 
     for finding in findings {
-        match finding {
-            Finding::TypeMismatch { .. } | Finding::MethodMismatch { .. } => risks.push(finding),
-            Finding::MissingEndpoint { .. } => missing.push(finding),
-            Finding::OrphanedEndpoint { .. } => orphaned.push(finding),
-            Finding::EnvVarCall { .. } => env_var_calls.push(finding),
-            Finding::DependencyConflict { tier: t, .. } => {
+        match &finding.issue {
+            Issue::TypeMismatch { .. }
+            | Issue::MethodMismatch { .. }
+            | Issue::QueryParamMismatch { .. }
+            | Issue::HeaderMismatch { .. } => risks.push(finding),
+            Issue::MissingEndpoint { .. } => missing.push(finding),
+            Issue::OrphanedEndpoint { .. } => orphaned.push(finding),
+            Issue::EnvVarCall { .. } => env_var_calls.push(finding),
+            Issue::ProfileTargetDrift { .. } => profile_drift.push(finding),
+            Issue::DependencyConflict { tier: t, .. } => {
                 if t == tier::MAJOR {
                     major_dependencies.push(finding);
                 } else {
                     unparseable_dependencies.push(finding);
                 }
             }
-            Finding::SharedExternalContract { .. } => shared_contracts.push(finding),
+            Issue::SharedExternalContract { .. } => shared_contracts.push(finding),
+            Issue::PolicyViolation { .. } => policy_violations.push(finding),
+            Issue::BreakingChange { .. } => breaking.push(finding),
         }
     }
 
     CategorizedFindings {
+        breaking,
         risks,
+        policy_violations,
         missing,
         orphaned,
         configuration: group_env_var_suggestions(&env_var_calls),
+        profile_drift,
         major_dependencies,
         unparseable_dependencies,
         shared_contracts,
@@ -596,13 +711,14 @@ fn format_critical_section(risks: &[&Finding]) -> String {
     );
     output.push_str("| Endpoint | Issue |\n| :--- | :--- |\n");
     for finding in risks {
-        let (endpoint, detail) = match finding {
-            Finding::TypeMismatch {
+        let (endpoint, detail) = match &finding.issue {
+            Issue::TypeMismatch {
                 method,
                 path,
                 producer_type,
                 consumer_type,
                 detail,
+                fields,
                 producer_provenance,
                 ..
             } => (
@@ -618,10 +734,21 @@ fn format_critical_section(risks: &[&Finding]) -> String {
                         ""
                     },
                     consumer_type,
-                    detail
+                    // The field table below says it better than the raw
+                    // compiler text.
+                    if fields.is_empty() {
+                        detail.clone()
+                    } else {
+                        format!(
+                            "{} field{} disagree{}",
+                            fields.len(),
+                            plural(fields.len()),
+                            if fields.len() == 1 { "s" } else { "" }
+                        )
+                    }
                 ),
             ),
-            Finding::MethodMismatch {
+            Issue::MethodMismatch {
                 method,
                 path,
                 expected_method,
@@ -633,15 +760,123 @@ fn format_critical_section(risks: &[&Finding]) -> String {
                     method, expected_method
                 ),
             ),
-            // categorize_findings only routes the two risk kinds here.
+            Issue::QueryParamMismatch {
+                method,
+                path,
+                unread,
+                missing,
+                ..
+            } => {
+                let keys = |keys: &[String]| {
+                    keys.iter()
+                        .map(|k| format!("`{}`", k))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let mut issues = Vec::new();
+                if !unread.is_empty() {
+                    issues.push(format!(
+                        "call sends {} the producer never reads",
+                        keys(unread)
+                    ));
+                }
+                if !missing.is_empty() {
+                    issues.push(format!("call omits required {}", keys(missing)));
+                }
+                (format!("{} {}", method, path), issues.join("; "))
+            }
+            Issue::HeaderMismatch {
+                method,
+                path,
+                missing,
+                content_types,
+                accepted,
+                ..
+            } => {
+                let quoted = |values: &[String]| {
+                    values
+                        .iter()
+                        .map(|v| format!("`{}`", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let mut issues = Vec::new();
+                if !missing.is_empty() {
+                    issues.push(format!("call never sends {}", quoted(missing)));
+                }
+                if !content_types.is_empty() {
+                    issues.push(format!(
+                        "call sends {} but the producer parses {}",
+                        quoted(content_types),
+                        quoted(accepted)
+                    ));
+                }
+                (format!("{} {}", method, path), issues.join("; "))
+            }
+            // categorize_findings only routes the risk kinds here.
             _ => continue,
         };
         output.push_str(&format!("| `{}` | {} |\n", cell(&endpoint), cell(&detail)));
     }
+    for finding in risks {
+        if let Issue::TypeMismatch {
+            method,
+            path,
+            fields,
+            ..
+        } = &finding.issue
+            && !fields.is_empty()
+        {
+            output.push_str(&format_field_mismatches(method, path, fields));
+        }
+    }
     output.push_str("\n</details>");
     output
 }
 
+/// Per-field breakdown of one type mismatch, under the risks table.
+fn format_field_mismatches(method: &str, path: &str, fields: &[FieldMismatch]) -> String {
+    // The consumer-read column appears only when the call site was traced.
+    let usage_known = fields.iter().any(|f| f.read.is_some());
+    let mut output = format!(
+        "\n`{} {}`:\n\n| Field | Mismatch | Producer | Consumer |{}\n| :--- | :--- | :--- | :--- |{}\n",
+        code_span(method),
+        code_span(path),
+        if usage_known {
+            " Read by consumer |"
+        } else {
+            ""
+        },
+        if usage_known { " :--- |" } else { "" }
+    );
+    let side = |value: &Option<String>| match value {
+        Some(v) => format!("`{}`", code_cell(v)),
+        None => "—".to_string(),
+    };
+    for field in fields {
+        let name = if field.path.is_empty() {
+            "(whole type)".to_string()
+        } else {
+            format!("`{}`", code_cell(&field.path))
+        };
+        let read = match (usage_known, field.read) {
+            (false, _) => "",
+            (true, Some(true)) => " yes |",
+            (true, Some(false)) => " no |",
+            (true, None) => " — |",
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |{}\n",
+            name,
+            field.kind.label(),
+            side(&field.producer),
+            side(&field.consumer),
+            read
+        ));
+    }
+    output
+}
+
 /// Escape a value for a Markdown table cell: no pipes, and no line breaks
 /// (CRLF, lone CR, or LF) that would otherwise split the row.
 fn cell(value: &str) -> String {
@@ -703,12 +938,12 @@ fn format_connectivity_section(
         output.push_str(&format!("**Missing ({})**\n\n", missing.len()));
         output.push_str("| Method | Path | Called from |\n| :--- | :--- | :--- |\n");
         for finding in missing {
-            let Finding::MissingEndpoint {
+            let Issue::MissingEndpoint {
                 method,
                 path,
                 call_sites,
                 ..
-            } = finding
+            } = &finding.issue
             else {
                 continue;
             };
@@ -731,12 +966,13 @@ fn format_connectivity_section(
         output.push_str(&format!("**Orphaned ({})**\n\n", orphaned.len()));
         type OrphanRow<'a> = (&'a String, &'a String, &'a Option<String>, &'static str);
         fn orphan_row(finding: &Finding) -> Option<OrphanRow<'_>> {
-            match finding {
-                Finding::OrphanedEndpoint {
+            match &finding.issue {
+                Issue::OrphanedEndpoint {
                     method,
                     path,
                     service,
                     provenance,
+                    ..
                 } => {
                     // Mark mock/test-handler producers: an orphaned mock is
                     // expected (its consumers are usually not scanned), so the
@@ -825,6 +1061,165 @@ fn format_configuration_section(issues: &[EnvVarSuggestionGroup]) -> String {
     output
 }
 
+/// Render producer changes that break indexed consumers: the consumers that
+/// were compatible before this PR and aren't after it, with what changed.
+fn format_breaking_section(breaking: &[&Finding]) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "<details>\n<summary><strong>Breaking changes ({})</strong></summary>\n\n",
+        breaking.len()
+    ));
+    output.push_str(
+        "> This PR changes a producer type that these consumers were compatible with in the index. They will break once it ships.\n\n",
+    );
+    output.push_str("| Endpoint | Breaks | Change | Call sites |\n| :--- | :--- | :--- | :--- |\n");
+    for finding in breaking {
+        let Issue::BreakingChange {
+            method,
+            path,
+            consumers,
+            changes,
+            call_sites,
+            ..
+        } = &finding.issue
+        else {
+            // categorize_findings only routes BreakingChange here.
+            continue;
+        };
+        let consumer_list = consumers
+            .iter()
+            .map(|c| format!("`{}`", code_cell(c)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let change_list = if changes.is_empty() {
+            "-".to_string()
+        } else {
+            changes
+                .iter()
+                .map(|c| cell(c))
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let sites = if call_sites.is_empty() {
+            "-".to_string()
+        } else {
+            format!("`{}`", code_cell(&call_sites.join(", ")))
+        };
+        output.push_str(&format!(
+            "| `{} {}` | {} | {} | {} |\n",
+            code_cell(method),
+            code_cell(path),
+            consumer_list,
+            change_list,
+            sites
+        ));
+    }
+    output.push_str("\n</details>");
+    output
+}
+
+/// Render breaches of the repo's declarative `policy.rules`, one row per
+/// offending route or call, keyed by rule id.
+fn format_policy_section(violations: &[&Finding]) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "<details>\n<summary><strong>Policy violations ({})</strong></summary>\n\n",
+        violations.len()
+    ));
+    output.push_str(
+        "> These endpoints or calls break a rule declared under `policy.rules` in your `carrick.json`.\n\n",
+    );
+    output.push_str("| Rule | Endpoint | Issue | Location |\n| :--- | :--- | :--- | :--- |\n");
+    for finding in violations {
+        let Issue::PolicyViolation {
+            rule_id,
+            method,
+            path,
+            message,
+            call_sites,
+            ..
+        } = &finding.issue
+        else {
+            // categorize_findings only routes PolicyViolation here.
+            continue;
+        };
+        let sites = if call_sites.is_empty() {
+            "-".to_string()
+        } else {
+            format!("`{}`", code_cell(&call_sites.join(", ")))
+        };
+        output.push_str(&format!(
+            "| `{}` | `{} {}` | {} | {} |\n",
+            code_cell(rule_id),
+            code_cell(method),
+            code_cell(path),
+            cell(message),
+            sites
+        ));
+    }
+    output.push_str("\n</details>");
+    output
+}
+
+/// Render env-var calls whose target service differs between environment
+/// profiles: one row per call, one cell per profile naming where it points.
+fn format_profile_drift_section(drift: &[&Finding]) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "<details>\n<summary><strong>Environment profile drift ({})</strong></summary>\n\n",
+        drift.len()
+    ));
+    output.push_str(
+        "> These calls reach a different service depending on the environment profile, or hardcode one profile's host. Check the dotenv files listed under `profiles` in your `carrick.json`.\n\n",
+    );
+    output.push_str("| Call | Env var | Targets | Call sites |\n| :--- | :--- | :--- | :--- |\n");
+    for finding in drift {
+        let Issue::ProfileTargetDrift {
+            method,
+            path,
+            env_var,
+            targets,
+            pinned_origin,
+            call_sites,
+            ..
+        } = &finding.issue
+        else {
+            // categorize_findings only routes ProfileTargetDrift here.
+            continue;
+        };
+        let target_list = pinned_origin
+            .iter()
+            .map(|origin| format!("hardcoded: `{}`", code_cell(origin)))
+            .chain(targets.iter().map(|t| {
+                format!(
+                    "{}: `{}`",
+                    cell(&t.profile),
+                    code_cell(t.service.as_deref().unwrap_or(&t.origin))
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sites = if call_sites.is_empty() {
+            "-".to_string()
+        } else {
+            format!("`{}`", code_cell(&call_sites.join(", ")))
+        };
+        output.push_str(&format!(
+            "| `{} {}` | `{}` | {} | {} |\n",
+            code_cell(method),
+            code_cell(path),
+            code_cell(env_var),
+            target_list,
+            sites
+        ));
+    }
+    output.push_str("\n</details>");
+    output
+}
+
 /// Render the shared-external-contract groups (#379): repos whose call sites
 /// all target the same route that no indexed service defines. Deliberately
 /// role-free wording — none of the repos is a producer, and no contract
@@ -841,12 +1236,13 @@ fn format_shared_contract_section(shared: &[&Finding]) -> String {
     );
     output.push_str("| Method | Path | Encoded by | Call sites |\n| :--- | :--- | :--- | :--- |\n");
     for finding in shared {
-        let Finding::SharedExternalContract {
+        let Issue::SharedExternalContract {
             method,
             path,
             repos,
             call_sites,
-        } = finding
+            ..
+        } = &finding.issue
         else {
             // categorize_findings only routes SharedExternalContract here.
             continue;
@@ -889,11 +1285,11 @@ fn format_dependency_section(major: &[&Finding], unparseable: &[&Finding]) -> St
         }
         output.push_str(&format!("### {} ({})\n\n", heading, group.len()));
         for finding in group {
-            let Finding::DependencyConflict {
+            let Issue::DependencyConflict {
                 package_name,
                 versions,
                 ..
-            } = finding
+            } = &finding.issue
             else {
                 continue;
             };
@@ -929,12 +1325,13 @@ fn group_env_var_suggestions(findings: &[&Finding]) -> Vec<EnvVarSuggestionGroup
     let mut grouped: BTreeMap<(String, String, String), BTreeSet<String>> = BTreeMap::new();
 
     for finding in findings {
-        let Finding::EnvVarCall {
+        let Issue::EnvVarCall {
             method,
             path,
             env_var,
             call_sites,
-        } = finding
+            ..
+        } = &finding.issue
         else {
             continue;
         };
@@ -992,6 +1389,7 @@ mod tests {
             verified_endpoints: vec![],
             detected_graphql_libraries: vec![],
             graphql_operations_indexed: false,
+            baselined_findings: vec![],
             cross_repo_matches: vec![],
         }
     }
@@ -1028,6 +1426,86 @@ mod tests {
         assert!(output.contains("1 shared external contract"));
     }
 
+    #[test]
+    fn profile_drift_renders_per_profile_targets() {
+        use crate::env_profiles::ProfileTarget;
+        let target = |profile: &str, service: &str| ProfileTarget {
+            profile: profile.to_string(),
+            origin: format!("https://{service}.acme.dev"),
+            service: Some(service.to_string()),
+        };
+        let result = result_with(vec![Finding::profile_target_drift(
+            "GET",
+            "/orders",
+            "ORDERS_URL",
+            vec![target("production", "orders"), target("staging", "billing")],
+            None,
+            vec!["web/src/orders.ts:9".to_string()],
+        )]);
+        let output = format_analysis_results(result, &topology_baseline(), None);
+
+        assert!(output.contains("Environment profile drift (1)"), "{output}");
+        assert!(output.contains("production: `orders`, staging: `billing`"));
+        assert!(output.contains("1 profile drift"));
+        assert!(output.contains("<!-- CARRICK_ISSUE_COUNT:0 -->"));
+
+        let result = result_with(vec![Finding::profile_target_drift(
+            "GET",
+            "/orders",
+            "ORDERS_URL",
+            vec![target("production", "orders")],
+            Some("http://localhost:3001".to_string()),
+            vec!["web/src/legacy.ts:3".to_string()],
+        )]);
+        let output = format_analysis_results(result, &topology_baseline(), None);
+        assert!(
+            output.contains("hardcoded: `http://localhost:3001`, production: `orders`"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn baselined_findings_render_apart_from_the_headline() {
+        let mut result = result_with(vec![type_mismatch_finding()]);
+        result.baselined_findings = vec![Finding::orphaned_endpoint("GET", "/legacy", None)];
+        let output = format_analysis_results(result, &topology_baseline(), None);
+        assert!(
+            output.contains("<!-- CARRICK_ISSUE_COUNT:1 -->"),
+            "{output}"
+        );
+        assert!(output.contains("Baselined (1)"));
+        assert!(output.contains("| `orphaned_endpoint` | `GET /legacy` |"));
+        assert!(output.contains(&format!(
+            "<!-- CARRICK_FINDINGS:{} -->",
+            type_mismatch_finding().fingerprint()
+        )));
+
+        // Only baselined findings left: a clean run that says so.
+        let mut result = result_with(vec![]);
+        result.baselined_findings = vec![Finding::orphaned_endpoint("GET", "/legacy", None)];
+        let output = format_analysis_results(result, &topology_baseline(), None);
+        assert!(output.contains("<!-- CARRICK_ISSUE_COUNT:0 -->"));
+        assert!(output.contains("No new findings across 3 repos. 1 baselined finding remains."));
+        assert!(output.contains("Baselined (1)"));
+    }
+
+    #[test]
+    fn policy_severity_drives_the_headline() {
+        // An env-var suggestion re-graded to a gap counts; a type mismatch
+        // downgraded to advisory neither counts nor turns the callout red.
+        let result = result_with(vec![
+            Finding::env_var_call("GET", "/orders", "ORDERS_URL", vec![])
+                .with_severity(Severity::Gap),
+            type_mismatch_finding().with_severity(Severity::Advisory),
+        ]);
+        let output = format_analysis_results(result, &topology_baseline(), None);
+        assert!(
+            output.contains("<!-- CARRICK_ISSUE_COUNT:1 -->"),
+            "{output}"
+        );
+        assert!(output.contains("> [!WARNING]"), "{output}");
+    }
+
     fn type_mismatch_finding() -> Finding {
         Finding::type_mismatch(
             "GET",
@@ -1078,6 +1556,103 @@ mod tests {
         assert!(output.contains("<!-- CARRICK_ISSUE_COUNT:1 -->"));
     }
 
+    #[test]
+    fn test_type_mismatch_renders_field_table() {
+        let finding = type_mismatch_finding().with_field_mismatches(vec![
+            FieldMismatch {
+                path: "role".to_string(),
+                kind: crate::findings::FieldMismatchKind::Missing,
+                producer: None,
+                consumer: Some("required".to_string()),
+                read: None,
+            },
+            FieldMismatch {
+                path: "status".to_string(),
+                kind: crate::findings::FieldMismatchKind::EnumNarrowing,
+                producer: Some(r#""active" | "banned""#.to_string()),
+                consumer: Some(r#""active""#.to_string()),
+                read: None,
+            },
+        ]);
+        let output =
+            format_analysis_results(result_with(vec![finding]), &topology_baseline(), None);
+
+        // The row summarizes; the table carries the per-field breakdown.
+        assert!(output.contains("vs consumer `User[]`: 2 fields disagree |"));
+        assert!(!output.contains("Property 'role' is missing"));
+        assert!(output.contains("| Field | Mismatch | Producer | Consumer |"));
+        assert!(output.contains("| `role` | missing | — | `required` |"));
+        assert!(
+            output
+                .contains(r#"| `status` | enum narrowing | `"active" \| "banned"` | `"active"` |"#)
+        );
+    }
+
+    #[test]
+    fn test_field_table_marks_consumer_reads_when_traced() {
+        let finding = type_mismatch_finding().with_field_mismatches(vec![
+            FieldMismatch {
+                path: "role".to_string(),
+                kind: crate::findings::FieldMismatchKind::Missing,
+                producer: None,
+                consumer: Some("required".to_string()),
+                read: Some(true),
+            },
+            FieldMismatch {
+                path: "createdAt".to_string(),
+                kind: crate::findings::FieldMismatchKind::TypeChanged,
+                producer: Some("string".to_string()),
+                consumer: Some("Date".to_string()),
+                read: Some(false),
+            },
+        ]);
+        let output =
+            format_analysis_results(result_with(vec![finding]), &topology_baseline(), None);
+
+        assert!(output.contains("| Field | Mismatch | Producer | Consumer | Read by consumer |"));
+        assert!(output.contains("| `role` | missing | — | `required` | yes |"));
+        assert!(output.contains("| `createdAt` | type changed | `string` | `Date` | no |"));
+    }
+
+    #[test]
+    fn test_query_param_mismatch_renders_as_contract_risk() {
+        let finding = Finding::query_param_mismatch(
+            "GET",
+            "/api/users",
+            Some("api".to_string()),
+            vec!["web/src/users.ts:8".to_string()],
+            vec!["pageSize".to_string()],
+            vec!["tenant".to_string()],
+        );
+        let output =
+            format_analysis_results(result_with(vec![finding]), &topology_baseline(), None);
+
+        assert!(output.contains("Contract risks (1)"));
+        assert!(output.contains(
+            "| `GET /api/users` | call sends `pageSize` the producer never reads; call omits required `tenant` |"
+        ));
+    }
+
+    #[test]
+    fn test_header_mismatch_renders_as_contract_risk() {
+        let finding = Finding::header_mismatch(
+            "POST",
+            "/api/orders",
+            Some("api".to_string()),
+            vec!["web/src/orders.ts:12".to_string()],
+            vec!["authorization".to_string()],
+            vec!["text/plain".to_string()],
+            vec!["application/json".to_string()],
+        );
+        let output =
+            format_analysis_results(result_with(vec![finding]), &topology_baseline(), None);
+
+        assert!(output.contains("Contract risks (1)"));
+        assert!(output.contains(
+            "| `POST /api/orders` | call never sends `authorization`; call sends `text/plain` but the producer parses `application/json` |"
+        ));
+    }
+
     #[test]
     fn test_method_mismatch_renders_as_contract_risk() {
         let finding = Finding::method_mismatch(
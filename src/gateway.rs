@@ -0,0 +1,625 @@
+//! API-gateway and reverse-proxy path rewrites.
+//!
+//! Frontends often call a gateway rather than the service that actually serves
+//! the route:
+//!
+//! ```text
+//! browser  ──GET /api/orders/42──▶  gateway  ──GET /42──▶  orders-service
+//! ```
+//!
+//! The consumer's source only ever contains `/api/orders/42`, while the
+//! producer declares `GET /:id`. Without knowing what the gateway strips, the
+//! call can never pair with its producer and surfaces as a bogus
+//! `MissingEndpoint`.
+//!
+//! A [`GatewayConfig`] supplies that one deployment fact: a list of
+//! [`GatewayRoute`]s (`prefix` → `rewrite`, plus the service the prefix forwards
+//! to), declared in the `gateway` section of `carrick.json` and/or imported from
+//! the repo's own proxy config — `vercel.json` and `next.config.*` rewrites,
+//! nginx `location`/`proxy_pass` blocks, and Kong / Traefik declarative YAML.
+//! The rewrite is applied in [`crate::url_normalizer::UrlNormalizer::consumer_call_path`],
+//! so the consumer call is keyed on the path the producer actually receives.
+//!
+//! Importers are deliberately structural: they recover plain prefix forwarding
+//! (`/api/orders/:path*` → `http://orders/:path*`, `location /api/orders/ {
+//! proxy_pass http://orders/; }`). Regex locations, header/host-conditional
+//! routing, and rewrites whose placeholders are reordered are skipped rather
+//! than guessed.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::LazyLock;
+
+static NEXT_REWRITE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"source\s*:\s*["'`]([^"'`]+)["'`]\s*,\s*destination\s*:\s*["'`]([^"'`]+)["'`]"#)
+        .unwrap()
+});
+static NGINX_LOCATION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"location\s+(?:\^~\s+|=\s+)?(/[^\s{]*)\s*\{").unwrap());
+static NGINX_PROXY_PASS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"proxy_pass\s+([^;\s]+)\s*;").unwrap());
+static TRAEFIK_PATH_PREFIX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"PathPrefix\(\s*[`"']([^`"']+)[`"']"#).unwrap());
+
+/// The `gateway` section of `carrick.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GatewayConfig {
+    /// Prefix rewrites applied to outbound call paths, longest prefix first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<GatewayRoute>,
+    /// Proxy config files to import routes from, relative to `carrick.json`.
+    /// Drained into `routes` by [`GatewayConfig::resolve_imports`] when the
+    /// config is loaded, so a serialized config only ever carries concrete
+    /// routes (a peer repo's merged config never re-reads a path that is
+    /// relative to someone else's checkout).
+    #[serde(default, rename = "import", skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<String>,
+}
+
+/// One gateway prefix: calls under `prefix` are forwarded to `service` with
+/// `prefix` replaced by `rewrite`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GatewayRoute {
+    /// Path prefix the consumer calls, matched on whole segments
+    /// (`/api/orders` matches `/api/orders/1`, never `/api/orders-v2`).
+    pub prefix: String,
+    /// The service the gateway forwards this prefix to, by the id producers
+    /// are indexed under (`serviceName`, else the repo name). A rewritten
+    /// call only matches that service's producers when the index has any;
+    /// otherwise, and without a service, it matches every producer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Replacement for the matched prefix. Empty (the default) strips it.
+    #[serde(default)]
+    pub rewrite: String,
+}
+
+impl GatewayRoute {
+    pub fn new(prefix: &str, service: Option<String>, rewrite: &str) -> Self {
+        Self {
+            prefix: trim_trailing_slash(prefix).to_string(),
+            service,
+            rewrite: trim_trailing_slash(rewrite).to_string(),
+        }
+    }
+
+    /// Rewrite `path` if it falls under this route's prefix.
+    pub fn apply(&self, path: &str) -> Option<String> {
+        let prefix = trim_trailing_slash(&self.prefix);
+        let rest = if prefix.is_empty() {
+            path
+        } else {
+            let rest = path.strip_prefix(prefix)?;
+            if !rest.is_empty() && !rest.starts_with('/') {
+                return None;
+            }
+            rest
+        };
+        let rewritten = format!("{}{}", trim_trailing_slash(&self.rewrite), rest);
+        if rewritten.is_empty() {
+            Some("/".to_string())
+        } else if rewritten.starts_with('/') {
+            Some(rewritten)
+        } else {
+            Some(format!("/{rewritten}"))
+        }
+    }
+}
+
+impl GatewayConfig {
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty() && self.imports.is_empty()
+    }
+
+    /// Apply the most specific (longest-prefix) route matching `path`.
+    /// Returns the rewritten path and the route that produced it, or `None`
+    /// when no route covers the path.
+    pub fn rewrite_path(&self, path: &str) -> Option<(String, &GatewayRoute)> {
+        self.routes
+            .iter()
+            .filter_map(|route| route.apply(path).map(|rewritten| (rewritten, route)))
+            .max_by_key(|(_, route)| trim_trailing_slash(&route.prefix).len())
+    }
+
+    /// Read every `import` file (relative to `root`) and append its routes.
+    /// A missing or unparseable import is an error: a typo'd path would
+    /// otherwise silently leave every gateway call unmatched.
+    pub fn resolve_imports(&mut self, root: &Path) -> Result<(), String> {
+        for import in std::mem::take(&mut self.imports) {
+            let path = root.join(&import);
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("gateway import '{}': {}", import, e))?;
+            let routes = import_routes(&path, &content)
+                .map_err(|e| format!("gateway import '{}': {}", import, e))?;
+            self.routes.extend(routes);
+        }
+        Ok(())
+    }
+}
+
+/// Parse a proxy config file into gateway routes, dispatching on its name.
+pub fn import_routes(path: &Path, content: &str) -> Result<Vec<GatewayRoute>, String> {
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+
+    if file_name == "vercel.json" {
+        parse_vercel_json(content)
+    } else if file_name.starts_with("next.config.") {
+        Ok(parse_next_config(content))
+    } else if extension == "conf" || file_name == "nginx.conf" {
+        Ok(parse_nginx_conf(content))
+    } else if extension == "yml" || extension == "yaml" {
+        parse_declarative_yaml(content)
+    } else {
+        Err(format!(
+            "unrecognised format (expected vercel.json, next.config.*, an nginx *.conf, \
+             or Kong/Traefik *.yml); got '{}'",
+            file_name
+        ))
+    }
+}
+
+/// `vercel.json` `rewrites: [{ source, destination }]`.
+pub fn parse_vercel_json(content: &str) -> Result<Vec<GatewayRoute>, String> {
+    let value: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    Ok(value
+        .get("rewrites")
+        .and_then(|r| r.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|rule| {
+            let source = rule.get("source")?.as_str()?;
+            let destination = rule.get("destination")?.as_str()?;
+            route_from_rewrite(source, destination)
+        })
+        .collect())
+}
+
+/// `next.config.{js,mjs,ts}` `rewrites()` entries. The config is code, not
+/// data, so this reads `{ source: '...', destination: '...' }` literal pairs
+/// structurally; computed sources/destinations are not resolved.
+pub fn parse_next_config(content: &str) -> Vec<GatewayRoute> {
+    NEXT_REWRITE_RE
+        .captures_iter(content)
+        .filter_map(|caps| route_from_rewrite(&caps[1], &caps[2]))
+        .collect()
+}
+
+/// nginx `location /prefix/ { proxy_pass http://upstream/uri; }` blocks.
+///
+/// Follows nginx's own rule: a `proxy_pass` URL that carries a URI replaces
+/// the matched location prefix with that URI; one without (`http://orders`)
+/// forwards the path unchanged. Regex locations (`~`, `~*`) and variable
+/// upstreams (`$backend`) are skipped.
+pub fn parse_nginx_conf(content: &str) -> Vec<GatewayRoute> {
+    let mut routes = Vec::new();
+    for caps in NGINX_LOCATION_RE.captures_iter(content) {
+        let prefix = &caps[1];
+        let body_start = caps.get(0).map(|m| m.end()).unwrap_or(0);
+        let body = block_body(&content[body_start..]);
+        let Some(pass) = NGINX_PROXY_PASS_RE.captures(body) else {
+            continue;
+        };
+        let target = &pass[1];
+        if target.contains('$') {
+            continue;
+        }
+        let Some((host, uri)) = split_upstream_url(target) else {
+            continue;
+        };
+        let rewrite = match uri {
+            Some(uri) => uri,
+            None => prefix.to_string(),
+        };
+        routes.push(GatewayRoute::new(prefix, Some(host), &rewrite));
+    }
+    routes
+}
+
+/// Kong (`services[].routes[].paths`) or Traefik (`http.routers`) declarative
+/// YAML, told apart by their top-level keys.
+pub fn parse_declarative_yaml(content: &str) -> Result<Vec<GatewayRoute>, String> {
+    let value: serde_yaml::Value = serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+    if value.get("services").is_some_and(|s| s.is_sequence()) {
+        Ok(parse_kong(&value))
+    } else if value.get("http").and_then(|h| h.get("routers")).is_some() {
+        Ok(parse_traefik(&value))
+    } else {
+        Err(
+            "neither a Kong declarative config (`services`) nor a Traefik \
+             dynamic config (`http.routers`)"
+                .to_string(),
+        )
+    }
+}
+
+/// Kong forwards `service.path + (strip_path ? rest : full path)`;
+/// `strip_path` defaults to `true`. Regex paths (`~/...`) are skipped.
+fn parse_kong(value: &serde_yaml::Value) -> Vec<GatewayRoute> {
+    let mut routes = Vec::new();
+    let services = value.get("services").and_then(|s| s.as_sequence());
+    for service in services.into_iter().flatten() {
+        let name = service
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(str::to_string);
+        let (url_host, url_path) = service
+            .get("url")
+            .and_then(|u| u.as_str())
+            .and_then(split_upstream_url)
+            .map(|(host, uri)| (Some(host), uri.unwrap_or_default()))
+            .unwrap_or((None, String::new()));
+        let service_path = service
+            .get("path")
+            .and_then(|p| p.as_str())
+            .map(str::to_string)
+            .unwrap_or(url_path);
+        let service_name = name.or(url_host);
+
+        let service_routes = service.get("routes").and_then(|r| r.as_sequence());
+        for route in service_routes.into_iter().flatten() {
+            let strip = route
+                .get("strip_path")
+                .and_then(|s| s.as_bool())
+                .unwrap_or(true);
+            let paths = route.get("paths").and_then(|p| p.as_sequence());
+            for path in paths.into_iter().flatten().filter_map(|p| p.as_str()) {
+                if path.starts_with('~') {
+                    continue;
+                }
+                let rewrite = if strip {
+                    service_path.clone()
+                } else {
+                    format!("{}{}", trim_trailing_slash(&service_path), path)
+                };
+                routes.push(GatewayRoute::new(path, service_name.clone(), &rewrite));
+            }
+        }
+    }
+    routes
+}
+
+/// Traefik routers with a `PathPrefix(...)` rule. A `stripPrefix` middleware
+/// naming the prefix strips it; an `addPrefix` middleware prepends its prefix.
+fn parse_traefik(value: &serde_yaml::Value) -> Vec<GatewayRoute> {
+    let http = &value["http"];
+    let middlewares = http.get("middlewares");
+    let mut routes = Vec::new();
+    let Some(routers) = http.get("routers").and_then(|r| r.as_mapping()) else {
+        return routes;
+    };
+    for router in routers.values() {
+        let Some(rule) = router.get("rule").and_then(|r| r.as_str()) else {
+            continue;
+        };
+        // `orders@file` names the same service as `orders`.
+        let service = router
+            .get("service")
+            .and_then(|s| s.as_str())
+            .map(|s| s.split('@').next().unwrap_or(s).to_string());
+        let chain: Vec<&serde_yaml::Value> = router
+            .get("middlewares")
+            .and_then(|m| m.as_sequence())
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m.as_str())
+            .filter_map(|name| middlewares?.get(name.split('@').next().unwrap_or(name)))
+            .collect();
+
+        for caps in TRAEFIK_PATH_PREFIX_RE.captures_iter(rule) {
+            let prefix = trim_trailing_slash(&caps[1]).to_string();
+            let stripped = chain.iter().any(|m| {
+                m.get("stripPrefix")
+                    .and_then(|s| s.get("prefixes"))
+                    .and_then(|p| p.as_sequence())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|p| p.as_str())
+                    .any(|p| trim_trailing_slash(p) == prefix)
+            });
+            let added = chain
+                .iter()
+                .find_map(|m| m.get("addPrefix")?.get("prefix")?.as_str())
+                .unwrap_or_default();
+            let rewrite = if stripped {
+                added.to_string()
+            } else {
+                format!("{}{}", trim_trailing_slash(added), prefix)
+            };
+            routes.push(GatewayRoute::new(&prefix, service.clone(), &rewrite));
+        }
+    }
+    routes
+}
+
+/// Convert a `source` → `destination` rewrite (Vercel / Next.js syntax) into
+/// a prefix route. Both sides must be a literal prefix followed by the SAME
+/// trailing catch-all placeholder (`:path*`, `:path+`, `(.*)`), or fully
+/// literal; anything that reorders or renames placeholders is skipped.
+fn route_from_rewrite(source: &str, destination: &str) -> Option<GatewayRoute> {
+    let (service, dest_path) =
+        if destination.starts_with("http://") || destination.starts_with("https://") {
+            let (host, uri) = split_upstream_url(destination)?;
+            (Some(host), uri.unwrap_or_else(|| "/".to_string()))
+        } else {
+            (None, destination.to_string())
+        };
+
+    let (source_prefix, source_tail) = split_catch_all(source)?;
+    let (dest_prefix, dest_tail) = split_catch_all(&dest_path)?;
+    if source_tail != dest_tail {
+        return None;
+    }
+    Some(GatewayRoute::new(&source_prefix, service, &dest_prefix))
+}
+
+/// Split a rewrite path into its literal prefix and trailing catch-all
+/// placeholder (`None` for a fully literal path). `None` overall when a
+/// placeholder appears anywhere but the final segment.
+fn split_catch_all(path: &str) -> Option<(String, Option<String>)> {
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let segments: Vec<&str> = path.split('/').collect();
+    let is_placeholder = |seg: &str| seg.starts_with(':') || seg.contains('(') || seg == "*";
+    match segments.iter().position(|seg| is_placeholder(seg)) {
+        None => Some((path.to_string(), None)),
+        Some(idx) if idx == segments.len() - 1 => {
+            let tail = segments[idx];
+            let is_catch_all = tail.ends_with('*')
+                || tail.ends_with('+')
+                || tail == "(.*)"
+                || tail.ends_with("(.*)");
+            is_catch_all.then(|| (segments[..idx].join("/"), Some(tail.to_string())))
+        }
+        Some(_) => None,
+    }
+}
+
+/// `http://host:port/uri` → `(host, Some("/uri"))`; no path → `(host, None)`.
+fn split_upstream_url(url: &str) -> Option<(String, Option<String>)> {
+    let rest = url
+        .strip_prefix("http://")
+        .or_else(|| url.strip_prefix("https://"))?;
+    let (authority, uri) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], Some(rest[idx..].to_string())),
+        None => (rest, None),
+    };
+    let host = authority.split(':').next().unwrap_or(authority);
+    if host.is_empty() {
+        return None;
+    }
+    Some((host.to_string(), uri))
+}
+
+/// The text of a `{ ... }` block whose opening brace was just consumed.
+fn block_body(after_open: &str) -> &str {
+    let mut depth = 1usize;
+    for (idx, c) in after_open.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &after_open[..idx];
+                }
+            }
+            _ => {}
+        }
+    }
+    after_open
+}
+
+fn trim_trailing_slash(path: &str) -> &str {
+    path.trim_end_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_strips_prefix_on_segment_boundary() {
+        let route = GatewayRoute::new("/api/orders/", Some("orders".to_string()), "");
+        assert_eq!(route.apply("/api/orders/42"), Some("/42".to_string()));
+        assert_eq!(route.apply("/api/orders"), Some("/".to_string()));
+        // A longer sibling segment is a different route.
+        assert_eq!(route.apply("/api/orders-v2/42"), None);
+        assert_eq!(route.apply("/api/users/1"), None);
+    }
+
+    #[test]
+    fn route_replaces_prefix_with_rewrite() {
+        let route = GatewayRoute::new("/api/orders", None, "/v1/orders");
+        assert_eq!(
+            route.apply("/api/orders/42/items"),
+            Some("/v1/orders/42/items".to_string())
+        );
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let config = GatewayConfig {
+            routes: vec![
+                GatewayRoute::new("/api", Some("monolith".to_string()), ""),
+                GatewayRoute::new("/api/orders", Some("orders".to_string()), ""),
+            ],
+            imports: vec![],
+        };
+        let (path, route) = config.rewrite_path("/api/orders/7").unwrap();
+        assert_eq!(path, "/7");
+        assert_eq!(route.service.as_deref(), Some("orders"));
+        let (path, route) = config.rewrite_path("/api/users").unwrap();
+        assert_eq!(path, "/users");
+        assert_eq!(route.service.as_deref(), Some("monolith"));
+        assert!(config.rewrite_path("/health").is_none());
+    }
+
+    #[test]
+    fn vercel_rewrites_with_matching_catch_all() {
+        let json = r#"{
+            "rewrites": [
+                { "source": "/api/orders/:path*", "destination": "https://orders-service.internal/:path*" },
+                { "source": "/api/users/(.*)", "destination": "/users-api/(.*)" },
+                { "source": "/blog/:slug/edit", "destination": "/editor/:slug" }
+            ]
+        }"#;
+        let routes = parse_vercel_json(json).unwrap();
+        assert_eq!(
+            routes,
+            vec![
+                GatewayRoute::new(
+                    "/api/orders",
+                    Some("orders-service.internal".to_string()),
+                    ""
+                ),
+                GatewayRoute::new("/api/users", None, "/users-api"),
+            ],
+            "a rewrite with a mid-path placeholder is skipped, not guessed"
+        );
+    }
+
+    #[test]
+    fn next_config_rewrite_pairs() {
+        let js = r#"
+            module.exports = {
+              async rewrites() {
+                return [
+                  { source: '/api/orders/:path*', destination: 'http://orders:3000/v1/:path*' },
+                  { source: "/docs", destination: "/documentation" },
+                ];
+              },
+            };
+        "#;
+        assert_eq!(
+            parse_next_config(js),
+            vec![
+                GatewayRoute::new("/api/orders", Some("orders".to_string()), "/v1"),
+                GatewayRoute::new("/docs", None, "/documentation"),
+            ]
+        );
+    }
+
+    #[test]
+    fn nginx_proxy_pass_with_and_without_uri() {
+        let conf = r#"
+            server {
+                listen 80;
+                location /api/orders/ {
+                    proxy_set_header Host $host;
+                    proxy_pass http://orders-service:3000/;
+                }
+                location ^~ /api/users {
+                    proxy_pass http://users;
+                }
+                location ~ ^/legacy/(.*)$ {
+                    proxy_pass http://legacy/$1;
+                }
+                location /dyn/ {
+                    proxy_pass $backend;
+                }
+            }
+        "#;
+        assert_eq!(
+            parse_nginx_conf(conf),
+            vec![
+                GatewayRoute::new("/api/orders", Some("orders-service".to_string()), ""),
+                // No URI on proxy_pass: the path is forwarded unchanged.
+                GatewayRoute::new("/api/users", Some("users".to_string()), "/api/users"),
+            ]
+        );
+    }
+
+    #[test]
+    fn kong_declarative_strip_path_and_service_path() {
+        let yaml = r#"
+_format_version: "3.0"
+services:
+  - name: orders
+    url: http://orders-service:3000/internal
+    routes:
+      - name: orders-route
+        paths: [/api/orders]
+  - name: users
+    url: http://users:8080
+    routes:
+      - paths: ["/users"]
+        strip_path: false
+      - paths: ["~/regex/.*"]
+"#;
+        assert_eq!(
+            parse_declarative_yaml(yaml).unwrap(),
+            vec![
+                GatewayRoute::new("/api/orders", Some("orders".to_string()), "/internal"),
+                GatewayRoute::new("/users", Some("users".to_string()), "/users"),
+            ]
+        );
+    }
+
+    #[test]
+    fn traefik_routers_with_strip_and_add_prefix() {
+        let yaml = r#"
+http:
+  routers:
+    orders:
+      rule: "Host(`api.example.com`) && PathPrefix(`/api/orders`)"
+      service: orders@file
+      middlewares: [orders-strip, orders-v1]
+    users:
+      rule: "PathPrefix(`/users`)"
+      service: users
+  middlewares:
+    orders-strip:
+      stripPrefix:
+        prefixes: ["/api/orders"]
+    orders-v1:
+      addPrefix:
+        prefix: /v1
+"#;
+        let mut routes = parse_declarative_yaml(yaml).unwrap();
+        routes.sort_by(|a, b| a.prefix.cmp(&b.prefix));
+        assert_eq!(
+            routes,
+            vec![
+                GatewayRoute::new("/api/orders", Some("orders".to_string()), "/v1"),
+                GatewayRoute::new("/users", Some("users".to_string()), "/users"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unrecognised_yaml_is_an_error() {
+        assert!(parse_declarative_yaml("foo: bar").is_err());
+        assert!(import_routes(Path::new("routes.txt"), "").is_err());
+    }
+
+    #[test]
+    fn resolve_imports_drains_into_routes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("vercel.json"),
+            r#"{ "rewrites": [{ "source": "/api/:path*", "destination": "http://api/:path*" }] }"#,
+        )
+        .unwrap();
+        let mut config = GatewayConfig {
+            routes: vec![GatewayRoute::new("/gw/orders", None, "")],
+            imports: vec!["vercel.json".to_string()],
+        };
+        config.resolve_imports(dir.path()).unwrap();
+        assert!(config.imports.is_empty());
+        assert_eq!(config.routes.len(), 2);
+        assert_eq!(config.routes[1].service.as_deref(), Some("api"));
+
+        let mut missing = GatewayConfig {
+            routes: vec![],
+            imports: vec!["nginx/missing.conf".to_string()],
+        };
+        let err = missing.resolve_imports(dir.path()).unwrap_err();
+        assert!(err.contains("nginx/missing.conf"), "got: {err}");
+    }
+}
@@ -0,0 +1,1069 @@
+//! Header contracts between consumer calls and the endpoints they hit.
+//!
+//! Matching looks at method and path only, so a call to an auth-protected
+//! route that never sends `Authorization`, one that omits the `x-tenant-id`
+//! its handler reads, or a `fetch` posting a `JSON.stringify`'d body — sent
+//! as `text/plain` — to an `express.json()` route all passed. Both sides are
+//! recorded here:
+//!
+//! - a producer requires `authorization` when an auth middleware
+//!   ([`is_auth_middleware`]) sits in its middleware chain, plus the
+//!   `authorization` and custom `x-*` headers its handler reads outside a
+//!   fallback (`req.headers['x-tenant-id']`, `req.get('x-tenant-id')`,
+//!   `@Headers('x-tenant-id')`). The body parsers in the chain say which
+//!   content types it reads ([`parsed_content_types`]);
+//! - a consumer's [`SentHeaders`] come from the literal options of a direct
+//!   `fetch` / axios / ky / got / ofetch call ([`call_headers`]), with the
+//!   content type its body implies, plus whatever client defaults, instance
+//!   configs and request interceptors anywhere in the repo add
+//!   ([`ClientDefaults`]).
+//!
+//! A consumer's headers are `None` when any of them can't be read — a
+//! spread, a headers object held in a variable, an interceptor that sets a
+//! computed name — and such a call is never compared.
+
+use crate::env_alias::unwrap_transparent;
+use crate::http_instances::{HttpInstanceMap, REQUEST_METHODS, client_bindings, reference_text};
+use crate::parser::parse_module;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use swc_common::SourceMap;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Text a file must contain for [`ClientDefaults::collect`] to read it.
+pub const CLIENT_DEFAULT_MARKERS: &[&str] =
+    &["interceptors", "defaults.headers", ".create(", ".extend("];
+
+const JSON: &str = "application/json";
+const FORM: &str = "application/x-www-form-urlencoded";
+const MULTIPART: &str = "multipart/form-data";
+const TEXT: &str = "text/plain";
+
+/// Headers proxies and platforms add on the way in: a handler reading one
+/// doesn't need the consumer to send it.
+const INFRASTRUCTURE_HEADERS: &[&str] = &[
+    "x-forwarded-",
+    "x-real-ip",
+    "x-request-id",
+    "x-correlation-id",
+    "x-amzn-",
+    "x-vercel-",
+    "x-envoy-",
+    "x-b3-",
+];
+
+/// The per-method groups axios nests default headers under
+/// (`axios.defaults.headers.common`).
+const AXIOS_HEADER_GROUPS: &[&str] = &[
+    "common", "get", "post", "put", "patch", "delete", "head", "options",
+];
+
+/// The headers one consumer call sends.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SentHeaders {
+    /// Lowercased names, sorted. `cookie` stands in for the credentials a
+    /// client attaches itself: a same-origin relative URL,
+    /// `credentials: 'include'`, `withCredentials: true`.
+    pub names: Vec<String>,
+    /// Media type of the body, parameters dropped. `None` when the call
+    /// sends no body or its type can't be told.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+impl SentHeaders {
+    /// Whether the call sends `required`. Cookies and API-key or token
+    /// headers stand in for `authorization`.
+    fn satisfies(&self, required: &str) -> bool {
+        self.names.iter().any(|name| {
+            name == required
+                || (required == "authorization"
+                    && (name == "cookie"
+                        || ["auth", "token", "api-key", "apikey"]
+                            .iter()
+                            .any(|k| name.contains(k))))
+        })
+    }
+}
+
+/// What a call leaves out of an endpoint's header contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderGap {
+    /// Required headers the call doesn't send, sorted.
+    pub missing: Vec<String>,
+    /// The content type the call sends, when the endpoint parses none of
+    /// `accepted`.
+    pub content_type: Option<String>,
+    pub accepted: Vec<String>,
+}
+
+/// Compare what a call sends against the headers an endpoint reads and the
+/// middleware in front of it. `None` when they agree.
+pub fn header_gap(
+    request_headers: &[String],
+    middleware_chain: &[String],
+    sent: &SentHeaders,
+) -> Option<HeaderGap> {
+    let missing: Vec<String> = required_headers(request_headers, middleware_chain)
+        .into_iter()
+        .filter(|name| !sent.satisfies(name))
+        .collect();
+    let (content_type, accepted) =
+        match (&sent.content_type, parsed_content_types(middleware_chain)) {
+            (Some(sent_type), Some(accepted)) if !accepted.contains(&sent_type.as_str()) => (
+                Some(sent_type.clone()),
+                accepted.into_iter().map(str::to_string).collect(),
+            ),
+            _ => (None, Vec::new()),
+        };
+    if missing.is_empty() && content_type.is_none() {
+        None
+    } else {
+        Some(HeaderGap {
+            missing,
+            content_type,
+            accepted,
+        })
+    }
+}
+
+/// `authorization` behind an auth middleware, plus the `authorization` and
+/// custom `x-*` headers the handler reads.
+fn required_headers(request_headers: &[String], middleware_chain: &[String]) -> BTreeSet<String> {
+    let mut required: BTreeSet<String> = request_headers
+        .iter()
+        .filter(|name| {
+            name.as_str() == "authorization"
+                || (name.starts_with("x-")
+                    && !INFRASTRUCTURE_HEADERS
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix)))
+        })
+        .cloned()
+        .collect();
+    if middleware_chain.iter().any(|name| is_auth_middleware(name)) {
+        required.insert("authorization".to_string());
+    }
+    required
+}
+
+/// Whether a middleware or guard name reads as token authentication:
+/// `requireAuth`, `authenticate`, `auth.required`, `verifyJwt`,
+/// `JwtAuthGuard`, `bearerToken`. Optional auth, OAuth flows, CSRF tokens
+/// and `passport.*` (whose strategy isn't part of the name) don't count.
+pub fn is_auth_middleware(name: &str) -> bool {
+    let name = name.to_lowercase();
+    if name.starts_with("passport")
+        || ["optional", "oauth", "csrf", "xsrf"]
+            .iter()
+            .any(|s| name.contains(s))
+    {
+        return false;
+    }
+    ["auth", "jwt", "bearer", "token"]
+        .iter()
+        .any(|s| name.contains(s))
+}
+
+/// The content types the body parsers in a middleware chain read, sorted:
+/// `express.json` / `bodyParser.json`, `urlencoded`, `text`, koa-bodyparser
+/// and multer. `None` when the chain has no parser — Fastify, Hono and
+/// Elysia parse on their own — or one whose types aren't known, such as a
+/// raw parser or `koaBody`.
+pub fn parsed_content_types(middleware_chain: &[String]) -> Option<Vec<&'static str>> {
+    let mut accepted = BTreeSet::new();
+    for name in middleware_chain {
+        let lower = name.to_lowercase();
+        let first = name.split('.').next().unwrap_or_default();
+        let last = name.rsplit('.').next().unwrap_or_default();
+        let parser_module = matches!(first, "express" | "bodyParser" | "bodyparser");
+        let types: &[&'static str] = match name.as_str() {
+            "json" => &[JSON],
+            "urlencoded" => &[FORM],
+            // koa-bodyparser's default `enableTypes`.
+            "bodyParser" | "bodyparser" => &[JSON, FORM],
+            _ if parser_module && last == "json" => &[JSON],
+            _ if parser_module && last == "urlencoded" => &[FORM],
+            _ if parser_module && last == "text" => &[TEXT],
+            // upload.single('avatar'), multer().array('photos')
+            _ if matches!(last, "single" | "array" | "fields" | "any" | "none")
+                && (lower.contains("upload") || lower.contains("multer")) =>
+            {
+                &[MULTIPART]
+            }
+            _ if [
+                "body",
+                "parse",
+                "upload",
+                "multer",
+                "formidable",
+                "busboy",
+                "raw",
+            ]
+            .iter()
+            .any(|s| lower.contains(s)) =>
+            {
+                return None;
+            }
+            _ => &[],
+        };
+        accepted.extend(types);
+    }
+    (!accepted.is_empty()).then(|| accepted.into_iter().collect())
+}
+
+/// Headers the HTTP client packages add to every request, collected across
+/// a repo: `axios.defaults.headers`, instance configs
+/// (`axios.create({ headers })`, ky/got `hooks`, ofetch `onRequest`) and
+/// request interceptors. Applied to every non-`fetch` call — which instance
+/// a call goes through isn't tracked, so the union errs toward "sent".
+#[derive(Debug, Clone, Default)]
+pub struct ClientDefaults {
+    headers: BTreeSet<String>,
+    /// A default or interceptor sets headers that can't be read.
+    opaque: bool,
+    /// Packages the repo's instances are created from.
+    instance_packages: BTreeSet<&'static str>,
+}
+
+impl ClientDefaults {
+    /// Scan `files` for client defaults. Files that mention none of
+    /// `interceptors`, `defaults.headers`, `.create(` or `.extend(` are not
+    /// parsed.
+    pub fn collect(files: &[PathBuf]) -> Self {
+        let mut defaults = ClientDefaults::default();
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(file) else {
+                continue;
+            };
+            if !CLIENT_DEFAULT_MARKERS.iter().any(|s| content.contains(s)) {
+                continue;
+            }
+            let Some((_, module)) = parse_module(file) else {
+                continue;
+            };
+            defaults.scan(&module);
+        }
+        defaults
+    }
+
+    /// Fold one module's defaults in.
+    pub fn scan(&mut self, module: &Module) {
+        let mut visitor = DefaultsVisitor {
+            clients: client_bindings(module),
+            out: self,
+        };
+        module.visit_with(&mut visitor);
+    }
+
+    /// The package calls on an instance go through, when every instance in
+    /// the repo is created from the same one.
+    fn instance_package(&self) -> Option<&'static str> {
+        let mut packages = self.instance_packages.iter();
+        match (packages.next(), packages.next()) {
+            (Some(package), None) => Some(*package),
+            _ => None,
+        }
+    }
+}
+
+struct DefaultsVisitor<'a> {
+    clients: HashMap<String, &'static str>,
+    out: &'a mut ClientDefaults,
+}
+
+impl DefaultsVisitor<'_> {
+    fn record<N: VisitWith<HeaderWrites>>(&mut self, node: &N) {
+        let mut writes = HeaderWrites::default();
+        node.visit_with(&mut writes);
+        self.out.headers.extend(writes.names);
+        self.out.opaque |= writes.opaque;
+    }
+}
+
+impl Visit for DefaultsVisitor<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some((object, method)) = member_call(call) {
+            match method {
+                // axios.create({ headers }), ky.extend({ hooks })
+                "create" | "extend" => {
+                    if let Expr::Ident(client) = unwrap_transparent(object)
+                        && let Some(&package) = self.clients.get(client.sym.as_ref())
+                    {
+                        self.out.instance_packages.insert(package);
+                        self.record(&call.args);
+                    }
+                }
+                // api.interceptors.request.use(config => { … })
+                "use" if is_request_interceptors(object) => {
+                    match call.args.first().map(|arg| unwrap_transparent(&arg.expr)) {
+                        Some(handler @ (Expr::Arrow(_) | Expr::Fn(_))) => self.record(handler),
+                        _ => self.out.opaque = true,
+                    }
+                }
+                // axios.defaults.headers.common.set('X-Tenant', …)
+                _ if names_defaults(object) => self.record(call),
+                _ => {}
+            }
+        }
+        // Object.assign(axios.defaults.headers.common, { … })
+        if is_object_assign(call)
+            && call
+                .args
+                .first()
+                .is_some_and(|target| names_defaults(&target.expr))
+        {
+            self.record(call);
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        // axios.defaults.headers.common['Authorization'] = token
+        if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left
+            && member_names_defaults(member)
+        {
+            self.record(assign);
+        }
+        assign.visit_children_with(self);
+    }
+}
+
+/// Collects the header names written inside a config object, interceptor or
+/// hook.
+#[derive(Default)]
+struct HeaderWrites {
+    names: BTreeSet<String>,
+    opaque: bool,
+}
+
+impl HeaderWrites {
+    fn entries(&mut self, expr: &Expr) {
+        match header_entries(expr, true) {
+            Some(entries) => self.names.extend(entries.into_iter().map(|(name, _)| name)),
+            None => self.opaque = true,
+        }
+    }
+}
+
+impl Visit for HeaderWrites {
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left {
+            if prop_is(&member.prop, "headers") {
+                // config.headers = { ...config.headers, Authorization: token }
+                self.entries(&assign.right);
+            } else if in_headers(&member.obj) {
+                // config.headers.Authorization = …
+                match member_key(&member.prop) {
+                    Some(key) if AXIOS_HEADER_GROUPS.contains(&key.as_str()) => {
+                        self.entries(&assign.right)
+                    }
+                    Some(key) => {
+                        self.names.insert(key.to_lowercase());
+                    }
+                    None => self.opaque = true,
+                }
+            }
+        }
+        assign.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some((object, method)) = member_call(call)
+            && in_headers(object)
+        {
+            match method {
+                // request.headers.set('Authorization', …)
+                "set" | "append" => match call.args.first().and_then(|arg| string_lit(&arg.expr)) {
+                    Some(name) => {
+                        self.names.insert(name.to_lowercase());
+                    }
+                    None => self.opaque = true,
+                },
+                // AxiosHeaders: config.headers.setAuthorization(…)
+                _ if method.len() > 3 && method.starts_with("set") => {
+                    self.names.insert(kebab_case(&method[3..]));
+                }
+                _ => {}
+            }
+        }
+        // Object.assign(config.headers, { … })
+        if is_object_assign(call)
+            && call
+                .args
+                .first()
+                .is_some_and(|target| in_headers(&target.expr))
+        {
+            for source in &call.args[1..] {
+                if source.spread.is_some() {
+                    self.opaque = true;
+                } else {
+                    self.entries(&source.expr);
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_key_value_prop(&mut self, prop: &KeyValueProp) {
+        // { headers: { Authorization: … } }
+        if prop_name(&prop.key).as_deref() == Some("headers") {
+            self.entries(&prop.value);
+        }
+        prop.visit_children_with(self);
+    }
+}
+
+/// The headers one consumer call sends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallHeaders {
+    /// 1-based line of the call.
+    pub line_number: usize,
+    /// Start byte offset of the call expression.
+    pub span_start: u32,
+    /// `None` when they can't all be read.
+    pub sent: Option<SentHeaders>,
+    /// Whether its options may add query keys: a `params`, `searchParams`
+    /// or `query` option, or options that can't be read.
+    pub query_options: bool,
+}
+
+/// Every direct client call in `module` — `fetch`, axios, ky, got, ofetch,
+/// or a request on an instance in `instances` — with the headers it sends.
+/// Calls through an instance are only read when every instance in the repo
+/// comes from the same package, since their argument shapes differ.
+pub fn call_headers(
+    module: &Module,
+    cm: &SourceMap,
+    instances: &HttpInstanceMap,
+    defaults: &ClientDefaults,
+) -> Vec<CallHeaders> {
+    let mut visitor = CallHeaderVisitor {
+        cm,
+        clients: client_bindings(module),
+        instances,
+        defaults,
+        calls: Vec::new(),
+    };
+    module.visit_with(&mut visitor);
+    visitor.calls
+}
+
+struct CallHeaderVisitor<'a> {
+    cm: &'a SourceMap,
+    clients: HashMap<String, &'static str>,
+    instances: &'a HttpInstanceMap,
+    defaults: &'a ClientDefaults,
+    calls: Vec<CallHeaders>,
+}
+
+/// A recognized request: the client package (`fetch` for the global), and
+/// the arguments holding its URL, options and body.
+struct Request<'c> {
+    package: &'static str,
+    on_instance: bool,
+    url: Option<&'c Expr>,
+    options: Option<&'c Expr>,
+    data: Option<&'c Expr>,
+}
+
+impl CallHeaderVisitor<'_> {
+    /// The package `expr` is, or an instance of, and whether it's an
+    /// instance.
+    fn client(&self, expr: &Expr) -> Option<(&'static str, bool)> {
+        if let Expr::Ident(ident) = expr
+            && let Some(&package) = self.clients.get(ident.sym.as_ref())
+        {
+            return Some((package, false));
+        }
+        if reference_text(expr).is_some_and(|name| self.instances.contains_key(&name)) {
+            return Some((self.defaults.instance_package()?, true));
+        }
+        None
+    }
+
+    fn request<'c>(&self, call: &'c CallExpr) -> Option<Request<'c>> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        if call.args.iter().any(|arg| arg.spread.is_some()) {
+            return None;
+        }
+        let arg = |index: usize| call.args.get(index).map(|arg| &*arg.expr);
+        let callee = unwrap_transparent(callee);
+
+        // fetch(url, init), globalThis.fetch(url, init)
+        let is_fetch = match callee {
+            Expr::Ident(ident) => ident.sym == *"fetch",
+            Expr::Member(member) => {
+                prop_is(&member.prop, "fetch")
+                    && matches!(unwrap_transparent(&member.obj), Expr::Ident(obj)
+                        if matches!(obj.sym.as_ref(), "globalThis" | "window" | "self"))
+            }
+            _ => false,
+        };
+        if is_fetch {
+            return Some(Request {
+                package: "fetch",
+                on_instance: false,
+                url: arg(0),
+                options: arg(1),
+                data: None,
+            });
+        }
+
+        // axios(config), ky(url, options), api(url) — or axios.post(url, data)
+        let (package, on_instance, method) = match self.client(callee) {
+            Some((package, on_instance)) => (package, on_instance, None),
+            None => {
+                let Expr::Member(member) = callee else {
+                    return None;
+                };
+                let MemberProp::Ident(method) = &member.prop else {
+                    return None;
+                };
+                if !REQUEST_METHODS.contains(&method.sym.as_ref()) {
+                    return None;
+                }
+                let (package, on_instance) = self.client(unwrap_transparent(&member.obj))?;
+                (package, on_instance, Some(method.sym.as_ref()))
+            }
+        };
+        let (url, options, data) = match (package, method) {
+            ("axios", None) if matches!(arg(0).map(unwrap_transparent), Some(Expr::Object(_))) => {
+                (None, arg(0), None)
+            }
+            ("axios", Some("request")) => (None, arg(0), None),
+            ("axios", Some("post" | "put" | "patch")) => (arg(0), arg(2), arg(1)),
+            _ => (arg(0), arg(1), None),
+        };
+        Some(Request {
+            package,
+            on_instance,
+            url,
+            options,
+            data,
+        })
+    }
+
+    fn sent(&self, request: &Request<'_>) -> Option<SentHeaders> {
+        let mut names = BTreeSet::new();
+        // `Some(None)` for a content type set to something unreadable.
+        let mut explicit_type: Option<Option<String>> = None;
+        let mut body = request
+            .data
+            .map(|data| body_content_type(request.package, data));
+        // A relative URL is same-origin, where cookies go along by default.
+        let mut cookies = !request.on_instance && request.url.is_some_and(is_relative_url);
+        if request.package != "fetch" {
+            if self.defaults.opaque {
+                return None;
+            }
+            names.extend(self.defaults.headers.iter().cloned());
+            if self.defaults.headers.contains("content-type") {
+                explicit_type = Some(None);
+            }
+        }
+        if let Some(options) = request.options {
+            let Expr::Object(options) = unwrap_transparent(options) else {
+                return None;
+            };
+            for prop in &options.props {
+                let PropOrSpread::Prop(prop) = prop else {
+                    return None;
+                };
+                let (key, value) = match &**prop {
+                    Prop::KeyValue(kv) => (prop_name(&kv.key), Some(&*kv.value)),
+                    Prop::Shorthand(ident) => (Some(ident.sym.to_string()), None),
+                    _ => continue,
+                };
+                match (key.as_deref(), value) {
+                    (Some("headers"), Some(value)) => {
+                        for (name, value) in header_entries(value, false)? {
+                            if name == "content-type" {
+                                explicit_type = Some(value.map(|v| media_type(&v)));
+                            }
+                            names.insert(name);
+                        }
+                    }
+                    (Some("headers"), None) => return None,
+                    (Some("credentials"), Some(value)) => match string_lit(value).as_deref() {
+                        Some("include") => cookies = true,
+                        Some("omit") => cookies = false,
+                        _ => {}
+                    },
+                    (Some("withCredentials"), Some(value)) => {
+                        if matches!(unwrap_transparent(value), Expr::Lit(Lit::Bool(b)) if b.value) {
+                            cookies = true;
+                        }
+                    }
+                    // axios `auth`, got `username` / `password`: basic auth.
+                    (Some("auth" | "username" | "password"), _) => {
+                        names.insert("authorization".to_string());
+                    }
+                    (Some("data" | "body"), value) => {
+                        body = Some(value.and_then(|v| body_content_type(request.package, v)));
+                    }
+                    (Some("json"), _) => body = Some(Some(JSON)),
+                    (Some("form"), _) if request.package == "got" => body = Some(Some(FORM)),
+                    _ => {}
+                }
+            }
+        }
+        if cookies {
+            names.insert("cookie".to_string());
+        }
+        let content_type = body.and_then(|inferred| match explicit_type {
+            Some(explicit) => explicit,
+            None => inferred.map(str::to_string),
+        });
+        Some(SentHeaders {
+            names: names.into_iter().collect(),
+            content_type,
+        })
+    }
+}
+
+impl Visit for CallHeaderVisitor<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some(request) = self.request(call) {
+            self.calls.push(CallHeaders {
+                line_number: self.cm.lookup_char_pos(call.span.lo).line,
+                span_start: call.span.lo.0,
+                sent: self.sent(&request),
+                query_options: query_options(&request),
+            });
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// Whether `request`'s options may add query keys to its URL.
+fn query_options(request: &Request<'_>) -> bool {
+    let Some(options) = request.options else {
+        return false;
+    };
+    let Expr::Object(options) = unwrap_transparent(options) else {
+        return true;
+    };
+    options.props.iter().any(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return true;
+        };
+        let key = match &**prop {
+            Prop::KeyValue(kv) => prop_name(&kv.key),
+            Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+            _ => return false,
+        };
+        key.is_none_or(|key| matches!(key.as_str(), "params" | "searchParams" | "query"))
+    })
+}
+
+/// The content type a client sends a body with when no header says
+/// otherwise: fetch-based clients send strings as `text/plain`, axios and
+/// ofetch serialize objects as JSON, and `FormData` / `URLSearchParams` carry
+/// their own.
+fn body_content_type(package: &str, body: &Expr) -> Option<&'static str> {
+    let fetch_based = matches!(package, "fetch" | "ky" | "ky-universal" | "ofetch");
+    match unwrap_transparent(body) {
+        Expr::New(new) => match unwrap_transparent(&new.callee) {
+            Expr::Ident(class) if class.sym == *"FormData" => Some(MULTIPART),
+            Expr::Ident(class) if class.sym == *"URLSearchParams" => Some(FORM),
+            _ => None,
+        },
+        Expr::Object(_) | Expr::Array(_) if matches!(package, "axios" | "ofetch") => Some(JSON),
+        Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) if fetch_based => Some(TEXT),
+        Expr::Call(call) if fetch_based && is_json_stringify(call) => Some(TEXT),
+        _ => None,
+    }
+}
+
+/// The headers an object literal, `new Headers(...)` or array of pairs
+/// names, lowercased, each with its value when that is a literal string.
+/// With `inherited`, a reference to an existing headers object
+/// (`...config.headers`) adds nothing rather than hiding the set. `None`
+/// when any name can't be read.
+fn header_entries(expr: &Expr, inherited: bool) -> Option<Vec<(String, Option<String>)>> {
+    if inherited && in_headers(expr) {
+        return Some(Vec::new());
+    }
+    match unwrap_transparent(expr) {
+        Expr::Object(object) => {
+            let mut entries = Vec::new();
+            for prop in &object.props {
+                match prop {
+                    PropOrSpread::Spread(spread) => {
+                        entries.extend(header_entries(&spread.expr, inherited)?)
+                    }
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::KeyValue(kv) => {
+                            let name = prop_name(&kv.key)?;
+                            if AXIOS_HEADER_GROUPS.contains(&name.as_str())
+                                && matches!(unwrap_transparent(&kv.value), Expr::Object(_))
+                            {
+                                entries.extend(header_entries(&kv.value, inherited)?);
+                            } else {
+                                entries.push((name.to_lowercase(), string_lit(&kv.value)));
+                            }
+                        }
+                        Prop::Shorthand(ident) => entries.push((ident.sym.to_lowercase(), None)),
+                        _ => return None,
+                    },
+                }
+            }
+            Some(entries)
+        }
+        Expr::New(new) if matches!(unwrap_transparent(&new.callee), Expr::Ident(c) if c.sym == *"Headers") => {
+            match new.args.as_deref() {
+                None | Some([]) => Some(Vec::new()),
+                Some([init]) if init.spread.is_none() => header_entries(&init.expr, inherited),
+                _ => None,
+            }
+        }
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|elem| {
+                let elem = elem.as_ref().filter(|e| e.spread.is_none())?;
+                let Expr::Array(pair) = unwrap_transparent(&elem.expr) else {
+                    return None;
+                };
+                let name = pair
+                    .elems
+                    .first()?
+                    .as_ref()
+                    .and_then(|e| string_lit(&e.expr))?;
+                let value = pair
+                    .elems
+                    .get(1)
+                    .and_then(|e| e.as_ref())
+                    .and_then(|e| string_lit(&e.expr));
+                Some((name.to_lowercase(), value))
+            })
+            .collect(),
+        // config.headers = config.headers || {}
+        Expr::Bin(bin) if matches!(bin.op, BinaryOp::LogicalOr | BinaryOp::NullishCoalescing) => {
+            let mut entries = header_entries(&bin.left, inherited)?;
+            entries.extend(header_entries(&bin.right, inherited)?);
+            Some(entries)
+        }
+        _ => None,
+    }
+}
+
+/// `x.headers`, or a member chain below one (`axios.defaults.headers.common`).
+fn in_headers(expr: &Expr) -> bool {
+    match unwrap_transparent(expr) {
+        Expr::Member(member) => prop_is(&member.prop, "headers") || in_headers(&member.obj),
+        _ => false,
+    }
+}
+
+/// A member chain through `defaults.headers`.
+fn names_defaults(expr: &Expr) -> bool {
+    matches!(unwrap_transparent(expr), Expr::Member(member) if member_names_defaults(member))
+}
+
+fn member_names_defaults(member: &MemberExpr) -> bool {
+    (prop_is(&member.prop, "headers")
+        && matches!(unwrap_transparent(&member.obj), Expr::Member(inner)
+            if prop_is(&inner.prop, "defaults")))
+        || names_defaults(&member.obj)
+}
+
+/// `<x>.interceptors.request`.
+fn is_request_interceptors(expr: &Expr) -> bool {
+    matches!(unwrap_transparent(expr), Expr::Member(member)
+        if prop_is(&member.prop, "request")
+            && matches!(unwrap_transparent(&member.obj), Expr::Member(inner)
+                if prop_is(&inner.prop, "interceptors")))
+}
+
+fn is_object_assign(call: &CallExpr) -> bool {
+    matches!(member_call(call), Some((Expr::Ident(object), "assign")) if object.sym == *"Object")
+}
+
+fn is_json_stringify(call: &CallExpr) -> bool {
+    matches!(member_call(call), Some((Expr::Ident(object), "stringify")) if object.sym == *"JSON")
+}
+
+/// A literal string or a template without interpolations starting with a
+/// single `/`.
+fn is_relative_url(expr: &Expr) -> bool {
+    let text = match unwrap_transparent(expr) {
+        Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+        Expr::Tpl(tpl) => tpl
+            .quasis
+            .first()
+            .map(|quasi| quasi.raw.to_string())
+            .unwrap_or_default(),
+        _ => return false,
+    };
+    text.starts_with('/') && !text.starts_with("//")
+}
+
+/// `application/json; charset=utf-8` → `application/json`.
+fn media_type(value: &str) -> String {
+    value
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+/// `ContentType` → `content-type`.
+fn kebab_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('-');
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// The receiver and method name of `receiver.method(...)`.
+fn member_call(call: &CallExpr) -> Option<(&Expr, &str)> {
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let Expr::Member(member) = unwrap_transparent(callee) else {
+        return None;
+    };
+    let MemberProp::Ident(method) = &member.prop else {
+        return None;
+    };
+    Some((unwrap_transparent(&member.obj), method.sym.as_ref()))
+}
+
+fn prop_is(prop: &MemberProp, name: &str) -> bool {
+    matches!(prop, MemberProp::Ident(ident) if ident.sym == *name)
+}
+
+/// `x.name` / `x['name']` → `name`.
+fn member_key(prop: &MemberProp) -> Option<String> {
+    match prop {
+        MemberProp::Ident(ident) => Some(ident.sym.to_string()),
+        MemberProp::Computed(computed) => string_lit(&computed.expr),
+        MemberProp::PrivateName(_) => None,
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(s) => Some(s.value.to_string()),
+        PropName::Computed(computed) => string_lit(&computed.expr),
+        _ => None,
+    }
+}
+
+fn string_lit(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+            .quasis
+            .first()
+            .and_then(|quasi| quasi.cooked.as_ref())
+            .map(|cooked| cooked.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::sync::Lrc;
+
+    fn parse(source: &str) -> (Module, Lrc<SourceMap>) {
+        let tmp_dir = tempfile::tempdir().expect("tempdir");
+        let file_path = tmp_dir.path().join("input.ts");
+        std::fs::write(&file_path, source).expect("write file");
+
+        let (cm, module) = parse_module(&file_path).expect("parsed module");
+        (module, cm)
+    }
+
+    fn sent(names: &[&str], content_type: Option<&str>) -> Option<SentHeaders> {
+        Some(SentHeaders {
+            names: names.iter().map(|n| n.to_string()).collect(),
+            content_type: content_type.map(String::from),
+        })
+    }
+
+    fn headers_by_line(
+        source: &str,
+        defaults: &ClientDefaults,
+    ) -> Vec<(usize, Option<SentHeaders>)> {
+        let (module, cm) = parse(source);
+        let instances = HttpInstanceMap::from([("api".to_string(), "http://api".to_string())]);
+        call_headers(&module, &cm, &instances, defaults)
+            .into_iter()
+            .map(|c| (c.line_number, c.sent))
+            .collect()
+    }
+
+    #[test]
+    fn call_headers_read_literal_options_and_body_types() {
+        let calls = headers_by_line(
+            r#"import axios from "axios";
+await fetch(`${API}/orders`, { method: "POST", body: JSON.stringify(order) });
+await fetch("/api/orders", {
+  method: "POST",
+  headers: { "Content-Type": "application/json; charset=utf-8", "X-Tenant-Id": tenant },
+  body: JSON.stringify(order),
+});
+await axios.post(`${API}/orders`, { id }, { headers: { Authorization: `Bearer ${t}` } });
+await axios.get(`${API}/orders`, { withCredentials: true });
+await fetch(`${API}/orders`, { headers });
+await fetch(`${API}/orders`, { headers: { ...base, "X-Tenant-Id": t } });
+await fetch(`${API}/upload`, { method: "POST", body: new FormData() });
+"#,
+            &ClientDefaults::default(),
+        );
+        assert_eq!(
+            calls,
+            vec![
+                (2, sent(&[], Some("text/plain"))),
+                (
+                    3,
+                    sent(
+                        &["content-type", "cookie", "x-tenant-id"],
+                        Some("application/json")
+                    )
+                ),
+                (8, sent(&["authorization"], Some("application/json"))),
+                (9, sent(&["cookie"], None)),
+                (10, None),
+                (11, None),
+                (12, sent(&[], Some("multipart/form-data"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn client_defaults_and_interceptors_apply_to_client_calls() {
+        let mut defaults = ClientDefaults::default();
+        let (setup, _) = parse(
+            r#"import axios from "axios";
+export const api = axios.create({ baseURL: process.env.API_URL, headers: { "X-Tenant-Id": TENANT } });
+api.interceptors.request.use((config) => {
+  config.headers = config.headers || {};
+  config.headers.Authorization = `Bearer ${getToken()}`;
+  return config;
+});
+"#,
+        );
+        defaults.scan(&setup);
+
+        let calls = headers_by_line(
+            r#"import axios from "axios";
+await api.post("/orders", { id });
+await fetch(`${API}/orders`);
+"#,
+            &defaults,
+        );
+        assert_eq!(
+            calls,
+            vec![
+                (
+                    2,
+                    sent(&["authorization", "x-tenant-id"], Some("application/json"))
+                ),
+                // Plain fetch doesn't go through the axios setup.
+                (3, sent(&[], None)),
+            ]
+        );
+
+        // An interceptor setting headers it can't name hides them all.
+        let (opaque, _) = parse(
+            r#"import axios from "axios";
+axios.interceptors.request.use((config) => {
+  Object.assign(config.headers, authHeaders());
+  return config;
+});
+"#,
+        );
+        defaults.scan(&opaque);
+        let calls = headers_by_line("await api.get(\"/orders\");\n", &defaults);
+        assert_eq!(calls, vec![(1, None)]);
+    }
+
+    #[test]
+    fn header_gap_reports_missing_auth_custom_headers_and_unparsed_bodies() {
+        let chain = vec!["express.json".to_string(), "requireAuth".to_string()];
+        let reads = vec![
+            "x-tenant-id".to_string(),
+            "x-forwarded-for".to_string(),
+            "user-agent".to_string(),
+        ];
+        assert_eq!(
+            header_gap(&reads, &chain, &sent(&[], Some("text/plain")).unwrap()),
+            Some(HeaderGap {
+                missing: vec!["authorization".to_string(), "x-tenant-id".to_string()],
+                content_type: Some("text/plain".to_string()),
+                accepted: vec!["application/json".to_string()],
+            })
+        );
+        // A cookie or API key stands in for `Authorization`.
+        assert_eq!(
+            header_gap(
+                &reads,
+                &chain,
+                &sent(&["x-api-key", "x-tenant-id"], Some("application/json")).unwrap()
+            ),
+            None
+        );
+        // Without a known body parser the content type isn't checked.
+        assert_eq!(
+            header_gap(
+                &[],
+                &["koaBody".to_string()],
+                &sent(&[], Some("text/plain")).unwrap()
+            ),
+            None
+        );
+        assert!(is_auth_middleware("auth.required"));
+        assert!(is_auth_middleware("verifyJwt"));
+        assert!(!is_auth_middleware("optionalAuth"));
+        assert!(!is_auth_middleware("passport.authenticate"));
+        assert_eq!(
+            parsed_content_types(&["bodyParser".to_string(), "upload.single".to_string()]),
+            Some(vec![
+                "application/json",
+                "application/x-www-form-urlencoded",
+                "multipart/form-data"
+            ])
+        );
+    }
+
+    #[test]
+    fn call_headers_flag_options_that_may_add_query_keys() {
+        let (module, cm) = parse(
+            r#"import axios from "axios";
+await fetch("/api/orders");
+await fetch("/api/orders", { method: "POST" });
+await axios.get("/api/orders", { params: { page } });
+await fetch(url, { ...init });
+await axios.get("/api/orders", options);
+"#,
+        );
+        let flags: Vec<(usize, bool)> = call_headers(
+            &module,
+            &cm,
+            &HttpInstanceMap::new(),
+            &ClientDefaults::default(),
+        )
+        .into_iter()
+        .map(|c| (c.line_number, c.query_options))
+        .collect();
+        assert_eq!(
+            flags,
+            vec![(2, false), (3, false), (4, true), (5, true), (6, true)]
+        );
+    }
+}
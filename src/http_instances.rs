@@ -0,0 +1,595 @@
+//! Base URLs of configured HTTP client instances.
+//!
+//! The most common outbound-call shape creates a client instance once and
+//! calls it with relative paths:
+//!
+//! ```ts
+//! // clients.ts
+//! export const orders = axios.create({ baseURL: process.env.ORDERS_URL });
+//! // checkout.ts
+//! import { orders } from "./clients";
+//! await orders.get(`/orders/${id}`);
+//! ```
+//!
+//! The file analyzer reports the call target as `/orders/${id}`, so the base
+//! URL — and with it the env var that classifies the call and keys the
+//! cross-repo match — is lost unless the model happens to chase the instance
+//! down. This module records instances built by `axios.create`, `ky.create` /
+//! `ky.extend`, `ofetch.create` / `$fetch.create`, and `got.extend` together
+//! with their `baseURL` / `prefixUrl` ([`HttpInstanceExtractor`]), projects
+//! them onto a module's exports ([`exported_http_instances`]) and folds them
+//! into importers the same way env aliases are
+//! ([`merge_imported_http_instances`]). [`instance_calls`] then finds the
+//! calls made on an instance, and [`join_instance_target`] prefixes their
+//! relative targets with the base before URL normalization sees them.
+//!
+//! A base is kept as the target text it would interpolate: a literal
+//! (`http://orders:3000`), `${process.env.ORDERS_URL}`, or a reference the
+//! env-alias pass resolves later (`${ORDERS_BASE}`, `${config.ordersUrl}`).
+//! A base the defining module aliases from `process.env` is resolved there,
+//! so the env var survives the trip to an importer. Instances built from a
+//! config object held in a variable, or reassigned after creation, are not
+//! followed.
+
+use crate::env_alias::{
+    EnvAliasExtractor, EnvAliasMap, merge_imported_env_aliases, resolve_target_env_alias,
+    unwrap_transparent,
+};
+use crate::visitor::ImportedSymbol;
+use std::collections::HashMap;
+use swc_common::SourceMap;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Maps an instance's binding (e.g. `orders`, or `api.orders` through a
+/// namespace import) to its base URL as target text.
+pub type HttpInstanceMap = HashMap<String, String>;
+
+/// Client packages whose default/named exports build configured instances.
+const CLIENT_PACKAGES: &[&str] = &["axios", "ky", "ky-universal", "ofetch", "got"];
+
+/// The conventional local names of those exports and their packages,
+/// accepted even without an import (CommonJS globals, framework auto-imports
+/// such as Nuxt's `$fetch`).
+const CLIENT_NAMES: &[(&str, &str)] = &[
+    ("axios", "axios"),
+    ("ky", "ky"),
+    ("ofetch", "ofetch"),
+    ("$fetch", "ofetch"),
+    ("got", "got"),
+];
+
+/// Factory methods returning a new instance.
+const FACTORY_METHODS: &[&str] = &["create", "extend"];
+
+/// Text a module must contain to define an instance (a [`FACTORY_METHODS`]
+/// call).
+pub const INSTANCE_MARKERS: &[&str] = &[".create(", ".extend("];
+
+/// Config keys holding the base URL.
+const BASE_KEYS: &[&str] = &["baseURL", "baseUrl", "prefixUrl"];
+
+/// Instance methods that send a request. A callable instance
+/// (`api("orders")`) counts as well.
+pub(crate) const REQUEST_METHODS: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "head", "options", "request",
+];
+
+/// Visitor that collects `const x = <client>.create({ baseURL })` bindings —
+/// including instances derived from another (`base.extend({ ... })`, which
+/// keeps the parent's base unless it sets its own) — into an
+/// [`HttpInstanceMap`].
+#[derive(Default)]
+pub struct HttpInstanceExtractor {
+    clients: HashMap<String, &'static str>,
+    pub instances: HttpInstanceMap,
+}
+
+impl HttpInstanceExtractor {
+    /// Build the instance map for a parsed module.
+    pub fn build(module: &Module) -> HttpInstanceMap {
+        let mut extractor = HttpInstanceExtractor {
+            clients: client_bindings(module),
+            ..Default::default()
+        };
+        module.visit_with(&mut extractor);
+
+        // Resolve bases aliased from `process.env` in this module, so the
+        // env var is still known once the instance is imported elsewhere.
+        let aliases = EnvAliasExtractor::build(module);
+        let mut instances = extractor.instances;
+        for base in instances.values_mut() {
+            if let Some(resolved) = resolve_target_env_alias(base, &aliases) {
+                *base = resolved;
+            }
+        }
+        instances
+    }
+
+    /// The base URL of the instance `expr` evaluates to, if any.
+    fn instance_base(&self, expr: &Expr) -> Option<String> {
+        match unwrap_transparent(expr) {
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                let Expr::Member(member) = unwrap_transparent(callee) else {
+                    return None;
+                };
+                let MemberProp::Ident(method) = &member.prop else {
+                    return None;
+                };
+                if !FACTORY_METHODS.contains(&method.sym.as_ref()) {
+                    return None;
+                }
+                let own = call
+                    .args
+                    .first()
+                    .filter(|arg| arg.spread.is_none())
+                    .and_then(|arg| config_base(&arg.expr));
+                match unwrap_transparent(&member.obj) {
+                    Expr::Ident(ident) if self.clients.contains_key(ident.sym.as_ref()) => own,
+                    parent => own.or_else(|| self.instance_base(parent)),
+                }
+            }
+            Expr::Ident(ident) => self.instances.get(ident.sym.as_ref()).cloned(),
+            _ => None,
+        }
+    }
+}
+
+impl Visit for HttpInstanceExtractor {
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        for decl in &var_decl.decls {
+            let Pat::Ident(binding) = &decl.name else {
+                continue;
+            };
+            let Some(init) = &decl.init else {
+                continue;
+            };
+            // Keyed on the bare symbol, like env aliases: shadowing an
+            // instance name in a nested scope is rare enough to ignore.
+            if let Some(base) = self.instance_base(init) {
+                self.instances.insert(binding.id.sym.to_string(), base);
+            }
+        }
+
+        var_decl.visit_children_with(self);
+    }
+}
+
+/// Local names bound to a client package, with the package: every import
+/// specifier from one, `const axios = require("axios")`, and the conventional
+/// names.
+pub(crate) fn client_bindings(module: &Module) -> HashMap<String, &'static str> {
+    let mut clients: HashMap<String, &'static str> = CLIENT_NAMES
+        .iter()
+        .map(|(name, package)| (name.to_string(), *package))
+        .collect();
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                let Some(package) = CLIENT_PACKAGES
+                    .iter()
+                    .find(|p| **p == import.src.value.as_ref())
+                else {
+                    continue;
+                };
+                for spec in &import.specifiers {
+                    let local = match spec {
+                        ImportSpecifier::Named(s) => &s.local,
+                        ImportSpecifier::Default(s) => &s.local,
+                        ImportSpecifier::Namespace(s) => &s.local,
+                    };
+                    clients.insert(local.sym.to_string(), package);
+                }
+            }
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                for decl in &var_decl.decls {
+                    let (Pat::Ident(binding), Some(init)) = (&decl.name, &decl.init) else {
+                        continue;
+                    };
+                    if let Some(package) = require_source(init)
+                        .and_then(|src| CLIENT_PACKAGES.iter().find(|p| **p == src))
+                    {
+                        clients.insert(binding.id.sym.to_string(), package);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    clients
+}
+
+/// `require("x")` (optionally `.default`) → `x`.
+fn require_source(expr: &Expr) -> Option<&str> {
+    match unwrap_transparent(expr) {
+        Expr::Call(call) => {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            if !matches!(&**callee, Expr::Ident(ident) if ident.sym.as_ref() == "require") {
+                return None;
+            }
+            match call.args.first().map(|arg| unwrap_transparent(&arg.expr)) {
+                Some(Expr::Lit(Lit::Str(s))) => Some(s.value.as_ref()),
+                _ => None,
+            }
+        }
+        Expr::Member(member) => require_source(&member.obj),
+        _ => None,
+    }
+}
+
+/// The base URL set by an instance config object literal.
+fn config_base(expr: &Expr) -> Option<String> {
+    let Expr::Object(obj) = unwrap_transparent(expr) else {
+        return None;
+    };
+    obj.props.iter().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        match &**prop {
+            Prop::KeyValue(kv) => {
+                let key = match &kv.key {
+                    PropName::Ident(ident) => ident.sym.as_ref(),
+                    PropName::Str(s) => s.value.as_ref(),
+                    _ => return None,
+                };
+                if !BASE_KEYS.contains(&key) {
+                    return None;
+                }
+                base_text(&kv.value)
+            }
+            // `{ baseURL }` shorthand for a binding holding the base.
+            Prop::Shorthand(ident) if BASE_KEYS.contains(&ident.sym.as_ref()) => {
+                Some(format!("${{{}}}", ident.sym))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Render a base URL expression as call-target text. `??`/`||` defaults are
+/// dropped, as the env-var name is what classification needs.
+fn base_text(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) => {
+            let mut text = String::new();
+            for (i, quasi) in tpl.quasis.iter().enumerate() {
+                text.push_str(&quasi.raw);
+                if let Some(expr) = tpl.exprs.get(i) {
+                    text.push_str(&format!("${{{}}}", reference_text(expr)?));
+                }
+            }
+            Some(text)
+        }
+        Expr::Bin(bin) if matches!(bin.op, BinaryOp::NullishCoalescing | BinaryOp::LogicalOr) => {
+            base_text(&bin.left)
+        }
+        other => reference_text(other).map(|name| format!("${{{}}}", name)),
+    }
+}
+
+/// Dotted text of an identifier or member chain (`process.env.ORDERS_URL`,
+/// `config.ordersUrl`, `process.env["ORDERS_URL"]`).
+pub(crate) fn reference_text(expr: &Expr) -> Option<String> {
+    match unwrap_transparent(expr) {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Member(member) => {
+            let prop = match &member.prop {
+                MemberProp::Ident(ident) => ident.sym.to_string(),
+                MemberProp::Computed(computed) => match &*computed.expr {
+                    Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+                    _ => return None,
+                },
+                MemberProp::PrivateName(_) => return None,
+            };
+            Some(format!("{}.{}", reference_text(&member.obj)?, prop))
+        }
+        Expr::Bin(bin) if matches!(bin.op, BinaryOp::NullishCoalescing | BinaryOp::LogicalOr) => {
+            reference_text(&bin.left)
+        }
+        _ => None,
+    }
+}
+
+/// The instances a module makes visible to its importers, keyed by export
+/// name (`default` for the default export). `export { a as b }` renames are
+/// followed; re-exports from another module are not.
+pub fn exported_http_instances(module: &Module) -> HttpInstanceMap {
+    let locals = HttpInstanceExtractor::build(module);
+    let mut out = HttpInstanceMap::new();
+
+    for item in &module.body {
+        let ModuleItem::ModuleDecl(decl) = item else {
+            continue;
+        };
+        match decl {
+            ModuleDecl::ExportDecl(export_decl) => {
+                if let Decl::Var(var_decl) = &export_decl.decl {
+                    for d in &var_decl.decls {
+                        if let Pat::Ident(binding) = &d.name
+                            && let Some(base) = locals.get(binding.id.sym.as_ref())
+                        {
+                            out.insert(binding.id.sym.to_string(), base.clone());
+                        }
+                    }
+                }
+            }
+            ModuleDecl::ExportNamed(named) if named.src.is_none() => {
+                for spec in &named.specifiers {
+                    let ExportSpecifier::Named(named_spec) = spec else {
+                        continue;
+                    };
+                    let ModuleExportName::Ident(orig) = &named_spec.orig else {
+                        continue;
+                    };
+                    let Some(base) = locals.get(orig.sym.as_ref()) else {
+                        continue;
+                    };
+                    let exported = match &named_spec.exported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(s)) => s.value.to_string(),
+                        None => orig.sym.to_string(),
+                    };
+                    out.insert(exported, base.clone());
+                }
+            }
+            // `export default api` / `export default axios.create({ ... })`
+            ModuleDecl::ExportDefaultExpr(default_expr) => {
+                let extractor = HttpInstanceExtractor {
+                    clients: client_bindings(module),
+                    instances: locals.clone(),
+                };
+                if let Some(base) = extractor.instance_base(&default_expr.expr) {
+                    let aliases = EnvAliasExtractor::build(module);
+                    let base = resolve_target_env_alias(&base, &aliases).unwrap_or(base);
+                    out.insert("default".to_string(), base);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+/// Fold imported modules' exported instances into an importing file's map
+/// under its local import names. Named, default, and namespace imports fold
+/// exactly like env aliases (see [`merge_imported_env_aliases`]); local
+/// instances always win.
+pub fn merge_imported_http_instances<F>(
+    instances: &mut HttpInstanceMap,
+    imported_symbols: &HashMap<String, ImportedSymbol>,
+    resolve_module: F,
+) where
+    F: FnMut(&str) -> Option<EnvAliasMap>,
+{
+    merge_imported_env_aliases(instances, imported_symbols, resolve_module);
+}
+
+/// A request made on a known instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceCall {
+    /// 1-based line of the call.
+    pub line_number: usize,
+    /// Start byte offset of the call expression.
+    pub span_start: u32,
+    /// The instance's base URL.
+    pub base: String,
+}
+
+/// Every call in `module` that sends a request through an instance in
+/// `instances`: `orders.get(...)`, `api.orders.post(...)`, `api("items")`.
+pub fn instance_calls(
+    module: &Module,
+    cm: &SourceMap,
+    instances: &HttpInstanceMap,
+) -> Vec<InstanceCall> {
+    let mut visitor = InstanceCallVisitor {
+        cm,
+        instances,
+        calls: Vec::new(),
+    };
+    module.visit_with(&mut visitor);
+    visitor.calls
+}
+
+struct InstanceCallVisitor<'a> {
+    cm: &'a SourceMap,
+    instances: &'a HttpInstanceMap,
+    calls: Vec<InstanceCall>,
+}
+
+impl InstanceCallVisitor<'_> {
+    fn receiver_base(&self, callee: &Expr) -> Option<&String> {
+        let callee = unwrap_transparent(callee);
+        if let Some(base) = reference_text(callee).and_then(|name| self.instances.get(&name)) {
+            return Some(base);
+        }
+        let Expr::Member(member) = callee else {
+            return None;
+        };
+        let MemberProp::Ident(method) = &member.prop else {
+            return None;
+        };
+        if !REQUEST_METHODS.contains(&method.sym.as_ref()) {
+            return None;
+        }
+        reference_text(&member.obj).and_then(|name| self.instances.get(&name))
+    }
+}
+
+impl Visit for InstanceCallVisitor<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && let Some(base) = self.receiver_base(callee)
+        {
+            self.calls.push(InstanceCall {
+                line_number: self.cm.lookup_char_pos(call.span.lo).line,
+                span_start: call.span.lo.0,
+                base: base.clone(),
+            });
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// Prefix a relative call target with an instance's base URL. Absolute URLs
+/// (which axios and friends send as-is) and targets that already start with an
+/// interpolated base are left alone. Returns `None` when nothing changed.
+pub fn join_instance_target(base: &str, target: &str) -> Option<String> {
+    let path = target.trim_matches(['`', '"', '\'']);
+    if path.contains("://") || path.starts_with("${") || path.starts_with("//") {
+        return None;
+    }
+    if path.starts_with(base) {
+        return None;
+    }
+    let base = base.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    Some(if path.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}/{}", base, path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_file;
+    use crate::visitor::SymbolKind;
+    use swc_common::{
+        errors::{ColorConfig, Handler},
+        sync::Lrc,
+    };
+
+    fn parse(source: &str) -> (Module, Lrc<SourceMap>) {
+        let tmp_dir = tempfile::tempdir().expect("tempdir");
+        let file_path = tmp_dir.path().join("input.ts");
+        std::fs::write(&file_path, source).expect("write file");
+
+        let cm: Lrc<SourceMap> = Default::default();
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm.clone()));
+        let module = parse_file(&file_path, &cm, &handler).expect("parsed module");
+        (module, cm)
+    }
+
+    #[test]
+    fn records_instances_of_each_client_with_their_base() {
+        let (module, _) = parse(
+            r#"
+import axios from "axios";
+import ky from "ky";
+import { ofetch } from "ofetch";
+const got = require("got");
+
+const ORDERS_BASE = process.env.ORDERS_URL ?? "http://localhost:3001";
+const orders = axios.create({ baseURL: ORDERS_BASE, timeout: 5000 });
+const catalog = ky.create({ prefixUrl: `${process.env.CATALOG_URL}/v1` });
+const admin = catalog.extend({ headers: { "x-admin": "1" } });
+const billing = ofetch.create({ baseURL: "http://billing:4000" });
+const users = got.extend({ prefixUrl: config.usersUrl });
+const plain = axios.create({ timeout: 1000 });
+"#,
+        );
+        let instances = HttpInstanceExtractor::build(&module);
+
+        let get = |name: &str| instances.get(name).map(String::as_str);
+        assert_eq!(get("orders"), Some("${process.env.ORDERS_URL}"));
+        assert_eq!(get("catalog"), Some("${process.env.CATALOG_URL}/v1"));
+        assert_eq!(get("admin"), Some("${process.env.CATALOG_URL}/v1"));
+        assert_eq!(get("billing"), Some("http://billing:4000"));
+        assert_eq!(get("users"), Some("${config.usersUrl}"));
+        assert_eq!(get("plain"), None);
+    }
+
+    #[test]
+    fn exported_instances_follow_renames_and_fold_into_importers() {
+        let (module, _) = parse(
+            r#"
+import axios from "axios";
+const inventory = axios.create({ baseURL: process.env.INVENTORY_URL });
+export const orders = axios.create({ baseURL: process.env.ORDERS_URL });
+export { inventory as stock };
+export default axios.create({ baseURL: "http://gateway" });
+"#,
+        );
+        let exports = exported_http_instances(&module);
+        assert_eq!(exports.len(), 3);
+        assert_eq!(exports["stock"], "${process.env.INVENTORY_URL}");
+
+        let symbol = |kind: SymbolKind, imported: &str| ImportedSymbol {
+            local_name: String::new(),
+            imported_name: imported.to_string(),
+            source: "./clients".to_string(),
+            kind,
+        };
+        let imported = HashMap::from([
+            ("ordersApi".to_string(), symbol(SymbolKind::Named, "orders")),
+            (
+                "gateway".to_string(),
+                symbol(SymbolKind::Default, "default"),
+            ),
+            ("clients".to_string(), symbol(SymbolKind::Namespace, "*")),
+        ]);
+        let mut instances = HttpInstanceMap::new();
+        merge_imported_http_instances(&mut instances, &imported, |_| Some(exports.clone()));
+
+        assert_eq!(instances["ordersApi"], "${process.env.ORDERS_URL}");
+        assert_eq!(instances["gateway"], "http://gateway");
+        assert_eq!(instances["clients.stock"], "${process.env.INVENTORY_URL}");
+    }
+
+    #[test]
+    fn calls_on_instances_get_the_base_prefixed() {
+        let (module, cm) = parse(
+            r#"
+const a = await orders.get(`/orders/${id}`);
+const b = await clients.stock.post("/reserve", body);
+const c = await catalog("items").json();
+const d = await other.get("/nope");
+"#,
+        );
+        let instances = HttpInstanceMap::from([
+            (
+                "orders".to_string(),
+                "${process.env.ORDERS_URL}".to_string(),
+            ),
+            ("clients.stock".to_string(), "http://inventory/".to_string()),
+            (
+                "catalog".to_string(),
+                "${process.env.CATALOG_URL}/v1".to_string(),
+            ),
+        ]);
+        let calls = instance_calls(&module, &cm, &instances);
+        let lines: Vec<usize> = calls.iter().map(|c| c.line_number).collect();
+        assert_eq!(lines, vec![2, 3, 4]);
+
+        assert_eq!(
+            join_instance_target(&calls[0].base, "/orders/${id}").as_deref(),
+            Some("${process.env.ORDERS_URL}/orders/${id}")
+        );
+        assert_eq!(
+            join_instance_target(&calls[1].base, "/reserve").as_deref(),
+            Some("http://inventory/reserve")
+        );
+        assert_eq!(
+            join_instance_target(&calls[2].base, "items").as_deref(),
+            Some("${process.env.CATALOG_URL}/v1/items")
+        );
+        assert_eq!(
+            join_instance_target(&calls[0].base, "https://elsewhere/x"),
+            None
+        );
+        assert_eq!(
+            join_instance_target(&calls[0].base, "${process.env.ORDERS_URL}/orders/1"),
+            None
+        );
+    }
+}
@@ -0,0 +1,482 @@
+//! `carrick impact`: the blast radius of a change set.
+//!
+//! Before merging a refactor it helps to know which other services depend on
+//! the code it touched. The files changed since `--base` seed the walk:
+//! every operation (HTTP route, GraphQL field, socket event, pub/sub topic)
+//! defined in a changed file is impacted, and so is every operation whose
+//! handler reaches a function in a changed file through the
+//! [`FunctionDefinition`] call graph. Each impacted operation is then joined
+//! to the [`CrossRepoMatch`] edges that consume it, grouped by consuming
+//! service and call site.
+//!
+//! [`FunctionDefinition`]: crate::visitor::FunctionDefinition
+
+use crate::analyzer::{CrossRepoMatch, strip_ci_workspace_prefix};
+use crate::cloud_storage::CloudRepoData;
+use crate::operation::{OperationKey, Protocol};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::Path;
+
+/// `carrick impact` switches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpactOptions {
+    /// Git revision the change set is diffed against (`<base>..HEAD`).
+    pub base: String,
+    /// Print the report as JSON instead of Markdown.
+    pub json: bool,
+}
+
+/// An operation this repo defines that the change set touches, before it is
+/// joined to its consumers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpactSeed {
+    pub service: String,
+    pub key: OperationKey,
+    /// Where the operation is defined (`file:line`).
+    pub location: String,
+    /// Call chain from the operation's handler down to the changed function.
+    /// Empty when the operation's own file changed.
+    pub via: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImpactReport {
+    pub base: String,
+    pub changed_files: Vec<String>,
+    pub operations: Vec<ImpactedOperation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImpactedOperation {
+    /// `http`, `graphql`, `socket`, or `pubsub`.
+    pub protocol: String,
+    pub method: String,
+    pub path: String,
+    pub service: String,
+    pub location: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub via: Vec<String>,
+    pub consumers: Vec<ImpactedConsumer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImpactedConsumer {
+    pub service: String,
+    pub call_sites: Vec<String>,
+}
+
+/// Operations in `services` that the `changed` files (repo-relative, as
+/// `git diff --name-only` prints them) touch directly or through a call
+/// chain.
+pub fn affected_operations(changed: &[String], services: &[CloudRepoData]) -> Vec<ImpactSeed> {
+    let mut seeds = Vec::new();
+    for repo in services {
+        let service = repo
+            .service_name
+            .clone()
+            .unwrap_or_else(|| repo.repo_name.clone());
+        let reach = reaching_functions(changed, repo);
+        let mut seed = |key: OperationKey, file: &str, line: Option<u32>, handler: Option<&str>| {
+            let via = if is_changed(file, changed) {
+                Vec::new()
+            } else {
+                match handler.and_then(|h| reach.get(handler_function(h))) {
+                    Some(chain) => chain.clone(),
+                    None => return,
+                }
+            };
+            let file = strip_ci_workspace_prefix(file);
+            seeds.push(ImpactSeed {
+                service: service.clone(),
+                key,
+                location: match line {
+                    Some(line) => format!("{}:{}", file, line),
+                    None => file.to_string(),
+                },
+                via,
+            });
+        };
+
+        // HTTP routes come from the mount graph so the key carries the
+        // mount-resolved path the cross-repo edges are keyed on.
+        if let Some(graph) = &repo.mount_graph {
+            for endpoint in graph.get_resolved_endpoints() {
+                let mut parts = endpoint.file_location.splitn(3, ':');
+                let file = parts.next().unwrap_or_default();
+                let line = parts.next().and_then(|l| l.parse().ok());
+                seed(
+                    OperationKey::http(&endpoint.method, endpoint.full_path.clone()),
+                    file,
+                    line,
+                    endpoint.handler.as_deref(),
+                );
+            }
+        }
+        for endpoint in &repo.endpoints {
+            if endpoint.key.protocol() == Protocol::Http {
+                continue;
+            }
+            seed(
+                endpoint.key.clone(),
+                &endpoint.file_path.display().to_string(),
+                None,
+                endpoint.handler_name.as_deref(),
+            );
+        }
+    }
+    seeds.sort_by(|a, b| (&a.service, a.key.canonical()).cmp(&(&b.service, b.key.canonical())));
+    seeds.dedup_by(|a, b| a.service == b.service && a.key == b.key);
+    seeds
+}
+
+/// Every function that is defined in a changed file or (transitively) calls
+/// one, mapped to the call chain from it down to the changed function.
+fn reaching_functions(changed: &[String], repo: &CloudRepoData) -> HashMap<String, Vec<String>> {
+    let mut callers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, definition) in &repo.function_definitions {
+        for call in &definition.calls {
+            callers.entry(call.name.as_str()).or_default().push(name);
+        }
+    }
+
+    let mut reach: HashMap<String, Vec<String>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut touched: Vec<&String> = repo
+        .function_definitions
+        .iter()
+        .filter(|(_, d)| is_changed(&d.file_path.display().to_string(), changed))
+        .map(|(name, _)| name)
+        .collect();
+    // Sorted so the chain recorded for a function reachable two ways is
+    // deterministic.
+    touched.sort();
+    for name in touched {
+        reach.insert(name.clone(), vec![name.clone()]);
+        queue.push_back(name.as_str());
+    }
+    while let Some(callee) = queue.pop_front() {
+        let chain = reach[callee].clone();
+        let mut next: Vec<&str> = callers.get(callee).cloned().unwrap_or_default();
+        next.sort();
+        for caller in next {
+            if reach.contains_key(caller) {
+                continue;
+            }
+            let mut caller_chain = vec![caller.to_string()];
+            caller_chain.extend(chain.iter().cloned());
+            reach.insert(caller.to_string(), caller_chain);
+            queue.push_back(caller);
+        }
+    }
+    reach
+}
+
+/// The function a handler reference names: `usersController.list` → `list`.
+fn handler_function(handler: &str) -> &str {
+    handler.rsplit('.').next().unwrap_or(handler)
+}
+
+/// Whether `file` (absolute or repo-relative) is one of the changed paths.
+fn is_changed(file: &str, changed: &[String]) -> bool {
+    let file = Path::new(strip_ci_workspace_prefix(file));
+    changed.iter().any(|c| {
+        let c = Path::new(c);
+        file.ends_with(c) || (file.is_relative() && c.ends_with(file))
+    })
+}
+
+impl ImpactReport {
+    /// Join each seed to the producer/consumer edges that consume it.
+    pub fn build(
+        base: &str,
+        changed_files: Vec<String>,
+        seeds: Vec<ImpactSeed>,
+        matches: &[CrossRepoMatch],
+    ) -> Self {
+        let operations = seeds
+            .into_iter()
+            .map(|seed| {
+                let canonical = seed.key.canonical();
+                let mut consumers: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+                for edge in matches.iter().filter(|m| {
+                    m.relationship == carrick_match::MatchRelationship::ProducerConsumer
+                        && m.producer_repo == seed.service
+                        && m.producer_key == canonical
+                }) {
+                    let sites = consumers.entry(edge.consumer_repo.clone()).or_default();
+                    if let Some(location) = &edge.consumer_location {
+                        sites.insert(strip_ci_workspace_prefix(location).to_string());
+                    }
+                }
+                let (method, path) = seed.key.display_labels();
+                ImpactedOperation {
+                    protocol: canonical.split('|').next().unwrap_or_default().to_string(),
+                    method,
+                    path,
+                    service: seed.service,
+                    location: seed.location,
+                    via: seed.via,
+                    consumers: consumers
+                        .into_iter()
+                        .map(|(service, sites)| ImpactedConsumer {
+                            service,
+                            call_sites: sites.into_iter().collect(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        Self {
+            base: base.to_string(),
+            changed_files,
+            operations,
+        }
+    }
+
+    /// Services outside this repo that consume an impacted operation.
+    pub fn consuming_services(&self) -> BTreeSet<&str> {
+        self.operations
+            .iter()
+            .flat_map(|op| op.consumers.iter().map(|c| c.service.as_str()))
+            .collect()
+    }
+
+    /// Human-readable report, grouped by impacted operation.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("## Impact of changes since `{}`\n\n", self.base);
+        if self.operations.is_empty() {
+            out.push_str(&format!(
+                "{} changed file(s) define or reach no indexed operations.\n",
+                self.changed_files.len()
+            ));
+            return out;
+        }
+        let consuming = self.consuming_services();
+        out.push_str(&format!(
+            "{} changed file(s) touch **{} operation(s)** consumed by **{} service(s)**",
+            self.changed_files.len(),
+            self.operations.len(),
+            consuming.len()
+        ));
+        if consuming.is_empty() {
+            out.push_str(".\n");
+        } else {
+            let names: Vec<String> = consuming.iter().map(|s| format!("`{}`", s)).collect();
+            out.push_str(&format!(": {}.\n", names.join(", ")));
+        }
+
+        for op in &self.operations {
+            out.push_str(&format!(
+                "\n### `{} {}` ({})\n\n",
+                op.method, op.path, op.service
+            ));
+            out.push_str(&format!("Defined at `{}`", op.location));
+            if op.via.is_empty() {
+                out.push_str(", which changed.\n");
+            } else {
+                out.push_str(&format!(
+                    "; reaches changed code via `{}`.\n",
+                    op.via.join("` → `")
+                ));
+            }
+            if op.consumers.is_empty() {
+                out.push_str("\nNo indexed consumers.\n");
+                continue;
+            }
+            out.push_str("\n| Consumer | Call sites |\n| :--- | :--- |\n");
+            for consumer in &op.consumers {
+                let sites: Vec<String> = consumer
+                    .call_sites
+                    .iter()
+                    .map(|s| format!("`{}`", s))
+                    .collect();
+                out.push_str(&format!(
+                    "| {} | {} |\n",
+                    consumer.service,
+                    sites.join(", ")
+                ));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::ApiEndpointDetails;
+    use crate::mount_graph::{MountGraph, ResolvedEndpoint};
+    use crate::visitor::{FunctionCallRef, FunctionDefinition};
+    use std::path::PathBuf;
+
+    fn function(name: &str, file: &str, calls: &[&str]) -> (String, FunctionDefinition) {
+        let definition = FunctionDefinition {
+            name: name.to_string(),
+            file_path: file.into(),
+            node_type: Default::default(),
+            arguments: vec![],
+            body_source: None,
+            is_exported: true,
+            line_number: 1,
+            intent: None,
+            calls: calls
+                .iter()
+                .map(|callee| FunctionCallRef {
+                    name: callee.to_string(),
+                    file_path: String::new(),
+                    line_number: 0,
+                })
+                .collect(),
+            return_type: None,
+            return_is_explicit: false,
+            signature: None,
+            intent_input_hash: None,
+        };
+        (name.to_string(), definition)
+    }
+
+    fn route(method: &str, path: &str, file: &str, handler: &str) -> ResolvedEndpoint {
+        ResolvedEndpoint {
+            method: method.to_string(),
+            path: path.to_string(),
+            full_path: path.to_string(),
+            handler: Some(handler.to_string()),
+            owner: "app".to_string(),
+            file_location: format!("{}:10:1", file),
+            middleware_chain: vec![],
+            repo_name: None,
+            service_name: None,
+            provenance: Default::default(),
+            evidence: Default::default(),
+            query_params: None,
+            request_headers: Vec::new(),
+        }
+    }
+
+    fn edge(consumer: &str, key: &str, location: &str) -> CrossRepoMatch {
+        CrossRepoMatch {
+            producer_repo: "orders".to_string(),
+            producer_key: key.to_string(),
+            consumer_repo: consumer.to_string(),
+            consumer_key: key.to_string(),
+            consumer_location: Some(location.to_string()),
+            match_score: 1.0,
+            type_compatible: None,
+            type_verdict: None,
+            mismatch_reason: None,
+            producer_provenance: Default::default(),
+            relationship: carrick_match::MatchRelationship::ProducerConsumer,
+        }
+    }
+
+    #[test]
+    fn walks_call_graph_and_groups_consumers() {
+        let mut graph = MountGraph::new();
+        graph.endpoints = vec![
+            route("GET", "/orders/:id", "/repo/src/routes.ts", "getOrder"),
+            route(
+                "POST",
+                "/orders",
+                "/repo/src/routes.ts",
+                "ordersController.create",
+            ),
+            route("GET", "/health", "/repo/src/health.ts", "health"),
+            route("GET", "/legacy", "/repo/src/legacy.ts", "legacy"),
+        ];
+        let mut repo = CloudRepoData {
+            repo_name: "orders".to_string(),
+            service_name: None,
+            endpoints: vec![],
+            calls: vec![],
+            mounts: vec![],
+            apps: HashMap::new(),
+            imported_handlers: vec![],
+            function_definitions: HashMap::new(),
+            config_json: None,
+            package_json: None,
+            packages: None,
+            last_updated: chrono::Utc::now(),
+            commit_hash: "deadbeef".to_string(),
+            mount_graph: None,
+            bundled_types: None,
+            type_manifest: None,
+            file_results: None,
+            cached_detection: None,
+            cached_guidance: None,
+            cached_extraction_config: None,
+            package_json_hash: None,
+            cache_version: None,
+            type_extraction_status: None,
+            compat_verdicts: None,
+            capture_stub: None,
+        };
+        repo.mount_graph = Some(graph);
+        repo.function_definitions = [
+            function("getOrder", "/repo/src/handlers.ts", &["loadOrder"]),
+            function("create", "/repo/src/handlers.ts", &["validate"]),
+            function("loadOrder", "/repo/src/db.ts", &["query"]),
+            function("query", "/repo/src/lib/sql.ts", &[]),
+            function("validate", "/repo/src/validate.ts", &[]),
+            function("health", "/repo/src/health.ts", &[]),
+        ]
+        .into_iter()
+        .collect();
+        // A subscriber defined in a changed file is impacted directly.
+        let pubsub = ApiEndpointDetails {
+            owner: None,
+            key: OperationKey::pubsub("order.created"),
+            params: vec![],
+            request_body: None,
+            response_body: None,
+            handler_name: None,
+            request_type: None,
+            response_type: None,
+            file_path: PathBuf::from("/repo/src/lib/sql.ts"),
+            repo_name: None,
+            service_name: None,
+            provenance: Default::default(),
+        };
+        repo.endpoints.push(pubsub);
+
+        let changed = vec!["src/lib/sql.ts".to_string(), "src/health.ts".to_string()];
+        let seeds = affected_operations(&changed, &[repo]);
+        let keys: Vec<String> = seeds.iter().map(|s| s.key.canonical()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "http|GET|/health",
+                "http|GET|/orders/:id",
+                "pubsub|order.created"
+            ]
+        );
+        assert_eq!(seeds[1].via, vec!["getOrder", "loadOrder", "query"]);
+        assert!(seeds[0].via.is_empty());
+
+        let report = ImpactReport::build(
+            "main",
+            changed,
+            seeds,
+            &[
+                edge("web", "http|GET|/orders/:id", "src/api.ts:4"),
+                edge("web", "http|GET|/orders/:id", "src/page.ts:9"),
+                edge("billing", "http|GET|/orders/:id", "lib/client.ts:2"),
+                edge("billing", "http|POST|/orders", "lib/client.ts:7"),
+            ],
+        );
+        let order = &report.operations[1];
+        assert_eq!(order.consumers.len(), 2);
+        assert_eq!(order.consumers[1].service, "web");
+        assert_eq!(
+            order.consumers[1].call_sites,
+            ["src/api.ts:4", "src/page.ts:9"]
+        );
+        assert_eq!(
+            report.consuming_services().into_iter().collect::<Vec<_>>(),
+            ["billing", "web"]
+        );
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("reaches changed code via `getOrder` → `loadOrder` → `query`"));
+        assert!(markdown.contains("| web | `src/api.ts:4`, `src/page.ts:9` |"));
+    }
+}
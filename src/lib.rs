@@ -2,31 +2,49 @@ pub mod agent_service;
 pub mod agents;
 pub mod analyzer;
 pub mod app_context;
+pub mod baseline;
 pub mod call_site_extractor;
+pub mod changelog;
+pub mod client_wrappers;
 pub mod cloud_storage;
 pub mod config;
+pub mod deploy_manifests;
 pub mod engine;
 pub mod env_alias;
+pub mod env_profiles;
 pub mod eval_output;
 pub mod extractor;
+pub mod field_usage;
 pub mod file_based_router;
 pub mod file_finder;
+pub mod finding_anchors;
 pub mod findings;
 pub mod formatter;
 pub mod framework_detector;
+pub mod gateway;
 pub mod graphql;
+pub mod headers;
+pub mod http_instances;
+pub mod impact;
 pub mod intent_generator;
 pub mod logging;
+pub mod middleware;
 pub mod mount_graph;
 pub mod multi_agent_orchestrator;
+pub mod nestjs;
 pub mod oidc;
 pub mod operation;
 pub mod packages;
 pub mod parser;
+pub mod policy;
+pub mod query_params;
+pub mod serverless;
 pub mod services;
 pub mod signature_pass;
 pub mod socket_io;
+pub mod suppressions;
 pub mod swc_scanner;
+pub mod type_diff;
 pub mod type_manifest;
 pub mod url_normalizer;
 pub mod utils;
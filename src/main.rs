@@ -2,31 +2,49 @@ mod agent_service;
 mod agents;
 mod analyzer;
 mod app_context;
+mod baseline;
 mod call_site_extractor;
+mod changelog;
+mod client_wrappers;
 mod cloud_storage;
 mod config;
+mod deploy_manifests;
 mod engine;
 mod env_alias;
+mod env_profiles;
 mod eval_output;
 mod extractor;
+mod field_usage;
 mod file_based_router;
 mod file_finder;
+mod finding_anchors;
 mod findings;
 mod formatter;
 mod framework_detector;
+mod gateway;
 mod graphql;
+mod headers;
+mod http_instances;
+mod impact;
 mod intent_generator;
 mod logging;
+mod middleware;
 mod mount_graph;
 mod multi_agent_orchestrator;
+mod nestjs;
 mod oidc;
 mod operation;
 mod packages;
 mod parser;
+mod policy;
+mod query_params;
+mod serverless;
 mod services;
 mod signature_pass;
 mod socket_io;
+mod suppressions;
 mod swc_scanner;
+mod type_diff;
 mod type_manifest;
 mod url_normalizer;
 mod utils;
@@ -34,20 +52,43 @@ mod visitor;
 
 use crate::cloud_storage::{AwsStorage, LocalDirStorage, MockStorage};
 use crate::services::TypeSidecar;
-use engine::run_analysis_engine_with_sidecar;
+use engine::{EngineOptions, RunOutcome, run_analysis_engine_with_options};
+use findings::Severity;
 use std::env;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
+/// Subcommand selected by the first positional argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Analyze and report (the default).
+    Scan,
+    /// Analyze and record the current findings in `carrick-baseline.json`.
+    Baseline,
+    /// Report which operations and consumers the changes since `--base` reach.
+    Impact,
+    /// Diff two index snapshots into an API changelog.
+    Changelog,
+}
+
 /// CLI arguments for the carrick analyzer
 struct CliArgs {
+    command: Command,
     /// Path to the repository to analyze
     repo_path: String,
     /// Enable verbose (debug-level) terminal output
     verbose: bool,
     /// Skip incremental cache and run a full analysis
     no_cache: bool,
+    /// Exit non-zero when new findings at or above this severity exist
+    fail_on: Option<Severity>,
+    /// `impact`: git revision to diff against
+    base: Option<String>,
+    /// `impact`/`changelog`: print the report as JSON
+    json: bool,
+    /// `changelog`: the old and new snapshot
+    snapshots: Vec<String>,
 }
 
 impl CliArgs {
@@ -57,9 +98,25 @@ impl CliArgs {
     }
 
     fn parse_from(args: &[String]) -> Self {
-        let mut repo_path = ".".to_string();
+        Self::try_parse_from(args).unwrap_or_else(|message| {
+            eprintln!("{}", message);
+            Self::print_help();
+            std::process::exit(1);
+        })
+    }
+
+    /// Parse `args`, or the usage error to report. `--help` still prints
+    /// and exits here.
+    fn try_parse_from(args: &[String]) -> Result<Self, String> {
+        let mut command = Command::Scan;
+        let mut repo_path = None;
+        let mut seen_positional = false;
         let mut verbose = false;
         let mut no_cache = false;
+        let mut fail_on = None;
+        let mut base = None;
+        let mut json = false;
+        let mut snapshots = Vec::new();
 
         let mut i = 0;
         while i < args.len() {
@@ -74,23 +131,75 @@ impl CliArgs {
                 "--no-cache" => {
                     no_cache = true;
                 }
+                "--fail-on" => {
+                    i += 1;
+                    fail_on = Some(Self::parse_fail_on(args.get(i).map(String::as_str))?);
+                }
+                arg if arg.starts_with("--fail-on=") => {
+                    fail_on = Some(Self::parse_fail_on(arg.strip_prefix("--fail-on="))?);
+                }
+                "--base" => {
+                    i += 1;
+                    base = args.get(i).cloned();
+                }
+                arg if arg.starts_with("--base=") => {
+                    base = arg.strip_prefix("--base=").map(String::from);
+                }
+                "--json" => {
+                    json = true;
+                }
+                "baseline" if !seen_positional => {
+                    command = Command::Baseline;
+                    seen_positional = true;
+                }
+                "impact" if !seen_positional => {
+                    command = Command::Impact;
+                    seen_positional = true;
+                }
+                "changelog" if !seen_positional => {
+                    command = Command::Changelog;
+                    seen_positional = true;
+                }
+                arg if command == Command::Changelog && !arg.starts_with('-') => {
+                    snapshots.push(arg.to_string());
+                }
+                arg if !arg.starts_with('-') && repo_path.is_none() => {
+                    repo_path = Some(arg.to_string());
+                    seen_positional = true;
+                }
                 arg if !arg.starts_with('-') => {
-                    repo_path = arg.to_string();
+                    return Err(format!("Unexpected argument: {}", arg));
                 }
                 _ => {
-                    eprintln!("Unknown argument: {}", args[i]);
-                    Self::print_help();
-                    std::process::exit(1);
+                    return Err(format!("Unknown argument: {}", args[i]));
                 }
             }
             i += 1;
         }
 
-        Self {
-            repo_path,
+        if command == Command::Impact && base.as_deref().is_none_or(str::is_empty) {
+            return Err("impact requires --base <rev>".to_string());
+        }
+        if command == Command::Changelog && snapshots.len() != 2 {
+            return Err("changelog expects two snapshots: <OLD> <NEW>".to_string());
+        }
+
+        Ok(Self {
+            command,
+            repo_path: repo_path.unwrap_or_else(|| ".".to_string()),
             verbose,
             no_cache,
-        }
+            fail_on,
+            base,
+            json,
+            snapshots,
+        })
+    }
+
+    fn parse_fail_on(value: Option<&str>) -> Result<Severity, String> {
+        value
+            .and_then(Severity::parse)
+            .ok_or_else(|| "--fail-on expects one of: risk, gap, advisory".to_string())
     }
 
     fn print_help() {
@@ -99,6 +208,18 @@ impl CliArgs {
 
 USAGE:
     carrick [OPTIONS] [REPO_PATH]
+    carrick baseline [OPTIONS] [REPO_PATH]
+    carrick impact --base <REV> [--json] [OPTIONS] [REPO_PATH]
+    carrick changelog [--json] <OLD> <NEW>
+
+COMMANDS:
+    baseline       Record the current findings in carrick-baseline.json so later
+                   runs report only new ones
+    impact         Report the operations the changes since <REV> touch and the
+                   services and call sites that consume them
+    changelog      Diff two index snapshots (CloudRepoData JSON files, or
+                   <rev>:<path> in git) into an API changelog with a
+                   suggested semver bump
 
 ARGUMENTS:
     [REPO_PATH]    Path to the repository to analyze (default: current directory)
@@ -107,6 +228,11 @@ OPTIONS:
     -h, --help     Print this help message
     -v, --verbose  Enable verbose (debug-level) terminal output
     --no-cache     Skip incremental cache and run a full analysis
+    --fail-on <risk|gap|advisory>
+                   Exit with status 2 when new findings at or above this
+                   severity exist (baselined findings don't count)
+    --base <REV>   impact: git revision to diff HEAD against
+    --json         impact, changelog: print the report as JSON
 
 ENVIRONMENT VARIABLES:
     ACTIONS_ID_TOKEN_REQUEST_URL    GitHub Actions OIDC token endpoint (auto-set
@@ -124,13 +250,54 @@ async fn main() {
     let args = CliArgs::parse();
     logging::init(args.verbose);
 
-    if let Err(e) = run_analysis(args).await {
-        error!("Analysis failed: {}", e);
-        std::process::exit(1);
+    if args.command == Command::Changelog {
+        if let Err(e) = print_changelog(&args.snapshots, args.json) {
+            error!("Changelog failed: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let fail_on = args.fail_on;
+    match run_analysis(args).await {
+        Ok(outcome) if outcome.failing_findings > 0 => {
+            error!(
+                "{} finding(s) at or above '{}' severity (--fail-on)",
+                outcome.failing_findings,
+                fail_on.map_or("", severity_label)
+            );
+            std::process::exit(2);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            error!("Analysis failed: {}", e);
+            std::process::exit(1);
+        }
     }
 }
 
-async fn run_analysis(args: CliArgs) -> Result<(), Box<dyn std::error::Error>> {
+/// `carrick changelog`: needs no scan, sidecar, or storage.
+fn print_changelog(snapshots: &[String], json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let old = changelog::load_snapshot(&snapshots[0])?;
+    let new = changelog::load_snapshot(&snapshots[1])?;
+    let changelog = changelog::diff(&old, &new);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&changelog)?);
+    } else {
+        print!("{}", changelog.to_markdown());
+    }
+    Ok(())
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Risk => "risk",
+        Severity::Gap => "gap",
+        Severity::Advisory => "advisory",
+    }
+}
+
+async fn run_analysis(args: CliArgs) -> Result<RunOutcome, Box<dyn std::error::Error>> {
     // Validate the scan target up front. A nonexistent path would otherwise
     // walk zero files and "succeed" with an empty analysis.
     if !Path::new(&args.repo_path).is_dir() {
@@ -234,17 +401,30 @@ async fn run_analysis(args: CliArgs) -> Result<(), Box<dyn std::error::Error>> {
         None
     };
 
+    let options = EngineOptions {
+        no_cache: args.no_cache,
+        write_baseline: args.command == Command::Baseline,
+        fail_on: args.fail_on,
+        impact: match (args.command, args.base) {
+            (Command::Impact, Some(base)) => Some(impact::ImpactOptions {
+                base,
+                json: args.json,
+            }),
+            _ => None,
+        },
+    };
+
     if use_local_dir {
         info!("Using LocalDirStorage (offline eval harness)");
         let storage = LocalDirStorage::from_env()?;
-        run_analysis_engine_with_sidecar(storage, &args.repo_path, sidecar_ref, args.no_cache).await
+        run_analysis_engine_with_options(storage, &args.repo_path, sidecar_ref, &options).await
     } else if use_mock {
         info!("Using MockStorage");
         let storage = MockStorage::new();
-        run_analysis_engine_with_sidecar(storage, &args.repo_path, sidecar_ref, args.no_cache).await
+        run_analysis_engine_with_options(storage, &args.repo_path, sidecar_ref, &options).await
     } else {
         let storage = AwsStorage::new()?;
-        run_analysis_engine_with_sidecar(storage, &args.repo_path, sidecar_ref, args.no_cache).await
+        run_analysis_engine_with_options(storage, &args.repo_path, sidecar_ref, &options).await
     }
 
     // Sidecar will be automatically shut down when it goes out of scope (Drop impl)
@@ -375,9 +555,59 @@ mod tests {
     #[test]
     fn test_defaults() {
         let cli = CliArgs::parse_from(&args(&[]));
+        assert_eq!(cli.command, Command::Scan);
         assert_eq!(cli.repo_path, ".");
         assert!(!cli.verbose);
         assert!(!cli.no_cache);
+        assert_eq!(cli.fail_on, None);
+    }
+
+    #[test]
+    fn test_fail_on() {
+        let cli = CliArgs::parse_from(&args(&["--fail-on", "gap", "/some/path"]));
+        assert_eq!(cli.fail_on, Some(Severity::Gap));
+        assert_eq!(cli.repo_path, "/some/path");
+
+        let cli = CliArgs::parse_from(&args(&["--fail-on=RISK"]));
+        assert_eq!(cli.fail_on, Some(Severity::Risk));
+    }
+
+    #[test]
+    fn test_baseline_command() {
+        let cli = CliArgs::parse_from(&args(&["baseline", "/some/path"]));
+        assert_eq!(cli.command, Command::Baseline);
+        assert_eq!(cli.repo_path, "/some/path");
+
+        // Only the first positional selects the command, and only one
+        // repo path is accepted: a trailing `baseline` is an error rather
+        // than a second path that silently replaces the first.
+        assert_eq!(
+            CliArgs::try_parse_from(&args(&["/some/path", "baseline"])).err(),
+            Some("Unexpected argument: baseline".to_string())
+        );
+        assert!(CliArgs::try_parse_from(&args(&["baseline", "/a", "/b"])).is_err());
+    }
+
+    #[test]
+    fn test_impact_command() {
+        let cli = CliArgs::parse_from(&args(&["impact", "--base", "origin/main", "/some/path"]));
+        assert_eq!(cli.command, Command::Impact);
+        assert_eq!(cli.base.as_deref(), Some("origin/main"));
+        assert_eq!(cli.repo_path, "/some/path");
+        assert!(!cli.json);
+
+        let cli = CliArgs::parse_from(&args(&["impact", "--base=HEAD~3", "--json"]));
+        assert_eq!(cli.base.as_deref(), Some("HEAD~3"));
+        assert!(cli.json);
+    }
+
+    #[test]
+    fn test_changelog_command() {
+        let cli = CliArgs::parse_from(&args(&["changelog", "old.json", "main:api.json", "--json"]));
+        assert_eq!(cli.command, Command::Changelog);
+        assert_eq!(cli.snapshots, ["old.json", "main:api.json"]);
+        assert!(cli.json);
+        assert_eq!(cli.repo_path, ".");
     }
 
     #[test]
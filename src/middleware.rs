@@ -0,0 +1,539 @@
+//! Deterministic middleware chains for the mount graph.
+//!
+//! Extraction records routes and mounts but not what runs in front of them,
+//! so [`MountEdge::middleware_stack`] and
+//! [`ResolvedEndpoint::middleware_chain`] start out empty. This pass re-reads
+//! the route files and fills them in from the registration calls themselves:
+//!
+//! ```ts
+//! app.use(helmet());                              // router-level: `helmet`
+//! app.use('/admin', requireAuth, adminRouter);    // mount: `requireAuth`
+//! adminRouter.delete('/users/:id', requireRole('admin'), handler);
+//! fastify.get('/me', { preHandler: [verifyJwt] }, handler);
+//! ```
+//!
+//! A middleware is named after its expression: an identifier (`requireAuth`),
+//! a member path (`auth.required`), or the callee of a factory call
+//! (`requireRole('admin')` → `requireRole`). Inline function middleware has
+//! no name and is skipped. An endpoint's chain is, outermost first: the
+//! middleware of every mount above its owner (each parent's router-level
+//! middleware, then the mount's own), the owner's router-level middleware
+//! registered before the route, then the route's own.
+
+use crate::mount_graph::{MountEdge, MountGraph};
+use crate::parser::parse_file;
+use crate::type_manifest::parse_file_location;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use swc_common::{
+    GLOBALS, Globals, SourceMap,
+    errors::{ColorConfig, Handler},
+    sync::Lrc,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+const ROUTE_METHODS: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "head", "options", "all",
+];
+
+/// Fastify route-option and `addHook` names whose handlers run before the
+/// route handler.
+const FASTIFY_HOOKS: &[&str] = &["onRequest", "preParsing", "preValidation", "preHandler"];
+
+/// Fill `middleware_stack` on every mount edge and `middleware_chain` on
+/// every endpoint. Locations are resolved as given, then against `root`;
+/// files that can't be read or parsed leave their entries untouched.
+pub fn attach_middleware_chains(graph: &mut MountGraph, root: &Path) {
+    let mut files: HashMap<String, Option<FileMiddleware>> = HashMap::new();
+    let locations = graph
+        .endpoints
+        .iter()
+        .map(|e| e.file_location.as_str())
+        .chain(graph.nodes.values().map(|n| n.file_location.as_str()));
+    for location in locations {
+        let (file, _) = parse_file_location(location);
+        files
+            .entry(file)
+            .or_insert_with_key(|file| resolve(file, root).and_then(|path| scan_file(&path)));
+    }
+
+    // Anything mounted somewhere is a router, not a middleware, even when it
+    // is `use`d without a path.
+    let children: HashSet<String> = graph.mounts.iter().map(|m| m.child.clone()).collect();
+
+    for edge in &mut graph.mounts {
+        if let Some(stack) = files
+            .values()
+            .flatten()
+            .flat_map(|f| &f.uses)
+            .find(|u| u.mounts(edge))
+            .map(|u| u.middleware(&children))
+        {
+            edge.middleware_stack = stack;
+        }
+    }
+
+    // Router-level middleware per node, read from the file the node is
+    // created in.
+    let router_level = |node: &str, before: Option<u32>, file: &str| -> Vec<String> {
+        files
+            .get(file)
+            .and_then(Option::as_ref)
+            .map(|f| {
+                f.uses
+                    .iter()
+                    .filter(|u| u.receiver == node && u.path.is_none())
+                    .filter(|u| before.is_none_or(|line| u.line < line))
+                    .flat_map(|u| u.middleware(&children))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let node_file = |node: &str| {
+        graph
+            .nodes
+            .get(node)
+            .map(|n| parse_file_location(&n.file_location).0)
+            .unwrap_or_default()
+    };
+
+    let mut chains = Vec::with_capacity(graph.endpoints.len());
+    for endpoint in &graph.endpoints {
+        let (file, line) = parse_file_location(&endpoint.file_location);
+        let Some(route) = files
+            .get(&file)
+            .and_then(Option::as_ref)
+            .and_then(|f| f.route_at(line, &endpoint.method))
+        else {
+            chains.push(None);
+            continue;
+        };
+        let mut chain = Vec::new();
+        let mut visited = HashSet::new();
+        mount_chain(
+            &graph.mounts,
+            &endpoint.owner,
+            &mut visited,
+            &mut chain,
+            &|node| router_level(node, None, &node_file(node)),
+        );
+        chain.extend(router_level(&route.receiver, Some(line), &file));
+        chain.extend(route.middleware.iter().cloned());
+        let mut seen = HashSet::new();
+        chain.retain(|name| seen.insert(name.clone()));
+        chains.push(Some(chain));
+    }
+    for (endpoint, chain) in graph.endpoints.iter_mut().zip(chains) {
+        if let Some(chain) = chain {
+            endpoint.middleware_chain = chain;
+        }
+    }
+}
+
+/// Middleware contributed by the mounts above `node`, outermost first.
+fn mount_chain(
+    mounts: &[MountEdge],
+    node: &str,
+    visited: &mut HashSet<String>,
+    chain: &mut Vec<String>,
+    router_level: &dyn Fn(&str) -> Vec<String>,
+) {
+    if !visited.insert(node.to_string()) {
+        return;
+    }
+    let Some(edge) = mounts.iter().find(|m| m.child == node) else {
+        return;
+    };
+    mount_chain(mounts, &edge.parent, visited, chain, router_level);
+    chain.extend(router_level(&edge.parent));
+    chain.extend(edge.middleware_stack.iter().cloned());
+}
+
+fn resolve(file: &str, root: &Path) -> Option<PathBuf> {
+    let direct = PathBuf::from(file);
+    if direct.is_file() {
+        return Some(direct);
+    }
+    let joined = root.join(file.trim_start_matches("./"));
+    joined.is_file().then_some(joined)
+}
+
+/// Registration calls found in one file.
+#[derive(Debug, Default)]
+struct FileMiddleware {
+    routes: Vec<RouteSite>,
+    uses: Vec<UseSite>,
+}
+
+impl FileMiddleware {
+    /// The route registered at `line` for `method`, falling back to any
+    /// route on that line (`app.all`, or a method the extractor normalized).
+    fn route_at(&self, line: u32, method: &str) -> Option<&RouteSite> {
+        let on_line = || self.routes.iter().filter(|r| r.lines.contains(&line));
+        on_line()
+            .find(|r| r.method.eq_ignore_ascii_case(method))
+            .or_else(|| on_line().next())
+    }
+}
+
+#[derive(Debug)]
+struct RouteSite {
+    receiver: String,
+    method: String,
+    /// The call's first line and the line of its `.method` — chained
+    /// `router.route(p).get(..).post(..)` calls all start on one line.
+    lines: Vec<u32>,
+    middleware: Vec<String>,
+}
+
+/// A `recv.use(...)` call (or Fastify `addHook`).
+#[derive(Debug)]
+struct UseSite {
+    receiver: String,
+    line: u32,
+    path: Option<String>,
+    /// Named arguments in order; the mounted child, if any, is among them.
+    args: Vec<String>,
+}
+
+impl UseSite {
+    fn mounts(&self, edge: &MountEdge) -> bool {
+        self.receiver == edge.parent
+            && self.path.as_deref().unwrap_or("") == edge.path_prefix
+            && self.args.contains(&edge.child)
+    }
+
+    fn middleware(&self, children: &HashSet<String>) -> Vec<String> {
+        self.args
+            .iter()
+            .filter(|a| !children.contains(*a))
+            .cloned()
+            .collect()
+    }
+}
+
+fn scan_file(path: &Path) -> Option<FileMiddleware> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let handler = Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(cm.clone()));
+    GLOBALS.set(&Globals::new(), || {
+        let module = parse_file(path, &cm, &handler)?;
+        let mut collector = RegistrationCollector {
+            cm: &cm,
+            found: FileMiddleware::default(),
+        };
+        module.visit_with(&mut collector);
+        Some(collector.found)
+    })
+}
+
+struct RegistrationCollector<'a> {
+    cm: &'a Lrc<SourceMap>,
+    found: FileMiddleware,
+}
+
+impl RegistrationCollector<'_> {
+    fn line(&self, pos: swc_common::BytePos) -> u32 {
+        self.cm.lookup_char_pos(pos).line as u32
+    }
+}
+
+impl Visit for RegistrationCollector<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        call.visit_children_with(self);
+        let Callee::Expr(callee) = &call.callee else {
+            return;
+        };
+        let Expr::Member(member) = &**callee else {
+            return;
+        };
+        let MemberProp::Ident(prop) = &member.prop else {
+            return;
+        };
+        let name = prop.sym.as_ref();
+        let args: Vec<&Expr> = call.args.iter().map(|a| &*a.expr).collect();
+
+        if ROUTE_METHODS.contains(&name) {
+            // `router.route('/x').get(mw, handler)` carries no path argument.
+            let (receiver, rest) = match route_chain_receiver(&member.obj) {
+                Some(receiver) => (Some(receiver), &args[..]),
+                None => match args.split_first() {
+                    Some((first, rest)) if string_value(first).is_some() => {
+                        (receiver_name(&member.obj), rest)
+                    }
+                    _ => return,
+                },
+            };
+            let Some(receiver) = receiver else {
+                return;
+            };
+            let Some((_handler, before)) = rest.split_last() else {
+                return;
+            };
+            let middleware = before
+                .iter()
+                .flat_map(|arg| middleware_names(arg))
+                .collect();
+            let lines = vec![self.line(call.span.lo), self.line(prop.span.lo)];
+            self.found.routes.push(RouteSite {
+                receiver,
+                method: name.to_uppercase(),
+                lines,
+                middleware,
+            });
+        } else if name == "use" || name == "addHook" {
+            let Some(receiver) = receiver_name(&member.obj) else {
+                return;
+            };
+            let (path, rest) = match args.split_first() {
+                Some((first, rest)) if name == "addHook" => {
+                    // Only request-phase hooks guard a route.
+                    if !string_value(first).is_some_and(|h| FASTIFY_HOOKS.contains(&h.as_str())) {
+                        return;
+                    }
+                    (None, rest)
+                }
+                Some((first, rest)) => match string_value(first) {
+                    Some(path) => (Some(path), rest),
+                    None => (None, &args[..]),
+                },
+                None => return,
+            };
+            self.found.uses.push(UseSite {
+                receiver,
+                line: self.line(call.span.lo),
+                path,
+                args: rest.iter().flat_map(|arg| middleware_names(arg)).collect(),
+            });
+        }
+    }
+}
+
+/// The router behind a `router.route('/x').get(..).post(..)` chain.
+fn route_chain_receiver(expr: &Expr) -> Option<String> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    match callee_prop(call)? {
+        "route" => callee_receiver(call),
+        method if ROUTE_METHODS.contains(&method) => match &call.callee {
+            Callee::Expr(callee) => match &**callee {
+                Expr::Member(member) => route_chain_receiver(&member.obj),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn callee_prop(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(expr) => match &**expr {
+            Expr::Member(member) => match &member.prop {
+                MemberProp::Ident(prop) => Some(prop.sym.as_ref()),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn callee_receiver(call: &CallExpr) -> Option<String> {
+    match &call.callee {
+        Callee::Expr(expr) => match &**expr {
+            Expr::Member(member) => receiver_name(&member.obj),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The router a registration call is made on: `app`, `this.router`.
+fn receiver_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(ident) => Some(ident.sym.to_string()),
+        Expr::Member(member) => match &member.prop {
+            MemberProp::Ident(prop) => {
+                let obj = match &*member.obj {
+                    Expr::This(_) => "this".to_string(),
+                    obj => receiver_name(obj)?,
+                };
+                Some(format!("{}.{}", obj, prop.sym))
+            }
+            _ => None,
+        },
+        Expr::Paren(paren) => receiver_name(&paren.expr),
+        Expr::TsAs(ts_as) => receiver_name(&ts_as.expr),
+        Expr::TsNonNull(non_null) => receiver_name(&non_null.expr),
+        _ => None,
+    }
+}
+
+fn string_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+            Some(tpl.quasis.iter().map(|q| q.raw.to_string()).collect())
+        }
+        _ => None,
+    }
+}
+
+/// Names of the middleware one argument contributes: arrays are flattened,
+/// Fastify option objects contribute their hook values, and inline
+/// functions contribute nothing.
+fn middleware_names(expr: &Expr) -> Vec<String> {
+    match expr {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .flatten()
+            .flat_map(|elem| middleware_names(&elem.expr))
+            .collect(),
+        Expr::Object(object) => object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(kv) => Some(kv),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .filter(|kv| match &kv.key {
+                PropName::Ident(key) => FASTIFY_HOOKS.contains(&key.sym.as_ref()),
+                PropName::Str(key) => FASTIFY_HOOKS.contains(&key.value.to_string().as_str()),
+                _ => false,
+            })
+            .flat_map(|kv| middleware_names(&kv.value))
+            .collect(),
+        Expr::Call(call) => match &call.callee {
+            Callee::Expr(callee) => middleware_names(callee),
+            _ => Vec::new(),
+        },
+        Expr::Ident(_) | Expr::Member(_) => receiver_name(expr).into_iter().collect(),
+        Expr::Paren(paren) => middleware_names(&paren.expr),
+        Expr::TsAs(ts_as) => middleware_names(&ts_as.expr),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mount_graph::{GraphNode, NodeType, ResolvedEndpoint};
+
+    fn endpoint(method: &str, path: &str, owner: &str, location: &str) -> ResolvedEndpoint {
+        ResolvedEndpoint {
+            method: method.to_string(),
+            path: path.to_string(),
+            full_path: path.to_string(),
+            handler: None,
+            owner: owner.to_string(),
+            file_location: location.to_string(),
+            middleware_chain: vec![],
+            repo_name: None,
+            service_name: None,
+            provenance: Default::default(),
+            evidence: Default::default(),
+            query_params: None,
+            request_headers: Vec::new(),
+        }
+    }
+
+    fn node(name: &str, location: &str) -> (String, GraphNode) {
+        (
+            name.to_string(),
+            GraphNode {
+                name: name.to_string(),
+                node_type: NodeType::Unknown,
+                creation_site: None,
+                file_location: location.to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn chains_combine_mount_router_and_route_middleware() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/app.ts"),
+            "const app = express();\n\
+             app.use(helmet());\n\
+             app.use('/admin', requireAuth, adminRouter);\n\
+             app.use(ordersRouter);\n\
+             app.get('/health', (req, res) => res.send('ok'));\n\
+             app.post('/login', [rateLimit, validate(schema)], login);\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/admin.ts"),
+            "export const adminRouter = Router();\n\
+             adminRouter.get('/stats', stats);\n\
+             adminRouter.use(audit.log);\n\
+             adminRouter.delete('/users/:id', requireRole('admin'), async (req, res) => {});\n\
+             adminRouter.route('/jobs').get(list).post(csrf, create);\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("src/fastify.ts"),
+            "fastify.addHook('onRequest', tracing);\n\
+             fastify.addHook('onSend', compress);\n\
+             fastify.get('/me', { preHandler: [verifyJwt], schema }, me);\n",
+        )
+        .unwrap();
+
+        let mut graph = MountGraph::new();
+        graph.nodes.extend([
+            node("app", "src/app.ts:1"),
+            node("adminRouter", "src/admin.ts:1"),
+            node("ordersRouter", "src/app.ts:4"),
+        ]);
+        graph.mounts = vec![
+            MountEdge {
+                parent: "app".into(),
+                child: "adminRouter".into(),
+                path_prefix: "/admin".into(),
+                middleware_stack: vec![],
+            },
+            MountEdge {
+                parent: "app".into(),
+                child: "ordersRouter".into(),
+                path_prefix: "".into(),
+                middleware_stack: vec![],
+            },
+        ];
+        graph.endpoints = vec![
+            endpoint("GET", "/health", "app", "src/app.ts:5"),
+            endpoint("POST", "/login", "app", "src/app.ts:6"),
+            endpoint("GET", "/stats", "adminRouter", "src/admin.ts:2"),
+            endpoint("DELETE", "/users/:id", "adminRouter", "src/admin.ts:4"),
+            endpoint("POST", "/jobs", "adminRouter", "src/admin.ts:5"),
+            endpoint("GET", "/me", "fastify", "src/fastify.ts:3"),
+        ];
+
+        attach_middleware_chains(&mut graph, dir.path());
+
+        assert_eq!(graph.mounts[0].middleware_stack, vec!["requireAuth"]);
+        assert!(graph.mounts[1].middleware_stack.is_empty());
+        let chains: Vec<Vec<&str>> = graph
+            .endpoints
+            .iter()
+            .map(|e| e.middleware_chain.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            chains,
+            vec![
+                vec!["helmet"],
+                vec!["helmet", "rateLimit", "validate"],
+                vec!["helmet", "requireAuth"],
+                vec!["helmet", "requireAuth", "audit.log", "requireRole"],
+                vec!["helmet", "requireAuth", "audit.log", "csrf"],
+                vec!["tracing", "verifyJwt"],
+            ]
+        );
+    }
+}
@@ -64,6 +64,14 @@ pub struct ResolvedEndpoint {
     /// keeps its meaning.
     #[serde(default)]
     pub evidence: carrick_match::MatchEvidence,
+    /// The query keys the endpoint reads and requires, carried from
+    /// `EndpointResult::query_params`. `None` when unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_params: Option<crate::query_params::QueryContract>,
+    /// Headers the handler or its guards read, carried from
+    /// `EndpointResult::request_headers`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub request_headers: Vec<String>,
 }
 
 /// Represents a data-fetching call with its target
@@ -94,6 +102,21 @@ pub struct DataFetchingCall {
     /// producer by service but the consumer by repo (#368).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub service_name: Option<String>,
+    /// Query keys the call sends, from its target's literal or template
+    /// query string (`crate::query_params::sent_query_keys`); empty when it
+    /// provably sends none. `None` when the key set can't be read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_params: Option<Vec<String>>,
+    /// Headers the call sends (`crate::headers::call_headers`). `None` when
+    /// they can't all be read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_headers: Option<crate::headers::SentHeaders>,
+    /// The service this call is known to reach: the one a gateway route
+    /// forwards it to, or the sibling a deployment manifest maps its env var
+    /// or host to (`UrlNormalizer::consumer_call_target`). When an indexed
+    /// producer carries that id, the call only matches its producers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_service: Option<String>,
 }
 
 /// The complete mount and endpoint graph
@@ -282,11 +305,16 @@ impl MountGraph {
     /// agreement 4) declared elsewhere in the org; it is returned only when
     /// nothing more specific matches. The relative selection is
     /// data-derived — no absolute specificity threshold.
+    ///
+    /// `service` restricts the candidates to one service's producers
+    /// (`service_name ?? repo_name`): the service a call is known to reach
+    /// ([`DataFetchingCall::target_service`]).
     pub fn find_matching_endpoints_with_normalizer(
         &self,
         url: &str,
         method: &str,
         normalizer: &UrlNormalizer,
+        service: Option<&str>,
     ) -> Option<Vec<&ResolvedEndpoint>> {
         let normalized = normalizer.normalize(url);
 
@@ -301,6 +329,7 @@ impl MountGraph {
             .endpoints
             .iter()
             .filter(|endpoint| endpoint.method.eq_ignore_ascii_case(method))
+            .filter(|endpoint| Self::served_by(endpoint, service))
             .filter_map(|endpoint| {
                 carrick_match::match_agreement(&endpoint.full_path, &normalized.path)
                     .map(|agreement| (endpoint, agreement))
@@ -327,10 +356,12 @@ impl MountGraph {
     /// wildcard params against concrete segments: `POST /users/:id` must not
     /// turn a missing `GET /users/list` into a "wrong verb" risk. Segments
     /// match literally or param-to-param only (param names are not identity).
+    /// `service` restricts the candidates as it does there.
     pub fn find_exact_path_matches_any_method(
         &self,
         url: &str,
         normalizer: &UrlNormalizer,
+        service: Option<&str>,
     ) -> Option<Vec<&ResolvedEndpoint>> {
         let normalized = normalizer.normalize(url);
         if normalized.is_external || normalized.is_unresolved {
@@ -339,6 +370,7 @@ impl MountGraph {
         Some(
             self.endpoints
                 .iter()
+                .filter(|endpoint| Self::served_by(endpoint, service))
                 .filter(|endpoint| {
                     Self::paths_equal_modulo_param_names(&endpoint.full_path, &normalized.path)
                 })
@@ -346,6 +378,18 @@ impl MountGraph {
         )
     }
 
+    /// Whether `endpoint` belongs to `service` (`service_name ?? repo_name`);
+    /// always true without a service to restrict to.
+    fn served_by(endpoint: &ResolvedEndpoint, service: Option<&str>) -> bool {
+        service.is_none_or(|service| {
+            endpoint
+                .service_name
+                .as_deref()
+                .or(endpoint.repo_name.as_deref())
+                == Some(service)
+        })
+    }
+
     /// Whether two paths name the same declared route once param NAMES are
     /// ignored: same segment count, and each segment pair is literally equal
     /// or a param on BOTH sides (`:id` vs `:param`). Trailing `?` optional
@@ -476,6 +520,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         // Create config with internal domain
@@ -491,6 +537,7 @@ mod tests {
             "https://user-service.internal/users/123",
             "GET",
             &normalizer,
+            None,
         );
     }
 
@@ -752,6 +799,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let config = Config {
@@ -766,6 +815,7 @@ mod tests {
             "ENV_VAR:ORDER_SERVICE_URL:/orders",
             "POST",
             &normalizer,
+            None,
         );
         assert!(result.is_some());
         assert_eq!(result.unwrap().len(), 1);
@@ -775,6 +825,7 @@ mod tests {
             "ENV_VAR:STRIPE_API:/v1/charges",
             "POST",
             &normalizer,
+            None,
         );
         assert!(result.is_none());
     }
@@ -795,6 +846,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let config = Config {
@@ -808,6 +861,7 @@ mod tests {
             "${API_URL}/users/${userId}/orders/${orderId}",
             "GET",
             &normalizer,
+            None,
         );
         assert!(result.is_some());
         assert_eq!(result.unwrap().len(), 1);
@@ -829,6 +883,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
 
         let config = Config::default();
@@ -839,6 +895,7 @@ mod tests {
             "/users?page=1&limit=10",
             "GET",
             &normalizer,
+            None,
         );
         assert!(result.is_some());
         assert_eq!(result.unwrap().len(), 1);
@@ -863,13 +920,15 @@ mod tests {
                 service_name: None,
                 provenance: Default::default(),
                 evidence: carrick_match::MatchEvidence::RouteDefinition,
+                query_params: None,
+                request_headers: Vec::new(),
             });
         }
         let normalizer = UrlNormalizer::default_permissive();
 
         // The concrete route out-ranks the catch-all for its own path.
         let result = graph
-            .find_matching_endpoints_with_normalizer("/api/v1/chat/new", "GET", &normalizer)
+            .find_matching_endpoints_with_normalizer("/api/v1/chat/new", "GET", &normalizer, None)
             .expect("internal path");
         assert_eq!(
             result
@@ -882,7 +941,7 @@ mod tests {
 
         // With no better candidate, the catch-all is still the router truth.
         let result = graph
-            .find_matching_endpoints_with_normalizer("/api/v2/other", "GET", &normalizer)
+            .find_matching_endpoints_with_normalizer("/api/v2/other", "GET", &normalizer, None)
             .expect("internal path");
         assert_eq!(
             result
@@ -893,6 +952,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_matching_restricted_to_a_target_service() {
+        let mut graph = MountGraph::new();
+        for (service, full_path) in [("orders", "/:id"), ("users", "/:id"), ("users", "/me")] {
+            graph.endpoints.push(ResolvedEndpoint {
+                method: "GET".to_string(),
+                path: full_path.to_string(),
+                full_path: full_path.to_string(),
+                handler: None,
+                owner: "app".to_string(),
+                file_location: format!("{}/server.ts:1", service),
+                middleware_chain: vec![],
+                repo_name: None,
+                service_name: Some(service.to_string()),
+                provenance: Default::default(),
+                evidence: carrick_match::MatchEvidence::RouteDefinition,
+                query_params: None,
+                request_headers: Vec::new(),
+            });
+        }
+        let normalizer = UrlNormalizer::default_permissive();
+        let services = |result: Vec<&ResolvedEndpoint>| {
+            result
+                .iter()
+                .map(|e| e.service_name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let result = graph
+            .find_matching_endpoints_with_normalizer("/42", "GET", &normalizer, Some("orders"))
+            .unwrap();
+        assert_eq!(services(result), vec!["orders"]);
+        // `/me` is more specific, but only among the users service's routes.
+        let result = graph
+            .find_matching_endpoints_with_normalizer("/me", "GET", &normalizer, Some("orders"))
+            .unwrap();
+        assert_eq!(services(result), vec!["orders"]);
+        let result = graph
+            .find_exact_path_matches_any_method("/me", &normalizer, Some("orders"))
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
     // The path-matching unit tests (optional segments, symmetric params, param
     // syntaxes, wildcards) moved to crates/carrick-match with the functions
     // they exercise. CI runs them via `cargo test -p carrick-match`.
@@ -914,6 +1016,8 @@ mod tests {
             service_name: None,
             provenance: Default::default(),
             evidence: carrick_match::MatchEvidence::RouteDefinition,
+            query_params: None,
+            request_headers: Vec::new(),
         });
         crate::cloud_storage::CloudRepoData {
             repo_name: repo.to_string(),
@@ -1005,6 +1109,9 @@ mod tests {
                 call_kind: None,
                 repo_name: None,
                 service_name: None,
+                query_params: None,
+                sent_headers: None,
+                target_service: None,
             });
         let merged = MountGraph::merge_from_repos(&[repo]);
         assert_eq!(merged.data_calls.len(), 1);
@@ -18,6 +18,7 @@ use crate::{
         file_orchestrator::{FileCentricAnalysisResult, FileOrchestrator, ProcessingStats},
         framework_guidance_agent::{FrameworkGuidanceAgent, ProtocolGuidance},
     },
+    client_wrappers::ClientWrapper,
     framework_detector::{DetectionResult, FrameworkDetector},
     mount_graph::MountGraph,
     packages::Packages,
@@ -88,6 +89,7 @@ impl MultiAgentOrchestrator {
         graphql_producer_hints: &crate::graphql::GraphqlProducerHints,
         graphql_consumer_hints: &crate::graphql::GraphqlConsumerHints,
         normalizer: &UrlNormalizer,
+        client_wrappers: &[ClientWrapper],
     ) -> Result<MultiAgentAnalysisResult, Box<dyn std::error::Error>> {
         debug!("Starting AST-Gated File-Centric analysis...");
 
@@ -133,6 +135,7 @@ impl MultiAgentOrchestrator {
                 graphql_producer_hints,
                 graphql_consumer_hints,
                 normalizer,
+                client_wrappers,
             )
             .await?;
 
@@ -0,0 +1,970 @@
+//! Deterministic NestJS controller routes.
+//!
+//! A NestJS route is fully described by decorators:
+//!
+//! ```ts
+//! @Controller('orders')
+//! export class OrdersController {
+//!   @Post(':id/items')
+//!   addItem(@Param('id') id: string, @Body() dto: AddItemDto): Promise<Order> { … }
+//! }
+//! ```
+//!
+//! so the file analyzer has nothing to judge — method, path, and handler are
+//! literals. This module reads them straight off the AST instead:
+//!
+//! - [`NestRouting::collect`] gathers the repo-wide prefixes a controller's
+//!   paths sit under: a literal `app.setGlobalPrefix('api')` (with its
+//!   literal `exclude` entries) and `RouterModule.register([...])` module
+//!   paths, mapped onto controllers through `@Module({ controllers })`.
+//! - [`controller_routes`] walks one file's `@Controller` classes and returns
+//!   a [`NestRoute`] per literal route decorator (`@Get`, `@Post`, …, `@All`),
+//!   plus the spans of every decorator call those routes account for, so the
+//!   orchestrator can drop the matching gatekeeper candidates and skip the
+//!   LLM for a controller-only file.
+//!
+//! Types are anchored on two places only: the whole-body `@Body()`
+//! parameter (the request type) and the method's return type (the
+//! response). The type manifest has no slot for query or path-parameter
+//! types, so a `@Query()` / `@Param()` parameter's type is not sent to the
+//! sidecar: `@Query('key')` parameters feed the query-key check as literal
+//! keys, a bare `@Query()` DTO leaves the route's query keys unknown, and
+//! `@Param()` adds nothing beyond the route path's own `:params`.
+//!
+//! Only decorators imported from `@nestjs/common` count (a `@Controller`
+//! from `routing-controllers` means something else). Anything not literal —
+//! a constant prefix, a `version` option, `@Version()`, URI versioning via
+//! `app.enableVersioning` — is left to the LLM path unchanged.
+
+use crate::headers::is_auth_middleware;
+use crate::parser::parse_module;
+use crate::query_params::QueryContract;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use swc_common::{SourceMap, Span, Spanned, sync::Lrc};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// `pattern_matched` of every endpoint built from a NestJS controller.
+pub const NEST_CONTROLLER_PATTERN: &str = "nestjs-controller";
+
+const NEST_COMMON: &str = "@nestjs/common";
+
+/// Text a file must contain for [`NestRouting::collect`] to read it.
+pub const ROUTING_MARKERS: &[&str] = &[
+    "setGlobalPrefix",
+    "RouterModule",
+    "@Module",
+    "enableVersioning",
+];
+
+/// Route decorators and the HTTP method each declares.
+const ROUTE_DECORATORS: &[(&str, &str)] = &[
+    ("Get", "GET"),
+    ("Post", "POST"),
+    ("Put", "PUT"),
+    ("Patch", "PATCH"),
+    ("Delete", "DELETE"),
+    ("Options", "OPTIONS"),
+    ("Head", "HEAD"),
+    ("All", "ALL"),
+];
+
+/// Repo-wide path prefixes for NestJS controllers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NestRouting {
+    /// `app.setGlobalPrefix(...)`, when the repo sets exactly one literal.
+    global_prefix: Option<String>,
+    /// Literal `exclude` entries of the global prefix: route path and, for
+    /// `{ path, method: RequestMethod.X }` entries, the method it applies to.
+    global_prefix_exclude: Vec<(String, Option<String>)>,
+    /// Controller class → the `RouterModule` paths of the modules declaring it.
+    module_paths: HashMap<String, Vec<String>>,
+    /// `app.enableVersioning(...)` rewrites every path; such repos stay on
+    /// the LLM path.
+    versioned: bool,
+}
+
+impl NestRouting {
+    /// Scan the repo's files for global prefixes, `RouterModule` paths, and
+    /// module → controller wiring. Files without any of the three are not
+    /// parsed.
+    pub fn collect(files: &[PathBuf]) -> Self {
+        let mut visitor = RoutingVisitor::default();
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(file) else {
+                continue;
+            };
+            if !ROUTING_MARKERS.iter().any(|m| content.contains(m)) {
+                continue;
+            }
+            if let Some((_, module)) = parse_module(file) {
+                module.visit_with(&mut visitor);
+            }
+        }
+
+        let mut module_paths: HashMap<String, Vec<String>> = HashMap::new();
+        for (module, paths) in &visitor.router_paths {
+            for controller in visitor.module_controllers.get(module).into_iter().flatten() {
+                let entry = module_paths.entry(controller.clone()).or_default();
+                for path in paths {
+                    if !entry.contains(path) {
+                        entry.push(path.clone());
+                    }
+                }
+            }
+        }
+
+        // Two apps in one repo with different prefixes can't be told apart
+        // per controller; no prefix beats a wrong one.
+        let global_prefix = if visitor.global_prefixes.len() == 1 {
+            visitor.global_prefixes.into_iter().next()
+        } else {
+            None
+        };
+        NestRouting {
+            global_prefix,
+            global_prefix_exclude: visitor.global_prefix_exclude,
+            module_paths,
+            versioned: visitor.versioned,
+        }
+    }
+
+    /// Full paths of a route: global prefix (unless excluded), module path,
+    /// controller prefix, method path.
+    fn full_paths(
+        &self,
+        method: &str,
+        controller: &str,
+        controller_prefix: &str,
+        method_path: &str,
+    ) -> Vec<String> {
+        let module_paths = self
+            .module_paths
+            .get(controller)
+            .cloned()
+            .unwrap_or_else(|| vec![String::new()]);
+        module_paths
+            .iter()
+            .map(|module_path| {
+                let route = join_path(&[module_path, controller_prefix, method_path]);
+                match &self.global_prefix {
+                    Some(prefix) if !self.excluded(method, &route) => join_path(&[prefix, &route]),
+                    _ => route,
+                }
+            })
+            .collect()
+    }
+
+    fn excluded(&self, method: &str, route: &str) -> bool {
+        self.global_prefix_exclude.iter().any(|(path, only)| {
+            join_path(&[path]) == route && only.as_deref().is_none_or(|m| m == method)
+        })
+    }
+}
+
+/// One route declared by a controller method decorator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestRoute {
+    pub method: String,
+    /// Full path, prefixes included.
+    pub path: String,
+    pub controller: String,
+    /// Method name on the controller.
+    pub handler: String,
+    /// 1-based line the method declaration starts on, decorators included —
+    /// what the sidecar's line-based function lookup expects.
+    pub line_number: usize,
+    /// 1-based line of the method name. An LLM-emitted endpoint for the same
+    /// route lands between `line_number` and this.
+    pub name_line: usize,
+    pub span_start: u32,
+    pub span_end: u32,
+    /// Name of the whole-body `@Body()` parameter, if any — the only
+    /// parameter whose type is anchored (see the module docs).
+    pub body_param: Option<String>,
+    pub body_param_line: Option<usize>,
+    /// The keys its `@Query('key')` parameters read; `None` when a whole-query
+    /// `@Query()`, the raw `@Req()` or a custom parameter decorator could
+    /// read others.
+    pub query_params: Option<QueryContract>,
+    /// The headers its `@Headers('name')` parameters read, plus
+    /// `authorization` behind an auth guard (`@UseGuards(JwtAuthGuard)`).
+    pub request_headers: Vec<String>,
+}
+
+/// The literal routes of one file's controllers.
+#[derive(Debug, Default)]
+pub struct NestControllers {
+    pub routes: Vec<NestRoute>,
+    /// `(lo, hi)` spans of the decorator calls `routes` account for —
+    /// directly comparable with gatekeeper candidate spans.
+    pub owned_spans: HashSet<(u32, u32)>,
+}
+
+/// Extract the literal controller routes of one file.
+pub fn controller_routes(file_path: &Path, routing: &NestRouting) -> NestControllers {
+    if routing.versioned {
+        return NestControllers::default();
+    }
+    let Some((cm, module)) = parse_module(file_path) else {
+        return NestControllers::default();
+    };
+    let imports = NestImports::of(&module);
+    if imports.is_empty() {
+        return NestControllers::default();
+    }
+    let mut visitor = ControllerVisitor {
+        cm: &cm,
+        imports: &imports,
+        routing,
+        out: NestControllers::default(),
+    };
+    module.visit_with(&mut visitor);
+    visitor.out
+}
+
+/// `/`-joined, slash-normalized path with a leading `/`.
+fn join_path(segments: &[&str]) -> String {
+    let joined: Vec<&str> = segments
+        .iter()
+        .map(|s| s.trim().trim_matches('/'))
+        .filter(|s| !s.is_empty())
+        .collect();
+    format!("/{}", joined.join("/"))
+}
+
+/// A string literal, or a template literal with no substitutions.
+fn literal_str(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+            .quasis
+            .first()
+            .map(|q| q.cooked.as_ref().unwrap_or(&q.raw).to_string()),
+        Expr::Paren(p) => literal_str(&p.expr),
+        Expr::TsConstAssertion(c) => literal_str(&c.expr),
+        Expr::TsAs(a) => literal_str(&a.expr),
+        _ => None,
+    }
+}
+
+/// A literal path argument: a string, or an array of strings.
+fn literal_paths(expr: &Expr) -> Option<Vec<String>> {
+    match expr {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|e| match e {
+                Some(ExprOrSpread { spread: None, expr }) => literal_str(expr),
+                _ => None,
+            })
+            .collect(),
+        _ => literal_str(expr).map(|s| vec![s]),
+    }
+}
+
+fn prop_key(key: &PropName) -> Option<&str> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.as_ref()),
+        PropName::Str(s) => Some(s.value.as_ref()),
+        _ => None,
+    }
+}
+
+/// The value of `key` in an object literal.
+fn object_prop<'a>(object: &'a ObjectLit, key: &str) -> Option<&'a Expr> {
+    object.props.iter().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) if prop_key(&kv.key) == Some(key) => Some(&*kv.value),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+fn ident_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(i) => Some(i.sym.to_string()),
+        _ => None,
+    }
+}
+
+fn member_prop(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(p),
+            ..
+        }) => Some(p.sym.as_ref()),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct RoutingVisitor {
+    global_prefixes: BTreeSet<String>,
+    global_prefix_exclude: Vec<(String, Option<String>)>,
+    router_paths: HashMap<String, Vec<String>>,
+    module_controllers: HashMap<String, Vec<String>>,
+    versioned: bool,
+}
+
+impl RoutingVisitor {
+    fn record_global_prefix(&mut self, call: &CallExpr) {
+        let Some(prefix) = call.args.first().and_then(|a| literal_str(&a.expr)) else {
+            return;
+        };
+        self.global_prefixes.insert(prefix);
+        let Some(Expr::Object(options)) = call.args.get(1).map(|a| &*a.expr) else {
+            return;
+        };
+        let Some(Expr::Array(exclude)) = object_prop(options, "exclude") else {
+            return;
+        };
+        for element in exclude.elems.iter().flatten() {
+            match &*element.expr {
+                Expr::Object(entry) => {
+                    let Some(path) = object_prop(entry, "path").and_then(literal_str) else {
+                        continue;
+                    };
+                    let method = object_prop(entry, "method")
+                        .and_then(member_prop)
+                        .filter(|m| *m != "ALL")
+                        .map(str::to_string);
+                    self.global_prefix_exclude.push((path, method));
+                }
+                expr => {
+                    if let Some(path) = literal_str(expr) {
+                        self.global_prefix_exclude.push((path, None));
+                    }
+                }
+            }
+        }
+    }
+
+    /// `[{ path, module, children }]`, recursively; a child may also be a
+    /// bare module class, which inherits its parent's path.
+    fn record_routes(&mut self, routes: &ArrayLit, parent: &str) {
+        for element in routes.elems.iter().flatten() {
+            match &*element.expr {
+                Expr::Object(route) => {
+                    let path = object_prop(route, "path")
+                        .and_then(literal_str)
+                        .unwrap_or_default();
+                    let full = join_path(&[parent, &path]);
+                    if let Some(module) = object_prop(route, "module").and_then(ident_name) {
+                        self.router_paths
+                            .entry(module)
+                            .or_default()
+                            .push(full.clone());
+                    }
+                    if let Some(Expr::Array(children)) = object_prop(route, "children") {
+                        self.record_routes(children, &full);
+                    }
+                }
+                Expr::Ident(module) => {
+                    self.router_paths
+                        .entry(module.sym.to_string())
+                        .or_default()
+                        .push(join_path(&[parent]));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Visit for RoutingVisitor {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && let Expr::Member(member) = &**callee
+            && let MemberProp::Ident(prop) = &member.prop
+        {
+            match prop.sym.as_ref() {
+                "setGlobalPrefix" => self.record_global_prefix(call),
+                "enableVersioning" => self.versioned = true,
+                "register" if ident_name(&member.obj).as_deref() == Some("RouterModule") => {
+                    if let Some(Expr::Array(routes)) = call.args.first().map(|a| &*a.expr) {
+                        self.record_routes(routes, "");
+                    }
+                }
+                _ => {}
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, node: &ClassDecl) {
+        for decorator in &node.class.decorators {
+            let Expr::Call(call) = &*decorator.expr else {
+                continue;
+            };
+            let Callee::Expr(callee) = &call.callee else {
+                continue;
+            };
+            if ident_name(callee).as_deref() != Some("Module") {
+                continue;
+            }
+            let Some(Expr::Object(metadata)) = call.args.first().map(|a| &*a.expr) else {
+                continue;
+            };
+            let Some(Expr::Array(controllers)) = object_prop(metadata, "controllers") else {
+                continue;
+            };
+            self.module_controllers
+                .entry(node.ident.sym.to_string())
+                .or_default()
+                .extend(
+                    controllers
+                        .elems
+                        .iter()
+                        .flatten()
+                        .filter_map(|e| ident_name(&e.expr)),
+                );
+        }
+        node.visit_children_with(self);
+    }
+}
+
+/// How a file refers to `@nestjs/common` exports.
+#[derive(Default)]
+struct NestImports {
+    /// Local binding → exported name (`{ Get as HttpGet }`).
+    named: HashMap<String, String>,
+    /// `import * as common from '@nestjs/common'`.
+    namespaces: HashSet<String>,
+}
+
+impl NestImports {
+    fn of(module: &Module) -> Self {
+        let mut imports = NestImports::default();
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if import.src.value.as_ref() != NEST_COMMON {
+                continue;
+            }
+            for specifier in &import.specifiers {
+                match specifier {
+                    ImportSpecifier::Named(named) => {
+                        let imported = match &named.imported {
+                            Some(ModuleExportName::Ident(i)) => i.sym.to_string(),
+                            Some(ModuleExportName::Str(s)) => s.value.to_string(),
+                            None => named.local.sym.to_string(),
+                        };
+                        imports.named.insert(named.local.sym.to_string(), imported);
+                    }
+                    ImportSpecifier::Namespace(ns) => {
+                        imports.namespaces.insert(ns.local.sym.to_string());
+                    }
+                    ImportSpecifier::Default(_) => {}
+                }
+            }
+        }
+        imports
+    }
+
+    fn is_empty(&self) -> bool {
+        self.named.is_empty() && self.namespaces.is_empty()
+    }
+
+    /// The `@nestjs/common` export a decorator call refers to, with its
+    /// arguments.
+    fn resolve<'a>(&'a self, decorator: &'a Decorator) -> Option<(&'a str, &'a [ExprOrSpread])> {
+        let Expr::Call(call) = &*decorator.expr else {
+            return None;
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let name = match &**callee {
+            Expr::Ident(i) => self.named.get(i.sym.as_ref())?.as_str(),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if ident_name(obj).is_some_and(|o| self.namespaces.contains(&o)) => {
+                prop.sym.as_ref()
+            }
+            _ => return None,
+        };
+        Some((name, &call.args))
+    }
+}
+
+struct ControllerVisitor<'a> {
+    cm: &'a Lrc<SourceMap>,
+    imports: &'a NestImports,
+    routing: &'a NestRouting,
+    out: NestControllers,
+}
+
+impl ControllerVisitor<'_> {
+    fn line(&self, span: Span) -> usize {
+        self.cm.lookup_char_pos(span.lo).line
+    }
+
+    fn own(&mut self, decorators: &[Decorator]) {
+        for decorator in decorators {
+            if let Expr::Call(call) = &*decorator.expr {
+                self.out
+                    .owned_spans
+                    .insert((call.span.lo.0, call.span.hi.0));
+            }
+        }
+    }
+
+    /// The literal prefixes of a `@Controller(...)` decorator; `None` when
+    /// the class is not a controller or its prefix isn't literal.
+    fn controller_prefixes(&self, class: &Class) -> Option<Vec<String>> {
+        let args = class
+            .decorators
+            .iter()
+            .find_map(|d| match self.imports.resolve(d) {
+                Some(("Controller", args)) => Some(args),
+                _ => None,
+            })?;
+        let Some(first) = args.first() else {
+            return Some(vec![String::new()]);
+        };
+        match &*first.expr {
+            Expr::Object(options) => {
+                if object_prop(options, "version").is_some() {
+                    return None;
+                }
+                match object_prop(options, "path") {
+                    Some(path) => literal_paths(path),
+                    None => Some(vec![String::new()]),
+                }
+            }
+            expr => literal_paths(expr),
+        }
+    }
+
+    fn visit_controller(&mut self, name: &str, class: &Class) {
+        let Some(prefixes) = self.controller_prefixes(class) else {
+            return;
+        };
+        self.own(&class.decorators);
+        let guarded = self.auth_guarded(&class.decorators);
+        for member in &class.body {
+            match member {
+                ClassMember::Method(method) => self.visit_route(name, &prefixes, guarded, method),
+                ClassMember::ClassProp(prop) => self.own(&prop.decorators),
+                ClassMember::Constructor(ctor) => {
+                    for param in &ctor.params {
+                        match param {
+                            ParamOrTsParamProp::Param(p) => self.own(&p.decorators),
+                            ParamOrTsParamProp::TsParamProp(p) => self.own(&p.decorators),
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_route(
+        &mut self,
+        controller: &str,
+        prefixes: &[String],
+        guarded: bool,
+        method: &ClassMethod,
+    ) {
+        let decorators = &method.function.decorators;
+        let mut route = None;
+        for decorator in decorators {
+            match self.imports.resolve(decorator) {
+                Some(("Version", _)) => return,
+                Some((name, args)) => {
+                    if let Some((_, verb)) = ROUTE_DECORATORS.iter().find(|(d, _)| *d == name) {
+                        route = Some((*verb, args));
+                    }
+                }
+                None => {}
+            }
+        }
+        let Some((verb, args)) = route else {
+            return;
+        };
+        let method_paths = match args.first() {
+            None => vec![String::new()],
+            Some(arg) => match literal_paths(&arg.expr) {
+                Some(paths) => paths,
+                None => return,
+            },
+        };
+        let Some(handler) = prop_key(&method.key) else {
+            return;
+        };
+
+        let start = decorators
+            .iter()
+            .map(|d| d.span.lo)
+            .chain([method.span.lo])
+            .min()
+            .unwrap_or(method.span.lo);
+        let line_number = self.cm.lookup_char_pos(start).line;
+        let name_line = self.line(method.key.span());
+
+        let body = method.function.params.iter().find_map(|param| {
+            let whole_body = param.decorators.iter().any(
+                |d| matches!(self.imports.resolve(d), Some(("Body", args)) if args.is_empty()),
+            );
+            match &param.pat {
+                Pat::Ident(ident) if whole_body => {
+                    Some((ident.id.sym.to_string(), self.line(param.span)))
+                }
+                _ => None,
+            }
+        });
+
+        let query_params = self.query_params(method);
+        let mut request_headers = self.request_headers(method);
+        if guarded || self.auth_guarded(decorators) {
+            request_headers.insert("authorization".to_string());
+        }
+        let request_headers: Vec<String> = request_headers.into_iter().collect();
+
+        for prefix in prefixes {
+            for method_path in &method_paths {
+                for path in self
+                    .routing
+                    .full_paths(verb, controller, prefix, method_path)
+                {
+                    self.out.routes.push(NestRoute {
+                        method: verb.to_string(),
+                        path,
+                        controller: controller.to_string(),
+                        handler: handler.to_string(),
+                        line_number,
+                        name_line,
+                        span_start: start.0,
+                        span_end: method.span.hi.0,
+                        body_param: body.as_ref().map(|(name, _)| name.clone()),
+                        body_param_line: body.as_ref().map(|(_, line)| *line),
+                        query_params: query_params.clone(),
+                        request_headers: request_headers.clone(),
+                    });
+                }
+            }
+        }
+        self.own(decorators);
+        for param in &method.function.params {
+            self.own(&param.decorators);
+        }
+    }
+}
+
+impl ControllerVisitor<'_> {
+    /// The query keys a controller method's parameters read.
+    fn query_params(&self, method: &ClassMethod) -> Option<QueryContract> {
+        let mut keys = Vec::new();
+        for decorator in method
+            .function
+            .params
+            .iter()
+            .flat_map(|param| &param.decorators)
+        {
+            match self.imports.resolve(decorator)? {
+                ("Query", [key, ..]) => keys.push(literal_str(&key.expr)?),
+                ("Body" | "Param" | "Headers" | "Ip" | "HostParam" | "Session", _) => {}
+                _ => return None,
+            }
+        }
+        Some(QueryContract::new(keys, Vec::new()))
+    }
+
+    /// The headers a controller method's `@Headers('name')` parameters read,
+    /// lowercased.
+    fn request_headers(&self, method: &ClassMethod) -> BTreeSet<String> {
+        method
+            .function
+            .params
+            .iter()
+            .flat_map(|param| &param.decorators)
+            .filter_map(|decorator| match self.imports.resolve(decorator)? {
+                ("Headers", [name, ..]) => literal_str(&name.expr).map(|n| n.to_lowercase()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether `@UseGuards(...)` among `decorators` names an auth guard:
+    /// `JwtAuthGuard`, `new ApiKeyAuthGuard()`, or Passport's
+    /// `AuthGuard('jwt')` by its strategy (`AuthGuard('local')` is a login).
+    fn auth_guarded(&self, decorators: &[Decorator]) -> bool {
+        decorators.iter().any(|decorator| {
+            let Some(("UseGuards", guards)) = self.imports.resolve(decorator) else {
+                return false;
+            };
+            guards.iter().any(|guard| {
+                let name = match &*guard.expr {
+                    Expr::Call(call) => match call.args.first() {
+                        Some(strategy) => literal_str(&strategy.expr),
+                        None => match &call.callee {
+                            Callee::Expr(callee) => ident_name(callee),
+                            _ => None,
+                        },
+                    },
+                    Expr::New(new) => ident_name(&new.callee),
+                    expr => ident_name(expr),
+                };
+                name.is_some_and(|name| is_auth_middleware(&name))
+            })
+        })
+    }
+}
+
+impl Visit for ControllerVisitor<'_> {
+    fn visit_class_decl(&mut self, node: &ClassDecl) {
+        self.visit_controller(node.ident.sym.as_ref(), &node.class);
+    }
+
+    fn visit_class_expr(&mut self, node: &ClassExpr) {
+        if let Some(ident) = &node.ident {
+            self.visit_controller(ident.sym.as_ref(), &node.class);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, source: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, source).unwrap();
+        path
+    }
+
+    fn routes(controllers: &NestControllers) -> Vec<(String, String, String)> {
+        controllers
+            .routes
+            .iter()
+            .map(|r| (r.method.clone(), r.path.clone(), r.handler.clone()))
+            .collect()
+    }
+
+    const ORDERS_CONTROLLER: &str = r#"import { Body, Controller, Get, Param, Post, HttpCode } from '@nestjs/common';
+import { CreateOrderDto } from './dto';
+
+@Controller('orders')
+export class OrdersController {
+  constructor(private readonly orders: OrdersService) {}
+
+  @Get()
+  list(): Promise<Order[]> {
+    return this.orders.list();
+  }
+
+  @Post()
+  @HttpCode(201)
+  create(@Body() dto: CreateOrderDto): Promise<Order> {
+    return this.orders.create(dto);
+  }
+
+  @Get(':id')
+  get(@Param('id') id: string) {
+    return this.orders.get(id);
+  }
+
+  @Get(ROUTE)
+  dynamic() {}
+
+  helper() {}
+}
+"#;
+
+    #[test]
+    fn literal_routes_and_body_param() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = write(dir.path(), "orders.controller.ts", ORDERS_CONTROLLER);
+        let out = controller_routes(&file, &NestRouting::default());
+        assert_eq!(
+            routes(&out),
+            vec![
+                ("GET".into(), "/orders".into(), "list".into()),
+                ("POST".into(), "/orders".into(), "create".into()),
+                ("GET".into(), "/orders/:id".into(), "get".into()),
+            ]
+        );
+        let create = &out.routes[1];
+        assert_eq!(create.controller, "OrdersController");
+        // Starts on the first decorator, `@Post()`.
+        assert_eq!(create.line_number, 13);
+        assert_eq!(create.name_line, 15);
+        assert_eq!(create.body_param.as_deref(), Some("dto"));
+        assert_eq!(create.body_param_line, Some(15));
+        assert_eq!(out.routes[2].body_param, None);
+        // Controller + the three routes' decorator calls (`@HttpCode`,
+        // `@Body`, `@Param` too), but not the non-literal `@Get(ROUTE)`,
+        // which stays with the LLM.
+        assert_eq!(out.owned_spans.len(), 7);
+    }
+
+    #[test]
+    fn query_decorators_give_the_read_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = write(
+            dir.path(),
+            "users.controller.ts",
+            r#"import { Controller, Get, Param, Query, Req } from '@nestjs/common';
+
+@Controller('users')
+export class UsersController {
+  @Get()
+  list(@Query('limit') limit: string, @Query('cursor') cursor?: string) {}
+
+  @Get('search')
+  search(@Query() filters: SearchDto) {}
+
+  @Get('raw')
+  raw(@Req() req: Request) {}
+
+  @Get(':id')
+  get(@Param('id') id: string) {}
+}
+"#,
+        );
+        let out = controller_routes(&file, &NestRouting::default());
+        let keys: Vec<Option<Vec<String>>> = out
+            .routes
+            .iter()
+            .map(|r| r.query_params.as_ref().map(|q| q.keys.clone()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                Some(vec!["cursor".to_string(), "limit".to_string()]),
+                None,
+                None,
+                Some(vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn header_decorators_and_auth_guards_give_the_read_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = write(
+            dir.path(),
+            "orders.controller.ts",
+            r#"import { Controller, Get, Post, Headers, UseGuards } from '@nestjs/common';
+import { AuthGuard } from '@nestjs/passport';
+
+@Controller('orders')
+export class OrdersController {
+  @Post()
+  create(@Headers('X-Tenant-Id') tenant: string) {}
+
+  @Get()
+  @UseGuards(AuthGuard('jwt'))
+  list() {}
+
+  @Post('login')
+  @UseGuards(AuthGuard('local'))
+  login() {}
+}
+
+@Controller('admin')
+@UseGuards(JwtAuthGuard)
+export class AdminController {
+  @Get()
+  index() {}
+}
+"#,
+        );
+        let out = controller_routes(&file, &NestRouting::default());
+        let headers: Vec<(String, Vec<String>)> = out
+            .routes
+            .iter()
+            .map(|r| (r.path.clone(), r.request_headers.clone()))
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                ("/orders".to_string(), vec!["x-tenant-id".to_string()]),
+                ("/orders".to_string(), vec!["authorization".to_string()]),
+                ("/orders/login".to_string(), vec![]),
+                ("/admin".to_string(), vec!["authorization".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn global_prefix_and_router_module_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = write(
+            dir.path(),
+            "main.ts",
+            "async function bootstrap() {\n  const app = await NestFactory.create(AppModule);\n  app.setGlobalPrefix('api/', { exclude: ['health', { path: 'ping', method: RequestMethod.GET }] });\n}\n",
+        );
+        let app = write(
+            dir.path(),
+            "app.module.ts",
+            "@Module({ imports: [RouterModule.register([{ path: 'admin', module: AdminModule, children: [{ path: 'billing', module: BillingModule }] }])] })\nexport class AppModule {}\n@Module({ controllers: [InvoicesController] })\nexport class BillingModule {}\n",
+        );
+        let routing = NestRouting::collect(&[main, app]);
+
+        let invoices = write(
+            dir.path(),
+            "invoices.controller.ts",
+            "import { Controller, Get as Fetch } from '@nestjs/common';\n@Controller({ path: ['invoices', 'bills'] })\nexport class InvoicesController {\n  @Fetch([':id'])\n  one() {}\n}\n",
+        );
+        assert_eq!(
+            routes(&controller_routes(&invoices, &routing)),
+            vec![
+                (
+                    "GET".into(),
+                    "/api/admin/billing/invoices/:id".into(),
+                    "one".into()
+                ),
+                (
+                    "GET".into(),
+                    "/api/admin/billing/bills/:id".into(),
+                    "one".into()
+                ),
+            ]
+        );
+
+        let health = write(
+            dir.path(),
+            "health.controller.ts",
+            "import * as common from '@nestjs/common';\n@common.Controller()\nexport class HealthController {\n  @common.Get('health') check() {}\n  @common.Get('ping') ping() {}\n  @common.Post('ping') pong() {}\n}\n",
+        );
+        assert_eq!(
+            routes(&controller_routes(&health, &routing)),
+            vec![
+                ("GET".into(), "/health".into(), "check".into()),
+                ("GET".into(), "/ping".into(), "ping".into()),
+                ("POST".into(), "/api/ping".into(), "pong".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_nest_and_versioned_controllers_are_left_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let other = write(
+            dir.path(),
+            "users.controller.ts",
+            "import { Controller, Get } from 'routing-controllers';\n@Controller('/users')\nexport class UsersController {\n  @Get('/') all() {}\n}\n",
+        );
+        assert!(
+            controller_routes(&other, &NestRouting::default())
+                .routes
+                .is_empty()
+        );
+
+        let versioned = write(
+            dir.path(),
+            "cats.controller.ts",
+            "import { Controller, Get, Version } from '@nestjs/common';\n@Controller({ path: 'cats', version: '1' })\nexport class CatsController {\n  @Get() all() {}\n}\n@Controller('dogs')\nexport class DogsController {\n  @Version('2')\n  @Get() all() {}\n}\n",
+        );
+        let out = controller_routes(&versioned, &NestRouting::default());
+        assert!(out.routes.is_empty());
+        assert_eq!(out.owned_spans.len(), 1, "only the dogs @Controller call");
+    }
+}
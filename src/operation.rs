@@ -347,6 +347,23 @@ impl OperationKey {
         }
     }
 
+    /// Inverse of [`OperationKey::display_labels`]: rebuild the key from a
+    /// report `(label, name)` pair. Findings carry only the display labels,
+    /// so their fingerprints recover the canonical key through this. Any
+    /// label that is not a GraphQL kind, socket direction, or `PUBSUB` is an
+    /// HTTP method.
+    pub fn from_display_labels(label: &str, name: &str) -> Self {
+        match label {
+            "QUERY" => Self::graphql(GraphqlOperationKind::Query, name),
+            "MUTATION" => Self::graphql(GraphqlOperationKind::Mutation, name),
+            "SUBSCRIPTION" => Self::graphql(GraphqlOperationKind::Subscription, name),
+            "CLIENT->SERVER" => Self::socket(name, SocketDirection::ClientToServer),
+            "SERVER->CLIENT" => Self::socket(name, SocketDirection::ServerToClient),
+            "PUBSUB" => Self::pubsub(name),
+            method => Self::http(method, name),
+        }
+    }
+
     /// Stable identity string for hashing and dedup keys.
     pub fn canonical(&self) -> String {
         match self {
@@ -394,6 +411,19 @@ mod tests {
         assert_eq!(key.as_http(), Some(("UNKNOWN", "/users")));
     }
 
+    #[test]
+    fn display_labels_round_trip_for_every_protocol() {
+        for key in [
+            OperationKey::http("get", "/users/:id"),
+            OperationKey::graphql(GraphqlOperationKind::Mutation, "createUser"),
+            OperationKey::socket("chat:message", SocketDirection::ServerToClient),
+            OperationKey::pubsub("orders.created"),
+        ] {
+            let (label, name) = key.display_labels();
+            assert_eq!(OperationKey::from_display_labels(&label, &name), key);
+        }
+    }
+
     #[test]
     fn serde_round_trip_carries_protocol_tag() {
         let key = OperationKey::http("POST", "/api/orders");
@@ -1,7 +1,11 @@
 use std::fs;
 use std::path::Path;
-use swc_common::{FileName, GLOBALS, Globals, Mark, SourceMap, errors::Handler, sync::Lrc};
-use swc_ecma_ast::Module;
+use swc_common::{
+    FileName, GLOBALS, Globals, Mark, SourceMap,
+    errors::{ColorConfig, Handler},
+    sync::Lrc,
+};
+use swc_ecma_ast::{Expr, Module};
 use swc_ecma_parser::{EsSyntax, Parser, StringInput, Syntax, TsSyntax, lexer::Lexer};
 use swc_ecma_transforms_base::resolver;
 use swc_ecma_visit::VisitMutWith;
@@ -94,6 +98,31 @@ pub fn parse_file(
     }
 }
 
+/// Parse a file on its own source map, with diagnostics silenced. The
+/// module is resolved like [`parse_file`]'s, so same-named bindings in
+/// different scopes carry distinct `to_id()`s.
+pub fn parse_module(file_path: &Path) -> Option<(Lrc<SourceMap>, Module)> {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let handler =
+        Handler::with_tty_emitter(ColorConfig::Never, false, false, Some(source_map.clone()));
+    let module = parse_file(file_path, &source_map, &handler)?;
+    Some((source_map, module))
+}
+
+/// Strip parentheses and TypeScript-only wrappers (`as`, `satisfies`,
+/// `as const`, `!`, `<T>x`), which don't change the runtime value.
+pub fn unwrap_expr(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(e) => unwrap_expr(&e.expr),
+        Expr::TsAs(e) => unwrap_expr(&e.expr),
+        Expr::TsSatisfies(e) => unwrap_expr(&e.expr),
+        Expr::TsNonNull(e) => unwrap_expr(&e.expr),
+        Expr::TsTypeAssertion(e) => unwrap_expr(&e.expr),
+        Expr::TsConstAssertion(e) => unwrap_expr(&e.expr),
+        _ => expr,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::parse_file;
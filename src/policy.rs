@@ -0,0 +1,726 @@
+//! Repo-level finding policy: the `policy` block of `carrick.json`.
+//!
+//! Each finding kind has a fixed default severity (see
+//! [`Finding::severity`]). A team can re-grade them:
+//!
+//! ```json
+//! {
+//!   "policy": {
+//!     "severity": { "env_var_call": "gap", "orphaned_endpoint": "advisory" },
+//!     "overrides": [
+//!       { "path": "/internal/**", "severity": "advisory" },
+//!       { "kind": "type_mismatch", "service": "legacy-billing", "severity": "gap" }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! `severity` maps a kind to its new severity. It re-grades only findings
+//! still at their kind's default: a type mismatch the analyzer downgraded
+//! because the consumer reads none of the mismatched fields, or a violation
+//! graded by its rule, keeps that grade. `overrides` are applied in
+//! order after it, and the last matching entry wins; an entry matches when
+//! every selector it sets (`kind`, `path` glob, `service`) matches the
+//! finding. `path` is matched against the API path a finding reports
+//! (`/internal/users/:id`), not the source file it was found in. Together
+//! with `--fail-on` this makes the scanner itself a CI gate (see
+//! [`count_failing`]).
+//!
+//! The block also carries governance `rules`, evaluated by the analyzer
+//! over the merged index and reported as [`Issue::PolicyViolation`]s:
+//!
+//! ```json
+//! {
+//!   "policy": {
+//!     "teams": { "payments": ["billing", "ledger"] },
+//!     "rules": [
+//!       { "id": "admin-auth", "check": "require_middleware", "path": "/admin/**", "middleware": ["requireAuth", "passport.*"] },
+//!       { "id": "internal-only", "check": "forbid_call", "path": "/internal/**", "crossTeam": true },
+//!       { "id": "typed-posts", "check": "require_request_type", "method": "POST" }
+//!     ]
+//!   }
+//! }
+//! ```
+
+use crate::analyzer::CrossRepoMatch;
+use crate::cloud_storage::{ManifestRole, ManifestTypeKind, ManifestTypeState, TypeManifestEntry};
+use crate::findings::{Finding, Issue, Severity};
+use crate::mount_graph::{MountGraph, ResolvedEndpoint};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Policy {
+    /// Finding kind → severity.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity: BTreeMap<String, Severity>,
+    /// Scoped re-gradings, applied in order after `severity`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<SeverityOverride>,
+    /// Governance rules, reported as `policy_violation` findings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<PolicyRule>,
+    /// Team → the service ids it owns, for `crossTeam` rules. A service in
+    /// no team is a team of its own.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub teams: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeverityOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Glob over the finding's API path (the route or call path it reports,
+    /// `/internal/users/:id`), never a source file: `*` matches within one
+    /// segment, `**` any number of segments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    pub severity: Severity,
+}
+
+/// One governance rule. `path` (an API-path glob, as in overrides) and
+/// `method` scope it; both default to everything.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyRule {
+    pub id: String,
+    pub check: RuleCheck,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// `require_middleware`: accepted middleware names, `*` wildcards
+    /// allowed; any one of them in the chain satisfies the rule.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub middleware: Vec<String>,
+    /// `forbid_call`: only flag calls between services of different teams.
+    #[serde(default)]
+    pub cross_team: bool,
+    /// Severity of this rule's violations; `gap` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleCheck {
+    /// Every matching route has a matching middleware in its chain.
+    RequireMiddleware,
+    /// No other service calls a matching route.
+    ForbidCall,
+    /// Every matching route accepts a typed request body.
+    RequireRequestType,
+}
+
+impl Policy {
+    pub fn is_empty(&self) -> bool {
+        self.severity.is_empty() && self.overrides.is_empty()
+    }
+
+    /// Reject kinds no finding has, so a typo doesn't silently re-grade
+    /// nothing.
+    pub fn validate(&self) -> Result<(), String> {
+        let kinds = self
+            .severity
+            .keys()
+            .chain(self.overrides.iter().filter_map(|o| o.kind.as_ref()));
+        for kind in kinds {
+            if !Finding::KINDS.contains(&kind.as_str()) {
+                return Err(format!(
+                    "unknown finding kind '{}' (expected one of: {})",
+                    kind,
+                    Finding::KINDS.join(", ")
+                ));
+            }
+        }
+        let mut ids = HashSet::new();
+        for rule in &self.rules {
+            if rule.id.trim().is_empty() {
+                return Err("policy rule with an empty id".to_string());
+            }
+            if !ids.insert(rule.id.as_str()) {
+                return Err(format!("duplicate policy rule id '{}'", rule.id));
+            }
+            if rule.check == RuleCheck::RequireMiddleware && rule.middleware.is_empty() {
+                return Err(format!(
+                    "policy rule '{}' requires middleware but lists none",
+                    rule.id
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate `rules` over the merged index: routes and their middleware
+    /// chains from `graph`, service-to-service calls from `matches`, and
+    /// request types from `manifest`.
+    pub fn evaluate_rules(
+        &self,
+        graph: &MountGraph,
+        matches: &[CrossRepoMatch],
+        manifest: &[TypeManifestEntry],
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for rule in &self.rules {
+            let violations = match rule.check {
+                RuleCheck::RequireMiddleware => graph
+                    .endpoints
+                    .iter()
+                    .filter(|e| rule.applies_to(&e.method, &e.full_path))
+                    .filter(|e| {
+                        !e.middleware_chain.iter().any(|name| {
+                            rule.middleware
+                                .iter()
+                                .any(|pattern| segment_matches(pattern, name))
+                        })
+                    })
+                    .map(|e| {
+                        let chain = if e.middleware_chain.is_empty() {
+                            "no middleware".to_string()
+                        } else {
+                            format!("chain: {}", e.middleware_chain.join(", "))
+                        };
+                        endpoint_violation(
+                            rule,
+                            e,
+                            format!(
+                                "no middleware matching {} ({})",
+                                rule.middleware.join(" | "),
+                                chain
+                            ),
+                        )
+                    })
+                    .collect(),
+                RuleCheck::ForbidCall => self.forbidden_calls(rule, matches),
+                RuleCheck::RequireRequestType => graph
+                    .endpoints
+                    .iter()
+                    .filter(|e| rule.applies_to(&e.method, &e.full_path))
+                    .filter(|e| !has_typed_request(e, manifest))
+                    .map(|e| endpoint_violation(rule, e, "request body is untyped".to_string()))
+                    .collect(),
+            };
+            findings.extend(violations.into_iter().map(|f| match rule.severity {
+                Some(severity) => f.with_severity(severity),
+                None => f,
+            }));
+        }
+        findings
+    }
+
+    /// Producer→consumer edges into a rule's routes, one finding per
+    /// (route, calling service).
+    fn forbidden_calls(&self, rule: &PolicyRule, matches: &[CrossRepoMatch]) -> Vec<Finding> {
+        let mut grouped: BTreeMap<(String, String, String, String), BTreeSet<String>> =
+            BTreeMap::new();
+        for edge in matches {
+            if edge.relationship != carrick_match::MatchRelationship::ProducerConsumer {
+                continue;
+            }
+            let mut parts = edge.producer_key.splitn(3, '|');
+            let (Some("http"), Some(method), Some(path)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+            if !rule.applies_to(method, path)
+                || (rule.cross_team
+                    && self.team_of(&edge.producer_repo) == self.team_of(&edge.consumer_repo))
+            {
+                continue;
+            }
+            grouped
+                .entry((
+                    method.to_string(),
+                    path.to_string(),
+                    edge.producer_repo.clone(),
+                    edge.consumer_repo.clone(),
+                ))
+                .or_default()
+                .extend(edge.consumer_location.clone());
+        }
+        grouped
+            .into_iter()
+            .map(|((method, path, producer, consumer), sites)| {
+                Finding::policy_violation(
+                    &rule.id,
+                    method,
+                    path,
+                    Some(producer.clone()),
+                    Some(consumer.clone()),
+                    format!("`{}` calls this route of `{}`", consumer, producer),
+                    sites.into_iter().collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn team_of<'a>(&'a self, service: &'a str) -> &'a str {
+        self.teams
+            .iter()
+            .find(|(_, members)| members.iter().any(|m| m == service))
+            .map_or(service, |(team, _)| team.as_str())
+    }
+
+    /// Re-grade `findings` per the policy.
+    pub fn apply(&self, findings: Vec<Finding>) -> Vec<Finding> {
+        if self.is_empty() {
+            return findings;
+        }
+        findings
+            .into_iter()
+            .map(|finding| match self.severity_for(&finding) {
+                Some(severity) if severity != finding.severity() => finding.with_severity(severity),
+                _ => finding,
+            })
+            .collect()
+    }
+
+    fn severity_for(&self, finding: &Finding) -> Option<Severity> {
+        // The kind map re-grades the kind's default only; a finding graded
+        // when it was raised keeps that grade unless an override names it.
+        let by_kind = if finding.is_regraded() {
+            None
+        } else {
+            self.severity.get(finding.kind()).copied()
+        };
+        self.overrides
+            .iter()
+            .rev()
+            .find(|o| o.matches(finding))
+            .map(|o| o.severity)
+            .or(by_kind)
+    }
+}
+
+impl PolicyRule {
+    fn applies_to(&self, method: &str, path: &str) -> bool {
+        self.method
+            .as_ref()
+            .is_none_or(|m| m.eq_ignore_ascii_case(method))
+            && self
+                .path
+                .as_ref()
+                .is_none_or(|glob| glob_matches(glob, path))
+    }
+}
+
+fn endpoint_violation(rule: &PolicyRule, endpoint: &ResolvedEndpoint, message: String) -> Finding {
+    Finding::policy_violation(
+        &rule.id,
+        &endpoint.method,
+        &endpoint.full_path,
+        endpoint
+            .service_name
+            .clone()
+            .or_else(|| endpoint.repo_name.clone()),
+        None,
+        message,
+        vec![endpoint.file_location.clone()],
+    )
+}
+
+/// Whether the manifest holds a resolved request type for this route.
+fn has_typed_request(endpoint: &ResolvedEndpoint, manifest: &[TypeManifestEntry]) -> bool {
+    manifest.iter().any(|entry| {
+        entry.role == ManifestRole::Producer
+            && entry.type_kind == ManifestTypeKind::Request
+            && entry.type_state != ManifestTypeState::Unknown
+            && entry.key.as_http().is_some_and(|(method, path)| {
+                method.eq_ignore_ascii_case(&endpoint.method) && path == endpoint.full_path
+            })
+    })
+}
+
+impl SeverityOverride {
+    fn matches(&self, finding: &Finding) -> bool {
+        self.kind.as_ref().is_none_or(|k| k == finding.kind())
+            && self.path.as_ref().is_none_or(|glob| {
+                finding_path(finding).is_some_and(|path| glob_matches(glob, path))
+            })
+            && self
+                .service
+                .as_ref()
+                .is_none_or(|s| finding_services(finding).contains(&s.as_str()))
+    }
+}
+
+/// How many findings are at or above `threshold` — the `--fail-on` gate.
+pub fn count_failing(findings: &[Finding], threshold: Severity) -> usize {
+    findings
+        .iter()
+        .filter(|f| f.severity().at_least(threshold))
+        .count()
+}
+
+fn finding_path(finding: &Finding) -> Option<&str> {
+    match &finding.issue {
+        Issue::TypeMismatch { path, .. }
+        | Issue::MethodMismatch { path, .. }
+        | Issue::MissingEndpoint { path, .. }
+        | Issue::OrphanedEndpoint { path, .. }
+        | Issue::EnvVarCall { path, .. }
+        | Issue::ProfileTargetDrift { path, .. }
+        | Issue::SharedExternalContract { path, .. }
+        | Issue::PolicyViolation { path, .. }
+        | Issue::BreakingChange { path, .. }
+        | Issue::QueryParamMismatch { path, .. }
+        | Issue::HeaderMismatch { path, .. } => Some(path),
+        Issue::DependencyConflict { .. } => None,
+    }
+}
+
+/// Service ids a finding names: its producer service, the repos sharing an
+/// external contract, or the repos pinning a conflicting dependency.
+fn finding_services(finding: &Finding) -> Vec<&str> {
+    match &finding.issue {
+        Issue::TypeMismatch { service, .. }
+        | Issue::MethodMismatch { service, .. }
+        | Issue::MissingEndpoint { service, .. }
+        | Issue::OrphanedEndpoint { service, .. }
+        | Issue::PolicyViolation { service, .. }
+        | Issue::QueryParamMismatch { service, .. }
+        | Issue::HeaderMismatch { service, .. } => service.as_deref().into_iter().collect(),
+        Issue::SharedExternalContract { repos, .. } => repos.iter().map(String::as_str).collect(),
+        Issue::BreakingChange {
+            service, consumers, ..
+        } => service
+            .as_deref()
+            .into_iter()
+            .chain(consumers.iter().map(String::as_str))
+            .collect(),
+        Issue::DependencyConflict { versions, .. } => {
+            versions.iter().map(|v| v.repo.as_str()).collect()
+        }
+        Issue::EnvVarCall { .. } | Issue::ProfileTargetDrift { .. } => Vec::new(),
+    }
+}
+
+/// Segment-wise glob match. `**` spans zero or more whole segments; `*`
+/// matches any run of characters within one segment.
+fn glob_matches(glob: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = glob.trim_matches('/').split('/').collect();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match_segments(&pattern, &segments)
+}
+
+fn match_segments(pattern: &[&str], segments: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => {
+            (0..=segments.len()).any(|skip| match_segments(rest, &segments[skip..]))
+        }
+        Some((first, rest)) => segments.split_first().is_some_and(|(segment, remaining)| {
+            segment_matches(first, segment) && match_segments(rest, remaining)
+        }),
+    }
+}
+
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    let Some((head, tail)) = pattern.split_once('*') else {
+        return pattern == segment;
+    };
+    let Some(rest) = segment.strip_prefix(head) else {
+        return false;
+    };
+    (0..=rest.len())
+        .filter(|&i| rest.is_char_boundary(i))
+        .any(|i| segment_matches(tail, &rest[i..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(json: &str) -> Policy {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn kind_defaults_then_last_matching_override_win() {
+        let policy = policy(
+            r#"{
+                "severity": { "env_var_call": "gap", "orphaned_endpoint": "advisory" },
+                "overrides": [
+                    { "path": "/internal/**", "severity": "advisory" },
+                    { "kind": "type_mismatch", "service": "legacy", "severity": "gap" },
+                    { "path": "/internal/admin/*", "severity": "risk" }
+                ]
+            }"#,
+        );
+        policy.validate().unwrap();
+        let findings = policy.apply(vec![
+            Finding::env_var_call("GET", "/orders", "ORDERS_URL", vec![]),
+            Finding::orphaned_endpoint("GET", "/health", None),
+            Finding::missing_endpoint("GET", "/internal/jobs/1", None, vec![]),
+            Finding::missing_endpoint("GET", "/internal/admin/users", None, vec![]),
+            Finding::type_mismatch("GET", "/a", Some("legacy".into()), vec![], "A", "B", ""),
+            Finding::type_mismatch("GET", "/a", Some("orders".into()), vec![], "A", "B", ""),
+        ]);
+        let severities: Vec<Severity> = findings.iter().map(Finding::severity).collect();
+        assert_eq!(
+            severities,
+            vec![
+                Severity::Gap,
+                Severity::Advisory,
+                Severity::Advisory,
+                Severity::Risk,
+                Severity::Gap,
+                Severity::Risk,
+            ]
+        );
+        assert_eq!(count_failing(&findings, Severity::Risk), 2);
+        assert_eq!(count_failing(&findings, Severity::Gap), 4);
+        assert_eq!(count_failing(&findings, Severity::Advisory), 6);
+    }
+
+    #[test]
+    fn kind_map_keeps_usage_downgrades_but_overrides_still_apply() {
+        let policy = policy(
+            r#"{
+                "severity": { "type_mismatch": "gap" },
+                "overrides": [ { "path": "/admin/**", "kind": "type_mismatch", "severity": "risk" } ]
+            }"#,
+        );
+        policy.validate().unwrap();
+        let mismatch = |path: &str| Finding::type_mismatch("GET", path, None, vec![], "A", "B", "");
+        let findings = policy.apply(vec![
+            mismatch("/orders"),
+            // The consumer reads none of the mismatched fields.
+            mismatch("/orders").with_severity(Severity::Advisory),
+            mismatch("/admin/users").with_severity(Severity::Advisory),
+        ]);
+        let severities: Vec<Severity> = findings.iter().map(Finding::severity).collect();
+        assert_eq!(
+            severities,
+            vec![Severity::Gap, Severity::Advisory, Severity::Risk]
+        );
+    }
+
+    #[test]
+    fn unknown_kinds_are_rejected() {
+        let err = policy(r#"{ "severity": { "type-mismatch": "gap" } }"#)
+            .validate()
+            .unwrap_err();
+        assert!(
+            err.contains("unknown finding kind 'type-mismatch'"),
+            "{err}"
+        );
+    }
+
+    fn route(method: &str, path: &str, service: &str, chain: &[&str]) -> ResolvedEndpoint {
+        ResolvedEndpoint {
+            method: method.to_string(),
+            path: path.to_string(),
+            full_path: path.to_string(),
+            handler: None,
+            owner: "app".to_string(),
+            file_location: format!("src/{}.ts:3", service),
+            middleware_chain: chain.iter().map(|m| m.to_string()).collect(),
+            repo_name: None,
+            service_name: Some(service.to_string()),
+            provenance: Default::default(),
+            evidence: Default::default(),
+            query_params: None,
+            request_headers: Vec::new(),
+        }
+    }
+
+    fn call(producer: &str, key: &str, consumer: &str, location: &str) -> CrossRepoMatch {
+        CrossRepoMatch {
+            producer_repo: producer.to_string(),
+            producer_key: key.to_string(),
+            consumer_repo: consumer.to_string(),
+            consumer_key: key.to_string(),
+            consumer_location: Some(location.to_string()),
+            match_score: 1.0,
+            type_compatible: None,
+            type_verdict: None,
+            mismatch_reason: None,
+            producer_provenance: Default::default(),
+            relationship: carrick_match::MatchRelationship::ProducerConsumer,
+        }
+    }
+
+    fn request_type(method: &str, path: &str, state: ManifestTypeState) -> TypeManifestEntry {
+        TypeManifestEntry {
+            key: crate::operation::OperationKey::http(method, path),
+            role: ManifestRole::Producer,
+            type_kind: ManifestTypeKind::Request,
+            type_alias: "Endpoint_abc_Request".to_string(),
+            file_path: "src/orders.ts".to_string(),
+            line_number: 3,
+            is_explicit: true,
+            type_state: state,
+            evidence: crate::cloud_storage::TypeEvidence {
+                file_path: "src/orders.ts".to_string(),
+                span_start: None,
+                span_end: None,
+                line_number: 3,
+                infer_kind: crate::services::type_sidecar::InferKind::RequestBody,
+                is_explicit: true,
+                type_state: state,
+            },
+            resolved_definition: None,
+            expanded_definition: None,
+            primary_type_symbol: None,
+            accessed_fields: None,
+        }
+    }
+
+    #[test]
+    fn rules_flag_routes_calls_and_untyped_bodies() {
+        let policy = policy(
+            r#"{
+                "teams": { "payments": ["billing", "ledger"] },
+                "rules": [
+                    { "id": "admin-auth", "check": "require_middleware", "path": "/admin/**", "middleware": ["requireAuth", "passport.*"] },
+                    { "id": "internal-only", "check": "forbid_call", "path": "/internal/**", "crossTeam": true, "severity": "risk" },
+                    { "id": "typed-posts", "check": "require_request_type", "method": "POST" }
+                ]
+            }"#,
+        );
+        policy.validate().unwrap();
+        let mut graph = MountGraph::new();
+        graph.endpoints = vec![
+            route("GET", "/admin/users", "orders", &["helmet", "requireAuth"]),
+            route(
+                "DELETE",
+                "/admin/users/:id",
+                "orders",
+                &["passport.authenticate"],
+            ),
+            route("GET", "/admin/stats", "orders", &["helmet"]),
+            route("POST", "/orders", "orders", &[]),
+            route("POST", "/refunds", "billing", &[]),
+        ];
+        let matches = vec![
+            call(
+                "ledger",
+                "http|GET|/internal/balances",
+                "billing",
+                "src/a.ts:4",
+            ),
+            call(
+                "ledger",
+                "http|GET|/internal/balances",
+                "orders",
+                "src/b.ts:9",
+            ),
+            call(
+                "ledger",
+                "http|GET|/internal/balances",
+                "orders",
+                "src/b.ts:20",
+            ),
+            call("ledger", "http|GET|/public/rates", "orders", "src/b.ts:30"),
+        ];
+        let manifest = vec![
+            request_type("POST", "/orders", ManifestTypeState::Explicit),
+            request_type("POST", "/refunds", ManifestTypeState::Unknown),
+        ];
+
+        let findings = policy.evaluate_rules(&graph, &matches, &manifest);
+        assert_eq!(
+            findings,
+            vec![
+                Finding::policy_violation(
+                    "admin-auth",
+                    "GET",
+                    "/admin/stats",
+                    Some("orders".into()),
+                    None,
+                    "no middleware matching requireAuth | passport.* (chain: helmet)",
+                    vec!["src/orders.ts:3".into()],
+                ),
+                // Same team (payments) is allowed; orders is not.
+                Finding::policy_violation(
+                    "internal-only",
+                    "GET",
+                    "/internal/balances",
+                    Some("ledger".into()),
+                    Some("orders".into()),
+                    "`orders` calls this route of `ledger`",
+                    vec!["src/b.ts:20".into(), "src/b.ts:9".into()],
+                )
+                .with_severity(Severity::Risk),
+                Finding::policy_violation(
+                    "typed-posts",
+                    "POST",
+                    "/refunds",
+                    Some("billing".into()),
+                    None,
+                    "request body is untyped",
+                    vec!["src/billing.ts:3".into()],
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn each_forbidden_consumer_of_a_route_is_its_own_violation() {
+        let policy = policy(
+            r#"{ "rules": [ { "id": "internal-only", "check": "forbid_call", "path": "/internal/**" } ] }"#,
+        );
+        let matches = vec![
+            call(
+                "ledger",
+                "http|GET|/internal/balances",
+                "orders",
+                "src/a.ts:4",
+            ),
+            call(
+                "ledger",
+                "http|GET|/internal/balances",
+                "search",
+                "src/a.ts:4",
+            ),
+        ];
+
+        let findings = policy.evaluate_rules(&MountGraph::new(), &matches, &[]);
+        let consumers: Vec<_> = findings
+            .iter()
+            .map(|f| match &f.issue {
+                Issue::PolicyViolation { consumer, .. } => consumer.clone(),
+                other => panic!("unexpected finding {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            consumers,
+            vec![Some("orders".to_string()), Some("search".to_string())]
+        );
+        // Baselining one caller must not hide the other.
+        assert_ne!(findings[0].fingerprint(), findings[1].fingerprint());
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let err = policy(r#"{ "rules": [ { "id": "a", "check": "require_middleware" } ] }"#)
+            .validate()
+            .unwrap_err();
+        assert!(err.contains("lists none"), "{err}");
+        let err = policy(
+            r#"{ "rules": [
+                { "id": "a", "check": "forbid_call" },
+                { "id": "a", "check": "require_request_type" }
+            ] }"#,
+        )
+        .validate()
+        .unwrap_err();
+        assert!(err.contains("duplicate policy rule id 'a'"), "{err}");
+    }
+
+    #[test]
+    fn glob_matching() {
+        assert!(glob_matches("/api/**", "/api/users/:id"));
+        assert!(glob_matches("/api/**", "/api"));
+        assert!(glob_matches("/api/*/orders", "/api/v2/orders"));
+        assert!(glob_matches("/v*/users", "/v1/users"));
+        assert!(!glob_matches("/api/*", "/api/users/:id"));
+        assert!(!glob_matches("/v*/users", "/api/users"));
+    }
+}
@@ -0,0 +1,228 @@
+//! Query-string contracts between consumer calls and the endpoints they hit.
+//!
+//! URL normalization drops the query string (`/users?page=1` → `/users`), so
+//! matching alone can't tell a consumer sending `?pageSize=20` from one
+//! sending the `?limit=20` the handler actually reads. Both sides are
+//! recorded here instead:
+//!
+//! - a consumer's keys come from the literal or template query string of its
+//!   call target ([`target_query_keys`]), so `` `/users?page=${p}&limit=20` ``
+//!   sends `limit` and `page`;
+//! - a producer's [`QueryContract`] comes from its Fastify `querystring` /
+//!   Elysia `query` schema (which also says what is required), its NestJS
+//!   `@Query('x')` parameters, or the `req.query.x` reads a statically
+//!   resolved handler makes.
+//!
+//! Either side is `None` when it can't be read completely — an interpolated
+//! `?${qs}`, a handler that hands the whole query object or request to a
+//! helper, a bare `@Query()` DTO — and a mismatch is only reported when both
+//! are known.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The query keys an endpoint reads, and the subset it requires.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryContract {
+    /// Every key the route declares or its handler reads, sorted.
+    pub keys: Vec<String>,
+    /// Keys a declared schema marks required, sorted. Handler reads never
+    /// make a key required: a missing one usually just takes a default.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+impl QueryContract {
+    pub fn new(
+        keys: impl IntoIterator<Item = String>,
+        required: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let required: BTreeSet<String> = required.into_iter().collect();
+        let keys: BTreeSet<String> = keys.into_iter().chain(required.iter().cloned()).collect();
+        QueryContract {
+            keys: keys.into_iter().collect(),
+            required: required.into_iter().collect(),
+        }
+    }
+
+    /// Compare the keys a call sends against the contract: the sent keys the
+    /// endpoint never reads, and the required keys the call omits. `None`
+    /// when they agree.
+    pub fn mismatch(&self, sent: &[String]) -> Option<(Vec<String>, Vec<String>)> {
+        let unread: Vec<String> = sent
+            .iter()
+            .filter(|key| !self.keys.contains(key))
+            .cloned()
+            .collect();
+        let missing: Vec<String> = self
+            .required
+            .iter()
+            .filter(|key| !sent.contains(key))
+            .cloned()
+            .collect();
+        if unread.is_empty() && missing.is_empty() {
+            None
+        } else {
+            Some((unread, missing))
+        }
+    }
+}
+
+/// The query keys a call target sends, sorted: `/users?page=${p}&limit=20`
+/// sends `limit` and `page`, and `ids[]=1` sends `ids`. `None` when the target
+/// has no query string, or when any part of it is interpolated as a whole
+/// (`?${qs}`, `&${extra}`) or names its key through an interpolation, since
+/// the full key set is then unknown.
+pub fn target_query_keys(target: &str) -> Option<Vec<String>> {
+    let interpolated = interpolated_bytes(target);
+    let top_level = |i: usize, byte: u8| target.as_bytes()[i] == byte && !interpolated[i];
+    let start = (0..target.len()).find(|&i| top_level(i, b'?'))? + 1;
+    let end = (start..target.len())
+        .find(|&i| top_level(i, b'#'))
+        .unwrap_or(target.len());
+    let mut keys = BTreeSet::new();
+    let mut pair_start = start;
+    for i in start..=end {
+        if i < end && !top_level(i, b'&') {
+            continue;
+        }
+        let key_end = (pair_start..i).find(|&j| top_level(j, b'=')).unwrap_or(i);
+        if key_end > pair_start {
+            if interpolated[pair_start..key_end].contains(&true) {
+                return None;
+            }
+            let key = &target[pair_start..key_end];
+            keys.insert(key.strip_suffix("[]").unwrap_or(key).to_string());
+        }
+        pair_start = i + 1;
+    }
+    if keys.is_empty() {
+        return None;
+    }
+    Some(keys.into_iter().collect())
+}
+
+/// The query keys a call sends: its target's ([`target_query_keys`]), or none
+/// at all when the target has no query string, its options can't add one
+/// (`query_options == Some(false)`), and every interpolation in it is
+/// followed by a `/` (`${API}/users/${id}/posts`), so none can carry a query
+/// string either. `None` when the key set is unknown.
+pub fn sent_query_keys(target: &str, query_options: Option<bool>) -> Option<Vec<String>> {
+    if query_options != Some(false) {
+        return target_query_keys(target);
+    }
+    let interpolated = interpolated_bytes(target);
+    let bytes = target.as_bytes();
+    let has_query = (0..bytes.len()).any(|i| bytes[i] == b'?' && !interpolated[i]);
+    if has_query {
+        return target_query_keys(target);
+    }
+    let closed = (0..bytes.len())
+        .filter(|&i| interpolated[i] && interpolated.get(i + 1) != Some(&true))
+        .all(|i| bytes.get(i + 1) == Some(&b'/'));
+    closed.then(Vec::new)
+}
+
+/// Which bytes of a template's text sit inside a `${…}` interpolation,
+/// delimiters included.
+fn interpolated_bytes(target: &str) -> Vec<bool> {
+    let bytes = target.as_bytes();
+    let mut inside = vec![false; bytes.len()];
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'$' if bytes.get(i + 1) == Some(&b'{') => {
+                depth += 1;
+                inside[i] = true;
+                i += 1;
+            }
+            b'{' if depth > 0 => depth += 1,
+            b'}' if depth > 0 => {
+                inside[i] = true;
+                depth -= 1;
+            }
+            _ => {}
+        }
+        if depth > 0 {
+            inside[i] = true;
+        }
+        i += 1;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(target: &str) -> Option<Vec<String>> {
+        target_query_keys(target)
+    }
+
+    fn some(keys: &[&str]) -> Option<Vec<String>> {
+        Some(keys.iter().map(|k| k.to_string()).collect())
+    }
+
+    #[test]
+    fn target_query_keys_reads_literal_and_template_query_strings() {
+        assert_eq!(
+            keys("${process.env.API_URL}/users?page=${page}&limit=20"),
+            some(&["limit", "page"])
+        );
+        assert_eq!(
+            keys("/search?q=${encodeURIComponent(term)}&tags[]=a&tags[]=b#top"),
+            some(&["q", "tags"])
+        );
+        assert_eq!(keys("/users?flag"), some(&["flag"]));
+        assert_eq!(
+            keys("/users?active=${a && b}&sort=${s}"),
+            some(&["active", "sort"])
+        );
+        // A `?` inside an interpolation isn't the query string.
+        assert_eq!(keys("/users/${id ? id : 'me'}"), None);
+        assert_eq!(keys("/users"), None);
+        assert_eq!(keys("/users?"), None);
+        // Opaque parts leave the key set unknown.
+        assert_eq!(keys("/users?${new URLSearchParams(filters)}"), None);
+        assert_eq!(keys("/users?page=1&${extra}"), None);
+        assert_eq!(keys("/users?${key}=1"), None);
+    }
+
+    #[test]
+    fn sent_query_keys_reads_a_bare_target_as_sending_none() {
+        let none: Option<Vec<String>> = Some(Vec::new());
+        assert_eq!(sent_query_keys("/users", Some(false)), none);
+        assert_eq!(
+            sent_query_keys("${API}/users/${id}/posts", Some(false)),
+            none
+        );
+        assert_eq!(
+            sent_query_keys("/users?page=1", Some(false)),
+            some(&["page"])
+        );
+        // Options that may add keys, or a call that wasn't read.
+        assert_eq!(sent_query_keys("/users", Some(true)), None);
+        assert_eq!(sent_query_keys("/users", None), None);
+        // A trailing interpolation may carry the query string.
+        assert_eq!(sent_query_keys("/users${qs}", Some(false)), None);
+        assert_eq!(sent_query_keys("/users/${id}", Some(false)), None);
+    }
+
+    #[test]
+    fn contract_reports_unread_and_missing_keys() {
+        let contract = QueryContract::new(
+            ["limit".to_string(), "cursor".to_string()],
+            ["tenant".to_string()],
+        );
+        assert_eq!(contract.keys, vec!["cursor", "limit", "tenant"]);
+        assert_eq!(
+            contract.mismatch(&["pageSize".to_string(), "limit".to_string()]),
+            Some((vec!["pageSize".to_string()], vec!["tenant".to_string()]))
+        );
+        assert_eq!(
+            contract.mismatch(&["limit".to_string(), "tenant".to_string()]),
+            None
+        );
+    }
+}
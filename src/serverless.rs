@@ -0,0 +1,1557 @@
+//! Deterministic routes of Lambda-backed APIs.
+//!
+//! A Lambda API declares its routes away from the code that serves them:
+//!
+//! ```yaml
+//! functions:
+//!   getUser:
+//!     handler: src/handlers/users.get
+//!     events:
+//!       - httpApi: GET /users/{id}
+//! ```
+//!
+//! and the handler is a plain exported function in another file, with no
+//! framework call for the scanner to find. This module reads the route
+//! definitions and ties each method + path to its handler file and export:
+//!
+//! - [`ServerlessRouting::collect`] gathers the repo's routes from
+//!   `serverless.yml` `http`/`httpApi` events, SAM templates
+//!   (`AWS::Serverless::Function` `Api`/`HttpApi` events), SST `Api` routes
+//!   (`new Api(stack, id, { routes })`, `api.addRoutes(stack, {...})`,
+//!   `api.route('GET /x', handler)`) and CDK constructs (`httpApi.addRoutes`
+//!   with an `HttpLambdaIntegration`, REST `addResource(...).addMethod(...)`
+//!   with a `LambdaIntegration`) whose function is a `NodejsFunction` entry
+//!   or a `lambda.Function` asset + handler.
+//! - [`ServerlessRouting::handler_endpoints`] finds, in one handler file, the
+//!   exported function each route names and reads its response and request
+//!   off the body: `body: JSON.stringify(x)` in a success return, a returned
+//!   object or identifier, and `JSON.parse(event.body)`.
+//!
+//! API Gateway `{id}` / `{proxy+}` parameters become `:id` / `*`, and `ANY`
+//! becomes `ALL`. A path built from variables (`${self:custom.prefix}/x`),
+//! a handler string that resolves to no file, and a function the definition
+//! doesn't name literally are skipped.
+
+use crate::packages::MANIFEST_SKIP_DIRS;
+use crate::parser::parse_module;
+use crate::type_manifest::is_http_method;
+use crate::visitor::StaticEmission;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use swc_common::{SourceMap, SourceMapper, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+use tracing::debug;
+
+/// Extensions a handler string's file part may resolve to, in lookup order.
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "js", "mjs", "cjs"];
+
+/// Build and deploy output directories holding copies of the handlers.
+const BUILD_OUTPUT_DIRS: &[&str] = &[".serverless", ".aws-sam", "cdk.out", ".sst"];
+
+/// Text a source file must contain for [`ServerlessRouting::collect`] to
+/// read it as an SST or CDK definition.
+pub const DEFINITION_MARKERS: &[&str] = &["aws-cdk", "sst/constructs", "sst.aws."];
+
+/// Whether a YAML file named `name` holding `text` is a `serverless.yml` or
+/// SAM template [`ServerlessRouting::collect`] reads routes from.
+pub fn is_route_template(name: &str, text: &str) -> bool {
+    (name.ends_with(".yml") || name.ends_with(".yaml"))
+        && (name.starts_with("serverless.") || text.contains("AWS::Serverless::Function"))
+}
+
+/// Where a Lambda route was declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteSource {
+    ServerlessFramework,
+    Sam,
+    Sst,
+    Cdk,
+}
+
+impl RouteSource {
+    pub const ALL: [RouteSource; 4] = [
+        RouteSource::ServerlessFramework,
+        RouteSource::Sam,
+        RouteSource::Sst,
+        RouteSource::Cdk,
+    ];
+
+    /// `pattern_matched` of the endpoints built from this source.
+    pub fn pattern(self) -> &'static str {
+        match self {
+            RouteSource::ServerlessFramework => "serverless-yml",
+            RouteSource::Sam => "sam-template",
+            RouteSource::Sst => "sst-api",
+            RouteSource::Cdk => "cdk-api",
+        }
+    }
+}
+
+/// One declared route and the handler serving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerlessRoute {
+    pub method: String,
+    pub path: String,
+    /// Canonicalized path of the handler's source file.
+    pub handler_file: PathBuf,
+    /// Export serving the route (`handler`, `main`, `default`, …).
+    pub export: String,
+    pub source: RouteSource,
+}
+
+/// A route anchored on its handler declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerlessEndpoint {
+    pub method: String,
+    pub path: String,
+    pub export: String,
+    pub source: RouteSource,
+    /// 1-based line the handler declaration starts on; line 1 when the
+    /// export couldn't be found in the file.
+    pub line_number: usize,
+    pub span: Option<(u32, u32)>,
+    pub emission: StaticEmission,
+    pub response_text: Option<String>,
+    pub response_line: Option<usize>,
+    pub request_text: Option<String>,
+    pub request_line: Option<usize>,
+}
+
+/// Every Lambda route the repo declares.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerlessRouting {
+    routes: Vec<ServerlessRoute>,
+}
+
+impl ServerlessRouting {
+    /// Scan `service_root` for `serverless.yml` and SAM templates, and
+    /// `files` for SST and CDK definitions. Source files that mention
+    /// neither `aws-cdk` nor SST's constructs are not parsed.
+    pub fn collect(service_root: &Path, files: &[PathBuf]) -> Self {
+        let mut routes = Vec::new();
+
+        let walker = walkdir::WalkDir::new(service_root)
+            .into_iter()
+            .filter_entry(|e| {
+                e.depth() == 0
+                    || !(e.file_type().is_dir()
+                        && e.file_name().to_str().is_some_and(|n| {
+                            MANIFEST_SKIP_DIRS.contains(&n)
+                                || BUILD_OUTPUT_DIRS.contains(&n)
+                                || n == ".git"
+                        }))
+            });
+        for entry in walker.flatten() {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Some(name) = entry.file_name().to_str() else {
+                continue;
+            };
+            if !(name.ends_with(".yml") || name.ends_with(".yaml")) {
+                continue;
+            }
+            let Some(dir) = entry.path().parent() else {
+                continue;
+            };
+            let Ok(text) = std::fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            if !is_route_template(name, &text) {
+                continue;
+            }
+            let is_serverless = name.starts_with("serverless.");
+            for document in parse_documents(&text) {
+                if is_serverless {
+                    serverless_framework_routes(dir, &document, &mut routes);
+                } else {
+                    sam_routes(dir, &document, &mut routes);
+                }
+            }
+        }
+
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(file) else {
+                continue;
+            };
+            if !DEFINITION_MARKERS.iter().any(|m| content.contains(m)) {
+                continue;
+            }
+            let Some(source_dir) = file.parent() else {
+                continue;
+            };
+            let Some((_, module)) = parse_module(file) else {
+                continue;
+            };
+            let app_root = app_root(source_dir, service_root);
+            let mut visitor = DefinitionVisitor {
+                source_dir,
+                app_root: &app_root,
+                functions: HashMap::new(),
+                resources: HashMap::new(),
+                routes: Vec::new(),
+            };
+            module.visit_with(&mut visitor);
+            routes.extend(visitor.routes);
+        }
+
+        let mut deduped: Vec<ServerlessRoute> = Vec::new();
+        for route in routes {
+            if !deduped.contains(&route) {
+                deduped.push(route);
+            }
+        }
+        if !deduped.is_empty() {
+            debug!(routes = deduped.len(), "Collected serverless routes");
+        }
+        Self { routes: deduped }
+    }
+
+    /// The canonicalized handler files the routes name.
+    pub fn handler_files(&self) -> impl Iterator<Item = &Path> {
+        self.routes.iter().map(|r| r.handler_file.as_path())
+    }
+
+    /// The routes `file_path` serves, each anchored on its exported handler.
+    pub fn handler_endpoints(&self, file_path: &Path) -> Vec<ServerlessEndpoint> {
+        if self.routes.is_empty() {
+            return Vec::new();
+        }
+        let Ok(file) = file_path.canonicalize() else {
+            return Vec::new();
+        };
+        let routes: Vec<&ServerlessRoute> = self
+            .routes
+            .iter()
+            .filter(|r| r.handler_file == file)
+            .collect();
+        if routes.is_empty() {
+            return Vec::new();
+        }
+        let Some((cm, module)) = parse_module(file_path) else {
+            return Vec::new();
+        };
+
+        routes
+            .into_iter()
+            .map(|route| {
+                let mut endpoint = ServerlessEndpoint {
+                    method: route.method.clone(),
+                    path: route.path.clone(),
+                    export: route.export.clone(),
+                    source: route.source,
+                    line_number: 1,
+                    span: None,
+                    emission: StaticEmission::None,
+                    response_text: None,
+                    response_line: None,
+                    request_text: None,
+                    request_line: None,
+                };
+                let Some(handler) = find_handler(&module, &route.export) else {
+                    return endpoint;
+                };
+                let span = handler.span();
+                endpoint.line_number = cm.lookup_char_pos(span.lo).line;
+                endpoint.span = Some((span.lo.0, span.hi.0));
+
+                let mut scan = HandlerScan {
+                    cm: &cm,
+                    event: handler.first_param(),
+                    depth: 0,
+                    response: None,
+                    request: None,
+                };
+                match handler.body() {
+                    HandlerBody::Block(block) => block.visit_with(&mut scan),
+                    HandlerBody::Expr(expr) => {
+                        scan.classify_return(expr);
+                        expr.visit_with(&mut scan);
+                    }
+                }
+                if let Some((emission, text, line)) = scan.response {
+                    endpoint.emission = emission;
+                    endpoint.response_text = Some(text);
+                    endpoint.response_line = Some(line);
+                }
+                if let Some((text, line)) = scan.request {
+                    endpoint.request_text = Some(text);
+                    endpoint.request_line = Some(line);
+                }
+                endpoint
+            })
+            .collect()
+    }
+}
+
+fn parse_documents(text: &str) -> Vec<serde_yaml::Value> {
+    use serde::Deserialize;
+    serde_yaml::Deserializer::from_str(text)
+        .filter_map(|doc| serde_yaml::Value::deserialize(doc).ok())
+        .filter(|doc| !doc.is_null())
+        .collect()
+}
+
+/// `functions.<name>.handler` + its `http` / `httpApi` events.
+fn serverless_framework_routes(
+    dir: &Path,
+    doc: &serde_yaml::Value,
+    out: &mut Vec<ServerlessRoute>,
+) {
+    let Some(functions) = doc.get("functions").and_then(|f| f.as_mapping()) else {
+        return;
+    };
+    for function in functions.values() {
+        let Some((handler_file, export)) = function
+            .get("handler")
+            .and_then(|h| h.as_str())
+            .and_then(|h| resolve_handler(dir, h))
+        else {
+            continue;
+        };
+        let events = function.get("events").and_then(|e| e.as_sequence());
+        for event in events.into_iter().flatten() {
+            let Some(event) = event.get("http").or_else(|| event.get("httpApi")) else {
+                continue;
+            };
+            let (method, path) = match event {
+                serde_yaml::Value::String(route) => match route.trim().split_once(' ') {
+                    Some((method, path)) => (method.to_string(), path.trim().to_string()),
+                    None => continue,
+                },
+                _ => {
+                    let (Some(method), Some(path)) = (
+                        event.get("method").and_then(|m| m.as_str()),
+                        event.get("path").and_then(|p| p.as_str()),
+                    ) else {
+                        continue;
+                    };
+                    (method.to_string(), path.to_string())
+                }
+            };
+            push_route(
+                out,
+                &method,
+                &path,
+                &handler_file,
+                &export,
+                RouteSource::ServerlessFramework,
+            );
+        }
+    }
+}
+
+/// `AWS::Serverless::Function` resources and their `Api` / `HttpApi` events.
+/// `CodeUri` falls back to `Globals.Function.CodeUri`.
+fn sam_routes(dir: &Path, doc: &serde_yaml::Value, out: &mut Vec<ServerlessRoute>) {
+    let Some(resources) = doc.get("Resources").and_then(|r| r.as_mapping()) else {
+        return;
+    };
+    let global_code_uri = doc
+        .get("Globals")
+        .and_then(|g| g.get("Function"))
+        .and_then(|f| f.get("CodeUri"))
+        .and_then(|c| c.as_str());
+    for resource in resources.values() {
+        if resource.get("Type").and_then(|t| t.as_str()) != Some("AWS::Serverless::Function") {
+            continue;
+        }
+        let Some(properties) = resource.get("Properties") else {
+            continue;
+        };
+        let code_uri = properties
+            .get("CodeUri")
+            .and_then(|c| c.as_str())
+            .or(global_code_uri)
+            .unwrap_or("");
+        let Some((handler_file, export)) = properties
+            .get("Handler")
+            .and_then(|h| h.as_str())
+            .and_then(|h| resolve_handler(&dir.join(code_uri), h))
+        else {
+            continue;
+        };
+        let events = properties.get("Events").and_then(|e| e.as_mapping());
+        for event in events.into_iter().flat_map(|e| e.values()) {
+            if !matches!(
+                event.get("Type").and_then(|t| t.as_str()),
+                Some("Api" | "HttpApi")
+            ) {
+                continue;
+            }
+            let Some(path) = event
+                .get("Properties")
+                .and_then(|p| p.get("Path"))
+                .and_then(|p| p.as_str())
+            else {
+                continue;
+            };
+            let method = event
+                .get("Properties")
+                .and_then(|p| p.get("Method"))
+                .and_then(|m| m.as_str())
+                .unwrap_or("ANY");
+            push_route(out, method, path, &handler_file, &export, RouteSource::Sam);
+        }
+    }
+}
+
+fn push_route(
+    out: &mut Vec<ServerlessRoute>,
+    method: &str,
+    path: &str,
+    handler_file: &Path,
+    export: &str,
+    source: RouteSource,
+) {
+    let (Some(method), Some(path)) = (route_method(method), route_path(path)) else {
+        return;
+    };
+    out.push(ServerlessRoute {
+        method,
+        path,
+        handler_file: handler_file.to_path_buf(),
+        export: export.to_string(),
+        source,
+    });
+}
+
+/// Upper-cased HTTP method; `ANY` / `*` serve every method.
+fn route_method(method: &str) -> Option<String> {
+    let method = method.trim().to_uppercase();
+    match method.as_str() {
+        "ANY" | "*" => Some("ALL".to_string()),
+        m if is_http_method(m) => Some(method),
+        _ => None,
+    }
+}
+
+/// API Gateway path → colon-style path: `{id}` → `:id`, `{proxy+}` → `*`.
+/// `None` for paths built from variables and for `$default`.
+fn route_path(path: &str) -> Option<String> {
+    let path = path.trim();
+    if path.is_empty() || path.contains('$') {
+        return None;
+    }
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(
+            |segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(param) if param.ends_with('+') => "*".to_string(),
+                Some(param) => format!(":{param}"),
+                None => segment.to_string(),
+            },
+        )
+        .collect();
+    Some(format!("/{}", segments.join("/")))
+}
+
+/// `src/handlers/users.get` relative to `dir` → the source file and `get`.
+fn resolve_handler(dir: &Path, handler: &str) -> Option<(PathBuf, String)> {
+    let (file, export) = handler.trim().rsplit_once('.')?;
+    if export.is_empty() || file.is_empty() {
+        return None;
+    }
+    Some((locate_source(&dir.join(file))?, export.to_string()))
+}
+
+/// The canonical source file `base` names: itself, `base.<ext>`, or
+/// `base/index.<ext>`.
+fn locate_source(base: &Path) -> Option<PathBuf> {
+    if base.is_file() {
+        return base.canonicalize().ok();
+    }
+    SOURCE_EXTENSIONS
+        .iter()
+        .map(|ext| PathBuf::from(format!("{}.{ext}", base.display())))
+        .chain(
+            SOURCE_EXTENSIONS
+                .iter()
+                .map(|ext| base.join(format!("index.{ext}"))),
+        )
+        .find(|candidate| candidate.is_file())
+        .and_then(|found| found.canonicalize().ok())
+}
+
+/// The directory relative handler strings in an SST or CDK definition
+/// resolve against: the nearest ancestor holding `sst.config.ts`,
+/// `sst.json` or `cdk.json` — where the tool runs — else the service root.
+fn app_root(source_dir: &Path, service_root: &Path) -> PathBuf {
+    source_dir
+        .ancestors()
+        .take_while(|dir| dir.starts_with(service_root))
+        .find(|dir| {
+            ["sst.config.ts", "sst.json", "cdk.json"]
+                .iter()
+                .any(|marker| dir.join(marker).is_file())
+        })
+        .unwrap_or(service_root)
+        .to_path_buf()
+}
+
+/// A string literal, or a template literal with no substitutions.
+fn literal_str(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+            .quasis
+            .first()
+            .map(|q| q.cooked.as_ref().unwrap_or(&q.raw).to_string()),
+        Expr::Paren(p) => literal_str(&p.expr),
+        Expr::TsConstAssertion(c) => literal_str(&c.expr),
+        Expr::TsAs(a) => literal_str(&a.expr),
+        _ => None,
+    }
+}
+
+fn prop_key(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.to_string()),
+        PropName::Str(s) => Some(s.value.to_string()),
+        _ => None,
+    }
+}
+
+/// The value of `key` in an object literal.
+fn object_prop<'a>(object: &'a ObjectLit, key: &str) -> Option<&'a Expr> {
+    object.props.iter().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) if prop_key(&kv.key).as_deref() == Some(key) => Some(&*kv.value),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// `Name` of `new Name(...)` / `new ns.Name(...)`.
+fn constructor_name(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Ident(i) => Some(i.sym.as_ref()),
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(p),
+            ..
+        }) => Some(p.sym.as_ref()),
+        _ => None,
+    }
+}
+
+fn arg(args: &[ExprOrSpread], index: usize) -> Option<&Expr> {
+    args.get(index)
+        .filter(|a| a.spread.is_none())
+        .map(|a| &*a.expr)
+}
+
+fn object_arg(args: &[ExprOrSpread], index: usize) -> Option<&ObjectLit> {
+    match arg(args, index) {
+        Some(Expr::Object(object)) => Some(object),
+        _ => None,
+    }
+}
+
+struct DefinitionVisitor<'a> {
+    /// Directory of the definition file, for `__dirname`.
+    source_dir: &'a Path,
+    /// Where relative handler and asset paths resolve.
+    app_root: &'a Path,
+    /// Bindings of Lambda function constructs → handler file and export.
+    functions: HashMap<String, (PathBuf, String)>,
+    /// Bindings of REST API resources → their path.
+    resources: HashMap<String, String>,
+    routes: Vec<ServerlessRoute>,
+}
+
+impl DefinitionVisitor<'_> {
+    /// A filesystem path expression: a literal (relative to the app root),
+    /// `path.join(__dirname, ...)` / `path.resolve(...)` over literals, or
+    /// `` `${__dirname}/...` ``.
+    fn path_expr(&self, expr: &Expr) -> Option<PathBuf> {
+        if let Some(path) = literal_str(expr) {
+            return Some(self.app_root.join(path));
+        }
+        match expr {
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                if !matches!(constructor_name(callee), Some("join" | "resolve")) {
+                    return None;
+                }
+                let mut args = call.args.iter().map(|a| &*a.expr).peekable();
+                let mut path = match args.peek() {
+                    Some(Expr::Ident(i)) if &*i.sym == "__dirname" => {
+                        args.next();
+                        self.source_dir.to_path_buf()
+                    }
+                    _ => self.app_root.to_path_buf(),
+                };
+                for segment in args {
+                    path.push(literal_str(segment)?);
+                }
+                Some(path)
+            }
+            Expr::Tpl(tpl) if tpl.exprs.len() == 1 && tpl.quasis.len() == 2 => {
+                let Expr::Ident(i) = &*tpl.exprs[0] else {
+                    return None;
+                };
+                if &*i.sym != "__dirname" || !tpl.quasis[0].raw.is_empty() {
+                    return None;
+                }
+                Some(
+                    self.source_dir
+                        .join(tpl.quasis[1].raw.trim_start_matches('/')),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// `new NodejsFunction(scope, id, { entry, handler })`, or
+    /// `new lambda.Function(scope, id, { code: Code.fromAsset(dir), handler })`
+    /// (SST's `new Function(stack, id, { handler })` resolves against the app
+    /// root).
+    fn lambda_function(&self, expr: &Expr) -> Option<(PathBuf, String)> {
+        let Expr::New(new) = expr else {
+            return None;
+        };
+        let options = object_arg(new.args.as_deref()?, 2)?;
+        match constructor_name(&new.callee)? {
+            "NodejsFunction" => {
+                let entry = self.path_expr(object_prop(options, "entry")?)?;
+                let export = object_prop(options, "handler")
+                    .and_then(literal_str)
+                    .unwrap_or_else(|| "handler".to_string());
+                Some((locate_source(&entry)?, export))
+            }
+            "Function" => {
+                let handler = object_prop(options, "handler").and_then(literal_str)?;
+                let dir = match object_prop(options, "code") {
+                    Some(Expr::Call(call)) => {
+                        let Callee::Expr(callee) = &call.callee else {
+                            return None;
+                        };
+                        if constructor_name(callee) != Some("fromAsset") {
+                            return None;
+                        }
+                        self.path_expr(arg(&call.args, 0)?)?
+                    }
+                    Some(_) => return None,
+                    None => self.app_root.to_path_buf(),
+                };
+                resolve_handler(&dir, &handler)
+            }
+            _ => None,
+        }
+    }
+
+    /// A function construct, inline or through its binding.
+    fn lambda_ref(&self, expr: &Expr) -> Option<(PathBuf, String)> {
+        match expr {
+            Expr::Ident(i) => self.functions.get(&*i.sym).cloned(),
+            _ => self.lambda_function(expr),
+        }
+    }
+
+    /// `new HttpLambdaIntegration(id, fn)` / `new LambdaIntegration(fn)`.
+    fn integration_handler(&self, expr: &Expr) -> Option<(PathBuf, String)> {
+        let Expr::New(new) = expr else {
+            return None;
+        };
+        let args = new.args.as_deref()?;
+        match constructor_name(&new.callee)? {
+            "HttpLambdaIntegration" => self.lambda_ref(arg(args, 1)?),
+            "LambdaIntegration" => self.lambda_ref(arg(args, 0)?),
+            _ => None,
+        }
+    }
+
+    /// Path of a REST API resource: `api.root`, a bound resource, and
+    /// `.addResource('x')` / `.resourceForPath('/x')` on either.
+    fn resource_path(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::Member(MemberExpr {
+                prop: MemberProp::Ident(p),
+                ..
+            }) if &*p.sym == "root" => Some(String::new()),
+            Expr::Ident(i) => self.resources.get(&*i.sym).cloned(),
+            Expr::Call(call) => {
+                let Callee::Expr(callee) = &call.callee else {
+                    return None;
+                };
+                let Expr::Member(member) = &**callee else {
+                    return None;
+                };
+                let MemberProp::Ident(method) = &member.prop else {
+                    return None;
+                };
+                match &*method.sym {
+                    "addResource" | "resourceForPath" => {
+                        let parent = self.resource_path(&member.obj)?;
+                        let segment = literal_str(arg(&call.args, 0)?)?;
+                        Some(format!("{}/{}", parent, segment.trim_matches('/')))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// An SST route target: `"src/get.handler"`, `{ handler }` or
+    /// `{ function: ... }`.
+    fn sst_handler(&self, expr: &Expr) -> Option<(PathBuf, String)> {
+        if let Some(handler) = literal_str(expr) {
+            return resolve_handler(self.app_root, &handler);
+        }
+        let Expr::Object(object) = expr else {
+            return None;
+        };
+        object_prop(object, "handler")
+            .or_else(|| object_prop(object, "function"))
+            .and_then(|inner| self.sst_handler(inner))
+    }
+
+    /// `"GET /notes/{id}"` → method and path.
+    fn sst_route(&mut self, key: &str, target: &Expr) {
+        let Some((method, path)) = key.trim().split_once(' ') else {
+            return;
+        };
+        if let Some((file, export)) = self.sst_handler(target) {
+            push_route(
+                &mut self.routes,
+                method,
+                path,
+                &file,
+                &export,
+                RouteSource::Sst,
+            );
+        }
+    }
+
+    /// An SST `routes` object: `{ "GET /notes": "src/list.handler", ... }`.
+    fn sst_routes(&mut self, routes: &ObjectLit) {
+        for prop in &routes.props {
+            let PropOrSpread::Prop(prop) = prop else {
+                continue;
+            };
+            let Prop::KeyValue(kv) = &**prop else {
+                continue;
+            };
+            if let Some(key) = prop_key(&kv.key) {
+                self.sst_route(&key, &kv.value);
+            }
+        }
+    }
+
+    /// CDK `httpApi.addRoutes({ path, methods: [HttpMethod.GET], integration })`.
+    fn cdk_http_routes(&mut self, options: &ObjectLit) {
+        let Some(path) = object_prop(options, "path").and_then(literal_str) else {
+            return;
+        };
+        let Some((file, export)) =
+            object_prop(options, "integration").and_then(|i| self.integration_handler(i))
+        else {
+            return;
+        };
+        let methods: Vec<String> = match object_prop(options, "methods") {
+            Some(Expr::Array(array)) => array
+                .elems
+                .iter()
+                .flatten()
+                .filter_map(|e| {
+                    literal_str(&e.expr).or_else(|| match &*e.expr {
+                        Expr::Member(MemberExpr {
+                            prop: MemberProp::Ident(p),
+                            ..
+                        }) => Some(p.sym.to_string()),
+                        _ => None,
+                    })
+                })
+                .collect(),
+            _ => vec!["ANY".to_string()],
+        };
+        for method in methods {
+            push_route(
+                &mut self.routes,
+                &method,
+                &path,
+                &file,
+                &export,
+                RouteSource::Cdk,
+            );
+        }
+    }
+}
+
+impl Visit for DefinitionVisitor<'_> {
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init) {
+            if let Some(handler) = self.lambda_function(init) {
+                self.functions.insert(binding.id.sym.to_string(), handler);
+            } else if let Some(path) = self.resource_path(init) {
+                self.resources.insert(binding.id.sym.to_string(), path);
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        // SST v2: `new Api(stack, "api", { routes: { ... } })`.
+        if matches!(
+            constructor_name(&new.callee),
+            Some("Api" | "ApiGatewayV1Api")
+        ) && let Some(Expr::Object(routes)) = new
+            .args
+            .as_deref()
+            .and_then(|args| object_arg(args, 2))
+            .and_then(|options| object_prop(options, "routes"))
+        {
+            self.sst_routes(routes);
+        }
+        new.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && let Expr::Member(member) = &**callee
+            && let MemberProp::Ident(method) = &member.prop
+        {
+            match &*method.sym {
+                // SST v2 `api.addRoutes(stack, { ... })`; CDK
+                // `httpApi.addRoutes({ path, methods, integration })`.
+                "addRoutes" => match call.args.len() {
+                    2 => {
+                        if let Some(routes) = object_arg(&call.args, 1) {
+                            self.sst_routes(routes);
+                        }
+                    }
+                    1 => {
+                        if let Some(options) = object_arg(&call.args, 0) {
+                            self.cdk_http_routes(options);
+                        }
+                    }
+                    _ => {}
+                },
+                // SST v3 `api.route("GET /x", "src/get.handler")`.
+                "route" if call.args.len() >= 2 => {
+                    if let (Some(key), Some(target)) =
+                        (arg(&call.args, 0).and_then(literal_str), arg(&call.args, 1))
+                    {
+                        self.sst_route(&key, target);
+                    }
+                }
+                // CDK REST `resource.addMethod('GET', new LambdaIntegration(fn))`.
+                "addMethod" => {
+                    let resolved = (
+                        arg(&call.args, 0).and_then(literal_str),
+                        self.resource_path(&member.obj),
+                        arg(&call.args, 1).and_then(|i| self.integration_handler(i)),
+                    );
+                    if let (Some(method), Some(path), Some((file, export))) = resolved {
+                        let path = if path.is_empty() {
+                            "/".to_string()
+                        } else {
+                            path
+                        };
+                        push_route(
+                            &mut self.routes,
+                            &method,
+                            &path,
+                            &file,
+                            &export,
+                            RouteSource::Cdk,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// An exported Lambda handler.
+enum HandlerFn<'m> {
+    Function(&'m Function, Span),
+    Arrow(&'m ArrowExpr),
+}
+
+enum HandlerBody<'m> {
+    Block(&'m BlockStmt),
+    Expr(&'m Expr),
+}
+
+impl<'m> HandlerFn<'m> {
+    fn span(&self) -> Span {
+        match self {
+            HandlerFn::Function(_, span) => *span,
+            HandlerFn::Arrow(arrow) => arrow.span,
+        }
+    }
+
+    /// Name of the `event` parameter.
+    fn first_param(&self) -> Option<String> {
+        let pat = match self {
+            HandlerFn::Function(function, _) => &function.params.first()?.pat,
+            HandlerFn::Arrow(arrow) => arrow.params.first()?,
+        };
+        match pat {
+            Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+            _ => None,
+        }
+    }
+
+    fn body(&self) -> HandlerBody<'m> {
+        match self {
+            HandlerFn::Function(function, _) => match &function.body {
+                Some(block) => HandlerBody::Block(block),
+                None => HandlerBody::Block(EMPTY_BLOCK),
+            },
+            HandlerFn::Arrow(arrow) => match &*arrow.body {
+                BlockStmtOrExpr::BlockStmt(block) => HandlerBody::Block(block),
+                BlockStmtOrExpr::Expr(expr) => HandlerBody::Expr(expr),
+            },
+        }
+    }
+}
+
+static EMPTY_BLOCK: &BlockStmt = &BlockStmt {
+    span: swc_common::DUMMY_SP,
+    ctxt: swc_common::SyntaxContext::empty(),
+    stmts: Vec::new(),
+};
+
+/// The function exported as `export`: `export const/function`, an export
+/// specifier, `export default`, or CommonJS `exports.x = ...` /
+/// `module.exports = { x }`.
+fn find_handler<'m>(module: &'m Module, export: &str) -> Option<HandlerFn<'m>> {
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(decl)) => {
+                if let Some(handler) = declared_fn(module, &decl.decl, export) {
+                    return Some(handler);
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(decl)) if export == "default" => {
+                if let DefaultDecl::Fn(f) = &decl.decl {
+                    return Some(HandlerFn::Function(&f.function, decl.span));
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(e)) if export == "default" => {
+                return fn_of(module, &e.expr, 0);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) if named.src.is_none() => {
+                for spec in &named.specifiers {
+                    let ExportSpecifier::Named(spec) = spec else {
+                        continue;
+                    };
+                    let ModuleExportName::Ident(orig) = &spec.orig else {
+                        continue;
+                    };
+                    let exported = match &spec.exported {
+                        Some(ModuleExportName::Ident(i)) => i.sym.to_string(),
+                        Some(ModuleExportName::Str(s)) => s.value.to_string(),
+                        None => orig.sym.to_string(),
+                    };
+                    if exported == export {
+                        return local_fn(module, &orig.sym, 0);
+                    }
+                }
+            }
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                let Expr::Assign(assign) = &**expr else {
+                    continue;
+                };
+                let AssignTarget::Simple(SimpleAssignTarget::Member(target)) = &assign.left else {
+                    continue;
+                };
+                if let Some(handler) = commonjs_export(module, target, &assign.right, export) {
+                    return Some(handler);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `exports.x = fn`, `module.exports.x = fn`, `module.exports = { x }`.
+fn commonjs_export<'m>(
+    module: &'m Module,
+    target: &MemberExpr,
+    value: &'m Expr,
+    export: &str,
+) -> Option<HandlerFn<'m>> {
+    let MemberProp::Ident(prop) = &target.prop else {
+        return None;
+    };
+    let is_ident = |expr: &Expr, name: &str| matches!(expr, Expr::Ident(i) if &*i.sym == name);
+    let is_module_exports = |expr: &Expr| match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(p),
+            ..
+        }) => is_ident(obj, "module") && &*p.sym == "exports",
+        _ => false,
+    };
+    if &*prop.sym == "exports" && is_ident(&target.obj, "module") {
+        let Expr::Object(object) = value else {
+            return None;
+        };
+        return object.props.iter().find_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::Shorthand(i) if &*i.sym == export => local_fn(module, &i.sym, 0),
+                Prop::KeyValue(kv) if prop_key(&kv.key).as_deref() == Some(export) => {
+                    fn_of(module, &kv.value, 0)
+                }
+                _ => None,
+            },
+            _ => None,
+        });
+    }
+    if &*prop.sym == export && (is_ident(&target.obj, "exports") || is_module_exports(&target.obj))
+    {
+        return fn_of(module, value, 0);
+    }
+    None
+}
+
+fn declared_fn<'m>(module: &'m Module, decl: &'m Decl, name: &str) -> Option<HandlerFn<'m>> {
+    match decl {
+        Decl::Fn(f) if &*f.ident.sym == name => Some(HandlerFn::Function(&f.function, f.span())),
+        Decl::Var(var) => var.decls.iter().find_map(|d| match (&d.name, &d.init) {
+            (Pat::Ident(binding), Some(init)) if &*binding.id.sym == name => fn_of(module, init, 0),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// A top-level function or binding named `name`, exported or not.
+fn local_fn<'m>(module: &'m Module, name: &str, depth: usize) -> Option<HandlerFn<'m>> {
+    if depth > 4 {
+        return None;
+    }
+    module.body.iter().find_map(|item| {
+        let decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+            _ => return None,
+        };
+        declared_fn(module, decl, name)
+    })
+}
+
+/// The function an expression evaluates to, unwrapping wrappers such as
+/// `middy(fn).use(...)` down to their first argument.
+fn fn_of<'m>(module: &'m Module, expr: &'m Expr, depth: usize) -> Option<HandlerFn<'m>> {
+    if depth > 4 {
+        return None;
+    }
+    match expr {
+        Expr::Arrow(arrow) => Some(HandlerFn::Arrow(arrow)),
+        Expr::Fn(f) => Some(HandlerFn::Function(&f.function, f.function.span)),
+        Expr::Paren(p) => fn_of(module, &p.expr, depth + 1),
+        Expr::TsAs(a) => fn_of(module, &a.expr, depth + 1),
+        Expr::TsSatisfies(s) => fn_of(module, &s.expr, depth + 1),
+        Expr::Ident(i) => local_fn(module, &i.sym, depth + 1),
+        Expr::Call(call) => match &call.callee {
+            Callee::Expr(callee) => match &**callee {
+                // `middy(fn).use(...)`: the wrapped call is the receiver.
+                Expr::Member(member) if matches!(&*member.obj, Expr::Call(_)) => {
+                    fn_of(module, &member.obj, depth + 1)
+                }
+                _ => fn_of(module, arg(&call.args, 0)?, depth + 1),
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Reads a handler body's success payload and parsed request body.
+struct HandlerScan<'a> {
+    cm: &'a SourceMap,
+    /// Name of the handler's `event` parameter.
+    event: Option<String>,
+    /// Nesting depth of functions inside the handler; only returns at depth
+    /// 0 answer the request.
+    depth: usize,
+    response: Option<(StaticEmission, String, usize)>,
+    request: Option<(String, usize)>,
+}
+
+impl HandlerScan<'_> {
+    fn snippet(&self, span: Span) -> (String, usize) {
+        (
+            self.cm.span_to_snippet(span).unwrap_or_default(),
+            self.cm.lookup_char_pos(span.lo).line,
+        )
+    }
+
+    /// A returned value: `{ statusCode: 200, body: JSON.stringify(x) }`
+    /// sends `x`; an object without `statusCode`, an array or an identifier
+    /// is the payload itself (API Gateway serializes it). Error statuses and
+    /// non-JSON bodies answer nothing.
+    fn classify_return(&mut self, expr: &Expr) {
+        if self.response.is_some() {
+            return;
+        }
+        match expr {
+            Expr::Paren(p) => self.classify_return(&p.expr),
+            Expr::TsAs(a) => self.classify_return(&a.expr),
+            Expr::TsSatisfies(s) => self.classify_return(&s.expr),
+            Expr::Object(object) => {
+                if object_prop(object, "statusCode").is_none() {
+                    let (text, line) = self.snippet(object.span);
+                    self.response = Some((StaticEmission::Return, text, line));
+                    return;
+                }
+                if let Some(Expr::Lit(Lit::Num(status))) = object_prop(object, "statusCode")
+                    && status.value >= 400.0
+                {
+                    return;
+                }
+                if let Some(Expr::Call(call)) = object_prop(object, "body")
+                    && is_json_call(call, "stringify")
+                    && let Some(payload) = arg(&call.args, 0)
+                {
+                    let (text, line) = self.snippet(payload.span());
+                    self.response = Some((StaticEmission::Send, text, line));
+                }
+            }
+            Expr::Array(_) | Expr::Ident(_) => {
+                let (text, line) = self.snippet(expr.span());
+                self.response = Some((StaticEmission::Return, text, line));
+            }
+            _ => {}
+        }
+    }
+
+    /// `JSON.parse(event.body)`, `JSON.parse(event.body ?? '{}')`, …
+    fn parses_body(&self, expr: &Expr) -> bool {
+        let Expr::Call(call) = expr else {
+            return false;
+        };
+        if !is_json_call(call, "parse") {
+            return false;
+        }
+        let mut source = arg(&call.args, 0);
+        while let Some(expr) = source {
+            source = match expr {
+                Expr::Paren(p) => Some(&p.expr),
+                Expr::TsNonNull(n) => Some(&n.expr),
+                Expr::TsAs(a) => Some(&a.expr),
+                Expr::Bin(bin)
+                    if matches!(bin.op, BinaryOp::NullishCoalescing | BinaryOp::LogicalOr) =>
+                {
+                    Some(&bin.left)
+                }
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(p),
+                    ..
+                }) if &*p.sym == "body" => {
+                    return matches!(
+                        (&**obj, &self.event),
+                        (Expr::Ident(i), Some(event)) if &*i.sym == event.as_str()
+                    );
+                }
+                _ => None,
+            };
+        }
+        false
+    }
+}
+
+/// `JSON.<method>(...)`.
+fn is_json_call(call: &CallExpr, method: &str) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    matches!(
+        &**callee,
+        Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(p), .. })
+            if matches!(&**obj, Expr::Ident(i) if &*i.sym == "JSON") && &*p.sym == method
+    )
+}
+
+impl Visit for HandlerScan<'_> {
+    fn visit_function(&mut self, function: &Function) {
+        self.depth += 1;
+        function.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.depth += 1;
+        arrow.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_return_stmt(&mut self, ret: &ReturnStmt) {
+        if self.depth == 0
+            && let Some(arg) = &ret.arg
+        {
+            self.classify_return(arg);
+        }
+        ret.visit_children_with(self);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        if self.request.is_none() {
+            let parsed = match expr {
+                // Keep the assertion: `JSON.parse(event.body) as CreateUser`
+                // carries the type.
+                Expr::TsAs(a) => self.parses_body(&a.expr),
+                _ => self.parses_body(expr),
+            };
+            if parsed {
+                self.request = Some(self.snippet(expr.span()));
+            }
+        }
+        expr.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn summary(routing: &ServerlessRouting, root: &Path) -> Vec<(String, String, String, String)> {
+        let root = root.canonicalize().unwrap();
+        routing
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.method.clone(),
+                    r.path.clone(),
+                    r.handler_file
+                        .strip_prefix(&root)
+                        .unwrap()
+                        .display()
+                        .to_string(),
+                    r.export.clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn route(
+        method: &str,
+        path: &str,
+        file: &str,
+        export: &str,
+    ) -> (String, String, String, String) {
+        (
+            method.to_string(),
+            path.to_string(),
+            file.to_string(),
+            export.to_string(),
+        )
+    }
+
+    #[test]
+    fn serverless_yml_and_sam_events_map_to_handler_exports() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "serverless.yml",
+            r#"
+service: users
+functions:
+  getUser:
+    handler: src/handlers/users.get
+    events:
+      - httpApi: GET /users/{id}
+      - http:
+          path: users/{id}/avatar
+          method: get
+  catchAll:
+    handler: src/handlers/users.proxy
+    events:
+      - http:
+          path: ${self:custom.prefix}/x
+          method: post
+      - httpApi:
+          path: /files/{proxy+}
+          method: any
+"#,
+        );
+        write(
+            root,
+            "src/handlers/users.ts",
+            "export const get = async () => ({});",
+        );
+        write(
+            root,
+            "orders/template.yaml",
+            r#"
+Transform: AWS::Serverless-2016-10-31
+Globals:
+  Function:
+    CodeUri: functions/
+Resources:
+  CreateOrder:
+    Type: AWS::Serverless::Function
+    Properties:
+      Handler: create.lambdaHandler
+      Events:
+        Create:
+          Type: Api
+          Properties:
+            Path: /orders
+            Method: post
+        Queue:
+          Type: SQS
+          Properties:
+            Queue: !GetAtt Queue.Arn
+"#,
+        );
+        write(
+            root,
+            "orders/functions/create.js",
+            "exports.lambdaHandler = async () => {};",
+        );
+        // Deploy output carries a copy of the definitions.
+        write(root, ".serverless/serverless.yml", "functions: {}");
+
+        let routing = ServerlessRouting::collect(root, &[]);
+        let mut routes = summary(&routing, root);
+        routes.sort();
+        assert_eq!(
+            routes,
+            vec![
+                route("ALL", "/files/*", "src/handlers/users.ts", "proxy"),
+                route("GET", "/users/:id", "src/handlers/users.ts", "get"),
+                route("GET", "/users/:id/avatar", "src/handlers/users.ts", "get"),
+                route(
+                    "POST",
+                    "/orders",
+                    "orders/functions/create.js",
+                    "lambdaHandler"
+                ),
+            ],
+            "the variable-built path and the SQS event declare no route"
+        );
+    }
+
+    #[test]
+    fn sst_and_cdk_constructs_map_routes_to_handler_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "sst.config.ts", "");
+        write(
+            root,
+            "stacks/Api.ts",
+            r#"
+import { Api, StackContext } from "sst/constructs";
+export function ApiStack({ stack }: StackContext) {
+  const api = new Api(stack, "api", {
+    routes: {
+      "GET /notes": "packages/functions/src/list.handler",
+      "POST /notes": { function: { handler: "packages/functions/src/create.main" } },
+      "$default": "packages/functions/src/fallback.handler",
+    },
+  });
+  api.addRoutes(stack, { "DELETE /notes/{id}": "packages/functions/src/remove.handler" });
+}
+"#,
+        );
+        for name in ["list", "create", "remove"] {
+            write(root, &format!("packages/functions/src/{name}.ts"), "");
+        }
+        write(root, "infra/cdk.json", "{}");
+        write(
+            root,
+            "infra/lib/stack.ts",
+            r#"
+import * as path from "path";
+import * as lambda from "aws-cdk-lib/aws-lambda";
+import { NodejsFunction } from "aws-cdk-lib/aws-lambda-nodejs";
+import { HttpApi, HttpMethod } from "aws-cdk-lib/aws-apigatewayv2";
+import { HttpLambdaIntegration } from "aws-cdk-lib/aws-apigatewayv2-integrations";
+import { LambdaIntegration, RestApi } from "aws-cdk-lib/aws-apigateway";
+
+const getUser = new NodejsFunction(this, "GetUser", {
+  entry: path.join(__dirname, "../lambda/users.ts"),
+  handler: "getUser",
+});
+httpApi.addRoutes({
+  path: "/users/{id}",
+  methods: [HttpMethod.GET, HttpMethod.PATCH],
+  integration: new HttpLambdaIntegration("GetUser", getUser),
+});
+const legacy = new lambda.Function(this, "Legacy", {
+  code: lambda.Code.fromAsset("legacy"),
+  handler: "app.handler",
+  runtime: lambda.Runtime.NODEJS_20_X,
+});
+const orders = rest.root.addResource("orders");
+orders.addResource("{orderId}").addMethod("GET", new LambdaIntegration(legacy));
+"#,
+        );
+        write(root, "infra/lambda/users.ts", "");
+        write(root, "infra/legacy/app.js", "");
+
+        let files = vec![root.join("stacks/Api.ts"), root.join("infra/lib/stack.ts")];
+        let routing = ServerlessRouting::collect(root, &files);
+        let mut routes = summary(&routing, root);
+        routes.sort();
+        assert_eq!(
+            routes,
+            vec![
+                route(
+                    "DELETE",
+                    "/notes/:id",
+                    "packages/functions/src/remove.ts",
+                    "handler"
+                ),
+                route("GET", "/notes", "packages/functions/src/list.ts", "handler"),
+                route("GET", "/orders/:orderId", "infra/legacy/app.js", "handler"),
+                route("GET", "/users/:id", "infra/lambda/users.ts", "getUser"),
+                route("PATCH", "/users/:id", "infra/lambda/users.ts", "getUser"),
+                route("POST", "/notes", "packages/functions/src/create.ts", "main"),
+            ]
+        );
+    }
+
+    #[test]
+    fn handler_endpoints_read_the_export_response_and_parsed_body() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "serverless.yml",
+            r#"
+functions:
+  get:
+    handler: src/users.get
+    events:
+      - httpApi: GET /users/{id}
+  create:
+    handler: src/users.create
+    events:
+      - httpApi: POST /users
+  list:
+    handler: src/users.list
+    events:
+      - httpApi: GET /users
+  ping:
+    handler: src/users.ping
+    events:
+      - httpApi: GET /ping
+"#,
+        );
+        write(
+            root,
+            "src/users.ts",
+            r#"import middy from "@middy/core";
+
+export const get = async (event: APIGatewayProxyEventV2) => {
+  const user = await findUser(event.pathParameters?.id);
+  if (!user) {
+    return { statusCode: 404, body: JSON.stringify({ message: "not found" }) };
+  }
+  return { statusCode: 200, body: JSON.stringify(user) };
+};
+
+async function createUser(event: APIGatewayProxyEventV2) {
+  const input = JSON.parse(event.body ?? "{}") as CreateUserInput;
+  return saveUser(input);
+}
+export const create = middy(createUser).use(jsonErrors());
+
+export function list(): Promise<User[]> {
+  return users;
+}
+
+export const ping = async () => ({ statusCode: 204 });
+"#,
+        );
+
+        let routing = ServerlessRouting::collect(root, &[]);
+        let mut endpoints = routing.handler_endpoints(&root.join("src/users.ts"));
+        endpoints.sort_by_key(|e| e.line_number);
+        let found: Vec<_> = endpoints
+            .iter()
+            .map(|e| {
+                (
+                    e.method.as_str(),
+                    e.path.as_str(),
+                    e.export.as_str(),
+                    e.line_number,
+                    e.emission,
+                    e.response_text.as_deref(),
+                    e.response_line,
+                    e.request_text.as_deref(),
+                    e.request_line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "GET",
+                    "/users/:id",
+                    "get",
+                    3,
+                    StaticEmission::Send,
+                    Some("user"),
+                    Some(8),
+                    None,
+                    None
+                ),
+                (
+                    "POST",
+                    "/users",
+                    "create",
+                    11,
+                    StaticEmission::None,
+                    None,
+                    None,
+                    Some(r#"JSON.parse(event.body ?? "{}") as CreateUserInput"#),
+                    Some(12)
+                ),
+                (
+                    "GET",
+                    "/users",
+                    "list",
+                    17,
+                    StaticEmission::Return,
+                    Some("users"),
+                    Some(18),
+                    None,
+                    None
+                ),
+                (
+                    "GET",
+                    "/ping",
+                    "ping",
+                    21,
+                    StaticEmission::None,
+                    None,
+                    None,
+                    None,
+                    None
+                ),
+            ]
+        );
+        assert!(
+            routing
+                .handler_endpoints(&root.join("serverless.yml"))
+                .is_empty()
+        );
+    }
+}
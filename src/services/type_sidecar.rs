@@ -12,6 +12,7 @@
 //! - **Parallel startup**: SWC scanning and LLM analysis can proceed while sidecar initializes
 //! - **JSON message protocol**: All communication is via stdin/stdout JSON messages
 
+use crate::config::{StrictnessProfile, WireSerialization};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::io::{BufRead, BufReader, Write};
@@ -290,6 +291,13 @@ pub struct CheckPairSpec {
     pub type_kind: ProbeTypeKind,
     pub producer: CheckPairEndpoint,
     pub consumer: CheckPairEndpoint,
+    /// The producer service's response serialization; `json` makes the
+    /// sidecar compare HTTP responses through `Jsonify<T>`.
+    #[serde(skip_serializing_if = "WireSerialization::is_native")]
+    pub serialization: WireSerialization,
+    /// The consumer service's strictness profile.
+    #[serde(skip_serializing_if = "StrictnessProfile::is_standard")]
+    pub strictness: StrictnessProfile,
 }
 
 /// Per-alias capture record (serialization tier + self-check outcome).
@@ -380,6 +388,9 @@ pub struct CheckVerdict {
     /// TS diagnostic codes attributed to this pair's probe, sorted.
     #[serde(default)]
     pub codes: Vec<i64>,
+    /// Incompatible pairs: the diagnostic broken down per field.
+    #[serde(default)]
+    pub fields: Vec<crate::findings::FieldMismatch>,
 }
 
 /// A service whose pairs are degraded wholesale (install failure or poison).
@@ -1788,10 +1799,25 @@ mod tests {
                 service_name: "b".into(),
                 alias: "B".into(),
             },
+            serialization: WireSerialization::Native,
+            strictness: StrictnessProfile::Standard,
         };
         let json = serde_json::to_string(&spec).unwrap();
         assert!(json.contains(r#""protocol":"pubsub""#));
         assert!(json.contains(r#""type_kind":"both""#));
+        assert!(!json.contains("serialization"));
+        let spec = CheckPairSpec {
+            serialization: WireSerialization::Json,
+            ..spec
+        };
+        let json = serde_json::to_string(&spec).unwrap();
+        assert!(json.contains(r#""serialization":"json""#));
+        let spec = CheckPairSpec {
+            strictness: StrictnessProfile::StrictOptional,
+            ..spec
+        };
+        let json = serde_json::to_string(&spec).unwrap();
+        assert!(json.contains(r#""strictness":"strict-optional""#));
 
         for (wire, bucket) in [
             ("compatible", VerdictBucket::Compatible),
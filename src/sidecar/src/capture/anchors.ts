@@ -214,9 +214,21 @@ export function resolveAnchor(
     located = builderReaim.node;
     reaimNote = builderReaim.note;
   }
-  let type = checker.getTypeAtLocation(located);
-  if ((request.unwrap ?? 'awaited') === 'awaited') {
-    type = checker.getAwaitedType(type) ?? type;
+  const awaited = (raw: ts.Type) =>
+    (request.unwrap ?? 'awaited') === 'awaited' ? (checker.getAwaitedType(raw) ?? raw) : raw;
+  let type = awaited(checker.getTypeAtLocation(located));
+  // A value the checker leaves as a top type (`any` off `req.body` or
+  // `res.json()`) that is run through a schema library's validator is typed
+  // by the schema's output. An annotated or otherwise concrete type is the
+  // author's own statement and stays as it is.
+  if (isTopType(type)) {
+    const validation = schemaValidationFor(checker, located);
+    if (validation) {
+      type = awaited(validation.output);
+      reaimNote =
+        `anchored on the output type of the schema validated by ` +
+        `\`${validation.call.getText().slice(0, 80)}\``;
+    }
   }
   // #433: the checker gave us nothing (whole top type) — try the syntactic
   // recovery for an inline literal type argument of an unresolvable generic
@@ -272,6 +284,199 @@ function isTopType(type: ts.Type): boolean {
   );
 }
 
+/**
+ * Schema-library validation calls (zod `S.parse(x)` / `S.safeParse(x)` and
+ * their async forms, yup `S.validate(x)` / `S.validateSync(x)` / `S.cast(x)`,
+ * io-ts `C.decode(x)`, valibot `parse(S, x)` / `safeParse(S, x)`). The method
+ * name only nominates a call; it counts when the schema operand's type carries
+ * an output marker (see `schemaOutputType`), so `JSON.parse(x)` never does.
+ */
+const SCHEMA_VALIDATION_METHODS = new Set([
+  'parse',
+  'safeParse',
+  'parseAsync',
+  'safeParseAsync',
+  'validate',
+  'validateSync',
+  'cast',
+  'decode',
+]);
+
+interface SchemaValidation {
+  call: ts.CallExpression;
+  /** The validated value. */
+  data: ts.Expression;
+  /** The schema's output type (`z.infer<typeof S>` and equivalents). */
+  output: ts.Type;
+}
+
+/**
+ * The output type a schema value declares, read off the phantom member each
+ * library keeps for its `infer` helper: zod `_output`, yup `__outputType`,
+ * io-ts `_A`, valibot `~types.output` (v1) / `_types.output` (v0). Undefined
+ * for a non-schema or a schema whose output decays to a top type.
+ */
+function schemaOutputType(
+  checker: ts.TypeChecker,
+  schema: ts.Expression
+): ts.Type | undefined {
+  const type = checker.getTypeAtLocation(schema);
+  if (isTopType(type)) return undefined;
+  const member = (of: ts.Type, name: string): ts.Type | undefined => {
+    const symbol = of.getProperty(name);
+    return symbol
+      ? checker.getNonNullableType(checker.getTypeOfSymbolAtLocation(symbol, schema))
+      : undefined;
+  };
+  for (const name of ['_output', '__outputType', '_A']) {
+    const output = member(type, name);
+    if (output && !isTopType(output)) return output;
+  }
+  for (const name of ['~types', '_types']) {
+    const types = member(type, name);
+    const output = types && member(types, 'output');
+    if (output && !isTopType(output)) return output;
+  }
+  return undefined;
+}
+
+function schemaValidationCall(
+  checker: ts.TypeChecker,
+  call: ts.CallExpression
+): SchemaValidation | undefined {
+  const callee = call.expression;
+  const name = ts.isPropertyAccessExpression(callee)
+    ? callee.name.text
+    : ts.isIdentifier(callee)
+      ? callee.text
+      : undefined;
+  if (!name || !SCHEMA_VALIDATION_METHODS.has(name)) return undefined;
+  const [first, second] = call.arguments;
+  // Function form first: `parse(S, x)` takes the schema as its first argument.
+  if (first && second) {
+    const output = schemaOutputType(checker, first);
+    if (output) return { call, data: second, output };
+  }
+  if (first && ts.isPropertyAccessExpression(callee)) {
+    const output = schemaOutputType(checker, callee.expression);
+    if (output) return { call, data: first, output };
+  }
+  return undefined;
+}
+
+/** Strip `await`, parentheses, and type assertions. */
+function unwrapExpression(node: ts.Node): ts.Node {
+  while (
+    ts.isAwaitExpression(node) ||
+    ts.isParenthesizedExpression(node) ||
+    ts.isAsExpression(node) ||
+    ts.isNonNullExpression(node) ||
+    ts.isSatisfiesExpression(node)
+  ) {
+    node = node.expression;
+  }
+  return node;
+}
+
+/**
+ * The schema validation governing the located value, in three shapes:
+ *  1. the located node IS the validation (`S.parse(req.body)`);
+ *  2. it is the validated value, possibly through `await`/casts and a
+ *     `.json()` hop (`S.parse(await res.json())` located on `res.json()`);
+ *  3. it initializes (or is) a binding that flows into exactly one
+ *     validation in the file (`const raw = await res.json(); S.parse(raw)`,
+ *     or `const result = S.safeParse(body)` located on `result`).
+ * Anything else — including a binding validated against two different
+ * schemas — keeps the raw type.
+ */
+function schemaValidationFor(
+  checker: ts.TypeChecker,
+  located: ts.Node
+): SchemaValidation | undefined {
+  const inner = unwrapExpression(located);
+  if (ts.isCallExpression(inner)) {
+    const direct = schemaValidationCall(checker, inner);
+    if (direct) return direct;
+  }
+
+  let current: ts.Node = located;
+  for (;;) {
+    const up: ts.Node | undefined = current.parent;
+    if (!up) break;
+    if (
+      ts.isAwaitExpression(up) ||
+      ts.isParenthesizedExpression(up) ||
+      ts.isAsExpression(up) ||
+      ts.isNonNullExpression(up) ||
+      ts.isSatisfiesExpression(up)
+    ) {
+      current = up;
+      continue;
+    }
+    if (
+      ts.isPropertyAccessExpression(up) &&
+      up.expression === current &&
+      up.name.text === 'json' &&
+      ts.isCallExpression(up.parent) &&
+      up.parent.expression === up
+    ) {
+      current = up.parent;
+      continue;
+    }
+    break;
+  }
+  const parent = current.parent;
+  if (parent && ts.isCallExpression(parent)) {
+    const validation = schemaValidationCall(checker, parent);
+    if (
+      validation &&
+      unwrapExpression(validation.data) === unwrapExpression(current)
+    ) {
+      return validation;
+    }
+  }
+
+  const declaration =
+    parent && ts.isVariableDeclaration(parent) && parent.initializer === current
+      ? parent
+      : ts.isVariableDeclaration(located)
+        ? located
+        : ts.isIdentifier(located) &&
+            ts.isVariableDeclaration(located.parent) &&
+            located.parent.name === located
+          ? located.parent
+          : undefined;
+  if (!declaration || !ts.isIdentifier(declaration.name)) return undefined;
+  if (declaration.initializer) {
+    const init = unwrapExpression(declaration.initializer);
+    if (ts.isCallExpression(init)) {
+      const initialized = schemaValidationCall(checker, init);
+      if (initialized) return initialized;
+    }
+  }
+  const binding = checker.getSymbolAtLocation(declaration.name);
+  if (!binding) return undefined;
+  const uses: SchemaValidation[] = [];
+  const visit = (node: ts.Node): void => {
+    if (ts.isCallExpression(node)) {
+      const validation = schemaValidationCall(checker, node);
+      const data = validation && unwrapExpression(validation.data);
+      if (
+        validation &&
+        data &&
+        ts.isIdentifier(data) &&
+        checker.getSymbolAtLocation(data) === binding
+      ) {
+        uses.push(validation);
+      }
+    }
+    ts.forEachChild(node, visit);
+  };
+  visit(declaration.getSourceFile());
+  const distinct = new Set(uses.map((u) => u.output));
+  return uses.length > 0 && distinct.size === 1 ? uses[0] : undefined;
+}
+
 /**
  * The checker's intrinsic ERROR type: resolution of the reference FAILED
  * (missing package on a bare checkout, unresolvable symbol). Distinct from a
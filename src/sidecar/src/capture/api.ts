@@ -244,8 +244,27 @@ export interface CheckPairSpec {
   type_kind: ProbeTypeKind;
   producer: CheckPairEndpoint;
   consumer: CheckPairEndpoint;
+  /**
+   * How the producer puts its response on the wire. `json` (HTTP responses
+   * only) compares the consumer against `Jsonify<Sent>` — what
+   * `JSON.stringify` actually sends — instead of the handler's in-memory
+   * type. Absent means `native`.
+   */
+  serialization?: WireSerialization;
+  /**
+   * Which assignability rules judge the pair (the consumer service's
+   * `strictness` in `carrick.json`). Absent means `standard`. See
+   * `pushAssignment` in check-probe for what each profile compares.
+   */
+  strictness?: StrictnessProfile;
 }
 
+/** Named compatibility rule sets, loosest first. */
+export type StrictnessProfile = 'lenient' | 'standard' | 'strict-optional' | 'exact';
+
+/** Producer response serialization mode (per service, from `carrick.json`). */
+export type WireSerialization = 'native' | 'json';
+
 /**
  * Four-bucket classifier output (pinned decision 7):
  *  - compatible: no diagnostics; the value-level assignment holds.
@@ -279,6 +298,30 @@ export interface CheckVerdict {
   diagnostic?: string;
   /** TS diagnostic codes attributed to this pair's probe, sorted. */
   codes: number[];
+  /**
+   * Incompatible pairs only: the mismatch broken down by field, read from the
+   * compiler's elaboration (see `check-explain.ts`). Absent when nothing in
+   * the elaboration was recognised.
+   */
+  fields?: FieldMismatch[];
+}
+
+export type FieldMismatchKind =
+  | 'missing'
+  | 'extra_required'
+  | 'type_changed'
+  | 'nullability'
+  | 'enum_narrowing';
+
+/** One field-level difference between the producer and consumer types. */
+export interface FieldMismatch {
+  /** Dotted field path from the checked type's root; empty for the whole type. */
+  path: string;
+  kind: FieldMismatchKind;
+  /** The producer's type at the path; absent when the producer lacks it. */
+  producer?: string;
+  /** The consumer's type at the path; absent when the consumer lacks it. */
+  consumer?: string;
 }
 
 /**
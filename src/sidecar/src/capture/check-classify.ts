@@ -10,7 +10,9 @@
  *   surface import error (probe lines 1-2)-> unverifiable
  *   IsAny gate fired (TS2344)             -> gate_caught_baked_any
  *   IsUnknown/IsNever gate fired (TS2344) -> unverifiable
- *   assignment-class error                -> incompatible
+ *   assignment-class error                -> incompatible (+ field breakdown
+ *                                            from the checker, see
+ *                                            check-explain)
  *   no diagnostics                        -> compatible     [lowest precedence]
  *
  * Gate precedence over the assignment line is load-bearing: an `unknown` side
@@ -20,7 +22,7 @@
  * Seam: node builtins + this bundle only.
  */
 
-import type { CheckVerdict } from './api.js';
+import type { CheckVerdict, FieldMismatch } from './api.js';
 import type { GateName, ProbePlan, Side } from './check-probe.js';
 import { scrubDiagnostic, type ScrubContext } from './check-scrub.js';
 
@@ -92,11 +94,15 @@ export interface ClassifyInput {
    */
   poisonReason: (serviceName: string, alias: string) => string | undefined;
   scrubCtx: ScrubContext;
+  /** Field-level breakdown of an incompatible pair (check-explain's
+   * `probeExplainer`); absent, an incompatible verdict carries only the
+   * compiler text. */
+  explain?: (plan: ProbePlan) => FieldMismatch[];
 }
 
 /** Classify one pair into exactly one bucket, honouring the precedence order. */
 export function classifyPair(input: ClassifyInput): CheckVerdict {
-  const { plan, probeDiags, poisonReason, scrubCtx } = input;
+  const { plan, probeDiags, poisonReason, scrubCtx, explain } = input;
   const codes = [...new Set(probeDiags.map((d) => d.code))].sort((a, b) => a - b);
   const base = { pair_id: plan.pairId, pair_key: plan.spec.pair_key, codes };
 
@@ -163,15 +169,18 @@ export function classifyPair(input: ClassifyInput): CheckVerdict {
     (d) => d.line === plan.assignmentLine && ASSIGNMENT_CODES.has(d.code)
   );
   if (assignDiag) {
+    const diagnostic = scrubDiagnostic(
+      assignDiag.message,
+      scrubCtx,
+      plan.sentEndpoint.alias,
+      plan.expectedEndpoint.alias
+    );
+    const fields = explain?.(plan) ?? [];
     return {
       ...base,
       bucket: 'incompatible',
-      diagnostic: scrubDiagnostic(
-        assignDiag.message,
-        scrubCtx,
-        plan.sentEndpoint.alias,
-        plan.expectedEndpoint.alias
-      ),
+      diagnostic,
+      ...(fields.length > 0 ? { fields } : {}),
     };
   }
 
@@ -0,0 +1,245 @@
+/**
+ * Field-level explanation of an incompatible pair for the v2 check phase.
+ *
+ * The `tsc` CLI stays the judge; this pass only explains a pair it already
+ * found incompatible. The compiler's elaborated error is exact but truncated
+ * (`Type '{ ... }' is not assignable to type '{ ... }'` with the interesting
+ * member elided, and only the first failing member elaborated), so instead of
+ * parsing that text we ask the checker directly: load the probe program, take
+ * the declared type of the probe's `expected` binding and the type of the
+ * value assigned to it, and walk both with `getPropertiesOfType` /
+ * `isTypeAssignableTo`, descending only into members that don't assign. Each
+ * failing leaf becomes a field mismatch — the dotted field path, what the
+ * producer and the consumer have there, and the kind of mismatch:
+ *
+ *   missing         the producer lacks a field the consumer reads
+ *   extra_required  the producer requires a field the consumer doesn't send
+ *   type_changed    both sides have the field with incompatible types
+ *   nullability     one side admits null/undefined (or is optional) where
+ *                   the other requires a value
+ *   enum_narrowing  a literal one side can produce is not in the other's
+ *                   literal union
+ *
+ * Producer/consumer roles come from the probe direction (who is `sent`), so
+ * the same walk reads correctly for response and request pairs. Best-effort
+ * by construction: a probe the program can't load yields no entries, and the
+ * verdict's `diagnostic` still carries the full compiler text.
+ *
+ * Seam: `typescript` + this bundle only.
+ */
+
+import * as path from 'node:path';
+import ts from 'typescript';
+import type { FieldMismatch, FieldMismatchKind } from './api.js';
+import type { Direction, ProbePlan } from './check-probe.js';
+
+/** Most entries reported for one pair. */
+const MAX_FIELDS = 20;
+/** Longest type text kept per side. */
+const MAX_TYPE_CHARS = 80;
+/** Deepest field path walked; recursive types stop here. */
+const MAX_DEPTH = 8;
+
+/** The strict-optional profile's marker for "this optional member may be
+ * absent" (see `pushAssignment` in check-probe). */
+const ABSENT_TYPE = 'typeof compatAbsent';
+
+function joinPath(prefix: string, field: string): string {
+  return prefix ? `${prefix}.${field}` : field;
+}
+
+function clip(text: string): string {
+  const collapsed = text.split(ABSENT_TYPE).join('absent').replace(/\s+/g, ' ').trim();
+  return collapsed.length <= MAX_TYPE_CHARS
+    ? collapsed
+    : collapsed.slice(0, MAX_TYPE_CHARS - 1) + '…';
+}
+
+function members(type: ts.Type): ts.Type[] {
+  return type.isUnion() ? type.types : [type];
+}
+
+function isObjectLike(type: ts.Type): boolean {
+  return (type.flags & (ts.TypeFlags.Object | ts.TypeFlags.Intersection)) !== 0;
+}
+
+function isLiteralUnion(type: ts.Type): boolean {
+  return members(type).every(
+    (member) => (member.flags & (ts.TypeFlags.Literal | ts.TypeFlags.BooleanLiteral)) !== 0
+  );
+}
+
+/** `null`, `undefined`, or the strict-optional `Absent` marker. */
+function isNullish(checker: ts.TypeChecker, type: ts.Type): boolean {
+  return (
+    (type.flags & (ts.TypeFlags.Null | ts.TypeFlags.Undefined)) !== 0 ||
+    ((type.flags & ts.TypeFlags.UniqueESSymbol) !== 0 &&
+      checker.typeToString(type) === ABSENT_TYPE)
+  );
+}
+
+/** Explain why `sent` does not assign to `expected`. */
+export function explainMismatch(
+  checker: ts.TypeChecker,
+  sent: ts.Type,
+  expected: ts.Type,
+  direction: Direction
+): FieldMismatch[] {
+  const producerIsSent = direction.sent === 'producer';
+  const out: FieldMismatch[] = [];
+  const text = (type: ts.Type) =>
+    checker.typeToString(type, undefined, ts.TypeFormatFlags.NoTruncation);
+
+  const push = (
+    fieldPath: string,
+    kind: FieldMismatchKind,
+    sentSide: string | undefined,
+    expectedSide: string | undefined
+  ) => {
+    const producer = producerIsSent ? sentSide : expectedSide;
+    const consumer = producerIsSent ? expectedSide : sentSide;
+    out.push({
+      path: fieldPath,
+      kind,
+      ...(producer !== undefined ? { producer: clip(producer) } : {}),
+      ...(consumer !== undefined ? { consumer: clip(consumer) } : {}),
+    });
+  };
+
+  const leaf = (fieldPath: string, s: ts.Type, e: ts.Type) => {
+    // Only the sent members that don't assign say why.
+    const failing = members(s).filter((member) => !checker.isTypeAssignableTo(member, e));
+    let kind: FieldMismatchKind = 'type_changed';
+    if (failing.length > 0 && failing.every((member) => isNullish(checker, member))) {
+      kind = 'nullability';
+    } else if (isLiteralUnion(s) && isLiteralUnion(e)) {
+      kind = 'enum_narrowing';
+    }
+    push(fieldPath, kind, text(s), text(e));
+  };
+
+  const walk = (fieldPath: string, s: ts.Type, e: ts.Type, depth: number) => {
+    if (out.length >= MAX_FIELDS || checker.isTypeAssignableTo(s, e)) return;
+    if (depth < MAX_DEPTH && checker.isArrayType(s) && checker.isArrayType(e)) {
+      const [sElement] = checker.getTypeArguments(s as ts.TypeReference);
+      const [eElement] = checker.getTypeArguments(e as ts.TypeReference);
+      if (sElement && eElement) {
+        walk(`${fieldPath}[]`, sElement, eElement, depth + 1);
+        return;
+      }
+    }
+    if (depth >= MAX_DEPTH || !isObjectLike(s) || !isObjectLike(e)) {
+      leaf(fieldPath, s, e);
+      return;
+    }
+    const before = out.length;
+    for (const eProp of checker.getPropertiesOfType(e)) {
+      const name = eProp.getName();
+      const field = joinPath(fieldPath, name);
+      const sProp = checker.getPropertyOfType(s, name);
+      if (!sProp) {
+        if ((eProp.flags & ts.SymbolFlags.Optional) === 0) {
+          // The sent side lacks a field the expected side requires.
+          push(field, producerIsSent ? 'missing' : 'extra_required', undefined, 'required');
+        }
+        continue;
+      }
+      const sType = checker.getTypeOfSymbol(sProp);
+      const eType = checker.getTypeOfSymbol(eProp);
+      if (
+        (sProp.flags & ts.SymbolFlags.Optional) !== 0 &&
+        (eProp.flags & ts.SymbolFlags.Optional) === 0 &&
+        checker.isTypeAssignableTo(checker.getNonNullableType(sType), eType) &&
+        !checker.isTypeAssignableTo(sType, eType)
+      ) {
+        push(field, 'nullability', 'optional', 'required');
+        continue;
+      }
+      walk(field, sType, eType, depth + 1);
+    }
+    // Every member assigns, yet the whole doesn't (signatures, index
+    // signatures): the pair differs at this level.
+    if (out.length === before) leaf(fieldPath, s, e);
+  };
+
+  walk('', sent, expected, 0);
+  return out.slice(0, MAX_FIELDS);
+}
+
+/** Explain the probe's assignment: `const expected: E = <sent>`. */
+export function explainProbe(
+  program: ts.Program,
+  probeFile: string,
+  direction: Direction
+): FieldMismatch[] {
+  const source = program.getSourceFile(probeFile);
+  if (!source) return [];
+  const checker = program.getTypeChecker();
+  for (const statement of source.statements) {
+    if (!ts.isVariableStatement(statement)) continue;
+    for (const declaration of statement.declarationList.declarations) {
+      if (
+        ts.isIdentifier(declaration.name) &&
+        declaration.name.text === 'expected' &&
+        declaration.type &&
+        declaration.initializer
+      ) {
+        return explainMismatch(
+          checker,
+          checker.getTypeAtLocation(declaration.initializer),
+          checker.getTypeFromTypeNode(declaration.type),
+          direction
+        );
+      }
+    }
+  }
+  return [];
+}
+
+/**
+ * An explainer over the assembled workspace's probes. Each explained pair
+ * gets a program rooted at its own probe file plus the workspace's shared
+ * declaration files, so the cost is that probe's import closure (the two
+ * stubs it references), not every probe in the workspace. Successive
+ * programs pass the previous one as `oldProgram`, so stub declarations
+ * shared between pairs are parsed once. The config is read on first use; a
+ * run with no incompatible pair never pays for it, and a config the compiler
+ * API can't load explains nothing.
+ */
+export function probeExplainer(
+  probesDir: string
+): (plan: ProbePlan) => FieldMismatch[] {
+  let config: { options: ts.CompilerOptions; shared: string[] } | null | undefined;
+  let program: ts.Program | undefined;
+  const load = (): typeof config => {
+    const configPath = path.join(probesDir, 'tsconfig.json');
+    const read = ts.readConfigFile(configPath, ts.sys.readFile);
+    if (read.error) return null;
+    const parsed = ts.parseJsonConfigFileContent(read.config, ts.sys, probesDir);
+    return {
+      options: parsed.options,
+      shared: parsed.fileNames.filter((f) => f.endsWith('.d.ts')),
+    };
+  };
+  return (plan) => {
+    if (config === undefined) {
+      try {
+        config = load();
+      } catch {
+        config = null;
+      }
+    }
+    if (!config) return [];
+    const probeFile = path.join(probesDir, 'probes', plan.fileName);
+    try {
+      program = ts.createProgram({
+        rootNames: [probeFile, ...config.shared],
+        options: config.options,
+        oldProgram: program,
+      });
+      return explainProbe(program, probeFile, plan.direction);
+    } catch {
+      return [];
+    }
+  };
+}
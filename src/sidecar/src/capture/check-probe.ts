@@ -17,6 +17,12 @@
  * at compare time (ts_check type-checker `unwrapGraphqlPayload`, deleted in
  * WP8); the type-level port here is its v2-native equivalent.
  *
+ * HTTP responses from a producer configured with `serialization: 'json'`
+ * compare `Jsonify<Sent>` instead of `Sent` (see the `json` branch): the
+ * consumer parses what `JSON.stringify` sent, so a handler's `Date` arrives as
+ * a `string`, `undefined` members vanish, `toJSON()` is honoured, and a `Map`
+ * arrives as `{}`.
+ *
  * Seam: node builtins + `typescript` + this bundle only. No imports needed here.
  */
 
@@ -25,6 +31,7 @@ import type {
   CheckPairSpec,
   ProbeProtocol,
   ProbeTypeKind,
+  StrictnessProfile,
 } from './api.js';
 
 /** Which pair endpoint supplies the `sent` value / the `expected` binding. */
@@ -113,6 +120,65 @@ export interface ProbePlan {
   assignmentLine: number;
 }
 
+/** Shared by the strictness mappings: functions are compared as declared. */
+const COMPAT_FN = `type CompatFn = (...args: any[]) => unknown;`;
+
+/**
+ * Emit the value-level assignment under the pair's strictness profile and
+ * return its line. Every profile transforms BOTH sides the same way, so the
+ * relation stays plain assignability and the compiler's elaboration still
+ * names the offending member:
+ *
+ *   standard         `Expected = Sent` as is (extra producer fields allowed,
+ *                    `a?: T` reads as `a: T | undefined`).
+ *   lenient          optionality and null/undefined are erased on both sides
+ *                    (`Lenient<T>`), so only field presence and base types
+ *                    are compared.
+ *   strict-optional  every optional member also admits a private `Absent`
+ *                    marker (`StrictOptional<T>`): an optional field can only
+ *                    satisfy an optional field, never a required
+ *                    `T | undefined` one.
+ *   exact            strict-optional, plus a member the expected side
+ *                    doesn't declare must be absent (`ExactOf<E, S>` adds it
+ *                    as `?: never`) — shapes must match exactly.
+ */
+function pushAssignment(
+  push: (text: string) => number,
+  strictness: StrictnessProfile,
+  comparand: { value: string; type: string }
+): number {
+  switch (strictness) {
+    case 'lenient':
+      push(COMPAT_FN);
+      push(
+        `type Lenient<T> = T extends CompatFn ? T : T extends readonly (infer E)[] ? Lenient<NonNullable<E>>[] : T extends object ? { [K in keyof T]-?: Lenient<NonNullable<T[K]>> } : T;`
+      );
+      push(`declare const sentLenient: Lenient<NonNullable<${comparand.type}>>;`);
+      return push(`const expected: Lenient<NonNullable<Expected>> = sentLenient;`);
+    case 'strict-optional':
+    case 'exact': {
+      push(COMPAT_FN);
+      push(`declare const compatAbsent: unique symbol;`);
+      push(`type Absent = typeof compatAbsent;`);
+      push(
+        `type StrictOptional<T> = T extends CompatFn ? T : T extends readonly (infer E)[] ? StrictOptional<E>[] : T extends object ? { [K in keyof T]: ({} extends Pick<T, K> ? Absent : never) | StrictOptional<T[K]> } : T;`
+      );
+      push(`declare const sentStrict: StrictOptional<${comparand.type}>;`);
+      if (strictness === 'strict-optional') {
+        return push(`const expected: StrictOptional<Expected> = sentStrict;`);
+      }
+      push(
+        `type ExactOf<E, S> = E extends CompatFn ? E : [E] extends [readonly (infer EE)[]] ? ([S] extends [readonly (infer SE)[]] ? ExactOf<EE, SE>[] : E) : E extends object ? ([S] extends [object] ? { [K in keyof E]: K extends keyof S ? ExactOf<E[K], S[K]> : E[K] } & { [K in Exclude<keyof S, keyof E>]?: never } : E) : E;`
+      );
+      return push(
+        `const expected: ExactOf<StrictOptional<Expected>, StrictOptional<${comparand.type}>> = sentStrict;`
+      );
+    }
+    case 'standard':
+      return push(`const expected: Expected = ${comparand.value};`);
+  }
+}
+
 /**
  * Build one probe, recording the exact line of every gate and the assignment so
  * the classifier never depends on hard-coded offsets. `packageOf` maps a
@@ -162,7 +228,9 @@ export function buildProbe(
   gateLines.set(push(`type _G_expected_never = Assert<Not<IsNever<Expected>>>;`), 'expected:never');
   push(`declare const sent: Sent;`);
 
-  let assignmentLine: number;
+  // The value (and its type) the expected binding is assigned from; the
+  // graphql and json branches below swap in a transformed comparand.
+  let comparand = { value: 'sent', type: 'Sent' };
   if (spec.protocol === 'graphql') {
     // GraphQL selection-set semantics (v1 `unwrapGraphqlPayload` parity): the
     // producer side (always `sent` for graphql — see the direction table) is
@@ -214,11 +282,45 @@ export function buildProbe(
       'sent:never'
     );
     push(`declare const sentComparand: GqlComparand;`);
-    assignmentLine = push(`const expected: Expected = sentComparand;`);
-  } else {
-    assignmentLine = push(`const expected: Expected = sent;`);
+    comparand = { value: 'sentComparand', type: 'GqlComparand' };
+  } else if (
+    spec.serialization === 'json' &&
+    spec.protocol === 'http' &&
+    spec.type_kind === 'response'
+  ) {
+    // JSON.stringify semantics on the producer side (always `sent` for an
+    // http response). `toJSON()` wins first (Date -> string); bigint throws
+    // and functions/symbols/undefined are dropped, so they map to `never` and
+    // their keys disappear; in arrays they become `null`. A member that may
+    // be undefined becomes optional. Map/Set serialize to `{}`. Class
+    // instances keep their data members and lose their methods.
+    push(
+      `type JsonifyValue<T> = T extends string | number | boolean | null ? T : T extends bigint | undefined | symbol | ((...args: any[]) => unknown) ? never : T extends { toJSON(...args: any[]): infer J } ? JsonifyValue<J> : T extends ReadonlyMap<unknown, unknown> | ReadonlySet<unknown> ? {} : T extends readonly (infer E)[] ? JsonifyElement<E>[] : T extends object ? JsonifyObject<T> : never;`
+    );
+    push(
+      `type JsonifyElement<E> = E extends undefined | symbol | ((...args: any[]) => unknown) ? null : JsonifyValue<E>;`
+    );
+    push(
+      `type JsonifyKeep<T, K extends keyof T> = K extends symbol ? false : [JsonifyValue<T[K]>] extends [never] ? false : true;`
+    );
+    push(
+      `type JsonifyObject<T> = { [K in keyof T as JsonifyKeep<T, K> extends true ? (undefined extends T[K] ? never : K) : never]: JsonifyValue<T[K]> } & { [K in keyof T as JsonifyKeep<T, K> extends true ? (undefined extends T[K] ? K : never) : never]?: JsonifyValue<T[K]> };`
+    );
+    push(`type Jsonify<T> = JsonifyValue<T>;`);
+    // Re-gate the comparand: a response that serializes to nothing (a bare
+    // bigint or function) must not read compatible against anything.
+    gateLines.set(push(`type _G_wire_any = Assert<Not<IsAny<Jsonify<Sent>>>>;`), 'sent:any');
+    gateLines.set(
+      push(`type _G_wire_unknown = Assert<Not<IsUnknown<Jsonify<Sent>>>>;`),
+      'sent:unknown'
+    );
+    gateLines.set(push(`type _G_wire_never = Assert<Not<IsNever<Jsonify<Sent>>>>;`), 'sent:never');
+    push(`declare const sentWire: Jsonify<Sent>;`);
+    comparand = { value: 'sentWire', type: 'Jsonify<Sent>' };
   }
 
+  const assignmentLine = pushAssignment(push, spec.strictness ?? 'standard', comparand);
+
   return {
     pairId: id,
     spec,
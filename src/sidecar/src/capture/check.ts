@@ -12,7 +12,10 @@
  * Seam: this file imports only node builtins, `typescript` (for the version
  * string), and the rest of this bundle. The sidecar reaches it only through
  * ./index.js. The judge is the `tsc` CLI (no compiler API), which keeps the
- * check phase TS7-ready by construction.
+ * check phase TS7-ready by construction; the compiler API only explains a
+ * pair the judge already found incompatible (check-explain). That costs one
+ * extra in-process program per incompatible pair, rooted at its probe file
+ * alone; compatible pairs compile once, in the judge.
  */
 
 import { spawn } from 'node:child_process';
@@ -35,6 +38,7 @@ import {
   parseTscOutput,
   type RawDiagnostic,
 } from './check-classify.js';
+import { probeExplainer } from './check-explain.js';
 import { scrubPaths, type ScrubContext } from './check-scrub.js';
 import {
   assembleWorkspace,
@@ -319,6 +323,7 @@ export async function runCheck(
     return scope.all || scope.aliases.has(alias) ? scope.reason : undefined;
   };
 
+  const explain = probeExplainer(ws.probesDir);
   const verdicts = sortVerdicts([
     ...probing.map((plan) =>
       classifyPair({
@@ -326,6 +331,7 @@ export async function runCheck(
         probeDiags: probeDiagsByPair.get(plan.pairId) ?? [],
         poisonReason,
         scrubCtx,
+        explain,
       })
     ),
     ...preGated,
@@ -0,0 +1,253 @@
+/**
+ * Schema-library validation as a type source.
+ *
+ * Handlers that validate `req.body` with a schema, and clients that validate
+ * `res.json()`, hold an `any` until the validator runs. The infer anchor must
+ * type such a value by the schema's OUTPUT — `z.infer<typeof S>` and its
+ * equivalents — instead of decaying to `any`:
+ *
+ *  - method form (`S.parse(x)`, `S.safeParse(x)`) and function form
+ *    (`parse(S, x)`);
+ *  - located on the validated value (through `await` and a `.json()` hop),
+ *    or on a binding that flows into a validation;
+ *  - only a top type is replaced: a validation call's own result, and an
+ *    annotated binding that is validated later, keep the type the checker
+ *    gives them;
+ *  - control: `JSON.parse(x)` (no schema output marker) keeps the raw type.
+ *
+ * The schema library is a local stand-in carrying each library's phantom
+ * output member (`_output`, `__outputType`, `_A`, `~types`).
+ */
+
+import { describe, it, before, after } from 'node:test';
+import * as assert from 'node:assert';
+import * as fs from 'node:fs';
+import * as os from 'node:os';
+import * as path from 'node:path';
+import { captureStub } from '../src/capture/index.js';
+import type { CaptureStubResult } from '../src/capture/api.js';
+
+let repoDir: string;
+let outRoot: string;
+
+const SCHEMA_LIB_TS = `
+export interface ZodLike<T> {
+  readonly _output: T;
+  parse(data: unknown): T;
+  safeParse(data: unknown): { success: boolean; data?: T; error?: Error };
+}
+export interface YupLike<T> {
+  readonly __outputType: T;
+  validate(data: unknown): Promise<T>;
+}
+export interface IoTsLike<A> {
+  readonly _A: A;
+  decode(data: unknown): { _tag: 'Left' | 'Right'; right?: A };
+}
+export interface ValibotLike<T> {
+  readonly '~types'?: { readonly input: unknown; readonly output: T } | undefined;
+}
+export declare function zodObject<T>(shape: T): ZodLike<T>;
+export declare function yupObject<T>(shape: T): YupLike<T>;
+export declare function ioTsType<A>(shape: A): IoTsLike<A>;
+export declare function valibotObject<T>(shape: T): ValibotLike<T>;
+export declare function parse<T>(schema: ValibotLike<T>, data: unknown): T;
+`;
+
+const HANDLERS_TS = `
+import { zodObject, yupObject, ioTsType, valibotObject, parse } from './schema-lib';
+
+const User = zodObject({ id: 0, email: '' });
+const Order = yupObject({ orderId: '', total: 0 });
+const Invoice = ioTsType({ invoiceId: '', paid: false });
+const Address = valibotObject({ street: '', city: '' });
+
+export function createUser(req: any) {
+  const user = User.parse(req.body);
+  return user;
+}
+
+export function checkUser(req: any) {
+  const result = User.safeParse(req.body);
+  return result;
+}
+
+export async function createOrder(req: any) {
+  return Order.validate(req.body);
+}
+
+export function readInvoice(res: any) {
+  return Invoice.decode(res.payload);
+}
+
+export function saveAddress(req: any) {
+  return parse(Address, req.body);
+}
+
+export async function loadUser(res: any) {
+  const loaded = User.parse(await res.json());
+  return loaded;
+}
+
+export async function loadUserLater(res: any) {
+  const raw = await res.json();
+  return User.parse(raw);
+}
+
+export function annotatedBody(req: { body: { legacyId: string } }) {
+  const typed: { legacyId: string } = req.body;
+  return User.parse(typed);
+}
+
+export function safeParseBody(req: any) {
+  const checked = User.safeParse(req.body);
+  return checked;
+}
+
+export function plainJson(text: string) {
+  const value = JSON.parse(text);
+  return value;
+}
+`;
+
+function writeRepo(): void {
+  fs.mkdirSync(path.join(repoDir, 'src'), { recursive: true });
+  fs.writeFileSync(
+    path.join(repoDir, 'tsconfig.json'),
+    JSON.stringify({
+      compilerOptions: {
+        strict: true,
+        rootDir: 'src',
+        module: 'esnext',
+        moduleResolution: 'bundler',
+        target: 'es2022',
+        skipLibCheck: true,
+      },
+      include: ['src'],
+    })
+  );
+  fs.writeFileSync(path.join(repoDir, 'src', 'schema-lib.ts'), SCHEMA_LIB_TS);
+  fs.writeFileSync(path.join(repoDir, 'src', 'handlers.ts'), HANDLERS_TS);
+}
+
+/**
+ * Infer anchor on `target` inside the unique occurrence of `context` in the
+ * handlers file (`context` defaults to the target itself).
+ */
+function inferAt(alias: string, target: string, context: string = target) {
+  const at = HANDLERS_TS.indexOf(context);
+  assert.ok(at >= 0, `fixture drift: '${context}' not found`);
+  assert.strictEqual(HANDLERS_TS.indexOf(context, at + 1), -1, `'${context}' is not unique`);
+  const offset = context.indexOf(target);
+  assert.ok(offset >= 0, `'${target}' is not inside '${context}'`);
+  return {
+    kind: 'infer' as const,
+    alias,
+    source_file: 'src/handlers.ts',
+    anchor_origin: 'deterministic-infer' as const,
+    span_start: at + offset,
+    span_end: at + offset + target.length,
+  };
+}
+
+describe('capture v2: schema-library validation as a type source', () => {
+  let result: CaptureStubResult;
+  let surface: string;
+
+  before(() => {
+    repoDir = fs.mkdtempSync(path.join(os.tmpdir(), 'carrick-schema-repo-'));
+    outRoot = fs.mkdtempSync(path.join(os.tmpdir(), 'carrick-schema-stub-'));
+    writeRepo();
+    result = captureStub({
+      repoRoot: repoDir,
+      serviceName: 'schema-svc',
+      outDir: path.join(outRoot, 'stub'),
+      anchors: [
+        inferAt('Zod_Parse_Body', 'req.body', 'user = User.parse(req.body)'),
+        inferAt('Zod_SafeParse_Body', 'req.body', 'checked = User.safeParse(req.body)'),
+        inferAt('Zod_SafeParse_Result', 'result', 'result = User.safeParse(req.body)'),
+        inferAt('Yup_Validate', 'req.body', 'Order.validate(req.body)'),
+        inferAt('IoTs_Decode', 'res.payload', 'Invoice.decode(res.payload)'),
+        inferAt('Valibot_Parse', 'req.body', 'parse(Address, req.body)'),
+        inferAt('Annotated_Binding', 'typed', 'typed: { legacyId: string } = req.body'),
+        inferAt('Zod_Json_Hop', 'res.json()', 'loaded = User.parse(await res.json())'),
+        inferAt('Zod_Binding_Flow', 'raw', 'raw = await res.json()'),
+        inferAt('Control_Json_Parse', 'JSON.parse(text)'),
+      ],
+    });
+    assert.strictEqual(result.success, true, JSON.stringify(result.errors));
+    surface = fs.readFileSync(
+      path.join(result.stub_dir, 'types', 'surface.d.ts'),
+      'utf8'
+    );
+  });
+
+  after(() => {
+    fs.rmSync(repoDir, { recursive: true, force: true });
+    fs.rmSync(outRoot, { recursive: true, force: true });
+  });
+
+  function aliasLine(alias: string): string {
+    const match = surface.match(new RegExp(`${alias} = ([\\s\\S]*?);\\n`));
+    assert.ok(match, `no surface line for ${alias}:\n${surface}`);
+    return match[1];
+  }
+
+  function assertSchemaOutput(alias: string, fields: string[]) {
+    const r = result.aliases.find((a) => a.alias === alias);
+    assert.ok(r, `no alias record for ${alias}`);
+    assert.strictEqual(r.serialization, 'node_builder', r.capture_failure_reason);
+    assert.strictEqual(r.self_check, 'ok', r.self_check_detail);
+    assert.strictEqual(r.top_type_at_self_check, false);
+    assert.match(r.self_check_detail ?? '', /output type of the schema/);
+    const line = aliasLine(alias);
+    for (const field of fields) {
+      assert.ok(line.includes(field), `${alias} should carry '${field}': ${line}`);
+    }
+  }
+
+  it('types a zod-validated request body by the schema output', () => {
+    assertSchemaOutput('Zod_Parse_Body', ['id: number', 'email: string']);
+  });
+
+  it('types a safeParse-validated body by the schema output', () => {
+    assertSchemaOutput('Zod_SafeParse_Body', ['id: number', 'email: string']);
+  });
+
+  it('reads yup, io-ts, and valibot output markers', () => {
+    assertSchemaOutput('Yup_Validate', ['orderId: string', 'total: number']);
+    assertSchemaOutput('IoTs_Decode', ['invoiceId: string', 'paid: boolean']);
+    assertSchemaOutput('Valibot_Parse', ['street: string', 'city: string']);
+  });
+
+  it('follows a validated response through await, .json(), and a binding', () => {
+    assertSchemaOutput('Zod_Json_Hop', ['email: string']);
+    assertSchemaOutput('Zod_Binding_Flow', ['email: string']);
+  });
+
+  /** The anchor kept the checker's own type for the located value. */
+  function assertCheckerType(alias: string, fields: string[]) {
+    const r = result.aliases.find((a) => a.alias === alias);
+    assert.ok(r, `no alias record for ${alias}`);
+    assert.strictEqual(r.serialization, 'node_builder', r.capture_failure_reason);
+    assert.doesNotMatch(r.self_check_detail ?? '', /output type of the schema/);
+    const line = aliasLine(alias);
+    for (const field of fields) {
+      assert.ok(line.includes(field), `${alias} should carry '${field}': ${line}`);
+    }
+  }
+
+  it('keeps a concrete type even when the value is validated', () => {
+    // The annotation is the author's statement; the schema does not replace it.
+    assertCheckerType('Annotated_Binding', ['legacyId: string']);
+    assert.ok(!aliasLine('Annotated_Binding').includes('email'));
+    // The validation call's own result is concrete: safeParse's wrapper.
+    assertCheckerType('Zod_SafeParse_Result', ['success']);
+  });
+
+  it('control: JSON.parse is not a schema validation', () => {
+    const r = result.aliases.find((a) => a.alias === 'Control_Json_Parse');
+    assert.ok(r);
+    assert.doesNotMatch(r.self_check_detail ?? '', /output type of the schema/);
+  });
+});
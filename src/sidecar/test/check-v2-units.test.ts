@@ -4,7 +4,8 @@
  * Pins the deterministic pieces the byte-stable end-to-end runner depends on:
  * the (protocol, type_kind) direction table (incl. the confirmed HTTP
  * request-body inversion), stable pair IDs, the four-bucket classifier
- * precedence, the diagnostic scrub, and semver-dedupe override computation.
+ * precedence, the checker-driven field explanation (an in-memory program per
+ * probe source), the diagnostic scrub, and semver-dedupe override computation.
  */
 
 import { describe, it } from 'node:test';
@@ -12,6 +13,7 @@ import * as assert from 'node:assert';
 import * as fs from 'node:fs';
 import * as os from 'node:os';
 import * as path from 'node:path';
+import ts from 'typescript';
 import {
   buildProbe,
   directionFor,
@@ -19,6 +21,7 @@ import {
   pairId,
 } from '../src/capture/check-probe.js';
 import { classifyPair, parseTscOutput } from '../src/capture/check-classify.js';
+import { explainProbe, probeExplainer } from '../src/capture/check-explain.js';
 import { scrubPaths, rewriteAliases } from '../src/capture/check-scrub.js';
 import { assembleWorkspace, computeDedupeOverrides } from '../src/capture/check-workspace.js';
 import type { CheckPairSpec } from '../src/capture/api.js';
@@ -26,6 +29,19 @@ import type { RawDiagnostic } from '../src/capture/check-classify.js';
 
 const PKG = (s: string) => `@carrick/${s}`;
 
+/** Explain the `const expected: E = sent` assignment in a standalone probe source. */
+function explain(source: string, direction: ReturnType<typeof directionFor>) {
+  const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'carrick-explain-'));
+  try {
+    const file = path.join(dir, 'probe.ts');
+    fs.writeFileSync(file, source);
+    const program = ts.createProgram([file], { strict: true, noEmit: true, types: [] });
+    return explainProbe(program, file, direction);
+  } finally {
+    fs.rmSync(dir, { recursive: true, force: true });
+  }
+}
+
 function spec(over: Partial<CheckPairSpec> = {}): CheckPairSpec {
   return {
     pair_key: 'k1',
@@ -116,6 +132,93 @@ describe('graphql probe shape (resolver-return envelope unwrap)', () => {
   });
 });
 
+describe('json wire serialization', () => {
+  it('http responses from a json producer compare Jsonify<Sent>', () => {
+    const plan = buildProbe(spec({ serialization: 'json' }), PKG);
+    assert.ok(plan.source.includes('type Jsonify<T> ='), plan.source);
+    // toJSON is honoured before the object mapping (Date -> string).
+    assert.ok(plan.source.includes('T extends { toJSON(...args: any[]): infer J }'));
+    const lines = plan.source.split('\n');
+    assert.strictEqual(lines[plan.assignmentLine - 1], 'const expected: Expected = sentWire;');
+    const names = [...plan.gateLines.values()];
+    assert.strictEqual(plan.gateLines.size, 9);
+    assert.strictEqual(names.filter((n) => n === 'sent:never').length, 2);
+  });
+
+  it('requests and non-http pairs keep the native comparison', () => {
+    for (const over of [
+      { serialization: 'json', type_kind: 'request' },
+      { serialization: 'json', protocol: 'socket', type_kind: 'both' },
+      { serialization: 'native' },
+    ] as const) {
+      const plan = buildProbe(spec(over), PKG);
+      assert.ok(!plan.source.includes('Jsonify'), JSON.stringify(over));
+      const lines = plan.source.split('\n');
+      assert.strictEqual(lines[plan.assignmentLine - 1], 'const expected: Expected = sent;');
+    }
+  });
+});
+
+describe('strictness profiles', () => {
+  const assignment = (over: Partial<CheckPairSpec>) => {
+    const plan = buildProbe(spec(over), PKG);
+    return { plan, line: plan.source.split('\n')[plan.assignmentLine - 1] };
+  };
+
+  it('standard (and absent) keeps the plain assignment', () => {
+    assert.strictEqual(assignment({}).line, 'const expected: Expected = sent;');
+    assert.strictEqual(
+      assignment({ strictness: 'standard' }).line,
+      'const expected: Expected = sent;'
+    );
+  });
+
+  it('each profile transforms both sides the same way', () => {
+    assert.strictEqual(
+      assignment({ strictness: 'lenient' }).line,
+      'const expected: Lenient<NonNullable<Expected>> = sentLenient;'
+    );
+    assert.strictEqual(
+      assignment({ strictness: 'strict-optional' }).line,
+      'const expected: StrictOptional<Expected> = sentStrict;'
+    );
+    const exact = assignment({ strictness: 'exact' });
+    assert.strictEqual(
+      exact.line,
+      'const expected: ExactOf<StrictOptional<Expected>, StrictOptional<Sent>> = sentStrict;'
+    );
+    assert.ok(exact.plan.source.includes('declare const sentStrict: StrictOptional<Sent>;'));
+  });
+
+  it('profiles compose with the transformed comparands', () => {
+    const gql = assignment({ protocol: 'graphql', strictness: 'strict-optional' });
+    assert.ok(gql.plan.source.includes('declare const sentStrict: StrictOptional<GqlComparand>;'));
+    const wire = assignment({ serialization: 'json', strictness: 'lenient' });
+    assert.ok(
+      wire.plan.source.includes('declare const sentLenient: Lenient<NonNullable<Jsonify<Sent>>>;')
+    );
+  });
+
+  it('an optional member meeting a required one explains as nullability', () => {
+    const fields = explain(
+      [
+        'declare const compatAbsent: unique symbol;',
+        'type Absent = typeof compatAbsent;',
+        'type StrictOptional<T> = { [K in keyof T]: ({} extends Pick<T, K> ? Absent : never) | T[K] };',
+        'type Order = { id: string; note?: string };',
+        'type View = { id: string; note: string | undefined };',
+        'declare const sentStrict: StrictOptional<Order>;',
+        'const expected: StrictOptional<View> = sentStrict;',
+      ].join('\n'),
+      directionFor('http', 'response')
+    );
+    assert.strictEqual(fields.length, 1, JSON.stringify(fields));
+    assert.strictEqual(fields[0].path, 'note');
+    assert.strictEqual(fields[0].kind, 'nullability');
+    assert.match(fields[0].producer!, /\babsent\b/);
+  });
+});
+
 describe('four-bucket classifier precedence', () => {
   const plan = buildProbe(spec(), PKG);
   const scrubCtx = { workspaceRoot: '/tmp/ws', packageLabelOf: () => undefined };
@@ -143,10 +246,15 @@ describe('four-bucket classifier precedence', () => {
       ],
       poisonReason: noPoison,
       scrubCtx,
+      explain: (explained) => {
+        assert.strictEqual(explained, plan);
+        return [{ path: 'b', kind: 'missing', consumer: 'required' }];
+      },
     });
     assert.strictEqual(v.bucket, 'incompatible');
     assert.match(v.diagnostic!, /Property 'b' is missing/);
     assert.deepStrictEqual(v.codes, [2741]);
+    assert.deepStrictEqual(v.fields, [{ path: 'b', kind: 'missing', consumer: 'required' }]);
   });
 
   it('IsAny gate (TS2344) -> gate_caught_baked_any on the right side', () => {
@@ -214,6 +322,101 @@ describe('four-bucket classifier precedence', () => {
   });
 });
 
+describe('field-level mismatch explanation', () => {
+  const response = directionFor('http', 'response');
+  const request = directionFor('http', 'request');
+
+  it('walks every failing member, not just the one tsc elaborates', () => {
+    const fields = explain(
+      [
+        "type OrderResponse = { customer: { id: string }; status: 'open' | 'closed' | 'void'; note: string | null; total: string; lines: { qty: string }[]; extra: boolean };",
+        "type WebOrder = { customer: { id: string; email: string }; status: 'open' | 'closed'; note: string; total: number; lines: { qty: number }[] };",
+        'declare const sent: OrderResponse;',
+        'const expected: WebOrder = sent;',
+      ].join('\n'),
+      response
+    );
+    assert.deepStrictEqual(
+      fields.map((f) => [f.path, f.kind]),
+      [
+        ['customer.email', 'missing'],
+        ['status', 'enum_narrowing'],
+        ['note', 'nullability'],
+        ['total', 'type_changed'],
+        ['lines[].qty', 'type_changed'],
+      ]
+    );
+    assert.deepStrictEqual(fields[0], { path: 'customer.email', kind: 'missing', consumer: 'required' });
+    assert.deepStrictEqual(fields[3], {
+      path: 'total',
+      kind: 'type_changed',
+      producer: 'string',
+      consumer: 'number',
+    });
+  });
+
+  it('reads missing fields from the request side as extra-required', () => {
+    const fields = explain(
+      [
+        'type CreateOrderBody = { sku: string };',
+        'type CreateOrder = { sku: string; region: string; currency: string; coupon?: string };',
+        'declare const sent: CreateOrderBody;',
+        'const expected: CreateOrder = sent;',
+      ].join('\n'),
+      request
+    );
+    assert.deepStrictEqual(fields, [
+      { path: 'region', kind: 'extra_required', producer: 'required' },
+      { path: 'currency', kind: 'extra_required', producer: 'required' },
+    ]);
+  });
+
+  it('explains the whole type when the sides are not objects', () => {
+    assert.deepStrictEqual(
+      explain(['declare const sent: string;', 'const expected: number = sent;'].join('\n'), response),
+      [{ path: '', kind: 'type_changed', producer: 'string', consumer: 'number' }]
+    );
+  });
+
+  it('explains nothing when the probe has no expected binding', () => {
+    assert.deepStrictEqual(explain('export const unrelated = 1;', response), []);
+  });
+
+  it('explains each workspace probe from a program rooted at that probe', () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'carrick-explain-ws-'));
+    try {
+      fs.mkdirSync(path.join(dir, 'probes'));
+      fs.writeFileSync(
+        path.join(dir, 'tsconfig.json'),
+        JSON.stringify({
+          compilerOptions: { strict: true, noEmit: true, types: [] },
+          include: ['probes/**/*.ts'],
+        })
+      );
+      fs.writeFileSync(
+        path.join(dir, 'probes', 'shared.d.ts'),
+        'type Order = { id: string; total: number };'
+      );
+      fs.writeFileSync(
+        path.join(dir, 'probes', 'pair_a.ts'),
+        'declare const sent: Order;\nconst expected: { id: number } = sent;\nexport {};\n'
+      );
+      fs.writeFileSync(
+        path.join(dir, 'probes', 'pair_b.ts'),
+        'declare const sent: Order;\nconst expected: { total: string } = sent;\nexport {};\n'
+      );
+      const base = buildProbe(spec(), PKG);
+      const plan = (fileName: string) => ({ ...base, fileName, direction: response });
+      const explainPair = probeExplainer(dir);
+      assert.deepStrictEqual(explainPair(plan('pair_a.ts')).map((f) => f.path), ['id']);
+      assert.deepStrictEqual(explainPair(plan('pair_b.ts')).map((f) => f.path), ['total']);
+      assert.deepStrictEqual(probeExplainer(path.join(dir, 'missing'))(plan('pair_a.ts')), []);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
+
 describe('tsc output parsing', () => {
   it('parses primary lines and folds indented elaboration', () => {
     const out = [
@@ -0,0 +1,369 @@
+//! Inline finding suppressions.
+//!
+//! A known false positive or an accepted risk can be silenced where it lives,
+//! the way lint rules are:
+//!
+//! ```ts
+//! // carrick-ignore-next-line missing_endpoint
+//! await fetch(`${process.env.LEGACY_URL}/ping`);
+//! ```
+//!
+//! The directive names one or more finding kinds (the wire `kind` tags,
+//! comma- or space-separated, `-` accepted for `_`), or none to cover every
+//! kind. A kind that isn't one of [`Finding::KINDS`] is reported as a warning
+//! and ignored, so a misspelled kind never silently suppresses nothing. It applies to the next line that is not itself a directive, and is
+//! honoured at both ends of a contract:
+//!
+//! - at a **call site**, it drops that call site from call-site findings
+//!   (missing endpoints, mismatches, env-var suggestions, ...); a finding
+//!   whose every call site is suppressed is dropped;
+//! - at an **endpoint definition**, it drops findings about that producer
+//!   (orphaned endpoints, breaking changes, and type/method/query-parameter/
+//!   header mismatches against it).
+//!
+//! Dependency conflicts have no source location and can only be accepted via
+//! the baseline file (see [`crate::baseline`]).
+
+use crate::analyzer::ApiEndpointDetails;
+use crate::file_finder::find_files;
+use crate::findings::{Finding, Issue};
+use crate::type_manifest::parse_file_location;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tracing::warn;
+
+const DIRECTIVE: &str = "carrick-ignore-next-line";
+
+/// Finding kinds one directive covers; `None` covers every kind.
+type KindFilter = Option<BTreeSet<String>>;
+
+/// Every `carrick-ignore-next-line` directive in a repo, keyed by
+/// repo-relative file path and the (1-based) line it applies to.
+#[derive(Debug, Default)]
+pub struct InlineSuppressions {
+    directives: BTreeMap<String, BTreeMap<u32, KindFilter>>,
+}
+
+impl InlineSuppressions {
+    /// Scan the repo's JS/TS sources for directives.
+    pub fn scan(repo_root: &Path) -> Self {
+        let (files, _, _) = find_files(
+            &repo_root.to_string_lossy(),
+            &["node_modules", "dist", "build", ".next"],
+        );
+        let mut directives = BTreeMap::new();
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(&file) else {
+                continue;
+            };
+            if !content.contains(DIRECTIVE) {
+                continue;
+            }
+            let rel = file
+                .strip_prefix(repo_root)
+                .unwrap_or(&file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let (parsed, unknown) = parse_directives(&content);
+            for (line, kind) in unknown {
+                warn!(
+                    "{}:{}: unknown finding kind '{}' in {} is ignored (expected one of: {})",
+                    rel,
+                    line,
+                    kind,
+                    DIRECTIVE,
+                    Finding::KINDS.join(", ")
+                );
+            }
+            if !parsed.is_empty() {
+                directives.insert(rel, parsed);
+            }
+        }
+        Self { directives }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Whether a `file:line[:col]` location carries a directive covering
+    /// `kind`. Finding locations may be relative to the repo root or to a
+    /// service directory, so paths match when one is a whole-component
+    /// suffix of the other.
+    fn suppresses(&self, location: &str, kind: &str) -> bool {
+        let (file, line) = parse_file_location(location);
+        let file = file.trim_start_matches("./");
+        self.directives.iter().any(|(path, lines)| {
+            path_suffix_match(path, file)
+                && lines
+                    .get(&line)
+                    .is_some_and(|filter| filter.as_ref().is_none_or(|kinds| kinds.contains(kind)))
+        })
+    }
+
+    /// Drop or trim suppressed findings. `endpoints` are the producers the
+    /// analysis indexed, used to locate endpoint definitions. Returns the
+    /// kept findings and how many were dropped outright.
+    pub fn apply(
+        &self,
+        findings: Vec<Finding>,
+        endpoints: &[ApiEndpointDetails],
+    ) -> (Vec<Finding>, usize) {
+        if self.is_empty() {
+            return (findings, 0);
+        }
+        let mut dropped = 0;
+        let mut kept = Vec::with_capacity(findings.len());
+        for mut finding in findings {
+            let kind = finding.kind();
+            if let Some((method, path)) = producer_key(&finding)
+                && endpoints
+                    .iter()
+                    .filter(|e| {
+                        e.key
+                            .as_http()
+                            .is_some_and(|(m, p)| m.eq_ignore_ascii_case(method) && p == path)
+                    })
+                    .any(|e| self.suppresses(&e.file_path.to_string_lossy(), kind))
+            {
+                dropped += 1;
+                continue;
+            }
+            if let Some(call_sites) = call_sites_mut(&mut finding)
+                && !call_sites.is_empty()
+            {
+                call_sites.retain(|site| !self.suppresses(site, kind));
+                if call_sites.is_empty() {
+                    dropped += 1;
+                    continue;
+                }
+            }
+            kept.push(finding);
+        }
+        (kept, dropped)
+    }
+}
+
+/// Directives in one source file: target line → kinds, plus the unknown
+/// kinds named, each with its directive's (1-based) line. A directive naming
+/// only unknown kinds covers nothing.
+fn parse_directives(content: &str) -> (BTreeMap<u32, KindFilter>, Vec<(u32, String)>) {
+    let mut out = BTreeMap::new();
+    let mut unknown = Vec::new();
+    let mut pending: Vec<KindFilter> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let directive = trimmed
+            .strip_prefix("//")
+            .or_else(|| trimmed.strip_prefix("/*"))
+            .map(|rest| rest.trim_end_matches("*/").trim())
+            .and_then(|rest| rest.strip_prefix(DIRECTIVE));
+        match directive {
+            Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+                let (kinds, unknown_kinds): (BTreeSet<String>, BTreeSet<String>) = rest
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|k| !k.is_empty())
+                    .map(|k| k.replace('-', "_"))
+                    .partition(|k| Finding::KINDS.contains(&k.as_str()));
+                let line = (idx + 1) as u32;
+                unknown.extend(unknown_kinds.iter().map(|k| (line, k.clone())));
+                if kinds.is_empty() && !unknown_kinds.is_empty() {
+                    continue;
+                }
+                pending.push((!kinds.is_empty()).then_some(kinds));
+            }
+            _ if !pending.is_empty() => {
+                let target = (idx + 1) as u32;
+                let merged = pending.drain(..).reduce(merge_filters).flatten();
+                out.insert(target, merged);
+            }
+            _ => {}
+        }
+    }
+    (out, unknown)
+}
+
+fn merge_filters(a: KindFilter, b: KindFilter) -> KindFilter {
+    match (a, b) {
+        (Some(mut a), Some(b)) => {
+            a.extend(b);
+            Some(a)
+        }
+        _ => None,
+    }
+}
+
+fn path_suffix_match(a: &str, b: &str) -> bool {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    !short.is_empty()
+        && long.ends_with(short)
+        && (long.len() == short.len() || long[..long.len() - short.len()].ends_with('/'))
+}
+
+/// The producer endpoint a finding is about, if it has one.
+fn producer_key(finding: &Finding) -> Option<(&str, &str)> {
+    match &finding.issue {
+        Issue::OrphanedEndpoint { method, path, .. }
+        | Issue::TypeMismatch { method, path, .. }
+        | Issue::BreakingChange { method, path, .. }
+        | Issue::QueryParamMismatch { method, path, .. }
+        | Issue::HeaderMismatch { method, path, .. } => Some((method, path)),
+        Issue::MethodMismatch {
+            expected_method,
+            path,
+            ..
+        } => Some((expected_method, path)),
+        _ => None,
+    }
+}
+
+fn call_sites_mut(finding: &mut Finding) -> Option<&mut Vec<String>> {
+    match &mut finding.issue {
+        Issue::TypeMismatch { call_sites, .. }
+        | Issue::MethodMismatch { call_sites, .. }
+        | Issue::MissingEndpoint { call_sites, .. }
+        | Issue::EnvVarCall { call_sites, .. }
+        | Issue::ProfileTargetDrift { call_sites, .. }
+        | Issue::SharedExternalContract { call_sites, .. }
+        | Issue::PolicyViolation { call_sites, .. }
+        | Issue::BreakingChange { call_sites, .. }
+        | Issue::QueryParamMismatch { call_sites, .. }
+        | Issue::HeaderMismatch { call_sites, .. } => Some(call_sites),
+        Issue::OrphanedEndpoint { .. } | Issue::DependencyConflict { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operation::OperationKey;
+    use std::path::PathBuf;
+
+    fn endpoint(method: &str, path: &str, location: &str) -> ApiEndpointDetails {
+        ApiEndpointDetails {
+            owner: None,
+            key: OperationKey::http(method, path),
+            params: vec![],
+            request_body: None,
+            response_body: None,
+            handler_name: None,
+            request_type: None,
+            response_type: None,
+            file_path: PathBuf::from(location),
+            repo_name: None,
+            service_name: None,
+            provenance: Default::default(),
+        }
+    }
+
+    #[test]
+    fn parses_directives_for_the_next_code_line() {
+        let (parsed, unknown) = parse_directives(
+            "const a = 1;\n\
+             // carrick-ignore-next-line missing_endpoint, env-var-call\n\
+             fetch('/a');\n\
+             /* carrick-ignore-next-line */\n\
+             // carrick-ignore-next-line type_mismatch\n\
+             fetch('/b');\n\
+             // carrick-ignore-next-lines is not a directive\n\
+             fetch('/c');\n",
+        );
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(
+            parsed[&3],
+            Some(BTreeSet::from([
+                "env_var_call".to_string(),
+                "missing_endpoint".to_string()
+            ]))
+        );
+        // A kind-less directive covers everything, even when stacked.
+        assert_eq!(parsed[&6], None);
+        assert!(unknown.is_empty());
+    }
+
+    #[test]
+    fn unknown_kinds_are_reported_and_suppress_nothing() {
+        let (parsed, unknown) = parse_directives(
+            "// carrick-ignore-next-line type_mismatchh\n\
+             fetch('/a');\n\
+             // carrick-ignore-next-line missing_endpoint, orphan\n\
+             fetch('/b');\n",
+        );
+        assert_eq!(
+            unknown,
+            vec![(1, "type_mismatchh".to_string()), (3, "orphan".to_string())]
+        );
+        // The misspelled-only directive covers nothing, rather than
+        // everything; the mixed one keeps its known kind.
+        assert!(!parsed.contains_key(&2));
+        assert_eq!(
+            parsed[&4],
+            Some(BTreeSet::from(["missing_endpoint".to_string()]))
+        );
+    }
+
+    #[test]
+    fn suppresses_call_sites_and_endpoint_definitions() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("web/src")).unwrap();
+        std::fs::write(
+            dir.path().join("web/src/client.ts"),
+            "// carrick-ignore-next-line missing_endpoint\n\
+             fetch('/legacy');\n\
+             fetch('/legacy');\n\
+             // carrick-ignore-next-line type_mismatch\n\
+             fetch('/users');\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("web/src/routes.ts"),
+            "// carrick-ignore-next-line orphaned_endpoint\n\
+             app.get('/health', h);\n",
+        )
+        .unwrap();
+        let suppressions = InlineSuppressions::scan(dir.path());
+
+        let findings = vec![
+            // Fully suppressed: its only call site carries the directive.
+            Finding::missing_endpoint("GET", "/legacy", None, vec!["web/src/client.ts:2".into()]),
+            // Partially suppressed: the unannotated site survives.
+            Finding::missing_endpoint(
+                "POST",
+                "/legacy",
+                None,
+                vec!["web/src/client.ts:2".into(), "web/src/client.ts:3".into()],
+            ),
+            // Wrong kind for the directive on line 5: kept.
+            Finding::missing_endpoint("GET", "/users", None, vec!["src/client.ts:5".into()]),
+            Finding::orphaned_endpoint("GET", "/health", None),
+            Finding::orphaned_endpoint("GET", "/other", None),
+        ];
+        let endpoints = vec![
+            endpoint("GET", "/health", "web/src/routes.ts:2"),
+            endpoint("GET", "/other", "web/src/routes.ts:9"),
+        ];
+
+        let (kept, dropped) = suppressions.apply(findings, &endpoints);
+        assert_eq!(dropped, 2);
+        assert_eq!(
+            kept,
+            vec![
+                Finding::missing_endpoint(
+                    "POST",
+                    "/legacy",
+                    None,
+                    vec!["web/src/client.ts:3".into()]
+                ),
+                Finding::missing_endpoint("GET", "/users", None, vec!["src/client.ts:5".into()]),
+                Finding::orphaned_endpoint("GET", "/other", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn path_suffix_match_respects_components() {
+        assert!(path_suffix_match("web/src/a.ts", "src/a.ts"));
+        assert!(path_suffix_match("src/a.ts", "src/a.ts"));
+        assert!(!path_suffix_match("web/src/data.ts", "a.ts"));
+    }
+}
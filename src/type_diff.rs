@@ -0,0 +1,301 @@
+//! Field-level diff of two resolved TypeScript type texts.
+//!
+//! The sidecar hands back types as expanded source text
+//! (`{ id: string; tags?: string[] }`), not a structured AST. Object literal
+//! types are compared member by member (recursing into nested objects and
+//! array element types); anything else reports a whole-type change. Used to
+//! say *what* changed in a producer's type, for breaking-change findings and
+//! the API changelog.
+
+use crate::cloud_storage::ManifestTypeKind;
+use crate::findings::truncate_chars;
+use std::fmt;
+
+/// Longest type text quoted in a change description.
+const MAX_TYPE_CHARS: usize = 80;
+
+/// One field-level change. `field` is a dotted path (`address.zip`,
+/// `tags[].name`); empty for a change to the whole type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeChange {
+    Removed {
+        field: String,
+    },
+    Added {
+        field: String,
+        required: bool,
+    },
+    BecameOptional {
+        field: String,
+    },
+    BecameRequired {
+        field: String,
+    },
+    Changed {
+        field: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl TypeChange {
+    /// Whether the change can break a consumer of a producer type of this
+    /// kind. A response must keep giving consumers everything they read;
+    /// a request must keep accepting everything consumers send.
+    pub fn is_breaking(&self, kind: ManifestTypeKind) -> bool {
+        let response = kind == ManifestTypeKind::Response;
+        match self {
+            TypeChange::Removed { .. } | TypeChange::BecameOptional { .. } => response,
+            TypeChange::Added { required, .. } => *required && !response,
+            TypeChange::BecameRequired { .. } => !response,
+            TypeChange::Changed { .. } => true,
+        }
+    }
+}
+
+impl fmt::Display for TypeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeChange::Removed { field } => write!(f, "`{}` removed", field),
+            TypeChange::Added { field, required } => write!(
+                f,
+                "`{}` added ({})",
+                field,
+                if *required { "required" } else { "optional" }
+            ),
+            TypeChange::BecameOptional { field } => write!(f, "`{}` became optional", field),
+            TypeChange::BecameRequired { field } => write!(f, "`{}` became required", field),
+            TypeChange::Changed { field, from, to } if field.is_empty() => {
+                write!(f, "type changed from `{}` to `{}`", from, to)
+            }
+            TypeChange::Changed { field, from, to } => {
+                write!(f, "`{}` changed from `{}` to `{}`", field, from, to)
+            }
+        }
+    }
+}
+
+/// Field-level differences between two resolved type texts, in the order
+/// the old type declares its members, then additions.
+pub fn diff(before: &str, after: &str) -> Vec<TypeChange> {
+    let mut changes = Vec::new();
+    diff_types("", before, after, &mut changes);
+    changes
+}
+
+fn diff_types(prefix: &str, before: &str, after: &str, out: &mut Vec<TypeChange>) {
+    let (before, after) = (before.trim(), after.trim());
+    if before == after {
+        return;
+    }
+    if let (Some(old), Some(new)) = (object_members(before), object_members(after)) {
+        for (name, old_member) in &old {
+            let field = join_field(prefix, name);
+            match new.iter().find(|(n, _)| n == name) {
+                None => out.push(TypeChange::Removed { field }),
+                Some((_, new_member)) => {
+                    if old_member.optional != new_member.optional {
+                        out.push(if new_member.optional {
+                            TypeChange::BecameOptional {
+                                field: field.clone(),
+                            }
+                        } else {
+                            TypeChange::BecameRequired {
+                                field: field.clone(),
+                            }
+                        });
+                    }
+                    diff_types(&field, &old_member.ty, &new_member.ty, out);
+                }
+            }
+        }
+        for (name, member) in &new {
+            if !old.iter().any(|(n, _)| n == name) {
+                out.push(TypeChange::Added {
+                    field: join_field(prefix, name),
+                    required: !member.optional,
+                });
+            }
+        }
+        return;
+    }
+    if let (Some(old), Some(new)) = (array_element(before), array_element(after)) {
+        diff_types(&format!("{}[]", prefix), old, new, out);
+        return;
+    }
+    let quote = |t: &str| {
+        truncate_chars(
+            &t.split_whitespace().collect::<Vec<_>>().join(" "),
+            MAX_TYPE_CHARS,
+        )
+    };
+    out.push(TypeChange::Changed {
+        field: prefix.to_string(),
+        from: quote(before),
+        to: quote(after),
+    });
+}
+
+fn join_field(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
+}
+
+struct Member {
+    optional: bool,
+    ty: String,
+}
+
+/// Members of an object type literal, in declaration order. `None` when the
+/// text isn't a single `{ ... }` literal. Index signatures and methods are
+/// skipped.
+fn object_members(text: &str) -> Option<Vec<(String, Member)>> {
+    let inner = text.strip_prefix('{')?.strip_suffix('}')?;
+    let mut members = Vec::new();
+    for part in split_top_level(inner, &[';', ',', '\n']) {
+        let part = part.trim();
+        let part = part.strip_prefix("readonly ").unwrap_or(part).trim();
+        if part.is_empty() || part.starts_with('[') {
+            continue;
+        }
+        let (name, ty) = split_top_level_once(part, ':')?;
+        let name = name.trim();
+        if name.contains('(') {
+            continue;
+        }
+        let (name, optional) = match name.strip_suffix('?') {
+            Some(name) => (name.trim(), true),
+            None => (name, false),
+        };
+        let name = name.trim_matches(|c| c == '"' || c == '\'');
+        members.push((
+            name.to_string(),
+            Member {
+                optional,
+                ty: ty.trim().to_string(),
+            },
+        ));
+    }
+    Some(members)
+}
+
+/// Element type of `T[]` or `Array<T>`.
+fn array_element(text: &str) -> Option<&str> {
+    if let Some(element) = text.strip_suffix("[]") {
+        let element = element.trim();
+        return Some(
+            element
+                .strip_prefix('(')
+                .and_then(|e| e.strip_suffix(')'))
+                .unwrap_or(element),
+        );
+    }
+    text.strip_prefix("Array<")?.strip_suffix('>')
+}
+
+/// Split on any of `separators` outside brackets and string literals.
+/// Returns `None`-free pieces; a text whose brackets don't balance is
+/// split as far as it goes.
+fn split_top_level<'a>(text: &'a str, separators: &[char]) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let mut prev = '\0';
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) => {
+                if c == q && prev != '\\' {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' | '`' => quote = Some(c),
+                '{' | '[' | '(' | '<' => depth += 1,
+                // `=>` is an arrow, not a closing bracket.
+                '>' if prev == '=' => {}
+                '}' | ']' | ')' | '>' => depth -= 1,
+                c if depth == 0 && separators.contains(&c) => {
+                    parts.push(&text[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            },
+        }
+        prev = c;
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn split_top_level_once(text: &str, separator: char) -> Option<(&str, &str)> {
+    let first = split_top_level(text, &[separator]).into_iter().next()?;
+    (first.len() < text.len()).then(|| (first, &text[first.len() + separator.len_utf8()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(before: &str, after: &str) -> Vec<String> {
+        diff(before, after)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn describes_field_level_changes() {
+        let before = r#"{ id: string; email: string; status: "active" | "closed"; address: { city: string; zip: string }; tags: { name: string }[]; note?: string }"#;
+        let after = r#"{ id: string; status: "active"; address: { city: string; zip?: string }; tags: { label: string }[]; note: string; region: string; nickname?: string }"#;
+        assert_eq!(
+            describe(before, after),
+            vec![
+                "`email` removed",
+                r#"`status` changed from `"active" | "closed"` to `"active"`"#,
+                "`address.zip` became optional",
+                "`tags[].name` removed",
+                "`tags[].label` added (required)",
+                "`note` became required",
+                "`region` added (required)",
+                "`nickname` added (optional)",
+            ]
+        );
+        assert_eq!(
+            describe("string", "number"),
+            vec!["type changed from `string` to `number`"]
+        );
+        assert!(diff("{ a: string }", "{ a: string; }").is_empty());
+    }
+
+    #[test]
+    fn breaking_depends_on_side() {
+        let changes = diff("{ a: string; b?: string }", "{ b: string; c: string }");
+        let breaking = |kind| {
+            changes
+                .iter()
+                .filter(|c| c.is_breaking(kind))
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(breaking(ManifestTypeKind::Response), vec!["`a` removed"]);
+        assert_eq!(
+            breaking(ManifestTypeKind::Request),
+            vec!["`b` became required", "`c` added (required)"]
+        );
+    }
+
+    #[test]
+    fn arrow_members_and_quoted_keys_parse() {
+        let members = object_members(
+            r#"{ "content-type": string; cb: (a: number) => void; [k: string]: unknown }"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = members.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["content-type", "cb"]);
+        assert_eq!(members[1].1.ty, "(a: number) => void");
+    }
+}
@@ -20,9 +20,15 @@
 //! 3. Template literals: `${API_URL}/users/${id}` → `/users/:id`
 //! 4. Query strings: `/users?page=1` → `/users`
 //! 5. Trailing slashes: `/users/` → `/users`
+//! 6. URL objects: `new URL(\`/users/${id}\`, USERS_BASE)` → `${USERS_BASE}/users/${id}`
+//!
+//! Consumer call keys additionally pass through the service's gateway
+//! rewrites (`carrick.json` `gateway`, see [`crate::gateway`]) so a call routed
+//! through an API gateway keys on the path the producer actually receives.
 
 use crate::config::Config;
-use std::collections::HashSet;
+use crate::gateway::GatewayConfig;
+use std::collections::{BTreeMap, HashSet};
 
 /// Result of URL normalization
 #[derive(Debug, Clone, PartialEq)]
@@ -51,6 +57,9 @@ pub struct UrlNormalizer {
     external_domains: HashSet<String>,
     internal_env_vars: HashSet<String>,
     external_env_vars: HashSet<String>,
+    gateway: GatewayConfig,
+    env_var_services: BTreeMap<String, String>,
+    host_services: BTreeMap<String, String>,
 }
 
 impl UrlNormalizer {
@@ -61,6 +70,9 @@ impl UrlNormalizer {
             external_domains: config.external_domains.clone(),
             internal_env_vars: config.internal_env_vars.clone(),
             external_env_vars: config.external_env_vars.clone(),
+            gateway: config.gateway.clone(),
+            env_var_services: config.env_var_services.clone(),
+            host_services: config.host_services.clone(),
         }
     }
 
@@ -71,6 +83,9 @@ impl UrlNormalizer {
             external_domains: HashSet::new(),
             internal_env_vars: HashSet::new(),
             external_env_vars: HashSet::new(),
+            gateway: GatewayConfig::default(),
+            env_var_services: BTreeMap::new(),
+            host_services: BTreeMap::new(),
         }
     }
 
@@ -98,6 +113,14 @@ impl UrlNormalizer {
         // matching a producer's `/api/users`.
         let url = url.trim_matches(|c| c == '`' || c == '"' || c == '\'');
 
+        // A `new URL(path, base)` expression reported verbatim: normalize the
+        // target it composes.
+        if let Some(composed) = compose_new_url_target(url) {
+            let mut normalized = self.normalize(&composed);
+            normalized.original = original;
+            return normalized;
+        }
+
         // Handle ENV_VAR: pattern first
         if url.starts_with("ENV_VAR:") {
             return self.normalize_env_var_pattern(url, original);
@@ -525,17 +548,65 @@ impl UrlNormalizer {
     ///
     /// The raw target is always retained separately on the call (`target_url`)
     /// for per-call classification/display; only the MATCH key is canonicalized.
+    ///
+    /// A stripped path that falls under a configured gateway prefix is then
+    /// rewritten to the path the gateway forwards (`/api/orders/:id` → `/:id`
+    /// for a gateway that strips `/api/orders`), so the key names the route
+    /// the producer declares. External calls are never rewritten.
     pub fn consumer_call_path(&self, url: &str) -> String {
+        self.consumer_call_target(url).0
+    }
+
+    /// [`Self::consumer_call_path`], plus the service the call is known to
+    /// reach: the one the gateway route that rewrote the path forwards to
+    /// ([`crate::gateway::GatewayRoute::service`]), else the sibling a
+    /// deployment manifest maps the call's env var or host to
+    /// ([`Config::env_var_services`], [`Config::host_services`]). The matcher
+    /// only pairs such a call with that service's producers: a stripped path
+    /// like `/:id` would otherwise match any service's route.
+    pub fn consumer_call_target(&self, url: &str) -> (String, Option<String>) {
         let trimmed = url.trim_matches(|c| c == '`' || c == '"' || c == '\'');
+        if let Some(composed) = compose_new_url_target(trimmed) {
+            return self.consumer_call_target(&composed);
+        }
         let is_relative_path = trimmed.starts_with('/') && !trimmed.starts_with("//");
         let is_absolute_url = trimmed.starts_with("http://")
             || trimmed.starts_with("https://")
             || trimmed.starts_with("//");
         let normalized = self.normalize(url);
         if normalized.is_internal || is_relative_path || is_absolute_url {
-            normalized.path
+            if normalized.is_external {
+                return (normalized.path, None);
+            }
+            let sibling = self.sibling_service(normalized.stripped_host.as_deref());
+            match self.gateway.rewrite_path(&normalized.path) {
+                Some((rewritten, route)) => (rewritten, route.service.clone().or(sibling)),
+                None => (normalized.path, sibling),
+            }
         } else {
-            url.to_string()
+            (url.to_string(), None)
+        }
+    }
+
+    /// The sibling service a stripped base names: an env var
+    /// (`ENV_VAR:X`, `process.env.X`, `${X}`) through `env_var_services`, or
+    /// a literal host through `host_services`.
+    fn sibling_service(&self, stripped_host: Option<&str>) -> Option<String> {
+        let stripped = stripped_host?;
+        let env_var = stripped
+            .strip_prefix("ENV_VAR:")
+            .or_else(|| stripped.strip_prefix("process.env."))
+            .or_else(|| {
+                stripped
+                    .strip_prefix("${")
+                    .and_then(|s| s.strip_suffix('}'))
+            });
+        match env_var {
+            Some(env_var) => self.env_var_services.get(env_var).cloned(),
+            None => {
+                let host = stripped.split(':').next().unwrap_or(stripped);
+                self.host_services.get(&host.to_ascii_lowercase()).cloned()
+            }
         }
     }
 
@@ -612,6 +683,124 @@ impl Default for UrlNormalizer {
     }
 }
 
+/// The target a `new URL(path, base)` expression composes, when the call
+/// text is one (optionally followed by `.toString()` / `.href`):
+/// `new URL(\`/orders/${id}\`, ORDERS_BASE)` → `${ORDERS_BASE}/orders/${id}`.
+/// Each argument must be a string or template literal, or a plain reference
+/// (`process.env.ORDERS_URL`, `config.ordersUrl`, optionally with a `??`/`||`
+/// default); anything else returns `None`.
+pub fn compose_new_url_target(target: &str) -> Option<String> {
+    let call = target.trim();
+    let call = call
+        .strip_suffix(".toString()")
+        .or_else(|| call.strip_suffix(".href"))
+        .unwrap_or(call);
+    let args = call.strip_prefix("new URL(")?.strip_suffix(')')?;
+    let args = split_top_level_args(args)?;
+    match args.as_slice() {
+        [path] => url_arg_text(path),
+        [path, base] => Some(join_url_base(&url_arg_text(path)?, &url_arg_text(base)?)),
+        _ => None,
+    }
+}
+
+/// Resolve `path` against `base` the way `new URL(path, base)` does. An
+/// interpolated base (`${ORDERS_URL}`) stands for an origin, optionally
+/// followed by a literal path: an absolute `path` replaces that path, a
+/// relative one replaces its last segment, and an absolute URL ignores the
+/// base altogether.
+pub fn join_url_base(path: &str, base: &str) -> String {
+    if path.contains("://") || path.starts_with("//") {
+        return path.to_string();
+    }
+    let origin_end = if let Some(scheme_end) = base.find("://") {
+        base[scheme_end + 3..]
+            .find('/')
+            .map_or(base.len(), |i| scheme_end + 3 + i)
+    } else if base.starts_with("${") {
+        base.find('}').map_or(base.len(), |i| i + 1)
+    } else {
+        0
+    };
+    let (origin, base_path) = base.split_at(origin_end);
+    if path.is_empty() {
+        return base.to_string();
+    }
+    if path.starts_with('/') {
+        return format!("{}{}", origin, path);
+    }
+    let dir = match base_path.rfind('/') {
+        Some(i) => &base_path[..=i],
+        None => "/",
+    };
+    format!("{}{}{}", origin, dir, path)
+}
+
+/// Split call arguments on their top-level commas. `None` when brackets or
+/// quotes don't balance.
+fn split_top_level_args(args: &str) -> Option<Vec<&str>> {
+    let mut out = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                out.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return None;
+        }
+    }
+    if quote.is_some() || depth != 0 {
+        return None;
+    }
+    let last = args[start..].trim();
+    if !last.is_empty() {
+        out.push(last);
+    }
+    Some(out)
+}
+
+/// A `new URL` argument as target text: a literal's contents, or a reference
+/// as `${reference}`.
+fn url_arg_text(arg: &str) -> Option<String> {
+    let arg = arg.trim().trim_end_matches('!');
+    for q in ['`', '"', '\''] {
+        if let Some(inner) = arg.strip_prefix(q).and_then(|a| a.strip_suffix(q)) {
+            return Some(inner.to_string());
+        }
+    }
+    let reference = ["??", "||"]
+        .iter()
+        .find_map(|op| arg.split_once(op).map(|(left, _)| left.trim()))
+        .unwrap_or(arg);
+    let is_reference = !reference.is_empty()
+        && !reference.starts_with('.')
+        && !reference.ends_with('.')
+        && reference
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '.');
+    is_reference.then(|| format!("${{{}}}", reference))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -836,6 +1025,47 @@ mod tests {
         assert_eq!(sq.path, "/users/:id");
     }
 
+    #[test]
+    fn new_url_expressions_compose_their_target() {
+        let cases = [
+            (
+                "new URL(`/orders/${id}`, ORDERS_BASE)",
+                Some("${ORDERS_BASE}/orders/${id}"),
+            ),
+            (
+                "new URL('/orders', process.env.ORDERS_URL ?? 'http://localhost').toString()",
+                Some("${process.env.ORDERS_URL}/orders"),
+            ),
+            (
+                "new URL('items', 'http://catalog:4000/api/v1/')",
+                Some("http://catalog:4000/api/v1/items"),
+            ),
+            (
+                "new URL('/items', 'http://catalog:4000/api/v1/')",
+                Some("http://catalog:4000/items"),
+            ),
+            (
+                "new URL('https://api.stripe.com/v1/charges')",
+                Some("https://api.stripe.com/v1/charges"),
+            ),
+            ("new URL(buildPath(id), BASE)", None),
+            ("fetch(new URL('/x', BASE))", None),
+        ];
+        for (target, want) in cases {
+            assert_eq!(
+                compose_new_url_target(target).as_deref(),
+                want,
+                "for {target:?}"
+            );
+        }
+
+        let normalizer = UrlNormalizer::new(&create_test_config());
+        assert_eq!(
+            normalizer.consumer_call_path("new URL(`/users/${id}`, process.env.SERVICE_URL)"),
+            "/users/:id"
+        );
+    }
+
     #[test]
     fn test_normalize_query_string_removal() {
         let normalizer = UrlNormalizer::default_permissive();
@@ -1013,6 +1243,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn consumer_call_path_applies_gateway_rewrites() {
+        use crate::gateway::GatewayRoute;
+
+        let mut config = create_test_config();
+        config.gateway.routes = vec![
+            GatewayRoute::new("/api/orders", Some("orders".to_string()), ""),
+            GatewayRoute::new("/v1/charges", Some("billing".to_string()), "/charges"),
+        ];
+        let normalizer = UrlNormalizer::new(&config);
+
+        // Relative and declared-internal calls through the gateway key on the
+        // forwarded path.
+        assert_eq!(
+            normalizer.consumer_call_path("/api/orders/${orderId}"),
+            "/:orderId"
+        );
+        assert_eq!(normalizer.consumer_call_path("${API_URL}/api/orders"), "/");
+        assert_eq!(
+            normalizer.consumer_call_path("https://gateway.company.com/api/orders/7/items"),
+            "/7/items"
+        );
+        // The rewrite names the service the route forwards to.
+        assert_eq!(
+            normalizer.consumer_call_target("/v1/charges/ch_1"),
+            ("/charges/ch_1".to_string(), Some("billing".to_string()))
+        );
+        // Outside every prefix: untouched.
+        assert_eq!(
+            normalizer.consumer_call_target("/api/users"),
+            ("/api/users".to_string(), None)
+        );
+        // An external call is never rewritten, even under a gateway prefix.
+        assert_eq!(
+            normalizer.consumer_call_path("https://api.stripe.com/v1/charges"),
+            "/v1/charges"
+        );
+        // The plain normalizer (used by the matcher on the already-rewritten
+        // key) does not apply the gateway a second time.
+        assert_eq!(normalizer.extract_path("/api/orders/1"), "/api/orders/1");
+    }
+
+    #[test]
+    fn consumer_call_target_names_manifest_siblings() {
+        let mut config = create_test_config();
+        config.internal_env_vars.insert("ORDERS_URL".to_string());
+        config
+            .env_var_services
+            .insert("ORDERS_URL".to_string(), "orders".to_string());
+        config.internal_domains.insert("users-svc".to_string());
+        config
+            .host_services
+            .insert("users-svc".to_string(), "users".to_string());
+        let normalizer = UrlNormalizer::new(&config);
+
+        assert_eq!(
+            normalizer.consumer_call_target("${process.env.ORDERS_URL}/orders/${id}"),
+            ("/orders/:id".to_string(), Some("orders".to_string()))
+        );
+        assert_eq!(
+            normalizer.consumer_call_target("http://users-svc:8080/users"),
+            ("/users".to_string(), Some("users".to_string()))
+        );
+        assert_eq!(normalizer.consumer_call_target("/users").1, None);
+    }
+
     #[test]
     fn test_is_probable_url() {
         let normalizer = UrlNormalizer::default_permissive();
@@ -1,8 +1,10 @@
 extern crate swc_common;
 extern crate swc_ecma_parser;
 
+use crate::parser::unwrap_expr;
+use crate::query_params::QueryContract;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     path::PathBuf,
 };
 use swc_common::{SourceMapper, Spanned};
@@ -843,6 +845,2526 @@ fn derive_handler_name(method: &str, first_arg: Option<&str>) -> String {
     format!("{}_handler", base)
 }
 
+/// Server frameworks whose literal route registrations
+/// [`StaticRouteExtractor`] resolves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ServerKind {
+    Express,
+    Koa,
+    Fastify,
+    Hono,
+    Elysia,
+}
+
+impl ServerKind {
+    fn of_package(source: &str) -> Option<ServerKind> {
+        match source {
+            "express" => Some(ServerKind::Express),
+            "koa" | "@koa/router" | "koa-router" => Some(ServerKind::Koa),
+            "fastify" | "fastify-plugin" => Some(ServerKind::Fastify),
+            "hono" | "hono/tiny" | "hono/quick" => Some(ServerKind::Hono),
+            "elysia" => Some(ServerKind::Elysia),
+            _ => None,
+        }
+    }
+}
+
+/// Typed RPC clients whose calls [`StaticRouteExtractor`] turns back into
+/// HTTP requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClientKind {
+    /// Hono's `hc<AppType>(base)`: `client.users[':id'].$get()`.
+    Hono,
+    /// Elysia's Eden `treaty<App>(base)` (`api.users({ id }).get()`) and the
+    /// older `edenTreaty` (`api.users[':id'].get()`).
+    Eden,
+}
+
+impl ClientKind {
+    fn of_import(source: &str, imported: &str) -> Option<ClientKind> {
+        match (source, imported) {
+            ("hono/client", "hc") => Some(ClientKind::Hono),
+            ("@elysiajs/eden", "treaty" | "edenTreaty") => Some(ClientKind::Eden),
+            _ => None,
+        }
+    }
+}
+
+/// How a statically resolved handler emits its response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticEmission {
+    /// `res.json(x)`, `reply.send(x)`, `ctx.body = x`, `c.json(x)`.
+    Send,
+    /// A Fastify or Elysia handler returning its payload.
+    Return,
+    /// No payload: `res.sendStatus(204)`, `res.end()`.
+    None,
+}
+
+/// A literal-path route registration such as `router.get('/users/:id', h)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticRoute {
+    pub line_number: u32,
+    /// Binding the route is registered on (`router`), or the plugin function
+    /// when it is registered on a Fastify plugin's instance parameter.
+    pub owner: String,
+    pub method: String,
+    pub path: String,
+    pub handler_name: String,
+    pub pattern: String,
+    /// Span of the registration call.
+    pub span_start: u32,
+    pub span_end: u32,
+    pub emission: StaticEmission,
+    pub response_text: Option<String>,
+    pub response_line: Option<u32>,
+    pub request_text: Option<String>,
+    pub request_line: Option<u32>,
+    /// The query keys the handler reads; `None` when it hands the query
+    /// object or request elsewhere, or sits behind a middleware factory
+    /// that may read them instead.
+    pub query_params: Option<QueryContract>,
+    /// The headers the handler reads outside a `||` / `??` fallback,
+    /// lowercased.
+    pub request_headers: Vec<String>,
+}
+
+/// A literal-path mount such as `app.use('/users', usersRouter)`,
+/// `fastify.register(routes, { prefix: '/v1' })` or Hono's
+/// `app.route('/users', users)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticMount {
+    pub line_number: u32,
+    pub parent: String,
+    pub child: String,
+    pub path: String,
+    /// Module specifier the child is imported from, when it isn't local.
+    pub import_source: Option<String>,
+    pub pattern: String,
+}
+
+/// The contract a Fastify route declares in its `schema` option, rendered
+/// as TypeScript type text. Recorded whether or not the route itself
+/// resolved statically, so an LLM-extracted endpoint picks it up too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteSchema {
+    pub line_number: u32,
+    pub method: String,
+    /// `schema.body`.
+    pub request: Option<String>,
+    /// The lowest 2xx entry of `schema.response`.
+    pub response: Option<String>,
+    /// `schema.querystring`: its keys and the required ones.
+    pub query: Option<QueryContract>,
+}
+
+/// A call through a typed RPC client with the route path rebuilt from the
+/// property chain: `client.users[':id'].$get()` is `GET /users/:id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticClientCall {
+    pub line_number: u32,
+    pub method: String,
+    /// The client's base URL text joined to the path
+    /// (`${process.env.API_URL}/users/:id`), or the bare path when the client
+    /// is imported and its base unknown.
+    pub target: String,
+    pub pattern: String,
+    /// Span of the call.
+    pub span_start: u32,
+    pub span_end: u32,
+    pub call_text: String,
+    /// The request body argument: hc's `json`/`form` option, or a treaty
+    /// call's first argument.
+    pub payload_text: Option<String>,
+    pub payload_line: Option<u32>,
+}
+
+/// Everything [`StaticRouteExtractor`] resolved in one module.
+#[derive(Debug, Default)]
+pub struct StaticRoutes {
+    pub routes: Vec<StaticRoute>,
+    pub mounts: Vec<StaticMount>,
+    pub schemas: Vec<RouteSchema>,
+    pub client_calls: Vec<StaticClientCall>,
+    /// `(lo, hi)` spans of every call the routes, mounts and client calls
+    /// account for: the registrations, setup calls like `listen`, middleware
+    /// factories, calls on a resolved handler's request/response parameters,
+    /// and the RPC client calls and their constructors.
+    pub owned_spans: HashSet<(u32, u32)>,
+}
+
+const ROUTE_METHODS: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "del", "head", "options", "all",
+];
+
+/// Calls on a server that configure it rather than declare routes.
+const SETUP_METHODS: &[&str] = &[
+    "listen",
+    "set",
+    "enable",
+    "disable",
+    "engine",
+    "param",
+    "routes",
+    "allowedMethods",
+    "addHook",
+    "decorate",
+    "decorateRequest",
+    "decorateReply",
+    "setErrorHandler",
+    "setNotFoundHandler",
+    "addSchema",
+    "ready",
+    "close",
+    "on",
+    "notFound",
+    "onError",
+    "onRequest",
+    "onBeforeHandle",
+    "onAfterHandle",
+    "onAfterResponse",
+    "derive",
+    "resolve",
+    "state",
+    "model",
+];
+
+/// Response methods that carry the response body.
+const SEND_METHODS: &[&str] = &["json", "send", "jsonp", "text", "html"];
+
+/// HTTP methods an RPC client call can end in.
+const CLIENT_METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Resolves Express, Koa-router, Fastify, Hono and Elysia route
+/// registrations and mounts whose paths are literals, so files made only of
+/// those skip the LLM.
+///
+/// Only bindings provably created by a framework count as servers:
+/// `express()`, `express.Router()`, `new Koa()`, `new Router({ prefix })`
+/// from `@koa/router`/`koa-router`, `Fastify()`, `new Hono()` (and its
+/// `basePath`), `new Elysia({ prefix })`, the instance parameter of a Fastify
+/// plugin and of an Elysia `group` callback. A registration resolves when its
+/// path is a literal and its handler is inline or declared in the same file
+/// and never lets its response parameter escape. Anything else stays unowned,
+/// so the scanner candidate for it still sends the file to the analyzer.
+///
+/// Calls through Hono's `hc` and Eden's `treaty` clients are read the same
+/// way, into [`StaticClientCall`]s.
+pub struct StaticRouteExtractor<'a> {
+    source_map: &'a swc_common::sync::Lrc<swc_common::SourceMap>,
+    /// Local binding → (module specifier, imported name). Default, namespace
+    /// and plain `require` bindings import `default`.
+    imports: HashMap<String, (String, String)>,
+    /// Top-level functions, by name.
+    functions: HashMap<String, HandlerFn<'a>>,
+    servers: HashMap<Id, Server>,
+    /// Servers created at the root of a builder chain
+    /// (`const app = new Elysia().get(…)`), by the `(lo, hi)` span of the
+    /// creating expression.
+    roots: HashMap<(u32, u32), Server>,
+    /// Bindings holding a typed RPC client.
+    clients: HashMap<Id, Client>,
+    /// Top-level `const` initializers, by name, for schemas declared apart
+    /// from their route.
+    consts: HashMap<String, &'a Expr>,
+    out: StaticRoutes,
+}
+
+#[derive(Debug, Clone)]
+struct Server {
+    kind: ServerKind,
+    /// Owner name for routes registered on this binding.
+    owner: String,
+    /// Koa-router or Elysia `prefix` option, or Hono `basePath`, joined onto
+    /// every path.
+    prefix: String,
+}
+
+impl Server {
+    fn receiver(&self) -> Receiver {
+        Receiver {
+            kind: self.kind,
+            owner: self.owner.clone(),
+            prefix: self.prefix.clone(),
+            route: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Client {
+    kind: ClientKind,
+    /// Base URL text the client was created with, without a trailing `/`.
+    base: String,
+}
+
+#[derive(Clone, Copy)]
+enum HandlerFn<'a> {
+    Function(&'a Function),
+    Arrow(&'a ArrowExpr),
+}
+
+impl<'a> HandlerFn<'a> {
+    fn params(&self) -> Vec<&'a Pat> {
+        match self {
+            HandlerFn::Function(f) => f.params.iter().map(|p| &p.pat).collect(),
+            HandlerFn::Arrow(a) => a.params.iter().collect(),
+        }
+    }
+
+    fn param_ident(&self, index: usize) -> Option<&'a Ident> {
+        pat_ident(self.params().get(index)?)
+    }
+
+    fn visit_body<V: Visit>(&self, visitor: &mut V) {
+        match self {
+            HandlerFn::Function(f) => f.body.visit_with(visitor),
+            HandlerFn::Arrow(a) => a.body.visit_with(visitor),
+        }
+    }
+}
+
+/// The server a route or mount call is made on.
+struct Receiver {
+    kind: ServerKind,
+    owner: String,
+    prefix: String,
+    /// Base path and span of the `app.route('/x')` an Express route chain
+    /// hangs off.
+    route: Option<(String, (u32, u32))>,
+}
+
+/// What a resolved handler does with its request and response.
+struct HandlerFacts {
+    emission: StaticEmission,
+    response: Option<(String, u32)>,
+    request: Option<(String, u32)>,
+    query: Option<QueryContract>,
+    headers: Vec<String>,
+    param_calls: Vec<(u32, u32)>,
+}
+
+impl<'a> StaticRouteExtractor<'a> {
+    pub fn extract(
+        module: &'a Module,
+        source_map: &'a swc_common::sync::Lrc<swc_common::SourceMap>,
+    ) -> StaticRoutes {
+        let mut extractor = StaticRouteExtractor {
+            source_map,
+            imports: HashMap::new(),
+            functions: HashMap::new(),
+            servers: HashMap::new(),
+            roots: HashMap::new(),
+            clients: HashMap::new(),
+            consts: HashMap::new(),
+            out: StaticRoutes::default(),
+        };
+        module.visit_with(&mut ImportCollector {
+            imports: &mut extractor.imports,
+        });
+        let serves = extractor
+            .imports
+            .values()
+            .any(|(source, _)| ServerKind::of_package(source).is_some());
+        if serves {
+            extractor.collect_functions(module);
+            module.visit_with(&mut ServerCollector {
+                extractor: &mut extractor,
+            });
+            // Plugins registered on a server and Elysia group callbacks make
+            // their instance parameter a server too; repeat for ones nested
+            // inside them.
+            for _ in 0..4 {
+                let before = extractor.servers.len();
+                module.visit_with(&mut PluginCollector {
+                    extractor: &mut extractor,
+                });
+                if extractor.servers.len() == before {
+                    break;
+                }
+            }
+        }
+        module.visit_with(&mut ClientCollector {
+            extractor: &mut extractor,
+        });
+        // An imported hc client can only be told by its calls, so any file
+        // importing a project module is walked.
+        if !serves
+            && extractor.clients.is_empty()
+            && !extractor
+                .imports
+                .values()
+                .any(|(source, _)| is_local_specifier(source))
+        {
+            return extractor.out;
+        }
+        module.visit_with(&mut extractor);
+        // A chain is visited outermost call first; report in source order.
+        extractor.out.routes.sort_by_key(|route| route.line_number);
+        extractor.out.mounts.sort_by_key(|mount| mount.line_number);
+        extractor.out
+    }
+
+    fn line(&self, span: swc_common::Span) -> u32 {
+        self.source_map.lookup_char_pos(span.lo).line as u32
+    }
+
+    /// The line of a registration's method name, so each link of a
+    /// `new Hono().get(…).post(…)` chain gets its own line.
+    fn call_line(&self, call: &CallExpr) -> u32 {
+        match &call.callee {
+            Callee::Expr(callee) => match unwrap_expr(callee) {
+                Expr::Member(member) => self.line(member.prop.span()),
+                _ => self.line(call.span),
+            },
+            _ => self.line(call.span),
+        }
+    }
+
+    fn snippet(&self, span: swc_common::Span) -> (String, u32) {
+        (
+            self.source_map.span_to_snippet(span).unwrap_or_default(),
+            self.line(span),
+        )
+    }
+
+    fn own(&mut self, span: swc_common::Span) {
+        self.out.owned_spans.insert((span.lo.0, span.hi.0));
+    }
+
+    fn collect_functions(&mut self, module: &'a Module) {
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(f),
+                    ..
+                })) => {
+                    let name = f.ident.as_ref().map(|i| i.sym.to_string());
+                    self.fastify_plugin(HandlerFn::Function(&f.function), name.clone(), false);
+                    if let Some(name) = name {
+                        self.functions
+                            .insert(name, HandlerFn::Function(&f.function));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            match decl {
+                Decl::Fn(f) => {
+                    let name = f.ident.sym.to_string();
+                    self.fastify_plugin(
+                        HandlerFn::Function(&f.function),
+                        Some(name.clone()),
+                        false,
+                    );
+                    self.functions
+                        .insert(name, HandlerFn::Function(&f.function));
+                }
+                Decl::Var(var) => {
+                    for declarator in &var.decls {
+                        self.collect_function_declarator(declarator);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_function_declarator(&mut self, declarator: &'a VarDeclarator) {
+        let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref())
+        else {
+            return;
+        };
+        let name = binding.id.sym.to_string();
+        self.consts.insert(name.clone(), init);
+        let annotated_plugin = binding.type_ann.as_ref().is_some_and(|ann| {
+            type_ref_name(&ann.type_ann).is_some_and(|n| n.starts_with("FastifyPlugin"))
+        });
+        let (function, known_plugin) = match unwrap_expr(init) {
+            // `fp(async (fastify) => …)` from `fastify-plugin`
+            Expr::Call(call)
+                if callee_ident(call).is_some_and(|callee| {
+                    self.imports
+                        .get(callee)
+                        .is_some_and(|(source, _)| source == "fastify-plugin")
+                }) =>
+            {
+                match call.args.first().and_then(|arg| inline_fn(&arg.expr)) {
+                    Some(function) => (function, true),
+                    None => return,
+                }
+            }
+            expr => match inline_fn(expr) {
+                Some(function) => (function, annotated_plugin),
+                None => return,
+            },
+        };
+        self.fastify_plugin(function, Some(name.clone()), known_plugin);
+        self.functions.insert(name, function);
+    }
+
+    /// Treat a function's first parameter as a Fastify instance when it is
+    /// typed `FastifyInstance` or the function is `known` to be a plugin.
+    fn fastify_plugin(&mut self, plugin: HandlerFn<'_>, name: Option<String>, known: bool) {
+        let Some(param) = plugin.param_ident(0) else {
+            return;
+        };
+        let typed = match plugin.params().first() {
+            Some(Pat::Ident(binding)) => binding.type_ann.as_ref().is_some_and(|ann| {
+                type_ref_name(&ann.type_ann).is_some_and(|n| n == "FastifyInstance")
+            }),
+            _ => false,
+        };
+        if known || typed {
+            let owner = name.unwrap_or_else(|| param.sym.to_string());
+            self.servers.entry(param.to_id()).or_insert(Server {
+                kind: ServerKind::Fastify,
+                owner,
+                prefix: String::new(),
+            });
+        }
+    }
+
+    /// The server `init` creates, with its route prefix.
+    fn server_created_by(&self, init: &Expr) -> Option<(ServerKind, String)> {
+        match unwrap_expr(init) {
+            Expr::Call(call) => {
+                // express(), Router(), Fastify()
+                if let Some(callee) = callee_ident(call) {
+                    let (source, imported) = self.imports.get(callee)?;
+                    return match (source.as_str(), imported.as_str()) {
+                        ("express", "default" | "Router") => {
+                            Some((ServerKind::Express, String::new()))
+                        }
+                        ("fastify", "default" | "fastify" | "Fastify") => {
+                            Some((ServerKind::Fastify, String::new()))
+                        }
+                        _ => None,
+                    };
+                }
+                let (object, prop) = member_callee(call)?;
+                // new Hono().basePath('/api')
+                if prop == "basePath" {
+                    let (kind, prefix) = self.server_created_by(object)?;
+                    let path = literal(&call.args.first()?.expr)?;
+                    return (kind == ServerKind::Hono)
+                        .then(|| (kind, join_route_path(&prefix, &path)));
+                }
+                // express.Router()
+                let (source, _) = self.imports.get(unwrap_ident(object)?.sym.as_ref())?;
+                (source == "express" && prop == "Router")
+                    .then(|| (ServerKind::Express, String::new()))
+            }
+            // new Koa(), new Router({ prefix: '/users' }), new Hono(),
+            // new Elysia({ prefix: '/users' })
+            Expr::New(new) => {
+                let (source, imported) =
+                    self.imports.get(unwrap_ident(&new.callee)?.sym.as_ref())?;
+                match (source.as_str(), imported.as_str()) {
+                    ("koa", _) => Some((ServerKind::Koa, String::new())),
+                    ("@koa/router" | "koa-router", _) => {
+                        Some((ServerKind::Koa, prefix_option(new)?))
+                    }
+                    ("hono" | "hono/tiny" | "hono/quick", "Hono") => {
+                        Some((ServerKind::Hono, String::new()))
+                    }
+                    ("elysia", "Elysia") => Some((ServerKind::Elysia, prefix_option(new)?)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The server at the root of a Hono or Elysia builder chain such as
+    /// `new Elysia().get(…).listen(3000)`, with the creating expression.
+    fn chain_root<'e>(&self, init: &'e Expr) -> Option<(&'e Expr, ServerKind, String)> {
+        let mut expr = unwrap_expr(init);
+        loop {
+            if let Some((kind, prefix)) = self.server_created_by(expr) {
+                let chained = !std::ptr::eq(expr, unwrap_expr(init));
+                return (!chained || matches!(kind, ServerKind::Hono | ServerKind::Elysia))
+                    .then_some((expr, kind, prefix));
+            }
+            let Expr::Call(call) = expr else {
+                return None;
+            };
+            expr = unwrap_expr(member_callee(call)?.0);
+        }
+    }
+
+    /// The client `init` creates: `hc<AppType>(base)`, `treaty<App>(base)`.
+    fn client_created_by<'e>(&self, init: &'e Expr) -> Option<(ClientKind, &'e CallExpr)> {
+        let Expr::Call(call) = unwrap_expr(init) else {
+            return None;
+        };
+        let (source, imported) = self.imports.get(callee_ident(call)?)?;
+        Some((ClientKind::of_import(source, imported)?, call))
+    }
+
+    /// A client's base URL argument as target text: literals as written (Eden
+    /// defaults a bare `localhost:3000` to http), anything else as a `${…}`
+    /// interpolation.
+    fn client_base(&self, kind: ClientKind, base: &Expr) -> String {
+        let base = match unwrap_expr(base) {
+            Expr::Lit(Lit::Str(str)) => {
+                let base = str.value.to_string();
+                if kind == ClientKind::Eden && !base.contains("://") && !base.starts_with('/') {
+                    format!("http://{}", base)
+                } else {
+                    base
+                }
+            }
+            Expr::Tpl(tpl) => self.snippet(tpl.span).0.trim_matches('`').to_string(),
+            expr => format!("${{{}}}", self.snippet(expr.span()).0),
+        };
+        base.trim_end_matches('/').to_string()
+    }
+
+    fn receiver(&self, expr: &Expr) -> Option<Receiver> {
+        let expr = unwrap_expr(expr);
+        let span = expr.span();
+        if let Some(server) = self.roots.get(&(span.lo.0, span.hi.0)) {
+            return Some(server.receiver());
+        }
+        match expr {
+            Expr::Ident(ident) => Some(self.servers.get(&ident.to_id())?.receiver()),
+            // app.route('/x').get(h).post(h), router.get(…).post(…)
+            Expr::Call(call) => {
+                let (object, prop) = member_callee(call)?;
+                let inner = self.receiver(object)?;
+                match (inner.kind, prop) {
+                    (ServerKind::Hono, "basePath") => {
+                        let path = literal(&call.args.first()?.expr)?;
+                        Some(Receiver {
+                            prefix: join_route_path(&inner.prefix, &path),
+                            ..inner
+                        })
+                    }
+                    // Every other Hono and Elysia builder method returns the
+                    // app itself.
+                    (ServerKind::Hono | ServerKind::Elysia, _) => Some(inner),
+                    (_, "route") => {
+                        if inner.kind != ServerKind::Express || inner.route.is_some() {
+                            return None;
+                        }
+                        let path = literal(&call.args.first()?.expr)?;
+                        Some(Receiver {
+                            route: Some((path, (call.span.lo.0, call.span.hi.0))),
+                            ..inner
+                        })
+                    }
+                    _ => (ROUTE_METHODS.contains(&prop) || prop == "use").then_some(inner),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The function a route's handler argument refers to, and its name.
+    fn handler<'b>(&'b self, expr: &'b Expr) -> Option<(HandlerFn<'b>, Option<String>)> {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) => self
+                .functions
+                .get(ident.sym.as_ref())
+                .map(|function| (*function, Some(ident.sym.to_string()))),
+            // Fastify route options: { schema, handler }
+            Expr::Object(options) => self.handler(object_prop(options, "handler")??),
+            // asyncHandler(async (req, res) => …)
+            Expr::Call(call) if call.args.len() == 1 && call.args[0].spread.is_none() => {
+                self.handler(&call.args[0].expr)
+            }
+            Expr::Fn(FnExpr { ident, function }) => Some((
+                HandlerFn::Function(function),
+                ident.as_ref().map(|i| i.sym.to_string()),
+            )),
+            Expr::Arrow(arrow) => Some((HandlerFn::Arrow(arrow), None)),
+            _ => None,
+        }
+    }
+
+    /// Find how `handler` answers, or `None` when it can't be told statically.
+    fn analyze(&self, kind: ServerKind, handler: HandlerFn<'_>) -> Option<HandlerFacts> {
+        let (request_index, response_index) = match kind {
+            ServerKind::Koa | ServerKind::Hono | ServerKind::Elysia => (0, 0),
+            _ => (0, 1),
+        };
+        let params = handler.params();
+        // Elysia handlers destructure their context, `({ body, params })`,
+        // and answer with their return value.
+        let destructured_body = match (kind, params.first()) {
+            (ServerKind::Elysia, Some(Pat::Object(context))) => {
+                Some(context.props.iter().find_map(|prop| match prop {
+                    ObjectPatProp::Assign(assign) if assign.key.sym == *"body" => {
+                        Some(self.snippet(assign.key.span))
+                    }
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(key),
+                        value,
+                    }) if key.sym == *"body" => Some(self.snippet(value.span())),
+                    _ => None,
+                }))
+            }
+            _ => None,
+        };
+        // An Elysia context's `query`, bound whole or destructured further.
+        let mut query_objects = Vec::new();
+        let mut query_keys = BTreeSet::new();
+        let mut query_opaque = false;
+        if let (ServerKind::Elysia, Some(Pat::Object(context))) = (kind, params.first()) {
+            for prop in &context.props {
+                match prop {
+                    ObjectPatProp::Assign(assign) if assign.key.sym == *"query" => {
+                        query_objects.push(assign.key.id.to_id());
+                    }
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(key),
+                        value,
+                    }) if key.sym == *"query" => match &**value {
+                        Pat::Object(pattern) => match object_pattern_keys(pattern) {
+                            Some(keys) => query_keys.extend(keys),
+                            None => query_opaque = true,
+                        },
+                        value => match pat_ident(value) {
+                            Some(ident) => query_objects.push(ident.to_id()),
+                            None => query_opaque = true,
+                        },
+                    },
+                    _ => {}
+                }
+            }
+        }
+        // An Elysia context's `headers`, likewise.
+        let mut header_objects = Vec::new();
+        let mut header_reads = BTreeSet::new();
+        if let (ServerKind::Elysia, Some(Pat::Object(context))) = (kind, params.first()) {
+            for prop in &context.props {
+                match prop {
+                    ObjectPatProp::Assign(assign) if assign.key.sym == *"headers" => {
+                        header_objects.push(assign.key.id.to_id());
+                    }
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(key),
+                        value,
+                    }) if key.sym == *"headers" => match &**value {
+                        Pat::Object(pattern) => header_reads.extend(required_pattern_keys(pattern)),
+                        value => header_objects.extend(pat_ident(value).map(Ident::to_id)),
+                    },
+                    _ => {}
+                }
+            }
+        }
+        // A destructured `({ body }, res)` or `({ request, response })` hides
+        // the reads and writes the scan below looks for.
+        if destructured_body.is_none()
+            && [request_index, response_index]
+                .iter()
+                .any(|&index| index < params.len() && handler.param_ident(index).is_none())
+        {
+            return None;
+        }
+        let mut scan = HandlerScan {
+            extractor: self,
+            kind,
+            request: handler.param_ident(request_index).map(Ident::to_id),
+            response: match kind {
+                ServerKind::Elysia => None,
+                _ => handler.param_ident(response_index).map(Ident::to_id),
+            },
+            depth: 0,
+            sends: Vec::new(),
+            returns: Vec::new(),
+            request_body: destructured_body.flatten(),
+            query_objects,
+            query_keys,
+            query_opaque,
+            header_objects,
+            header_reads,
+            fallback: 0,
+            param_calls: Vec::new(),
+            escaped: false,
+        };
+        handler.visit_body(&mut scan);
+        if let HandlerFn::Arrow(arrow) = handler
+            && let BlockStmtOrExpr::Expr(body) = &*arrow.body
+            && !scan.rooted_at_response(body)
+        {
+            scan.returns.push(self.snippet(body.span()));
+        }
+        // Express, Koa and Hono ignore a handler's return value unless it is
+        // the response, so a handler that returns something else without
+        // sending is relying on some wrapper to send it.
+        let answers_with_return = matches!(kind, ServerKind::Fastify | ServerKind::Elysia);
+        if scan.escaped
+            || (!answers_with_return && scan.sends.is_empty() && !scan.returns.is_empty())
+        {
+            return None;
+        }
+        let (emission, response) = match scan.sends.into_iter().next() {
+            Some(sent) => (StaticEmission::Send, Some(sent)),
+            None if answers_with_return && !scan.returns.is_empty() => {
+                (StaticEmission::Return, scan.returns.into_iter().next())
+            }
+            _ => (StaticEmission::None, None),
+        };
+        Some(HandlerFacts {
+            emission,
+            response,
+            request: scan.request_body,
+            query: (!scan.query_opaque).then(|| QueryContract::new(scan.query_keys, Vec::new())),
+            headers: scan.header_reads.into_iter().collect(),
+            param_calls: scan.param_calls,
+        })
+    }
+
+    /// Own a middleware argument's factory calls and, for an inline
+    /// middleware, the calls it makes on its own parameters.
+    fn own_middleware(&mut self, expr: &Expr) {
+        match unwrap_expr(expr) {
+            Expr::Call(call) => {
+                self.own(call.span);
+                for arg in &call.args {
+                    self.own_middleware(&arg.expr);
+                }
+            }
+            expr => {
+                if let Some(function) = inline_fn(expr) {
+                    let mut calls = ParamCalls {
+                        params: function
+                            .params()
+                            .into_iter()
+                            .filter_map(pat_ident)
+                            .map(Ident::to_id)
+                            .collect(),
+                        spans: Vec::new(),
+                    };
+                    function.visit_body(&mut calls);
+                    self.out.owned_spans.extend(calls.spans);
+                }
+            }
+        }
+    }
+
+    /// `router.get('/users', auth, handler)`, `router.get('name', '/users', h)`
+    /// (Koa), `app.route('/users').get(handler)` (Express) and
+    /// `app.get('/users', handler, { body })` (Elysia).
+    fn route(&mut self, call: &CallExpr, receiver: Receiver, method: &str) {
+        let args = &call.args;
+        let (paths, rest) = match &receiver.route {
+            Some((path, _)) => (vec![path.clone()], &args[..]),
+            None => {
+                let named = receiver.kind == ServerKind::Koa
+                    && args.len() >= 3
+                    && literal(&args[0].expr).is_some()
+                    && literal(&args[1].expr).is_some();
+                let path_index = usize::from(named);
+                let Some(paths) = args
+                    .get(path_index)
+                    .and_then(|arg| literal_paths(&arg.expr))
+                else {
+                    return;
+                };
+                (paths, &args[path_index + 1..])
+            }
+        };
+        // Elysia takes the handler first and its hook options after it.
+        let (handler, middleware, hook) = match (receiver.kind, rest) {
+            (ServerKind::Elysia, [handler, rest @ ..]) => (handler, &[][..], rest.first()),
+            (_, [middleware @ .., handler]) => (handler, middleware, None),
+            _ => {
+                // `app.get('env')` reads an Express setting.
+                if receiver.kind == ServerKind::Express && receiver.route.is_none() {
+                    self.own(call.span);
+                }
+                return;
+            }
+        };
+        let methods = vec![match method {
+            "del" => "DELETE".to_string(),
+            method => method.to_uppercase(),
+        }];
+        if let Some(hook) = hook {
+            self.declared_schemas(call, &hook.expr, &methods);
+        }
+        let middleware: Vec<&Expr> = middleware.iter().map(|arg| &*arg.expr).collect();
+        self.emit_routes(call, receiver, methods, paths, &handler.expr, &middleware);
+    }
+
+    /// Hono's `app.on('PURGE', '/cache', h)` and
+    /// `app.on(['GET', 'POST'], '/form', h)`.
+    fn hono_on(&mut self, call: &CallExpr, receiver: Receiver) {
+        let [methods, paths, rest @ ..] = &call.args[..] else {
+            return;
+        };
+        let (Some(methods), Some(paths), Some((handler, middleware))) = (
+            literal_paths(&methods.expr),
+            literal_paths(&paths.expr),
+            rest.split_last(),
+        ) else {
+            return;
+        };
+        let methods = methods.iter().map(|m| m.to_uppercase()).collect();
+        let middleware: Vec<&Expr> = middleware.iter().map(|arg| &*arg.expr).collect();
+        self.emit_routes(call, receiver, methods, paths, &handler.expr, &middleware);
+    }
+
+    /// Fastify's full declaration: `fastify.route({ method, url, handler })`.
+    fn fastify_route(&mut self, call: &CallExpr, receiver: Receiver) {
+        let [options] = &call.args[..] else {
+            return;
+        };
+        let Expr::Object(options) = unwrap_expr(&options.expr) else {
+            return;
+        };
+        let methods = match object_prop(options, "method").flatten().map(unwrap_expr) {
+            Some(Expr::Array(array)) => array
+                .elems
+                .iter()
+                .map(|elem| literal(&elem.as_ref()?.expr))
+                .collect::<Option<Vec<_>>>(),
+            Some(method) => literal(method).map(|method| vec![method]),
+            None => None,
+        };
+        let path = object_prop(options, "url")
+            .or_else(|| object_prop(options, "path"))
+            .flatten()
+            .and_then(literal);
+        let (Some(methods), Some(path), Some(Some(handler))) =
+            (methods, path, object_prop(options, "handler"))
+        else {
+            return;
+        };
+        let methods = methods.iter().map(|m| m.to_uppercase()).collect();
+        self.emit_routes(call, receiver, methods, vec![path], handler, &[]);
+    }
+
+    fn emit_routes(
+        &mut self,
+        call: &CallExpr,
+        receiver: Receiver,
+        methods: Vec<String>,
+        paths: Vec<String>,
+        handler: &Expr,
+        middleware: &[&Expr],
+    ) {
+        if receiver.kind == ServerKind::Fastify {
+            self.route_schemas(call, &methods);
+        }
+        let Some((function, name)) = self.handler(handler) else {
+            return;
+        };
+        let Some(facts) = self.analyze(receiver.kind, function) else {
+            return;
+        };
+        let line_number = self.call_line(call);
+        // A middleware factory such as `validate(schema)` may be what reads
+        // the query, so the handler's reads aren't the whole story.
+        let query_params = facts.query.filter(|_| {
+            !middleware
+                .iter()
+                .any(|expr| matches!(unwrap_expr(expr), Expr::Call(_)))
+        });
+        for method in &methods {
+            for path in &paths {
+                let path = join_route_path(&receiver.prefix, path);
+                let method_lower = method.to_lowercase();
+                self.out.routes.push(StaticRoute {
+                    line_number,
+                    owner: receiver.owner.clone(),
+                    method: method.clone(),
+                    handler_name: name.clone().unwrap_or_else(|| "anonymous".to_string()),
+                    path,
+                    pattern: format!(".{}(", method_lower),
+                    span_start: call.span.lo.0,
+                    span_end: call.span.hi.0,
+                    emission: facts.emission,
+                    response_text: facts.response.as_ref().map(|(text, _)| text.clone()),
+                    response_line: facts.response.as_ref().map(|(_, line)| *line),
+                    request_text: facts.request.as_ref().map(|(text, _)| text.clone()),
+                    request_line: facts.request.as_ref().map(|(_, line)| *line),
+                    query_params: query_params.clone(),
+                    request_headers: facts.headers.clone(),
+                });
+            }
+        }
+        self.own(call.span);
+        if let Some((_, span)) = receiver.route {
+            self.out.owned_spans.insert(span);
+        }
+        self.out.owned_spans.extend(facts.param_calls);
+        for expr in middleware {
+            self.own_middleware(expr);
+        }
+    }
+
+    /// Record the `schema` option of a Fastify route, in either the
+    /// `fastify.get(path, { schema }, handler)` or the `fastify.route({ … })`
+    /// shape.
+    fn route_schemas(&mut self, call: &CallExpr, methods: &[String]) {
+        let Some(schema) = call
+            .args
+            .iter()
+            .find_map(|arg| match unwrap_expr(&arg.expr) {
+                Expr::Object(options) => object_prop(options, "schema").flatten(),
+                _ => None,
+            })
+        else {
+            return;
+        };
+        self.declared_schemas(call, schema, methods);
+    }
+
+    /// Record the `body`, `response` and `querystring` of a Fastify `schema`
+    /// or an Elysia hook. An Elysia `response` may be a single schema rather
+    /// than one per status, and its query schema is named `query`.
+    fn declared_schemas(&mut self, call: &CallExpr, schema: &Expr, methods: &[String]) {
+        let Some(Expr::Object(schema)) = self.resolve_const(schema, 0) else {
+            return;
+        };
+        let request = object_prop(schema, "body")
+            .flatten()
+            .and_then(|body| self.schema_root_type(body));
+        let response = object_prop(schema, "response")
+            .flatten()
+            .and_then(|response| match self.resolve_const(response, 0)? {
+                Expr::Object(by_status) => success_schema(by_status),
+                single @ Expr::Call(_) => Some(single),
+                _ => None,
+            })
+            .and_then(|response| self.schema_root_type(response));
+        let query = object_prop(schema, "querystring")
+            .or_else(|| object_prop(schema, "query"))
+            .flatten()
+            .and_then(|query| self.query_schema(query));
+        if request.is_none() && response.is_none() && query.is_none() {
+            return;
+        }
+        let line_number = self.call_line(call);
+        for method in methods {
+            self.out.schemas.push(RouteSchema {
+                line_number,
+                method: method.clone(),
+                request: request.clone(),
+                response: response.clone(),
+                query: query.clone(),
+            });
+        }
+    }
+
+    /// Follow identifiers to the same-file `const` they name.
+    fn resolve_const<'e>(&'e self, expr: &'e Expr, depth: usize) -> Option<&'e Expr> {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) if depth < 8 => {
+                self.resolve_const(self.consts.get(ident.sym.as_ref())?, depth + 1)
+            }
+            Expr::Ident(_) => None,
+            expr => Some(expr),
+        }
+    }
+
+    /// The keys a query schema declares and the ones it requires: a JSON
+    /// Schema's `properties` and `required`, or a TypeBox `Type.Object`'s
+    /// fields, required unless `Optional`. `None` for anything else, and for
+    /// a JSON Schema that admits additional keys.
+    fn query_schema(&self, schema: &Expr) -> Option<QueryContract> {
+        match self.resolve_const(schema, 0)? {
+            Expr::Object(schema) => {
+                if let Some(Expr::Lit(Lit::Bool(Bool { value: true, .. }))) =
+                    object_prop(schema, "additionalProperties")
+                        .flatten()
+                        .map(unwrap_expr)
+                {
+                    return None;
+                }
+                let Some(Expr::Object(properties)) = object_prop(schema, "properties")
+                    .flatten()
+                    .and_then(|properties| self.resolve_const(properties, 0))
+                else {
+                    return None;
+                };
+                let required = match object_prop(schema, "required").flatten().map(unwrap_expr) {
+                    Some(Expr::Array(array)) => array
+                        .elems
+                        .iter()
+                        .flatten()
+                        .filter_map(|elem| literal(&elem.expr))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                let fields = object_fields(properties)?;
+                Some(QueryContract::new(
+                    fields.into_iter().map(|(key, _)| key),
+                    required,
+                ))
+            }
+            Expr::Call(call) => {
+                let (object, builder) = member_callee(call)?;
+                if builder != "Object" || !self.is_typebox(object) {
+                    return None;
+                }
+                let Expr::Object(properties) = self.resolve_const(&call.args.first()?.expr, 0)?
+                else {
+                    return None;
+                };
+                let fields = object_fields(properties)?;
+                let required = fields
+                    .iter()
+                    .filter(|(_, value)| !is_typebox_optional(value))
+                    .map(|(key, _)| key.clone())
+                    .collect::<Vec<_>>();
+                Some(QueryContract::new(
+                    fields.into_iter().map(|(key, _)| key),
+                    required,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// A schema's TypeScript type, or `None` when nothing about it is known.
+    fn schema_root_type(&self, schema: &Expr) -> Option<String> {
+        Some(self.schema_type(schema, 0)).filter(|ty| ty != "unknown")
+    }
+
+    /// Render a JSON Schema literal or TypeBox builder as a TypeScript type.
+    /// Whatever can't be read (a `$ref` — `addSchema` ids aren't resolved —
+    /// an imported schema, a computed value) becomes `unknown` rather than a
+    /// guess, and so does an object with a spread or unreadable member, since
+    /// its known fields alone would read as the whole shape.
+    fn schema_type(&self, schema: &Expr, depth: usize) -> String {
+        const UNKNOWN: &str = "unknown";
+        if depth > 16 {
+            return UNKNOWN.to_string();
+        }
+        let Some(schema) = self.resolve_const(schema, 0) else {
+            return UNKNOWN.to_string();
+        };
+        match schema {
+            Expr::Object(object) => self.json_schema_type(object, depth),
+            Expr::Call(call) => self
+                .typebox_type(call, depth)
+                .unwrap_or_else(|| UNKNOWN.to_string()),
+            _ => UNKNOWN.to_string(),
+        }
+    }
+
+    fn json_schema_type(&self, schema: &ObjectLit, depth: usize) -> String {
+        let prop = |key: &str| object_prop(schema, key).flatten();
+        let list = |key: &str| match prop(key).map(unwrap_expr) {
+            Some(Expr::Array(array)) => Some(
+                array
+                    .elems
+                    .iter()
+                    .flatten()
+                    .map(|elem| &*elem.expr)
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        };
+
+        let ty = if prop("$ref").is_some() {
+            "unknown".to_string()
+        } else if let Some(value) = prop("const") {
+            literal_type(value).unwrap_or_else(|| "unknown".to_string())
+        } else if let Some(values) = list("enum") {
+            values
+                .iter()
+                .map(|value| literal_type(value))
+                .collect::<Option<Vec<_>>>()
+                .map(|values| union(&values))
+                .unwrap_or_else(|| "unknown".to_string())
+        } else if let Some(variants) = list("anyOf").or_else(|| list("oneOf")) {
+            union(
+                &variants
+                    .iter()
+                    .map(|variant| self.schema_type(variant, depth + 1))
+                    .collect::<Vec<_>>(),
+            )
+        } else if let Some(parts) = list("allOf") {
+            intersection(
+                &parts
+                    .iter()
+                    .map(|part| self.schema_type(part, depth + 1))
+                    .collect::<Vec<_>>(),
+            )
+        } else {
+            let types: Vec<String> = match prop("type").map(unwrap_expr) {
+                Some(Expr::Array(array)) => array
+                    .elems
+                    .iter()
+                    .flatten()
+                    .filter_map(|elem| literal(&elem.expr))
+                    .collect(),
+                Some(ty) => literal(ty).into_iter().collect(),
+                None if prop("properties").is_some() => vec!["object".to_string()],
+                None => Vec::new(),
+            };
+            if types.is_empty() {
+                "unknown".to_string()
+            } else {
+                union(
+                    &types
+                        .iter()
+                        .map(|ty| match ty.as_str() {
+                            "string" => "string".to_string(),
+                            "number" | "integer" => "number".to_string(),
+                            "boolean" => "boolean".to_string(),
+                            "null" => "null".to_string(),
+                            "array" => array_of(
+                                &prop("items")
+                                    .map(|items| self.schema_type(items, depth + 1))
+                                    .unwrap_or_else(|| "unknown".to_string()),
+                            ),
+                            "object" => self.json_object_type(schema, depth),
+                            _ => "unknown".to_string(),
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+        match prop("nullable").map(unwrap_expr) {
+            Some(Expr::Lit(Lit::Bool(Bool { value: true, .. }))) => union(&[ty, "null".into()]),
+            _ => ty,
+        }
+    }
+
+    fn json_object_type(&self, schema: &ObjectLit, depth: usize) -> String {
+        let required: HashSet<String> =
+            match object_prop(schema, "required").flatten().map(unwrap_expr) {
+                Some(Expr::Array(array)) => array
+                    .elems
+                    .iter()
+                    .flatten()
+                    .filter_map(|elem| literal(&elem.expr))
+                    .collect(),
+                _ => HashSet::new(),
+            };
+        let properties = match object_prop(schema, "properties")
+            .flatten()
+            .and_then(|properties| self.resolve_const(properties, 0))
+        {
+            Some(Expr::Object(properties)) => properties,
+            _ => {
+                let values = match object_prop(schema, "additionalProperties")
+                    .flatten()
+                    .map(unwrap_expr)
+                {
+                    Some(Expr::Lit(_)) | None => "unknown".to_string(),
+                    Some(values) => self.schema_type(values, depth + 1),
+                };
+                return format!("Record<string, {}>", values);
+            }
+        };
+        let fields: Option<Vec<(String, bool, String)>> = properties
+            .props
+            .iter()
+            .map(|prop| {
+                let (name, value) = schema_member(prop)?;
+                let optional = !required.contains(&name);
+                Some((name, optional, self.schema_type(value, depth + 1)))
+            })
+            .collect();
+        fields.map_or_else(|| "unknown".to_string(), |fields| object_type(&fields))
+    }
+
+    /// Whether `object` is the `Type` (or `t`) namespace of a TypeBox import.
+    fn is_typebox(&self, object: &Expr) -> bool {
+        unwrap_ident(object)
+            .and_then(|ident| self.imports.get(ident.sym.as_ref()))
+            .is_some_and(|(source, _)| {
+                matches!(
+                    source.as_str(),
+                    "@sinclair/typebox" | "typebox" | "@fastify/type-provider-typebox" | "elysia"
+                )
+            })
+    }
+
+    /// `Type.Object({ … })` and friends from `@sinclair/typebox`.
+    fn typebox_type(&self, call: &CallExpr, depth: usize) -> Option<String> {
+        let (object, builder) = member_callee(call)?;
+        if !self.is_typebox(object) {
+            return None;
+        }
+        let arg = |index: usize| call.args.get(index).map(|arg| &*arg.expr);
+        let each = |index: usize| match arg(index).and_then(|a| self.resolve_const(a, 0)) {
+            Some(Expr::Array(array)) => array
+                .elems
+                .iter()
+                .flatten()
+                .map(|elem| self.schema_type(&elem.expr, depth + 1))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let inner = |index: usize| {
+            arg(index).map_or("unknown".to_string(), |a| self.schema_type(a, depth + 1))
+        };
+        Some(match builder {
+            "String" => "string".to_string(),
+            "Number" | "Integer" => "number".to_string(),
+            "Boolean" => "boolean".to_string(),
+            "Null" => "null".to_string(),
+            "Any" => "any".to_string(),
+            "Literal" => literal_type(arg(0)?)?,
+            "Array" => array_of(&inner(0)),
+            "Optional" | "ReadonlyOptional" | "Readonly" => inner(0),
+            "Union" => union(&each(0)),
+            "Intersect" => intersection(&each(0)),
+            "Tuple" => format!("[{}]", each(0).join(", ")),
+            "Record" => format!("Record<{}, {}>", inner(0), inner(1)),
+            "Partial" => format!("Partial<{}>", inner(0)),
+            "Required" => format!("Required<{}>", inner(0)),
+            "Object" => {
+                let Some(Expr::Object(properties)) = arg(0).and_then(|a| self.resolve_const(a, 0))
+                else {
+                    return None;
+                };
+                let fields: Vec<(String, bool, String)> = properties
+                    .props
+                    .iter()
+                    .map(|prop| {
+                        let (name, value) = schema_member(prop)?;
+                        Some((
+                            name,
+                            is_typebox_optional(value),
+                            self.schema_type(value, depth + 1),
+                        ))
+                    })
+                    .collect::<Option<_>>()?;
+                object_type(&fields)
+            }
+            _ => return None,
+        })
+    }
+
+    /// The mount child an argument names: a local server or plugin, or a
+    /// binding imported from a project module.
+    fn mount_child(&self, expr: &Expr) -> Option<(String, Option<String>)> {
+        let ident = unwrap_ident(expr)?;
+        if let Some(server) = self.servers.get(&ident.to_id()) {
+            return Some((server.owner.clone(), None));
+        }
+        // A same-file Fastify plugin: its instance parameter is the server.
+        if let Some(server) = self
+            .functions
+            .get(ident.sym.as_ref())
+            .and_then(|plugin| plugin.param_ident(0))
+            .and_then(|param| self.servers.get(&param.to_id()))
+        {
+            return Some((server.owner.clone(), None));
+        }
+        let (source, _) = self.imports.get(ident.sym.as_ref())?;
+        is_local_specifier(source).then(|| (ident.sym.to_string(), Some(source.clone())))
+    }
+
+    /// `app.use([path,] ...routersOrMiddleware)`
+    fn mount_use(&mut self, call: &CallExpr, receiver: Receiver) {
+        let args = &call.args;
+        let Some(first) = args.first() else {
+            return;
+        };
+        let (paths, rest) = match literal_paths(&first.expr) {
+            Some(paths) => (paths, &args[1..]),
+            None => {
+                // `app.use(API_PREFIX, router)`: a computed mount path.
+                let computed = match unwrap_expr(&first.expr) {
+                    Expr::Ident(ident) => {
+                        !self.servers.contains_key(&ident.to_id())
+                            && !self.imports.contains_key(ident.sym.as_ref())
+                            && !self.functions.contains_key(ident.sym.as_ref())
+                    }
+                    Expr::Member(_) | Expr::Tpl(_) | Expr::Bin(_) => true,
+                    _ => false,
+                };
+                if computed && args.len() > 1 {
+                    return;
+                }
+                (vec!["/".to_string()], &args[..])
+            }
+        };
+
+        let mut children = Vec::new();
+        for arg in rest {
+            if arg.spread.is_some() {
+                return;
+            }
+            match unwrap_expr(&arg.expr) {
+                Expr::Ident(_) => children.extend(self.mount_child(&arg.expr)),
+                Expr::Call(inner) => {
+                    // Koa: router.use('/users', users.routes())
+                    if let Some((object, "routes")) = member_callee(inner) {
+                        if receiver.kind != ServerKind::Koa {
+                            return;
+                        }
+                        let Some(child) = self.mount_child(object) else {
+                            return;
+                        };
+                        children.push(child);
+                        self.own(inner.span);
+                    } else if callee_ident(inner) == Some("require") {
+                        return;
+                    } else {
+                        self.own_middleware(&arg.expr);
+                    }
+                }
+                Expr::Member(_) => return,
+                expr => self.own_middleware(expr),
+            }
+        }
+
+        let line_number = self.call_line(call);
+        for path in &paths {
+            for (child, import_source) in &children {
+                self.out.mounts.push(StaticMount {
+                    line_number,
+                    parent: receiver.owner.clone(),
+                    child: child.clone(),
+                    path: join_route_path(&receiver.prefix, path),
+                    import_source: import_source.clone(),
+                    pattern: ".use(".to_string(),
+                });
+            }
+        }
+        self.own(call.span);
+    }
+
+    /// `client.users[':id'].$get({ param })` through an `hc` client, or
+    /// `api.users({ id }).get()` / `api.users.post(body)` through a treaty.
+    /// A binding imported from a project module counts as an `hc` client
+    /// when called with a `$get`-style method below at least one property.
+    fn client_call(&self, call: &CallExpr) -> Option<StaticClientCall> {
+        let (object, method) = member_callee(call)?;
+        let (dollar, verb) = match method.strip_prefix('$') {
+            Some(verb) => (true, verb),
+            None => (false, method),
+        };
+        if !CLIENT_METHODS.contains(&verb) {
+            return None;
+        }
+        let mut segments = Vec::new();
+        let root = rpc_path(object, &mut segments)?;
+        let client = match self.clients.get(&root.to_id()) {
+            Some(client) => client.clone(),
+            None if dollar
+                && !segments.is_empty()
+                && self
+                    .imports
+                    .get(root.sym.as_ref())
+                    .is_some_and(|(source, _)| is_local_specifier(source)) =>
+            {
+                Client {
+                    kind: ClientKind::Hono,
+                    base: String::new(),
+                }
+            }
+            None => return None,
+        };
+        if client.kind == ClientKind::Hono && !dollar {
+            return None;
+        }
+        let path = segments
+            .iter()
+            .filter(|segment| *segment != "index")
+            .fold(String::new(), |path, segment| {
+                format!("{}/{}", path, segment)
+            });
+        let payload = match client.kind {
+            ClientKind::Hono => match call.args.first().map(|arg| unwrap_expr(&arg.expr)) {
+                Some(Expr::Object(options)) => object_prop(options, "json")
+                    .or_else(|| object_prop(options, "form"))
+                    .flatten(),
+                _ => None,
+            },
+            // A treaty GET's argument is its request options.
+            ClientKind::Eden if matches!(verb, "get" | "head") => None,
+            ClientKind::Eden => call.args.first().map(|arg| &*arg.expr),
+        }
+        .map(|payload| self.snippet(payload.span()));
+        let (call_text, line_number) = self.snippet(call.span);
+        Some(StaticClientCall {
+            line_number,
+            method: verb.to_uppercase(),
+            target: format!(
+                "{}{}",
+                client.base,
+                if path.is_empty() { "/" } else { &path }
+            ),
+            pattern: format!(".{}(", method),
+            span_start: call.span.lo.0,
+            span_end: call.span.hi.0,
+            call_text,
+            payload_text: payload.as_ref().map(|(text, _)| text.clone()),
+            payload_line: payload.as_ref().map(|(_, line)| *line),
+        })
+    }
+
+    /// Hono's `app.route('/users', users)`.
+    fn mount_route(&mut self, call: &CallExpr, receiver: Receiver) {
+        let [path, child] = &call.args[..] else {
+            return;
+        };
+        let (Some(path), Some((child, import_source))) =
+            (literal(&path.expr), self.mount_child(&child.expr))
+        else {
+            return;
+        };
+        self.out.mounts.push(StaticMount {
+            line_number: self.call_line(call),
+            parent: receiver.owner,
+            child,
+            path: join_route_path(&receiver.prefix, &path),
+            import_source,
+            pattern: ".route(".to_string(),
+        });
+        self.own(call.span);
+    }
+
+    /// `fastify.register(plugin, { prefix: '/v1' })`
+    fn mount_register(&mut self, call: &CallExpr, receiver: Receiver) {
+        let Some(plugin) = call.args.first() else {
+            return;
+        };
+        let prefix = match call.args.get(1).map(|arg| unwrap_expr(&arg.expr)) {
+            None => "/".to_string(),
+            Some(Expr::Object(options)) => match object_prop(options, "prefix") {
+                None => "/".to_string(),
+                Some(prefix) => match prefix.and_then(literal) {
+                    Some(prefix) => prefix,
+                    None => return,
+                },
+            },
+            Some(_) => return,
+        };
+        let child = match unwrap_expr(&plugin.expr) {
+            Expr::Ident(ident) => match self.mount_child(&plugin.expr) {
+                Some(child) => Some(child),
+                // A package plugin such as `@fastify/cors` adds no routes.
+                None if self
+                    .imports
+                    .get(ident.sym.as_ref())
+                    .is_some_and(|(source, _)| !is_local_specifier(source)) =>
+                {
+                    None
+                }
+                None => return,
+            },
+            expr => {
+                let Some(server) = inline_fn(expr)
+                    .and_then(|plugin| plugin.param_ident(0))
+                    .and_then(|param| self.servers.get(&param.to_id()))
+                else {
+                    return;
+                };
+                Some((server.owner.clone(), None))
+            }
+        };
+        if let Some((child, import_source)) = child {
+            self.out.mounts.push(StaticMount {
+                line_number: self.call_line(call),
+                parent: receiver.owner,
+                child,
+                path: prefix,
+                import_source,
+                pattern: ".register(".to_string(),
+            });
+        }
+        self.own(call.span);
+    }
+}
+
+impl Visit for StaticRouteExtractor<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Some(client_call) = self.client_call(call) {
+            self.own(call.span);
+            self.out.client_calls.push(client_call);
+        } else if let Some((object, prop)) = member_callee(call)
+            && let Some(receiver) = self.receiver(object)
+        {
+            let kind = receiver.kind;
+            if ROUTE_METHODS.contains(&prop) {
+                self.route(call, receiver, prop);
+            } else if prop == "route" && kind == ServerKind::Fastify {
+                self.fastify_route(call, receiver);
+            } else if prop == "route" && kind == ServerKind::Hono {
+                self.mount_route(call, receiver);
+            } else if prop == "on" && kind == ServerKind::Hono {
+                self.hono_on(call, receiver);
+            } else if prop == "use" && kind == ServerKind::Hono {
+                // Hono's `use` only adds middleware; sub-apps mount with
+                // `route`.
+                self.own(call.span);
+                for arg in &call.args {
+                    self.own_middleware(&arg.expr);
+                }
+            } else if prop == "use" && kind != ServerKind::Fastify {
+                self.mount_use(call, receiver);
+            } else if prop == "register" && kind == ServerKind::Fastify {
+                self.mount_register(call, receiver);
+            } else if matches!(prop, "group" | "guard")
+                && kind == ServerKind::Elysia
+                && call
+                    .args
+                    .last()
+                    .and_then(|callback| inline_fn(&callback.expr)?.param_ident(0))
+                    .is_some_and(|param| self.servers.contains_key(&param.to_id()))
+            {
+                self.own(call.span);
+            } else if SETUP_METHODS.contains(&prop) && receiver.route.is_none() {
+                self.own(call.span);
+                for arg in &call.args {
+                    if inline_fn(&arg.expr).is_some() {
+                        self.own_middleware(&arg.expr);
+                    }
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// ESM imports plus `const x = require('x')` and
+/// `const { Router } = require('express')`.
+struct ImportCollector<'m> {
+    imports: &'m mut HashMap<String, (String, String)>,
+}
+
+impl Visit for ImportCollector<'_> {
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        let source = import.src.value.to_string();
+        for specifier in &import.specifiers {
+            let (local, imported) = match specifier {
+                ImportSpecifier::Named(named) => (
+                    named.local.sym.to_string(),
+                    match &named.imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                        None => named.local.sym.to_string(),
+                    },
+                ),
+                ImportSpecifier::Default(default) => {
+                    (default.local.sym.to_string(), "default".to_string())
+                }
+                ImportSpecifier::Namespace(namespace) => {
+                    (namespace.local.sym.to_string(), "default".to_string())
+                }
+            };
+            self.imports.insert(local, (source.clone(), imported));
+        }
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let Some(Expr::Call(call)) = declarator.init.as_deref().map(unwrap_expr)
+            && callee_ident(call) == Some("require")
+            && let Some(source) = call.args.first().and_then(|arg| literal(&arg.expr))
+        {
+            match &declarator.name {
+                Pat::Ident(binding) => {
+                    self.imports
+                        .insert(binding.id.sym.to_string(), (source, "default".to_string()));
+                }
+                Pat::Object(object) => {
+                    for prop in &object.props {
+                        let (local, imported) = match prop {
+                            ObjectPatProp::Assign(assign) => {
+                                (assign.key.sym.to_string(), assign.key.sym.to_string())
+                            }
+                            ObjectPatProp::KeyValue(KeyValuePatProp {
+                                key: PropName::Ident(key),
+                                value,
+                            }) => match pat_ident(value) {
+                                Some(local) => (local.sym.to_string(), key.sym.to_string()),
+                                None => continue,
+                            },
+                            _ => continue,
+                        };
+                        self.imports.insert(local, (source.clone(), imported));
+                    }
+                }
+                _ => {}
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Every `const x = <server>` binding, at any depth, including the
+/// `const app = new Elysia().get(…)` builder-chain form.
+struct ServerCollector<'e, 'a> {
+    extractor: &'e mut StaticRouteExtractor<'a>,
+}
+
+impl Visit for ServerCollector<'_, '_> {
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref())
+            && let Some((root, kind, prefix)) = self.extractor.chain_root(init)
+        {
+            let server = Server {
+                kind,
+                owner: binding.id.sym.to_string(),
+                prefix,
+            };
+            let span = root.span();
+            self.extractor
+                .roots
+                .insert((span.lo.0, span.hi.0), server.clone());
+            self.extractor.servers.insert(binding.id.to_id(), server);
+        }
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Every `const client = hc<AppType>(base)` / `treaty<App>(base)` binding.
+struct ClientCollector<'e, 'a> {
+    extractor: &'e mut StaticRouteExtractor<'a>,
+}
+
+impl Visit for ClientCollector<'_, '_> {
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref())
+            && let Some((kind, call)) = self.extractor.client_created_by(init)
+        {
+            let base = call
+                .args
+                .first()
+                .map(|base| self.extractor.client_base(kind, &base.expr))
+                .unwrap_or_default();
+            self.extractor
+                .clients
+                .insert(binding.id.to_id(), Client { kind, base });
+            self.extractor.own(call.span);
+        }
+        declarator.visit_children_with(self);
+    }
+}
+
+/// Functions passed to a Fastify server's `register`, and Elysia `group` /
+/// `guard` callbacks.
+struct PluginCollector<'e, 'a> {
+    extractor: &'e mut StaticRouteExtractor<'a>,
+}
+
+impl Visit for PluginCollector<'_, '_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        // app.group('/users', (app) => app.get(…)), app.guard(hook, (app) => …)
+        if let Some((object, method @ ("group" | "guard"))) = member_callee(call)
+            && let Some(receiver) = self.extractor.receiver(object)
+            && receiver.kind == ServerKind::Elysia
+            && let Some(param) = call
+                .args
+                .last()
+                .and_then(|callback| inline_fn(&callback.expr)?.param_ident(0))
+        {
+            let prefix = match method {
+                "group" => call.args.first().and_then(|arg| literal(&arg.expr)),
+                _ => Some(String::new()),
+            };
+            if let Some(prefix) = prefix {
+                self.extractor
+                    .servers
+                    .entry(param.to_id())
+                    .or_insert(Server {
+                        kind: ServerKind::Elysia,
+                        owner: receiver.owner,
+                        prefix: join_route_path(&receiver.prefix, &prefix),
+                    });
+            }
+        } else if let Some((object, "register")) = member_callee(call)
+            && let Some(receiver) = self.extractor.receiver(object)
+            && receiver.kind == ServerKind::Fastify
+            && let Some(plugin) = call.args.first()
+        {
+            match unwrap_expr(&plugin.expr) {
+                Expr::Ident(ident) => {
+                    if let Some(function) = self.extractor.functions.get(ident.sym.as_ref()) {
+                        let function = *function;
+                        self.extractor
+                            .fastify_plugin(function, Some(ident.sym.to_string()), true);
+                    }
+                }
+                expr => {
+                    if let Some(param) = inline_fn(expr).and_then(|f| f.param_ident(0)) {
+                        let owner = format!(
+                            "{}_plugin_L{}",
+                            receiver.owner,
+                            self.extractor.line(call.span)
+                        );
+                        self.extractor
+                            .servers
+                            .entry(param.to_id())
+                            .or_insert(Server {
+                                kind: ServerKind::Fastify,
+                                owner,
+                                prefix: String::new(),
+                            });
+                    }
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// Walks a handler body for its response writes and request body reads.
+struct HandlerScan<'e, 'a> {
+    extractor: &'e StaticRouteExtractor<'a>,
+    kind: ServerKind,
+    request: Option<Id>,
+    response: Option<Id>,
+    /// Nesting depth of functions inside the handler.
+    depth: usize,
+    sends: Vec<(String, u32)>,
+    returns: Vec<(String, u32)>,
+    request_body: Option<(String, u32)>,
+    /// Bindings holding the query object itself (Elysia's `{ query }`).
+    query_objects: Vec<Id>,
+    query_keys: BTreeSet<String>,
+    /// The query object or the request flows somewhere the scan can't follow.
+    query_opaque: bool,
+    /// Bindings holding the headers object (Elysia's `{ headers }`,
+    /// `const headers = req.headers`).
+    header_objects: Vec<Id>,
+    header_reads: BTreeSet<String>,
+    /// Depth of `||` / `??` left operands and ternary tests: a header read
+    /// there has a fallback, so the handler copes without it.
+    fallback: usize,
+    param_calls: Vec<(u32, u32)>,
+    escaped: bool,
+}
+
+impl HandlerScan<'_, '_> {
+    fn is_param(&self, ident: &Ident) -> bool {
+        let id = ident.to_id();
+        self.request.as_ref() == Some(&id) || self.response.as_ref() == Some(&id)
+    }
+
+    fn rooted_at_response(&self, expr: &Expr) -> bool {
+        root_ident(expr).is_some_and(|root| self.response.as_ref() == Some(&root.to_id()))
+    }
+
+    /// `res.status(201).json(x)` → `Some(x)`; `res.status(404).json(x)`,
+    /// Hono's `c.json(x, 404)` and non-send calls → `None`.
+    fn success_payload<'c>(&self, call: &'c CallExpr) -> Option<&'c Expr> {
+        let (_, method) = member_callee(call)?;
+        if !SEND_METHODS.contains(&method) {
+            return None;
+        }
+        let mut receiver = member_callee(call)?.0;
+        while let Expr::Call(inner) = unwrap_expr(receiver) {
+            let (object, prop) = member_callee(inner)?;
+            if matches!(prop, "status" | "code")
+                && let Some(Expr::Lit(Lit::Num(code))) =
+                    inner.args.first().map(|arg| unwrap_expr(&arg.expr))
+                && code.value >= 400.0
+            {
+                return None;
+            }
+            receiver = object;
+        }
+        if let Some(Expr::Lit(Lit::Num(code))) = call.args.get(1).map(|arg| unwrap_expr(&arg.expr))
+            && code.value >= 400.0
+        {
+            return None;
+        }
+        Some(&call.args.first()?.expr)
+    }
+
+    /// `req.query`, `request.query`, `ctx.query`, `ctx.request.query`, or a
+    /// binding of Elysia's `query`.
+    fn is_query_object(&self, expr: &Expr) -> bool {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) => self.query_objects.contains(&ident.to_id()),
+            Expr::Member(member) => self.is_request_query(member),
+            _ => false,
+        }
+    }
+
+    fn is_request_query(&self, member: &MemberExpr) -> bool {
+        self.is_request_prop(member, &["query"])
+    }
+
+    /// `req.<name>`, or `ctx.request.<name>` for one of `names`.
+    fn is_request_prop(&self, member: &MemberExpr, names: &[&str]) -> bool {
+        let is_request =
+            |expr: &Expr| unwrap_ident(expr).is_some_and(|obj| self.request == Some(obj.to_id()));
+        matches!(&member.prop, MemberProp::Ident(prop) if names.contains(&prop.sym.as_ref()))
+            && match unwrap_expr(&member.obj) {
+                Expr::Member(inner) => {
+                    matches!(&inner.prop, MemberProp::Ident(prop) if prop.sym == *"request")
+                        && is_request(&inner.obj)
+                }
+                obj => is_request(obj),
+            }
+    }
+
+    /// Record `query.page` / `query['page']`; a computed key hides which
+    /// one is read.
+    fn read_query_key(&mut self, prop: &MemberProp) {
+        match prop {
+            MemberProp::Ident(prop) => {
+                self.query_keys.insert(prop.sym.to_string());
+            }
+            MemberProp::Computed(computed) => match literal(&computed.expr) {
+                Some(key) => {
+                    self.query_keys.insert(key);
+                }
+                None => self.query_opaque = true,
+            },
+            MemberProp::PrivateName(_) => self.query_opaque = true,
+        }
+    }
+
+    /// Hono's `c.req.query('page')` and `c.req.queries('tag')`. Reading the
+    /// whole query (`c.req.query()`) or a validated one
+    /// (`c.req.valid('query')`) hides which keys are used.
+    fn read_hono_query(&mut self, call: &CallExpr) {
+        let Some((object, method)) = member_callee(call) else {
+            return;
+        };
+        let Expr::Member(req) = unwrap_expr(object) else {
+            return;
+        };
+        if !(matches!(&req.prop, MemberProp::Ident(prop) if prop.sym == *"req")
+            && unwrap_ident(&req.obj).is_some_and(|obj| self.request == Some(obj.to_id())))
+        {
+            return;
+        }
+        let key = call.args.first().and_then(|arg| literal(&arg.expr));
+        match (method, key) {
+            ("query" | "queries", Some(key)) => {
+                self.query_keys.insert(key);
+            }
+            ("query" | "queries", None) => self.query_opaque = true,
+            ("valid", Some(target)) if target == "query" => self.query_opaque = true,
+            _ => {}
+        }
+    }
+
+    /// `req.headers`, `ctx.headers` / `ctx.header`, `ctx.request.headers`, or
+    /// a binding of one.
+    fn is_header_object(&self, expr: &Expr) -> bool {
+        match unwrap_expr(expr) {
+            Expr::Ident(ident) => self.header_objects.contains(&ident.to_id()),
+            Expr::Member(member) => self.is_request_prop(member, &["headers", "header"]),
+            _ => false,
+        }
+    }
+
+    fn read_header(&mut self, name: &str) {
+        if self.fallback == 0 {
+            self.header_reads.insert(name.to_lowercase());
+        }
+    }
+
+    /// `req.get('x-tenant-id')` / `req.header(...)` (Express), `ctx.get(...)`
+    /// (Koa), Hono's `c.req.header(...)`, and `get` on a Web `Headers`.
+    fn read_header_call(&mut self, call: &CallExpr) {
+        let Some((object, method)) = member_callee(call) else {
+            return;
+        };
+        let is_request =
+            |expr: &Expr| unwrap_ident(expr).is_some_and(|obj| self.request == Some(obj.to_id()));
+        let reads = match method {
+            // Hono's and Elysia's `c.get` reads a context variable instead.
+            "get" | "header"
+                if is_request(object)
+                    && !matches!(self.kind, ServerKind::Hono | ServerKind::Elysia) =>
+            {
+                true
+            }
+            "header" => matches!(unwrap_expr(object), Expr::Member(req)
+                if matches!(&req.prop, MemberProp::Ident(prop) if prop.sym == *"req")
+                    && is_request(&req.obj)),
+            "get" => self.is_header_object(object),
+            _ => false,
+        };
+        if reads && let Some(name) = call.args.first().and_then(|arg| literal(&arg.expr)) {
+            self.read_header(&name);
+        }
+    }
+
+    /// Hono's `c.req.json()`, `c.req.parseBody()` and
+    /// `c.req.valid('json' | 'form')`.
+    fn reads_hono_body(&self, call: &CallExpr) -> bool {
+        let Some((object, method)) = member_callee(call) else {
+            return false;
+        };
+        let Expr::Member(req) = unwrap_expr(object) else {
+            return false;
+        };
+        let from_request = matches!(&req.prop, MemberProp::Ident(prop) if prop.sym == *"req")
+            && unwrap_ident(&req.obj).is_some_and(|obj| self.request == Some(obj.to_id()));
+        from_request
+            && match method {
+                "json" | "parseBody" => true,
+                "valid" => call
+                    .args
+                    .first()
+                    .and_then(|arg| literal(&arg.expr))
+                    .is_some_and(|target| matches!(target.as_str(), "json" | "form")),
+                _ => false,
+            }
+    }
+}
+
+impl Visit for HandlerScan<'_, '_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && root_ident(callee).is_some_and(|root| self.is_param(root))
+        {
+            self.param_calls.push((call.span.lo.0, call.span.hi.0));
+            self.read_hono_query(call);
+            self.read_header_call(call);
+            if self.reads_hono_body(call) {
+                if self.request_body.is_none() {
+                    self.request_body = Some(self.extractor.snippet(call.span));
+                }
+            } else if self.rooted_at_response(callee)
+                && let Some(payload) = self.success_payload(call)
+            {
+                self.sends.push(self.extractor.snippet(payload.span()));
+            }
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        // Koa: ctx.body = payload
+        if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left
+            && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"body")
+            && unwrap_ident(&member.obj).is_some_and(|obj| self.response == Some(obj.to_id()))
+        {
+            self.sends.push(self.extractor.snippet(assign.right.span()));
+        }
+        assign.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        // req.query.page, ctx.request.query['page']
+        if self.is_query_object(&member.obj) {
+            self.read_query_key(&member.prop);
+            member.prop.visit_with(self);
+            return;
+        }
+        // req.headers['x-tenant-id'], ctx.headers.authorization
+        if self.is_header_object(&member.obj) {
+            let key = match &member.prop {
+                MemberProp::Ident(prop) => Some(prop.sym.to_string()),
+                MemberProp::Computed(computed) => literal(&computed.expr),
+                MemberProp::PrivateName(_) => None,
+            };
+            if let Some(key) = key.filter(|key| !matches!(key.as_str(), "get" | "has")) {
+                self.read_header(&key);
+            }
+            member.prop.visit_with(self);
+            return;
+        }
+        // The query object itself, read other than key by key.
+        if self.is_request_query(member) {
+            self.query_opaque = true;
+        }
+        // req.body, request.body, ctx.request.body
+        if self.request_body.is_none()
+            && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"body")
+        {
+            let reads_request = match unwrap_expr(&member.obj) {
+                Expr::Ident(obj) => {
+                    self.request == Some(obj.to_id()) && self.response != self.request
+                }
+                Expr::Member(inner) => {
+                    matches!(&inner.prop, MemberProp::Ident(prop) if prop.sym == *"request")
+                        && unwrap_ident(&inner.obj)
+                            .is_some_and(|obj| self.request == Some(obj.to_id()))
+                }
+                _ => false,
+            };
+            if reads_request {
+                self.request_body = Some(self.extractor.snippet(member.span));
+            }
+        }
+        // Property access on a parameter is not an escape; only the
+        // parameter itself flowing somewhere else is.
+        if !matches!(&*member.obj, Expr::Ident(obj) if self.is_param(obj)) {
+            member.obj.visit_with(self);
+        }
+        member.prop.visit_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        let id = ident.to_id();
+        if self.response.as_ref() == Some(&id) {
+            self.escaped = true;
+        }
+        if self.request.as_ref() == Some(&id) || self.query_objects.contains(&id) {
+            self.query_opaque = true;
+        }
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        // const { page, limit = 20 } = req.query
+        if let Some(init) = &declarator.init
+            && self.is_query_object(init)
+        {
+            match &declarator.name {
+                Pat::Object(pattern) => match object_pattern_keys(pattern) {
+                    Some(keys) => self.query_keys.extend(keys),
+                    None => self.query_opaque = true,
+                },
+                _ => self.query_opaque = true,
+            }
+            return;
+        }
+        // const { authorization } = req.headers; const headers = req.headers
+        if let Some(init) = &declarator.init
+            && self.is_header_object(init)
+        {
+            match &declarator.name {
+                Pat::Object(pattern) => {
+                    for key in required_pattern_keys(pattern) {
+                        self.read_header(&key);
+                    }
+                }
+                name => self
+                    .header_objects
+                    .extend(pat_ident(name).map(Ident::to_id)),
+            }
+            return;
+        }
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_bin_expr(&mut self, bin: &BinExpr) {
+        if matches!(bin.op, BinaryOp::LogicalOr | BinaryOp::NullishCoalescing) {
+            self.fallback += 1;
+            bin.left.visit_with(self);
+            self.fallback -= 1;
+            bin.right.visit_with(self);
+        } else {
+            bin.visit_children_with(self);
+        }
+    }
+
+    fn visit_cond_expr(&mut self, cond: &CondExpr) {
+        self.fallback += 1;
+        cond.test.visit_with(self);
+        self.fallback -= 1;
+        cond.cons.visit_with(self);
+        cond.alt.visit_with(self);
+    }
+
+    fn visit_return_stmt(&mut self, ret: &ReturnStmt) {
+        if self.depth == 0
+            && let Some(arg) = &ret.arg
+            && !self.rooted_at_response(arg)
+        {
+            self.returns.push(self.extractor.snippet(arg.span()));
+        }
+        ret.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.depth += 1;
+        function.visit_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.depth += 1;
+        arrow.visit_children_with(self);
+        self.depth -= 1;
+    }
+}
+
+/// Spans of calls made on any of `params`.
+struct ParamCalls {
+    params: Vec<Id>,
+    spans: Vec<(u32, u32)>,
+}
+
+impl Visit for ParamCalls {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee
+            && root_ident(callee).is_some_and(|root| self.params.contains(&root.to_id()))
+        {
+            self.spans.push((call.span.lo.0, call.span.hi.0));
+        }
+        call.visit_children_with(self);
+    }
+}
+
+fn unwrap_ident(expr: &Expr) -> Option<&Ident> {
+    match unwrap_expr(expr) {
+        Expr::Ident(ident) => Some(ident),
+        _ => None,
+    }
+}
+
+fn pat_ident(pat: &Pat) -> Option<&Ident> {
+    match pat {
+        Pat::Ident(binding) => Some(&binding.id),
+        Pat::Assign(AssignPat { left, .. }) => pat_ident(left),
+        _ => None,
+    }
+}
+
+/// The identifier at the bottom of a `a.b(c).d` chain.
+fn root_ident(expr: &Expr) -> Option<&Ident> {
+    match unwrap_expr(expr) {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Member(member) => root_ident(&member.obj),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => match unwrap_expr(callee) {
+            Expr::Member(member) => root_ident(&member.obj),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The root binding of an RPC client property chain, pushing its path
+/// segments: `client.users[':id']` → `["users", ":id"]`. Eden treaty's
+/// `api.users({ id })` call fills a path parameter named by its key.
+fn rpc_path<'e>(expr: &'e Expr, segments: &mut Vec<String>) -> Option<&'e Ident> {
+    match unwrap_expr(expr) {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Member(member) => {
+            let root = rpc_path(&member.obj, segments)?;
+            segments.push(match &member.prop {
+                MemberProp::Ident(prop) => prop.sym.to_string(),
+                MemberProp::Computed(computed) => literal(&computed.expr)?,
+                MemberProp::PrivateName(_) => return None,
+            });
+            Some(root)
+        }
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => {
+            let root = rpc_path(callee, segments)?;
+            let name = match args.first().map(|arg| unwrap_expr(&arg.expr)) {
+                Some(Expr::Object(params)) => params.props.first().and_then(|prop| match prop {
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                        Prop::KeyValue(kv) => prop_name(&kv.key).map(str::to_string),
+                        _ => None,
+                    },
+                    PropOrSpread::Spread(_) => None,
+                }),
+                _ => None,
+            };
+            segments.push(format!(":{}", name.as_deref().unwrap_or("param")));
+            Some(root)
+        }
+        _ => None,
+    }
+}
+
+fn callee_ident(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(callee) => unwrap_ident(callee).map(|ident| ident.sym.as_ref()),
+        _ => None,
+    }
+}
+
+/// `(object, "method")` for an `object.method(…)` call.
+fn member_callee(call: &CallExpr) -> Option<(&Expr, &str)> {
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let Expr::Member(member) = unwrap_expr(callee) else {
+        return None;
+    };
+    match &member.prop {
+        MemberProp::Ident(prop) => Some((&member.obj, prop.sym.as_ref())),
+        _ => None,
+    }
+}
+
+fn inline_fn(expr: &Expr) -> Option<HandlerFn<'_>> {
+    match unwrap_expr(expr) {
+        Expr::Fn(f) => Some(HandlerFn::Function(&f.function)),
+        Expr::Arrow(arrow) => Some(HandlerFn::Arrow(arrow)),
+        _ => None,
+    }
+}
+
+/// `None` when `key` is absent; `Some(None)` when the object may set it
+/// but not as a plain `key: value` (shorthand, method, spread).
+fn object_prop<'o>(object: &'o ObjectLit, key: &str) -> Option<Option<&'o Expr>> {
+    let mut found = None;
+    for prop in &object.props {
+        let PropOrSpread::Prop(prop) = prop else {
+            found = Some(None);
+            continue;
+        };
+        match &**prop {
+            Prop::KeyValue(kv) if prop_name(&kv.key) == Some(key) => {
+                found = Some(Some(&*kv.value));
+            }
+            Prop::Shorthand(ident) if ident.sym == *key => found = Some(None),
+            Prop::Method(method) if prop_name(&method.key) == Some(key) => found = Some(None),
+            _ => {}
+        }
+    }
+    found
+}
+
+fn prop_name(name: &PropName) -> Option<&str> {
+    match name {
+        PropName::Ident(ident) => Some(ident.sym.as_ref()),
+        PropName::Str(str) => Some(str.value.as_ref()),
+        _ => None,
+    }
+}
+
+/// A string literal or a template literal without substitutions.
+fn literal(expr: &Expr) -> Option<String> {
+    match unwrap_expr(expr) {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+            .quasis
+            .first()
+            .and_then(|quasi| quasi.cooked.as_ref())
+            .map(|cooked| cooked.to_string()),
+        _ => None,
+    }
+}
+
+/// A literal path, or an array of them.
+fn literal_paths(expr: &Expr) -> Option<Vec<String>> {
+    match unwrap_expr(expr) {
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .map(|elem| literal(&elem.as_ref()?.expr))
+            .collect(),
+        expr => literal(expr).map(|path| vec![path]),
+    }
+}
+
+fn type_ref_name(ty: &TsType) -> Option<&str> {
+    match ty {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(ident),
+            ..
+        }) => Some(ident.sym.as_ref()),
+        _ => None,
+    }
+}
+
+/// Specifiers that point into the project rather than at a package.
+fn is_local_specifier(source: &str) -> bool {
+    source.starts_with('.')
+        || source.starts_with('/')
+        || source.starts_with("@/")
+        || source.starts_with("~/")
+}
+
+/// The lowest 2xx entry of a Fastify `response` schema map (`200`, `'201'`,
+/// `'2xx'`).
+fn success_schema(by_status: &ObjectLit) -> Option<&Expr> {
+    by_status
+        .props
+        .iter()
+        .filter_map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(kv) => {
+                    let status = match &kv.key {
+                        PropName::Num(num) => num.value.to_string(),
+                        key => prop_name(key)?.to_string(),
+                    };
+                    let rank = match status.as_str() {
+                        "2xx" | "2XX" => 300,
+                        status => status
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|s| (200..300).contains(s))?,
+                    };
+                    Some((rank, &*kv.value))
+                }
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        })
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, schema)| schema)
+}
+
+/// The `key: value` fields of an object literal, or `None` when a spread,
+/// shorthand or computed key leaves the key set open.
+fn object_fields(object: &ObjectLit) -> Option<Vec<(String, &Expr)>> {
+    object
+        .props
+        .iter()
+        .map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(kv) => Some((prop_name(&kv.key)?.to_string(), &*kv.value)),
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        })
+        .collect()
+}
+
+/// The keys an object pattern destructures, or `None` when a rest element
+/// or computed key leaves them open.
+fn object_pattern_keys(pattern: &ObjectPat) -> Option<Vec<String>> {
+    pattern
+        .props
+        .iter()
+        .map(|prop| match prop {
+            ObjectPatProp::KeyValue(kv) => prop_name(&kv.key).map(str::to_string),
+            ObjectPatProp::Assign(assign) => Some(assign.key.sym.to_string()),
+            ObjectPatProp::Rest(_) => None,
+        })
+        .collect()
+}
+
+/// The keys of a destructuring pattern that take no default, lowercased.
+fn required_pattern_keys(pattern: &ObjectPat) -> Vec<String> {
+    pattern
+        .props
+        .iter()
+        .filter_map(|prop| match prop {
+            ObjectPatProp::KeyValue(kv) if !matches!(&*kv.value, Pat::Assign(_)) => {
+                prop_name(&kv.key).map(str::to_lowercase)
+            }
+            ObjectPatProp::Assign(assign) if assign.value.is_none() => {
+                Some(assign.key.sym.to_lowercase())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// A schema object member's name and value. `None` for a spread, a
+/// shorthand, a computed key or a method: members that can't be read.
+fn schema_member(prop: &PropOrSpread) -> Option<(String, &Expr)> {
+    match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) => Some((prop_name(&kv.key)?.to_string(), &*kv.value)),
+            _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+    }
+}
+
+fn is_typebox_optional(expr: &Expr) -> bool {
+    matches!(
+        unwrap_expr(expr),
+        Expr::Call(call) if member_callee(call)
+            .is_some_and(|(_, builder)| matches!(builder, "Optional" | "ReadonlyOptional"))
+    )
+}
+
+/// The TypeScript literal type of a JSON value.
+fn literal_type(expr: &Expr) -> Option<String> {
+    match unwrap_expr(expr) {
+        Expr::Lit(Lit::Str(str)) => serde_json::to_string(str.value.as_ref()).ok(),
+        Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+        Expr::Lit(Lit::Bool(b)) => Some(b.value.to_string()),
+        Expr::Lit(Lit::Null(_)) => Some("null".to_string()),
+        _ => None,
+    }
+}
+
+fn union(types: &[String]) -> String {
+    let mut seen = Vec::new();
+    for ty in types {
+        if !seen.contains(ty) {
+            seen.push(ty.clone());
+        }
+    }
+    match seen.len() {
+        0 => "never".to_string(),
+        1 => seen.remove(0),
+        _ if seen.iter().any(|ty| ty == "unknown") => "unknown".to_string(),
+        _ => seen.join(" | "),
+    }
+}
+
+fn intersection(types: &[String]) -> String {
+    match types {
+        [] => "unknown".to_string(),
+        [ty] => ty.clone(),
+        types => types
+            .iter()
+            .map(|ty| {
+                if ty.contains(" | ") {
+                    format!("({})", ty)
+                } else {
+                    ty.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" & "),
+    }
+}
+
+fn array_of(element: &str) -> String {
+    if element.contains(' ') && !element.starts_with('{') {
+        format!("Array<{}>", element)
+    } else {
+        format!("{}[]", element)
+    }
+}
+
+fn object_type(fields: &[(String, bool, String)]) -> String {
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(name, optional, ty)| {
+            let is_ident = name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+            let key = if is_ident {
+                name.clone()
+            } else {
+                serde_json::to_string(name).unwrap_or_else(|_| name.clone())
+            };
+            format!("{}{}: {}", key, if *optional { "?" } else { "" }, ty)
+        })
+        .collect();
+    format!("{{ {} }}", fields.join("; "))
+}
+
+/// The `prefix` option of `new Router({ prefix })` or
+/// `new Elysia({ prefix })`: empty when absent, `None` when it isn't a
+/// literal.
+fn prefix_option(new: &NewExpr) -> Option<String> {
+    match new.args.as_ref().and_then(|args| args.first()) {
+        None => Some(String::new()),
+        Some(options) => match unwrap_expr(&options.expr) {
+            Expr::Object(options) => match object_prop(options, "prefix") {
+                None => Some(String::new()),
+                Some(prefix) => literal(prefix?),
+            },
+            _ => None,
+        },
+    }
+}
+
+fn join_route_path(prefix: &str, path: &str) -> String {
+    let prefix = prefix.trim_end_matches('/');
+    if prefix.is_empty() {
+        return path.to_string();
+    }
+    match path {
+        "" | "/" => prefix.to_string(),
+        path if path.starts_with('/') => format!("{}{}", prefix, path),
+        path => format!("{}/{}", prefix, path),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1320,6 +3842,681 @@ mod tests {
         assert_eq!(json["arguments"][0]["type_string"], "string");
     }
 
+    fn static_routes(source: &str) -> StaticRoutes {
+        let (cm, module) = parse_ts(source);
+        StaticRouteExtractor::extract(&module, &cm)
+    }
+
+    #[test]
+    fn static_routes_resolve_literal_express_routes_and_mounts() {
+        let routes = static_routes(
+            r#"
+import express, { Router } from 'express';
+import users from './routes/users';
+
+const app = express();
+const router = Router();
+
+function createOrder(req, res) {
+  const order = save(req.body);
+  res.status(201).json(order);
+}
+
+router.get('/orders/:id', async (req, res) => {
+  const order = await load(req.params.id);
+  if (!order) return res.status(404).json({ error: 'missing' });
+  res.json(order);
+});
+router.post('/orders', createOrder);
+router.route('/health').get((req, res) => res.sendStatus(204));
+
+app.use(express.json());
+app.use('/api', router);
+app.use('/users', users);
+app.listen(3000);
+"#,
+        );
+
+        let summary: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.owner.as_str(),
+                    r.method.as_str(),
+                    r.path.as_str(),
+                    r.emission,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("router", "GET", "/orders/:id", StaticEmission::Send),
+                ("router", "POST", "/orders", StaticEmission::Send),
+                ("router", "GET", "/health", StaticEmission::None),
+            ]
+        );
+        let get = &routes.routes[0];
+        assert_eq!(get.response_text.as_deref(), Some("order"));
+        assert_eq!(get.handler_name, "anonymous");
+        let post = &routes.routes[1];
+        assert_eq!(post.handler_name, "createOrder");
+        assert_eq!(post.request_text.as_deref(), Some("req.body"));
+
+        let mounts: Vec<_> = routes
+            .mounts
+            .iter()
+            .map(|m| (m.parent.as_str(), m.child.as_str(), m.path.as_str()))
+            .collect();
+        assert_eq!(
+            mounts,
+            vec![("app", "router", "/api"), ("app", "users", "/users")]
+        );
+        assert_eq!(
+            routes.mounts[1].import_source.as_deref(),
+            Some("./routes/users")
+        );
+        // Registrations, chain links, `res` calls, `express.json()`, listen.
+        assert!(routes.owned_spans.len() >= 12);
+    }
+
+    #[test]
+    fn static_routes_leave_unresolvable_registrations_to_the_analyzer() {
+        let routes = static_routes(
+            r#"
+import express from 'express';
+import { controller } from './controller';
+
+const router = express.Router();
+const PREFIX = process.env.PREFIX;
+
+router.get('/a', controller.list);
+router.get(`/b/${PREFIX}`, (req, res) => res.json([]));
+router.get('/c', (req, res) => respond(res, []));
+router.get('/d', ({ params }, res) => res.json(params));
+router.use(PREFIX, router);
+"#,
+        );
+        assert!(routes.routes.is_empty());
+        assert!(routes.mounts.is_empty());
+        assert!(routes.owned_spans.is_empty());
+    }
+
+    #[test]
+    fn static_routes_resolve_koa_router_prefixes() {
+        let routes = static_routes(
+            r#"
+import Koa from 'koa';
+import Router from '@koa/router';
+
+const app = new Koa();
+const router = new Router({ prefix: '/users' });
+
+router.get('user', '/:id', async (ctx) => {
+  ctx.body = await findUser(ctx.params.id);
+});
+router.post('/', async (ctx) => {
+  ctx.body = await createUser(ctx.request.body);
+});
+
+app.use(router.routes()).use(router.allowedMethods());
+"#,
+        );
+        let summary: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.method.as_str(),
+                    r.path.as_str(),
+                    r.response_text.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("GET", "/users/:id", Some("await findUser(ctx.params.id)")),
+                ("POST", "/users", Some("await createUser(ctx.request.body)")),
+            ]
+        );
+        assert_eq!(
+            routes.routes[1].request_text.as_deref(),
+            Some("ctx.request.body")
+        );
+        assert_eq!(routes.mounts.len(), 1);
+        assert_eq!(routes.mounts[0].parent, "app");
+        assert_eq!(routes.mounts[0].child, "router");
+        assert_eq!(routes.mounts[0].path, "/");
+    }
+
+    #[test]
+    fn static_routes_resolve_fastify_plugins() {
+        let routes = static_routes(
+            r#"
+import Fastify, { FastifyInstance } from 'fastify';
+import cors from '@fastify/cors';
+
+async function userRoutes(fastify: FastifyInstance) {
+  fastify.get('/users/:id', async (request) => {
+    return findUser(request.params.id);
+  });
+  fastify.route({
+    method: 'POST',
+    url: '/users',
+    handler: async (request, reply) => {
+      reply.code(201).send(await createUser(request.body));
+    },
+  });
+}
+
+const app = Fastify();
+app.register(cors);
+app.register(userRoutes, { prefix: '/v1' });
+app.register(async (instance) => {
+  instance.get('/ping', async () => ({ ok: true }));
+});
+"#,
+        );
+        let summary: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.owner.as_str(),
+                    r.method.as_str(),
+                    r.path.as_str(),
+                    r.emission,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("userRoutes", "GET", "/users/:id", StaticEmission::Return),
+                ("userRoutes", "POST", "/users", StaticEmission::Send),
+                ("app_plugin_L21", "GET", "/ping", StaticEmission::Return),
+            ]
+        );
+        assert_eq!(
+            routes.routes[0].response_text.as_deref(),
+            Some("findUser(request.params.id)")
+        );
+        let mounts: Vec<_> = routes
+            .mounts
+            .iter()
+            .map(|m| (m.child.as_str(), m.path.as_str()))
+            .collect();
+        assert_eq!(mounts, vec![("userRoutes", "/v1"), ("app_plugin_L21", "/")]);
+    }
+
+    #[test]
+    fn static_routes_read_fastify_route_schemas() {
+        let routes = static_routes(
+            r#"
+import Fastify from 'fastify';
+import { Type } from '@sinclair/typebox';
+
+const app = Fastify();
+
+const User = Type.Object({
+  id: Type.Integer(),
+  name: Type.String(),
+  role: Type.Optional(Type.Union([Type.Literal('admin'), Type.Literal('member')])),
+});
+
+const createUserBody = {
+  type: 'object',
+  required: ['name'],
+  properties: {
+    name: { type: 'string' },
+    tags: { type: 'array', items: { type: 'string' } },
+    'e-mail': { type: ['string', 'null'] },
+  },
+} as const;
+
+app.post('/users', { schema: { body: createUserBody, response: { 201: User, 400: { type: 'object' } } } }, async (request, reply) => {
+  reply.code(201).send(await createUser(request.body));
+});
+
+app.route({
+  method: ['GET', 'HEAD'],
+  url: '/users',
+  schema: { response: { '2xx': Type.Array(User) } },
+  handler: listUsers.handle,
+});
+"#,
+        );
+
+        let user = r#"{ id: number; name: string; role?: "admin" | "member" }"#;
+        assert_eq!(
+            routes.schemas,
+            vec![
+                RouteSchema {
+                    line_number: 23,
+                    method: "POST".to_string(),
+                    request: Some(
+                        r#"{ name: string; tags?: string[]; "e-mail"?: string | null }"#
+                            .to_string()
+                    ),
+                    response: Some(user.to_string()),
+                    query: None,
+                },
+                RouteSchema {
+                    line_number: 27,
+                    method: "GET".to_string(),
+                    request: None,
+                    response: Some(format!("{}[]", user)),
+                    query: None,
+                },
+                RouteSchema {
+                    line_number: 27,
+                    method: "HEAD".to_string(),
+                    request: None,
+                    response: Some(format!("{}[]", user)),
+                    query: None,
+                },
+            ]
+        );
+        // The member-expression handler keeps `app.route` for the analyzer;
+        // its schema is recorded all the same.
+        assert_eq!(routes.routes.len(), 1);
+    }
+
+    #[test]
+    fn schema_objects_with_unreadable_members_are_unknown() {
+        let routes = static_routes(
+            r#"
+import Fastify from 'fastify';
+import { Type } from '@sinclair/typebox';
+
+const app = Fastify();
+const base = { id: { type: 'integer' } };
+
+app.post('/a', { schema: { body: { type: 'object', properties: { ...base, name: { type: 'string' } } } } }, async (request) => request.body);
+app.post('/b', { schema: { body: Type.Object({ ...Base.properties, name: Type.String() }) } }, async (request) => request.body);
+app.post('/c', { schema: { body: { type: 'object', required: ['user'], properties: { user: { $ref: 'user#' }, meta: { type: 'object', properties: { [key]: { type: 'string' } } } } } } }, async (request) => request.body);
+"#,
+        );
+
+        // The spreads leave `/a` and `/b` with no readable schema at all.
+        let requests: Vec<(u32, Option<&str>)> = routes
+            .schemas
+            .iter()
+            .map(|schema| (schema.line_number, schema.request.as_deref()))
+            .collect();
+        assert_eq!(
+            requests,
+            // `addSchema` ids aren't resolved; the computed key makes `meta`
+            // unknown rather than `{}`.
+            vec![(10, Some("{ user: unknown; meta?: unknown }"))]
+        );
+    }
+
+    #[test]
+    fn static_routes_record_the_query_keys_handlers_read() {
+        let routes = static_routes(
+            r#"
+import express from 'express';
+import Fastify from 'fastify';
+import { Type } from '@sinclair/typebox';
+
+const app = express();
+const api = Fastify();
+
+app.get('/users', (req, res) => {
+  const { cursor, sort = 'asc' } = req.query;
+  res.json(listUsers(req.query.limit, cursor, sort));
+});
+app.get('/orders', (req, res) => {
+  res.json(searchOrders(req.query));
+});
+app.get('/invoices', validate(invoiceQuery), (req, res) => {
+  res.json(listInvoices(req.query.page));
+});
+app.get('/health', (req, res) => res.sendStatus(204));
+
+api.get('/search', {
+  schema: {
+    querystring: {
+      type: 'object',
+      required: ['q'],
+      properties: { q: { type: 'string' }, limit: { type: 'integer' } },
+    },
+  },
+}, async (request) => search(request.query.q));
+api.get('/feed', { schema: { querystring: Type.Object({ since: Type.String(), tag: Type.Optional(Type.String()) }) } }, async () => feed());
+"#,
+        );
+
+        let keys = |contract: &Option<QueryContract>| {
+            contract
+                .as_ref()
+                .map(|c| (c.keys.join(","), c.required.join(",")))
+        };
+        let read: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| (r.path.as_str(), keys(&r.query_params)))
+            .collect();
+        assert_eq!(
+            read,
+            vec![
+                ("/users", Some(("cursor,limit,sort".into(), "".into()))),
+                // The whole query object goes to a helper.
+                ("/orders", None),
+                // A middleware factory may read what the handler doesn't.
+                ("/invoices", None),
+                ("/health", Some(("".into(), "".into()))),
+                ("/search", Some(("q".into(), "".into()))),
+                ("/feed", Some(("".into(), "".into()))),
+            ]
+        );
+        let declared: Vec<_> = routes.schemas.iter().map(|s| keys(&s.query)).collect();
+        assert_eq!(
+            declared,
+            vec![
+                Some(("limit,q".into(), "q".into())),
+                Some(("since,tag".into(), "since".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn static_routes_record_the_headers_handlers_read() {
+        let routes = static_routes(
+            r#"
+import express from 'express';
+import Koa from 'koa';
+import Router from '@koa/router';
+import { Hono } from 'hono';
+
+const app = express();
+const router = new Router();
+const hono = new Hono();
+
+app.post('/orders', (req, res) => {
+  const tenant = req.headers['x-tenant-id'];
+  const requestId = req.headers['x-request-id'] ?? randomUUID();
+  const { authorization, 'x-trace': trace = 'none' } = req.headers;
+  res.json(createOrder(tenant, req.get('X-Idempotency-Key'), requestId, authorization, trace));
+});
+router.get('/reports', (ctx) => {
+  ctx.body = report(ctx.get('x-org'), ctx.request.headers['x-beta'] ? 'beta' : 'stable');
+});
+hono.get('/me', (c) => c.json(me(c.req.header('Authorization'), c.get('user'))));
+"#,
+        );
+
+        let read: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| (r.path.as_str(), r.request_headers.join(",")))
+            .collect();
+        assert_eq!(
+            read,
+            vec![
+                (
+                    "/orders",
+                    "authorization,x-idempotency-key,x-tenant-id".to_string()
+                ),
+                // A ternary test only checks for the header.
+                ("/reports", "x-org".to_string()),
+                ("/me", "authorization".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn static_routes_resolve_hono_apps_and_route_mounts() {
+        let routes = static_routes(
+            r#"
+import { Hono } from 'hono';
+import { logger } from 'hono/logger';
+import users from './users';
+
+const posts = new Hono()
+  .get('/', (c) => c.json(listPosts()))
+  .post('/', async (c) => {
+    const input = c.req.valid('json');
+    if (!input.title) return c.json({ error: 'title' }, 400);
+    return c.json(createPost(input), 201);
+  });
+
+const app = new Hono().basePath('/api');
+app.use('*', logger());
+app.on(['PUT', 'PATCH'], '/settings', (c) => c.text('ok'));
+app.get('/health', (c) => c.body(null, 204));
+app.route('/posts', posts);
+app.route('/users', users);
+
+export type AppType = typeof app;
+"#,
+        );
+
+        let summary: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.owner.as_str(),
+                    r.method.as_str(),
+                    r.path.as_str(),
+                    r.emission,
+                    r.response_text.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "posts",
+                    "GET",
+                    "/",
+                    StaticEmission::Send,
+                    Some("listPosts()")
+                ),
+                (
+                    "posts",
+                    "POST",
+                    "/",
+                    StaticEmission::Send,
+                    Some("createPost(input)")
+                ),
+                (
+                    "app",
+                    "PUT",
+                    "/api/settings",
+                    StaticEmission::Send,
+                    Some("'ok'")
+                ),
+                (
+                    "app",
+                    "PATCH",
+                    "/api/settings",
+                    StaticEmission::Send,
+                    Some("'ok'")
+                ),
+                ("app", "GET", "/api/health", StaticEmission::None, None),
+            ]
+        );
+        // Each link of the builder chain keeps its own line.
+        assert_eq!(routes.routes[0].line_number, 7);
+        assert_eq!(routes.routes[1].line_number, 8);
+        assert_eq!(
+            routes.routes[1].request_text.as_deref(),
+            Some("c.req.valid('json')")
+        );
+
+        let mounts: Vec<_> = routes
+            .mounts
+            .iter()
+            .map(|m| {
+                (
+                    m.parent.as_str(),
+                    m.child.as_str(),
+                    m.path.as_str(),
+                    m.import_source.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            mounts,
+            vec![
+                ("app", "posts", "/api/posts", None),
+                ("app", "users", "/api/users", Some("./users")),
+            ]
+        );
+    }
+
+    #[test]
+    fn static_routes_resolve_elysia_chains_groups_and_hooks() {
+        let routes = static_routes(
+            r#"
+import { Elysia, t } from 'elysia';
+import { swagger } from '@elysiajs/swagger';
+import { users } from './users';
+
+export const app = new Elysia({ prefix: '/v1' })
+  .use(swagger())
+  .use(users)
+  .get('/', () => 'hello')
+  .post('/notes', ({ body }) => createNote(body), {
+    body: t.Object({ text: t.String() }),
+    response: t.Object({ id: t.Number() }),
+  })
+  .group('/admin', (admin) =>
+    admin.get('/stats', async ({ query }) => loadStats(query.range)),
+  )
+  .listen(3000);
+"#,
+        );
+
+        let summary: Vec<_> = routes
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.owner.as_str(),
+                    r.method.as_str(),
+                    r.path.as_str(),
+                    r.emission,
+                    r.response_text.as_deref(),
+                    r.request_text.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "app",
+                    "GET",
+                    "/v1",
+                    StaticEmission::Return,
+                    Some("'hello'"),
+                    None
+                ),
+                (
+                    "app",
+                    "POST",
+                    "/v1/notes",
+                    StaticEmission::Return,
+                    Some("createNote(body)"),
+                    Some("body"),
+                ),
+                (
+                    "app",
+                    "GET",
+                    "/v1/admin/stats",
+                    StaticEmission::Return,
+                    Some("loadStats(query.range)"),
+                    None,
+                ),
+            ]
+        );
+        assert_eq!(
+            routes.schemas,
+            vec![RouteSchema {
+                line_number: 10,
+                method: "POST".to_string(),
+                request: Some("{ text: string }".to_string()),
+                response: Some("{ id: number }".to_string()),
+                query: None,
+            }]
+        );
+        let mounts: Vec<_> = routes
+            .mounts
+            .iter()
+            .map(|m| (m.parent.as_str(), m.child.as_str(), m.path.as_str()))
+            .collect();
+        assert_eq!(mounts, vec![("app", "users", "/v1")]);
+    }
+
+    #[test]
+    fn static_routes_rebuild_rpc_client_calls_from_property_chains() {
+        let routes = static_routes(
+            r#"
+import { hc } from 'hono/client';
+import { treaty } from '@elysiajs/eden';
+import type { AppType } from '../server';
+import { api } from './api';
+
+const client = hc<AppType>(process.env.API_URL!);
+const eden = treaty<App>('localhost:3000');
+
+export async function run(id: string, draft: Draft) {
+  await client.users[':id'].$get({ param: { id } });
+  await client.posts.$post({ json: draft });
+  await client.index.$get();
+  await eden.users({ id }).get();
+  await eden.notes.post(draft);
+  await api.orders.$delete();
+  await client.users.$url();
+  await store.$patch({ open: true });
+}
+"#,
+        );
+
+        let summary: Vec<_> = routes
+            .client_calls
+            .iter()
+            .map(|c| {
+                (
+                    c.method.as_str(),
+                    c.target.as_str(),
+                    c.payload_text.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("GET", "${process.env.API_URL}/users/:id", None),
+                ("POST", "${process.env.API_URL}/posts", Some("draft")),
+                ("GET", "${process.env.API_URL}/", None),
+                ("GET", "http://localhost:3000/users/:id", None),
+                ("POST", "http://localhost:3000/notes", Some("draft")),
+                ("DELETE", "/orders", None),
+            ]
+        );
+        let first = &routes.client_calls[0];
+        assert_eq!(first.line_number, 11);
+        assert_eq!(first.pattern, ".$get(");
+        assert_eq!(
+            first.call_text,
+            "client.users[':id'].$get({ param: { id } })"
+        );
+        assert!(
+            routes
+                .owned_spans
+                .contains(&(first.span_start, first.span_end))
+        );
+        assert!(routes.routes.is_empty() && routes.mounts.is_empty());
+    }
+
     #[test]
     fn derive_handler_name_works() {
         assert_eq!(
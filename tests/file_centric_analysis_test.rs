@@ -125,6 +125,10 @@ fn test_file_analysis_result_structures() {
         emission_style: None,
         primary_type_symbol: None,
         type_import_source: None,
+        request_schema_type: None,
+        response_schema_type: None,
+        query_params: None,
+        request_headers: Vec::new(),
     };
     assert_eq!(endpoint.method, "GET");
     assert_eq!(endpoint.path, "/:id");
@@ -145,6 +149,8 @@ fn test_file_analysis_result_structures() {
         payload_expression_line: None,
         primary_type_symbol: None,
         type_import_source: None,
+        sent_headers: None,
+        query_options: None,
     };
     assert_eq!(data_call.target, "https://api.example.com/users");
     assert_eq!(data_call.method, Some("POST".to_string()));
@@ -190,6 +196,10 @@ fn test_file_analysis_result_serialization() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
             EndpointResult {
                 candidate_id: "span:350-400".to_string(),
@@ -208,6 +218,10 @@ fn test_file_analysis_result_serialization() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
         ],
         data_calls: vec![DataCallResult {
@@ -225,6 +239,8 @@ fn test_file_analysis_result_serialization() {
             payload_expression_line: None,
             primary_type_symbol: None,
             type_import_source: None,
+            sent_headers: None,
+            query_options: None,
         }],
     };
 
@@ -276,6 +292,13 @@ fn test_processing_stats_tracking() {
         total_endpoints: 10,
         file_based_endpoints: 2,
         route_descriptor_endpoints: 1,
+        nest_controller_endpoints: 0,
+        static_route_endpoints: 0,
+        serverless_endpoints: 0,
+        rpc_client_calls: 0,
+        client_wrapper_calls: 0,
+        http_instance_targets: 0,
+        url_object_targets: 0,
         pubsub_anchor_backfills: 0,
         pubsub_phantom_topic_drops: 0,
         total_data_calls: 4,
@@ -389,6 +412,10 @@ fn test_cross_file_import_resolution() {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
                 EndpointResult {
                     candidate_id: "span:510-540".to_string(),
@@ -407,6 +434,10 @@ fn test_cross_file_import_resolution() {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
                 EndpointResult {
                     candidate_id: "span:550-580".to_string(),
@@ -425,6 +456,10 @@ fn test_cross_file_import_resolution() {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
             ],
             data_calls: vec![],
@@ -457,6 +492,10 @@ fn test_cross_file_import_resolution() {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
                 EndpointResult {
                     candidate_id: "span:630-660".to_string(),
@@ -475,6 +514,10 @@ fn test_cross_file_import_resolution() {
                     emission_style: None,
                     primary_type_symbol: None,
                     type_import_source: None,
+                    request_schema_type: None,
+                    response_schema_type: None,
+                    query_params: None,
+                    request_headers: Vec::new(),
                 },
             ],
             data_calls: vec![],
@@ -525,6 +568,8 @@ fn test_data_call_extraction() {
                 payload_expression_line: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             },
             DataCallResult {
                 call_kind: None,
@@ -541,6 +586,8 @@ fn test_data_call_extraction() {
                 payload_expression_line: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             },
             DataCallResult {
                 call_kind: None,
@@ -557,6 +604,8 @@ fn test_data_call_extraction() {
                 payload_expression_line: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                sent_headers: None,
+                query_options: None,
             },
         ],
     };
@@ -664,6 +713,10 @@ fn test_nested_router_mounts() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             }],
             data_calls: vec![],
         },
@@ -704,6 +757,10 @@ fn test_multiple_http_methods_on_same_path() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
             EndpointResult {
                 candidate_id: "span:910-940".to_string(),
@@ -722,6 +779,10 @@ fn test_multiple_http_methods_on_same_path() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
             EndpointResult {
                 candidate_id: "span:950-980".to_string(),
@@ -740,6 +801,10 @@ fn test_multiple_http_methods_on_same_path() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
             EndpointResult {
                 candidate_id: "span:990-1020".to_string(),
@@ -758,6 +823,10 @@ fn test_multiple_http_methods_on_same_path() {
                 emission_style: None,
                 primary_type_symbol: None,
                 type_import_source: None,
+                request_schema_type: None,
+                response_schema_type: None,
+                query_params: None,
+                request_headers: Vec::new(),
             },
         ],
         data_calls: vec![],
@@ -813,6 +882,7 @@ app.post('/users', (req, res) => res.json({ created: true }));
             &Default::default(),
             &Default::default(),
             &carrick::url_normalizer::UrlNormalizer::default_permissive(),
+            &[],
         )
         .await;
 
@@ -820,9 +890,12 @@ app.post('/users', (req, res) => res.json({ created: true }));
     assert!(result.is_ok());
     let analysis_result = result.unwrap();
 
-    // Stats should reflect that we processed 1 file
-    assert_eq!(analysis_result.stats.files_processed, 1);
+    // Both routes are literal Express registrations, so they are resolved
+    // statically and the file never reaches the (mock) LLM.
+    assert_eq!(analysis_result.stats.files_processed, 0);
     assert_eq!(analysis_result.stats.files_skipped, 0);
+    assert_eq!(analysis_result.stats.static_route_endpoints, 2);
+    assert_eq!(analysis_result.stats.total_endpoints, 2);
 
     // SAFETY: Cleanup of environment variable set by this test
     unsafe { std::env::remove_var("CARRICK_MOCK_ALL") };
@@ -854,6 +927,7 @@ async fn test_file_orchestrator_handles_empty_files() {
             &Default::default(),
             &Default::default(),
             &carrick::url_normalizer::UrlNormalizer::default_permissive(),
+            &[],
         )
         .await;
 
@@ -890,6 +964,7 @@ async fn test_file_orchestrator_handles_missing_files() {
             &Default::default(),
             &Default::default(),
             &carrick::url_normalizer::UrlNormalizer::default_permissive(),
+            &[],
         )
         .await;
 
@@ -15,6 +15,10 @@
 //! - a valid type hint (must survive untouched),
 //! - a data call inside a custom wrapper function (must be kept and get
 //!   SWC spans), plus a cross-file mount that must resolve to full paths.
+//!
+//! The hallucinated endpoint and the framework-package type hint sit on a
+//! router the static route pass can't resolve, in
+//! `tests/fixtures/llm-fallback-api/`, so they still reach the analyzer.
 
 use carrick::agent_service::AgentService;
 use carrick::agents::file_analyzer_agent::EmissionStyle;
@@ -33,6 +37,10 @@ fn fixture_root() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/llm-mocked-api")
 }
 
+fn fallback_fixture_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/llm-fallback-api")
+}
+
 fn express_guidance() -> FrameworkGuidance {
     let pattern = |pattern: &str, description: &str| PatternExample {
         pattern: pattern.to_string(),
@@ -97,6 +105,7 @@ async fn mock_llm_output_flows_through_validation_and_mount_graph() {
             &Default::default(),
             &Default::default(),
             &carrick::url_normalizer::UrlNormalizer::default_permissive(),
+            &[],
         )
         .await
         .expect("analysis should succeed");
@@ -131,73 +140,22 @@ async fn mock_llm_output_flows_through_validation_and_mount_graph() {
         "no HTTP facts may be invented for a socket-only file"
     );
 
+    // users.ts registers literal routes with inline handlers, so the static
+    // route pass resolves it and its canned analyzer output is never asked
+    // for (the fallback is covered over `llm-fallback-api`).
     let users_result = result
         .file_results
         .iter()
         .find(|(path, _)| path.ends_with("users.ts"))
         .map(|(_, r)| r)
         .expect("users.ts should have a result");
-
-    // The hallucinated DELETE endpoint (candidate_id with no SWC match) is gone.
-    assert_eq!(
-        users_result.endpoints.len(),
-        2,
-        "expected the hallucinated endpoint to be dropped, got: {:?}",
-        users_result
-            .endpoints
-            .iter()
-            .map(|e| (&e.method, &e.path))
-            .collect::<Vec<_>>()
-    );
-    assert!(
-        !users_result.endpoints.iter().any(|e| e.method == "DELETE"),
-        "hallucinated DELETE endpoint must not survive the candidate gate"
-    );
-
-    // Surviving endpoints carry SWC spans for span-based type inference.
-    for endpoint in &users_result.endpoints {
-        assert!(
-            endpoint.call_expression_span_start.is_some()
-                && endpoint.call_expression_span_end.is_some(),
-            "gated endpoints must carry SWC spans: {:?}",
-            (&endpoint.method, &endpoint.path)
-        );
-    }
-
-    // GET /: the valid type hint (User from '../types') survives.
-    let get_endpoint = users_result
-        .endpoints
-        .iter()
-        .find(|e| e.method == "GET")
-        .expect("GET endpoint should survive");
-    assert_eq!(get_endpoint.primary_type_symbol.as_deref(), Some("User"));
-    assert_eq!(get_endpoint.type_import_source.as_deref(), Some("../types"));
-
-    // POST /: the framework-package import source is scrubbed so the
-    // sidecar infers from the payload expression instead.
-    let post_endpoint = users_result
+    let mut users_methods: Vec<&str> = users_result
         .endpoints
         .iter()
-        .find(|e| e.method == "POST")
-        .expect("POST endpoint should survive");
-    assert_eq!(
-        post_endpoint.type_import_source, None,
-        "framework package must not survive as a type import source"
-    );
-    assert_eq!(
-        post_endpoint.primary_type_symbol, None,
-        "a symbol whose claimed source does not match the import table must be nulled"
-    );
-    assert_eq!(
-        post_endpoint.response_expression_text.as_deref(),
-        Some("created"),
-        "expression locators must survive type-hint scrubbing"
-    );
-    assert_eq!(
-        post_endpoint.emission_style,
-        Some(EmissionStyle::ImperativeSend),
-        "emission_style must survive validation and candidate gating"
-    );
+        .map(|e| e.method.as_str())
+        .collect();
+    users_methods.sort();
+    assert_eq!(users_methods, vec!["GET", "POST"]);
 
     // reports.ts: emission_style routes type inference. The return-value
     // endpoint asks for the handler's return type (line-anchored
@@ -315,3 +273,110 @@ async fn mock_llm_output_flows_through_validation_and_mount_graph() {
         full_paths
     );
 }
+
+/// A router the static route pass can't resolve (each handler is wrapped in
+/// a guard) falls back to the analyzer, whose canned output goes through the
+/// candidate gate and type-hint scrubbing.
+#[tokio::test]
+#[serial]
+async fn unresolved_routes_fall_back_to_validated_llm_output() {
+    let root = fallback_fixture_root();
+    // SAFETY: serial test; env vars are process-global.
+    unsafe {
+        std::env::set_var("CARRICK_MOCK_ALL", "1");
+        std::env::set_var(
+            "CARRICK_MOCK_FIXTURE_DIR",
+            root.join("__llm__").to_string_lossy().to_string(),
+        );
+    }
+
+    let files = vec![root.join("src/routes/users.ts"), root.join("src/types.ts")];
+    let orchestrator = FileOrchestrator::new(AgentService::new());
+    let result = orchestrator
+        .analyze_files(
+            &files,
+            &ProtocolGuidance::from([(Protocol::Http, express_guidance())]),
+            &express_detection(),
+            &root,
+            &Default::default(),
+            &Default::default(),
+            &carrick::url_normalizer::UrlNormalizer::default_permissive(),
+            &[],
+        )
+        .await
+        .expect("analysis should succeed");
+
+    // SAFETY: cleanup of env vars set above.
+    unsafe {
+        std::env::remove_var("CARRICK_MOCK_ALL");
+        std::env::remove_var("CARRICK_MOCK_FIXTURE_DIR");
+    }
+
+    let users_result = result
+        .file_results
+        .iter()
+        .find(|(path, _)| path.ends_with("users.ts"))
+        .map(|(_, r)| r)
+        .expect("users.ts should have a result");
+
+    // The hallucinated DELETE endpoint (candidate_id with no SWC match) is gone.
+    assert_eq!(
+        users_result.endpoints.len(),
+        2,
+        "expected the hallucinated endpoint to be dropped, got: {:?}",
+        users_result
+            .endpoints
+            .iter()
+            .map(|e| (&e.method, &e.path))
+            .collect::<Vec<_>>()
+    );
+    assert!(
+        !users_result.endpoints.iter().any(|e| e.method == "DELETE"),
+        "hallucinated DELETE endpoint must not survive the candidate gate"
+    );
+
+    // Surviving endpoints carry SWC spans for span-based type inference.
+    for endpoint in &users_result.endpoints {
+        assert!(
+            endpoint.call_expression_span_start.is_some()
+                && endpoint.call_expression_span_end.is_some(),
+            "gated endpoints must carry SWC spans: {:?}",
+            (&endpoint.method, &endpoint.path)
+        );
+    }
+
+    // GET /: the valid type hint (User from '../types') survives.
+    let get_endpoint = users_result
+        .endpoints
+        .iter()
+        .find(|e| e.method == "GET")
+        .expect("GET endpoint should survive");
+    assert_eq!(get_endpoint.primary_type_symbol.as_deref(), Some("User"));
+    assert_eq!(get_endpoint.type_import_source.as_deref(), Some("../types"));
+
+    // POST /: the framework-package import source is scrubbed so the
+    // sidecar infers from the payload expression instead.
+    let post_endpoint = users_result
+        .endpoints
+        .iter()
+        .find(|e| e.method == "POST")
+        .expect("POST endpoint should survive");
+    assert_eq!(
+        post_endpoint.type_import_source, None,
+        "framework package must not survive as a type import source"
+    );
+    assert_eq!(
+        post_endpoint.primary_type_symbol, None,
+        "a symbol whose claimed source does not match the import table must be nulled"
+    );
+    assert_eq!(
+        post_endpoint.response_expression_text.as_deref(),
+        Some("created"),
+        "expression locators must survive type-hint scrubbing"
+    );
+    assert_eq!(
+        post_endpoint.emission_style,
+        Some(EmissionStyle::ImperativeSend),
+        "emission_style must survive validation and candidate gating"
+    );
+}